@@ -1,4 +1,4 @@
-import type { TeamSummary, TeamOverview, ProcessData, MemberListEntry, MemberDetail, FileReadResponse, FileWriteResponse, TreeResponse, SyncResponse, ApiError } from './types.js';
+import type { TeamSummary, TeamOverview, ProcessData, MemberListEntry, MemberDetail, FileReadResponse, FileWriteResponse, TreeResponse, SyncResponse, DeliverySummary, DeliveryDetail, ApiError } from './types.js';
 
 class ApiClient {
 	private baseUrl: string;
@@ -64,6 +64,18 @@ class ApiClient {
 		);
 	}
 
+	async fetchDeliveries(team: string): Promise<DeliverySummary[]> {
+		return this.request<DeliverySummary[]>(
+			`/api/teams/${encodeURIComponent(team)}/deliveries`
+		);
+	}
+
+	async fetchDelivery(team: string, id: string): Promise<DeliveryDetail> {
+		return this.request<DeliveryDetail>(
+			`/api/teams/${encodeURIComponent(team)}/deliveries/${encodeURIComponent(id)}`
+		);
+	}
+
 	async syncTeam(team: string): Promise<SyncResponse> {
 		return this.request<SyncResponse>(
 			`/api/teams/${encodeURIComponent(team)}/sync`,
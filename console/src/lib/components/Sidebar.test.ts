@@ -24,6 +24,7 @@ describe('Sidebar', () => {
 		expect(screen.getByText('Members')).toBeInTheDocument();
 		expect(screen.getByText('Knowledge')).toBeInTheDocument();
 		expect(screen.getByText('Invariants')).toBeInTheDocument();
+		expect(screen.getByText('Deliveries')).toBeInTheDocument();
 		expect(screen.getByText('Settings')).toBeInTheDocument();
 	});
 
@@ -12,6 +12,7 @@ export interface TeamOverview {
 	description: string;
 	version: string;
 	github_repo: string;
+	repo_url: string;
 	default_coding_agent: string | null;
 	roles: RoleSummary[];
 	members: MemberSummary[];
@@ -51,6 +52,17 @@ export interface MemberListEntry {
 	comment_emoji: string;
 	has_ralph_yml: boolean;
 	hat_count: number;
+	token_health: TokenHealth | null;
+	/** Set while the member is paused with `bm pause`. */
+	paused_at: string | null;
+}
+
+export interface TokenHealth {
+	state: 'healthy' | 'failing' | 'expiring' | 'expired';
+	last_refresh: string | null;
+	expires_at: string | null;
+	consecutive_failures: number;
+	last_error: string | null;
 }
 
 export interface MemberDetail {
@@ -133,6 +145,39 @@ export interface SyncResponse {
 	changed_files: string[];
 }
 
+export type DeliveryValidation =
+	| 'verified'
+	| 'unsigned'
+	| 'bad_signature'
+	| 'wrong_host'
+	| 'invalid_body';
+
+export type DeliveryAction = 'launched_members' | 'ignored' | 'no_event' | 'rejected';
+
+export interface DeliverySummary {
+	id: string;
+	received_at: string;
+	event: string | null;
+	/** Parsed event, e.g. `check_run 'ci / test' failure (#42)`. */
+	summary: string | null;
+	validation: DeliveryValidation;
+	action: DeliveryAction;
+	replay_count: number;
+}
+
+export interface DeliveryDetail {
+	id: string;
+	received_at: string;
+	event?: string;
+	summary?: string;
+	headers: Record<string, string>;
+	/** Not stored for rejected deliveries or oversized bodies. */
+	payload?: string;
+	validation: DeliveryValidation;
+	action: DeliveryAction;
+	replays?: { replayed_at: string; action: DeliveryAction }[];
+}
+
 export interface ApiError {
 	error: string;
 }
@@ -0,0 +1,102 @@
+import { describe, it, expect, vi } from 'vitest';
+import { render, screen, waitFor, fireEvent } from '@testing-library/svelte';
+import { readable } from 'svelte/store';
+import type { DeliveryDetail, DeliverySummary } from '$lib/types.js';
+
+const { mockDeliveries, mockDetail } = vi.hoisted(() => {
+	const mockDeliveries: DeliverySummary[] = [
+		{
+			id: '72d3162e-cc78-11e3-81ab-4c9367dc0958',
+			received_at: '2026-03-24T10:05:00Z',
+			event: 'issues',
+			summary: null,
+			validation: 'verified',
+			action: 'launched_members',
+			replay_count: 1
+		},
+		{
+			id: '5a1f0b2c-cc78-11e3-81ab-4c9367dc0958',
+			received_at: '2026-03-24T10:00:00Z',
+			event: 'push',
+			summary: null,
+			validation: 'bad_signature',
+			action: 'rejected',
+			replay_count: 0
+		}
+	];
+	const mockDetail: DeliveryDetail = {
+		id: '72d3162e-cc78-11e3-81ab-4c9367dc0958',
+		received_at: '2026-03-24T10:05:00Z',
+		event: 'issues',
+		headers: { 'x-github-event': 'issues', 'x-github-delivery': '72d3162e' },
+		payload: '{"action":"opened"}',
+		validation: 'verified',
+		action: 'launched_members',
+		replays: [{ replayed_at: '2026-03-24T11:00:00Z', action: 'launched_members' }]
+	};
+	return { mockDeliveries, mockDetail };
+});
+
+vi.mock('$app/stores', () => ({
+	page: readable({
+		url: new URL('http://localhost/teams/my-team/deliveries'),
+		params: { team: 'my-team' }
+	})
+}));
+
+vi.mock('$lib/api.js', () => ({
+	api: {
+		fetchDeliveries: vi.fn().mockResolvedValue(mockDeliveries),
+		fetchDelivery: vi.fn().mockResolvedValue(mockDetail),
+		fetchTeams: vi.fn().mockResolvedValue([])
+	}
+}));
+
+import DeliveriesPage from './+page.svelte';
+
+describe('Deliveries Page', () => {
+	it('lists deliveries with validation and action', async () => {
+		render(DeliveriesPage);
+
+		await waitFor(() => {
+			expect(screen.getByText('2 deliveries')).toBeInTheDocument();
+			expect(screen.getByText('issues')).toBeInTheDocument();
+			expect(screen.getByText('bad signature')).toBeInTheDocument();
+			expect(screen.getByText('rejected')).toBeInTheDocument();
+			expect(screen.getByText('replayed 1x')).toBeInTheDocument();
+		});
+	});
+
+	it('prefers the parsed event summary', async () => {
+		const { api } = await import('$lib/api.js');
+		vi.mocked(api.fetchDeliveries).mockResolvedValueOnce([
+			{
+				...mockDeliveries[0],
+				event: 'check_run',
+				summary: "check_run 'ci / test' failure (#42)"
+			}
+		]);
+		render(DeliveriesPage);
+
+		await waitFor(() => {
+			expect(screen.getByText("check_run 'ci / test' failure (#42)")).toBeInTheDocument();
+		});
+	});
+
+	it('shows headers and payload of the selected delivery', async () => {
+		render(DeliveriesPage);
+
+		await waitFor(() => {
+			expect(screen.getByText('issues')).toBeInTheDocument();
+		});
+		await fireEvent.click(screen.getByText('issues'));
+
+		await waitFor(() => {
+			expect(screen.getByText('x-github-delivery')).toBeInTheDocument();
+			expect(screen.getByText(/"action": "opened"/)).toBeInTheDocument();
+			expect(
+				screen.getByText(/bm daemon events replay 72d3162e-cc78-11e3-81ab-4c9367dc0958/)
+			).toBeInTheDocument();
+		});
+	});
+});
@@ -10,21 +10,33 @@ const { mockMembers } = vi.hoisted(() => {
 			role: 'superman',
 			comment_emoji: '\u{1f9b8}',
 			has_ralph_yml: true,
-			hat_count: 14
+			hat_count: 14,
+			token_health: {
+				state: 'failing',
+				last_refresh: '2026-03-24T10:00:00Z',
+				expires_at: '2026-03-24T11:00:00Z',
+				consecutive_failures: 3,
+				last_error: '502 Bad Gateway'
+			},
+			paused_at: null
 		},
 		{
 			name: 'superman-bob',
 			role: 'superman',
 			comment_emoji: '\u{1f9b8}',
 			has_ralph_yml: true,
-			hat_count: 14
+			hat_count: 14,
+			token_health: null,
+			paused_at: '2026-03-24T10:00:00Z'
 		},
 		{
 			name: 'chief-of-staff-mgr',
 			role: 'chief-of-staff',
 			comment_emoji: '\u{1f4cb}',
 			has_ralph_yml: true,
-			hat_count: 1
+			hat_count: 1,
+			token_health: null,
+			paused_at: null
 		}
 	];
 	return { mockMembers };
@@ -96,6 +108,23 @@ describe('Members List Page', () => {
 		});
 	});
 
+	it('shows token health for members the daemon tracks', async () => {
+		render(MembersPage);
+
+		await waitFor(() => {
+			const badge = screen.getByText('token failing');
+			expect(badge).toHaveAttribute('title', '502 Bad Gateway');
+		});
+	});
+
+	it('marks paused members', async () => {
+		render(MembersPage);
+
+		await waitFor(() => {
+			expect(screen.getAllByText('paused')).toHaveLength(1);
+		});
+	});
+
 	it('renders member links pointing to detail page', async () => {
 		render(MembersPage);
 
@@ -11,6 +11,7 @@ const { mockOverview } = vi.hoisted(() => {
 		description: 'A compact single-member team',
 		version: '1.0.0',
 		github_repo: 'myorg/my-team',
+		repo_url: 'https://github.com/myorg/my-team',
 		default_coding_agent: 'Claude Code',
 		roles: [
 			{ name: 'superman', description: 'All-in-one member' },
@@ -25,6 +25,56 @@ pub enum AgentCommand {
         #[command(subcommand)]
         command: LoopCommand,
     },
+    /// Knowledge proposals for the team repo
+    Knowledge {
+        #[command(subcommand)]
+        command: KnowledgeCommand,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum KnowledgeCommand {
+    /// Propose a knowledge/invariant file as a pull request to the team repo
+    Propose {
+        /// File name (e.g., flaky-tests.md)
+        name: String,
+        /// Why this should be kept (used as the PR description)
+        #[arg(long)]
+        reason: String,
+        /// Target scope: team, project, member, or member-project
+        #[arg(long, default_value = "member")]
+        scope: String,
+        /// Project for project and member-project scopes
+        #[arg(long)]
+        project: Option<String>,
+        /// Propose an invariant instead of knowledge
+        #[arg(long)]
+        invariant: bool,
+        /// Read the content from a file (defaults to stdin)
+        #[arg(long)]
+        file: Option<String>,
+    },
+    /// Search knowledge, invariants, prompts and hats in the team repo
+    Search {
+        /// Search terms (all must match)
+        #[arg(required = true)]
+        query: Vec<String>,
+        /// Only files in this scope: team, project, member, or member-project
+        #[arg(long)]
+        scope: Option<String>,
+        /// Only files belonging to this member
+        #[arg(long)]
+        member: Option<String>,
+        /// Only files belonging to this project
+        #[arg(long)]
+        project: Option<String>,
+        /// Only this kind of file: knowledge, invariant, prompt, or hats
+        #[arg(long)]
+        kind: Option<String>,
+        /// Maximum number of results
+        #[arg(long, default_value_t = 10)]
+        limit: usize,
+    },
 }
 
 #[derive(Subcommand)]
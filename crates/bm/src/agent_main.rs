@@ -2,9 +2,16 @@ use std::process;
 
 use clap::Parser;
 
-use bm::agent_cli::{AgentCli, AgentCommand, ClaudeCommand, ClaudeHookCommand, InboxCommand, InboxFormat, LoopCommand};
+use std::io::Read;
+
+use bm::agent_cli::{AgentCli, AgentCommand, ClaudeCommand, ClaudeHookCommand, InboxCommand, InboxFormat, KnowledgeCommand, LoopCommand};
+use bm::audit::{self, Actor, Record};
 use bm::brain::inbox;
+use bm::config;
 use bm::daemon::{DaemonClient, StartLoopRequest};
+use bm::forge;
+use bm::knowledge::{self, DocKind, KnowledgeEdit, KnowledgeKind, Placement, Proposal, Scope, SearchFilter};
+use bm::workspace;
 
 fn main() {
     let cli = AgentCli::parse();
@@ -13,6 +20,7 @@ fn main() {
         AgentCommand::Inbox { command } => run_inbox(command),
         AgentCommand::Claude { command } => run_claude(command),
         AgentCommand::Loop { command } => run_loop(command),
+        AgentCommand::Knowledge { command } => run_knowledge(command),
     };
 
     if let Err(e) = result {
@@ -69,7 +77,7 @@ fn run_loop(command: LoopCommand) -> anyhow::Result<()> {
                     "BM_TEAM_NAME not set. This command must be run from a BotMinter member workspace."
                 ))?;
 
-            let client = DaemonClient::connect(&team_name)?;
+            let client = DaemonClient::connect(&team_name)?.with_actor(agent_actor());
             let req = StartLoopRequest { prompt, member };
             let resp = client.start_loop(&req)?;
 
@@ -90,6 +98,132 @@ fn run_loop(command: LoopCommand) -> anyhow::Result<()> {
     }
 }
 
+/// The member whose workspace this agent runs in, for audit attribution.
+fn agent_actor() -> Actor {
+    let member = std::env::current_dir()
+        .ok()
+        .and_then(|cwd| inbox::discover_workspace_root(&cwd))
+        .and_then(|root| workspace::read_workspace_member(&root));
+    Actor::agent(member.as_deref().unwrap_or("unknown"))
+}
+
+fn run_knowledge(command: KnowledgeCommand) -> anyhow::Result<()> {
+    match command {
+        KnowledgeCommand::Propose {
+            name,
+            reason,
+            scope,
+            project,
+            invariant,
+            file,
+        } => {
+            let cwd = std::env::current_dir()?;
+            let root = inbox::discover_workspace_root(&cwd)
+                .ok_or_else(|| anyhow::anyhow!("Not in a BotMinter workspace (no .botminter.workspace found)"))?;
+            let member = workspace::read_workspace_member(&root)
+                .ok_or_else(|| anyhow::anyhow!("Workspace marker does not name a member"))?;
+            let team_repo = root.join("team");
+
+            let placement = Placement {
+                scope: scope.parse::<Scope>()?,
+                kind: if invariant {
+                    KnowledgeKind::Invariants
+                } else {
+                    KnowledgeKind::Knowledge
+                },
+                project: project.as_deref(),
+                member: Some(&member),
+            };
+            let path = placement.file_path(&name)?;
+            placement.check_exists(&team_repo)?;
+
+            let content = match file {
+                Some(f) => std::fs::read_to_string(&f)
+                    .map_err(|e| anyhow::anyhow!("Failed to read {}: {}", f, e))?,
+                None => {
+                    let mut buf = String::new();
+                    std::io::stdin().read_to_string(&mut buf)?;
+                    buf
+                }
+            };
+            if content.trim().is_empty() {
+                anyhow::bail!("No content to propose (pass --file or pipe it on stdin)");
+            }
+
+            let target = path.clone();
+            let proposal = Proposal {
+                title: format!("knowledge: {} proposes {}", member, path),
+                body: format!(
+                    "{}\n\nProposed by `{}` with `bm-agent knowledge propose`.",
+                    reason.trim(),
+                    member
+                ),
+                edits: vec![KnowledgeEdit::Write {
+                    path,
+                    content,
+                    overwrite: true,
+                }],
+            };
+            let team_name = std::env::var("BM_TEAM_NAME")
+                .map_err(|_| anyhow::anyhow!(
+                    "BM_TEAM_NAME not set. This command must be run from a BotMinter member workspace."
+                ))?;
+            let cfg = config::load()?;
+            let team = config::resolve_team(&cfg, Some(&team_name))?;
+            let team_forge = forge::for_workspace(team, &root)?;
+
+            let result = knowledge::open_pull_request(
+                team_forge.as_ref(),
+                &team.github_repo,
+                &team_repo,
+                &proposal,
+            );
+            let record = Record::new(Actor::agent(&member), "knowledge.propose")
+                .target(Some(target.as_str()))
+                .param("reason", reason.trim())
+                .param("pull_request", result.as_ref().ok().map(|(_, url)| url.clone()))
+                .outcome(&result);
+            audit::record_for_team(&team_name, record);
+            let (pushed, url) = result?;
+            eprintln!("Pushed branch {}.", pushed.branch);
+            println!("{}", url);
+
+            Ok(())
+        }
+        KnowledgeCommand::Search {
+            query,
+            scope,
+            member,
+            project,
+            kind,
+            limit,
+        } => {
+            let cwd = std::env::current_dir()?;
+            let root = inbox::discover_workspace_root(&cwd)
+                .ok_or_else(|| anyhow::anyhow!("Not in a BotMinter workspace (no .botminter.workspace found)"))?;
+
+            let filter = SearchFilter {
+                scope: scope.as_deref().map(str::parse::<Scope>).transpose()?,
+                member,
+                project,
+                kind: kind.as_deref().map(str::parse::<DocKind>).transpose()?,
+            };
+            let query = query.join(" ");
+            let hits = knowledge::search_team(&root.join("team"), &query, &filter, limit)?;
+
+            if hits.is_empty() {
+                println!("No matches for \"{}\".", query);
+            }
+            for hit in &hits {
+                println!("team/{} ({}, {})", hit.path, hit.scope.label(), hit.kind.label());
+                println!("  {}: {}", hit.line, hit.highlighted(|t| format!("**{}**", t)));
+            }
+
+            Ok(())
+        }
+    }
+}
+
 fn run_claude(command: ClaudeCommand) -> anyhow::Result<()> {
     match command {
         ClaudeCommand::Hook { command } => run_claude_hook(command),
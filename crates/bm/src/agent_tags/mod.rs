@@ -1,8 +1,31 @@
-//! Line-based filter for `+agent:NAME` / `-agent` inline tags.
+//! Line-based filter for conditional inline tags.
 //!
-//! Profile files contain sections that are agent-specific (Claude Code, Gemini CLI, etc.)
-//! or shared across all agents. This module filters content based on the resolved coding
-//! agent, stripping tag lines and including/excluding agent-specific blocks.
+//! Profile files contain sections that only apply to some teams: a coding agent
+//! (`+agent:claude-code`), a bridge type (`+bridge:tuwunel`), a formation
+//! (`+formation:k8s`) or brain mode (`+brain`). A block opens with `+CONDITION`
+//! and closes with `-NAME`, where `NAME` is one of those dimensions
+//! (conventionally the one the block tests, e.g. `-agent`):
+//!
+//! ```text
+//! <!-- +bridge:tuwunel | bridge:rocketchat -->
+//! Matrix-style rooms only.
+//! <!-- -bridge -->
+//! # +formation:k8s & !brain
+//! # -formation
+//! ```
+//!
+//! Conditions combine `DIMENSION:VALUE` and bare flags with `!`, `&`, `|` and
+//! parentheses. Blocks nest. The filter evaluates each condition against a
+//! [`TagContext`]: decided blocks are kept or dropped and their tag lines
+//! stripped; blocks that reference a dimension the context doesn't know are
+//! kept verbatim, tags included, so a later pass with more context (e.g. sync
+//! time, when the formation is known) can resolve them.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Dimension names accepted in close tags. Any other `-word` comment (e.g.
+/// `# -x` in a YAML list) is ordinary content.
+const DIMENSIONS: &[&str] = &["agent", "bridge", "formation", "brain"];
 
 /// Comment syntax used to detect agent tag lines.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -38,46 +61,207 @@ pub fn filter_file(content: &str, filename: &str, agent: &str) -> String {
     filter_agent_tags(content, agent, detect_comment_syntax(filename))
 }
 
-/// Parse an open tag line (`+agent:NAME`), returning the agent name if matched.
-fn parse_open_tag(line: &str, syntax: CommentSyntax) -> Option<&str> {
-    let trimmed = line.trim();
-    match syntax {
-        CommentSyntax::Html => {
-            let inner = trimmed.strip_prefix("<!--")?.strip_suffix("-->")?.trim();
-            inner.strip_prefix("+agent:")
+/// Values of the dimensions a team resolves tags against.
+///
+/// Dimensions absent from the context are "unknown": blocks that depend on
+/// them are left in place rather than dropped.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TagContext {
+    values: BTreeMap<String, String>,
+}
+
+impl TagContext {
+    /// A context that only knows the coding agent.
+    pub fn for_agent(agent: &str) -> Self {
+        Self::default().with("agent", agent)
+    }
+
+    /// Sets a valued dimension (`bridge`, `formation`, ...).
+    pub fn with(mut self, dimension: &str, value: &str) -> Self {
+        self.values.insert(dimension.to_string(), value.to_string());
+        self
+    }
+
+    /// Sets a flag dimension (`brain`).
+    pub fn with_flag(self, dimension: &str, on: bool) -> Self {
+        self.with(dimension, if on { "true" } else { "false" })
+    }
+
+    /// Sets a valued dimension if a value is known.
+    pub fn with_opt(self, dimension: &str, value: Option<&str>) -> Self {
+        match value {
+            Some(v) => self.with(dimension, v),
+            None => self,
         }
-        CommentSyntax::Hash => {
-            let inner = trimmed.strip_prefix('#')?.trim();
-            inner.strip_prefix("+agent:")
+    }
+
+    /// The value of a dimension, if known.
+    pub fn get(&self, dimension: &str) -> Option<&str> {
+        self.values.get(dimension).map(String::as_str)
+    }
+}
+
+/// A parsed tag condition.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Condition {
+    /// `dim:value`, or a bare flag `dim` when `value` is `None`.
+    Atom { dimension: String, value: Option<String> },
+    Not(Box<Condition>),
+    And(Box<Condition>, Box<Condition>),
+    Or(Box<Condition>, Box<Condition>),
+}
+
+impl Condition {
+    /// Three-valued evaluation: `None` when an unknown dimension decides the result.
+    fn eval(&self, ctx: &TagContext) -> Option<bool> {
+        match self {
+            Condition::Atom { dimension, value } => {
+                let actual = ctx.get(dimension)?;
+                Some(match value {
+                    Some(expected) => actual == expected,
+                    None => matches!(actual, "true" | "yes" | "1"),
+                })
+            }
+            Condition::Not(c) => c.eval(ctx).map(|b| !b),
+            Condition::And(a, b) => match (a.eval(ctx), b.eval(ctx)) {
+                (Some(false), _) | (_, Some(false)) => Some(false),
+                (Some(true), Some(true)) => Some(true),
+                _ => None,
+            },
+            Condition::Or(a, b) => match (a.eval(ctx), b.eval(ctx)) {
+                (Some(true), _) | (_, Some(true)) => Some(true),
+                (Some(false), Some(false)) => Some(false),
+                _ => None,
+            },
+        }
+    }
+
+    /// Appends every atom as `dim:value` or `dim`.
+    fn atoms(&self, out: &mut BTreeSet<String>) {
+        match self {
+            Condition::Atom { dimension, value: Some(v) } => {
+                out.insert(format!("{}:{}", dimension, v));
+            }
+            Condition::Atom { dimension, value: None } => {
+                out.insert(dimension.clone());
+            }
+            Condition::Not(c) => c.atoms(out),
+            Condition::And(a, b) | Condition::Or(a, b) => {
+                a.atoms(out);
+                b.atoms(out);
+            }
         }
     }
 }
 
-/// Check if a line is a close tag (`-agent`).
-fn is_close_tag(line: &str, syntax: CommentSyntax) -> bool {
-    let trimmed = line.trim();
-    match syntax {
-        CommentSyntax::Html => {
-            trimmed
-                .strip_prefix("<!--")
-                .and_then(|s| s.strip_suffix("-->"))
-                .map(|s| s.trim() == "-agent")
-                .unwrap_or(false)
+/// Recursive-descent parser for `a:b & !(c | d)`. `|` binds loosest.
+struct Parser<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Parser<'a> {
+    fn parse(input: &'a str) -> Option<Condition> {
+        let mut p = Parser { rest: input };
+        let cond = p.or()?;
+        p.rest.trim().is_empty().then_some(cond)
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        self.rest = self.rest.trim_start();
+        match self.rest.strip_prefix(c) {
+            Some(rest) => {
+                self.rest = rest;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn or(&mut self) -> Option<Condition> {
+        let mut left = self.and()?;
+        while self.eat('|') {
+            left = Condition::Or(Box::new(left), Box::new(self.and()?));
+        }
+        Some(left)
+    }
+
+    fn and(&mut self) -> Option<Condition> {
+        let mut left = self.unary()?;
+        while self.eat('&') {
+            left = Condition::And(Box::new(left), Box::new(self.unary()?));
+        }
+        Some(left)
+    }
+
+    fn unary(&mut self) -> Option<Condition> {
+        if self.eat('!') {
+            return Some(Condition::Not(Box::new(self.unary()?)));
         }
-        CommentSyntax::Hash => {
-            trimmed
-                .strip_prefix('#')
-                .map(|s| s.trim() == "-agent")
-                .unwrap_or(false)
+        if self.eat('(') {
+            let inner = self.or()?;
+            return self.eat(')').then_some(inner);
         }
+        let dimension = self.word(is_dimension_char)?;
+        if !dimension.starts_with(|c: char| c.is_ascii_lowercase()) {
+            return None;
+        }
+        let value = if self.rest.starts_with(':') {
+            self.rest = &self.rest[1..];
+            Some(self.word(is_value_char)?)
+        } else {
+            None
+        };
+        Some(Condition::Atom { dimension, value })
+    }
+
+    fn word(&mut self, allowed: fn(char) -> bool) -> Option<String> {
+        self.rest = self.rest.trim_start();
+        let end = self.rest.find(|c| !allowed(c)).unwrap_or(self.rest.len());
+        if end == 0 {
+            return None;
+        }
+        let (word, rest) = self.rest.split_at(end);
+        self.rest = rest;
+        Some(word.to_string())
     }
 }
 
+fn is_dimension_char(c: char) -> bool {
+    c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_' || c == '-'
+}
+
+fn is_value_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.' | '/')
+}
+
+/// Returns the text inside a tag comment (`<!-- X -->` or `# X`).
+fn tag_body(line: &str, syntax: CommentSyntax) -> Option<&str> {
+    let trimmed = line.trim();
+    let inner = match syntax {
+        CommentSyntax::Html => trimmed.strip_prefix("<!--")?.strip_suffix("-->")?,
+        CommentSyntax::Hash => trimmed.strip_prefix('#')?,
+    };
+    Some(inner.trim())
+}
+
+/// Parse an open tag line (`+CONDITION`), returning the condition if matched.
+fn parse_open_tag(line: &str, syntax: CommentSyntax) -> Option<Condition> {
+    Parser::parse(tag_body(line, syntax)?.strip_prefix('+')?)
+}
+
+/// Check if a line is a close tag (`-agent`, `-bridge`, ...).
+fn is_close_tag(line: &str, syntax: CommentSyntax) -> bool {
+    tag_body(line, syntax)
+        .and_then(|body| body.strip_prefix('-'))
+        .is_some_and(|name| DIMENSIONS.contains(&name))
+}
+
 /// Filter content by agent tag, including only sections matching the target agent.
 ///
 /// Content outside any tag pair is always included ("common" content). Content inside
 /// a `+agent:NAME` / `-agent` pair is included only when `NAME` matches `agent`.
-/// Tag lines themselves are always stripped from the output.
+/// Tag lines themselves are always stripped from the output. Blocks conditioned
+/// on other dimensions are left untouched (see [`filter_tags`]).
 ///
 /// # Arguments
 /// * `content` — the full file content to filter
@@ -87,23 +271,63 @@ fn is_close_tag(line: &str, syntax: CommentSyntax) -> bool {
 /// # Returns
 /// The filtered content with tag lines removed.
 pub fn filter_agent_tags(content: &str, agent: &str, comment_syntax: CommentSyntax) -> String {
+    filter_tags(content, &TagContext::for_agent(agent), comment_syntax)
+}
+
+/// Convenience wrapper: detect comment syntax from filename, then filter
+/// against a full tag context.
+pub fn filter_file_with(content: &str, filename: &str, ctx: &TagContext) -> String {
+    filter_tags(content, ctx, detect_comment_syntax(filename))
+}
+
+/// How an open tag resolved against the context.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Block {
+    Include,
+    Exclude,
+    /// Depends on an unknown dimension: content and tag lines are kept.
+    Deferred,
+}
+
+/// Filter content against a tag context.
+///
+/// Blocks whose condition holds are kept, blocks whose condition fails are
+/// dropped, and in both cases the tag lines are stripped. A block whose
+/// condition depends on a dimension missing from `ctx` is kept verbatim,
+/// including its tag lines. Nested blocks are included only if every
+/// enclosing block is.
+pub fn filter_tags(content: &str, ctx: &TagContext, comment_syntax: CommentSyntax) -> String {
     if content.is_empty() {
         return String::new();
     }
 
     let mut output = String::with_capacity(content.len());
-    let mut included = true;
+    let mut stack: Vec<Block> = Vec::new();
 
     for line in content.lines() {
-        if let Some(tag_agent) = parse_open_tag(line, comment_syntax) {
-            included = tag_agent == agent;
-            continue; // strip tag line
+        let excluded = stack.contains(&Block::Exclude);
+        if let Some(condition) = parse_open_tag(line, comment_syntax) {
+            let block = match condition.eval(ctx) {
+                Some(true) => Block::Include,
+                Some(false) => Block::Exclude,
+                None => Block::Deferred,
+            };
+            if block == Block::Deferred && !excluded {
+                output.push_str(line);
+                output.push('\n');
+            }
+            stack.push(block);
+            continue;
         }
         if is_close_tag(line, comment_syntax) {
-            included = true;
-            continue; // strip tag line
+            // A close tag without an open block is stripped.
+            if stack.pop() == Some(Block::Deferred) && !stack.contains(&Block::Exclude) {
+                output.push_str(line);
+                output.push('\n');
+            }
+            continue;
         }
-        if included {
+        if !excluded {
             output.push_str(line);
             output.push('\n');
         }
@@ -120,18 +344,27 @@ pub fn filter_agent_tags(content: &str, agent: &str, comment_syntax: CommentSynt
 /// Collects all distinct agent names referenced by `+agent:NAME` tags in the content.
 ///
 /// Returns an empty set if the content has no agent tags.
-pub fn collect_agent_names(content: &str, syntax: CommentSyntax) -> std::collections::BTreeSet<String> {
-    let mut agents = std::collections::BTreeSet::new();
+pub fn collect_agent_names(content: &str, syntax: CommentSyntax) -> BTreeSet<String> {
+    collect_tag_conditions(content, syntax)
+        .into_iter()
+        .filter_map(|atom| atom.strip_prefix("agent:").map(String::from))
+        .collect()
+}
+
+/// Collects every condition referenced by open tags, as `dim:value` for valued
+/// dimensions and `dim` for flags (e.g. `agent:claude-code`, `bridge:tuwunel`, `brain`).
+pub fn collect_tag_conditions(content: &str, syntax: CommentSyntax) -> BTreeSet<String> {
+    let mut atoms = BTreeSet::new();
     for line in content.lines() {
-        if let Some(name) = parse_open_tag(line, syntax) {
-            agents.insert(name.to_string());
+        if let Some(condition) = parse_open_tag(line, syntax) {
+            condition.atoms(&mut atoms);
         }
     }
-    agents
+    atoms
 }
 
-/// Returns `true` if all agent tags in the content are balanced
-/// (every `+agent:NAME` has a matching `-agent`).
+/// Returns `true` if all tags in the content are balanced
+/// (every `+CONDITION` has a matching close tag).
 pub fn tags_are_balanced(content: &str, syntax: CommentSyntax) -> bool {
     let mut depth = 0i32;
     for line in content.lines() {
@@ -667,4 +900,139 @@ cli:
 ";
         assert_eq!(filter_file(input, "ralph.yml", "claude-code"), expected);
     }
+
+    // --- generalised conditions ---
+
+    fn team_ctx() -> TagContext {
+        TagContext::for_agent("claude-code")
+            .with("bridge", "tuwunel")
+            .with("formation", "local")
+            .with_flag("brain", true)
+    }
+
+    #[test]
+    fn bridge_and_formation_blocks_filtered() {
+        let input = "\
+Common
+<!-- +bridge:telegram -->
+Telegram
+<!-- -bridge -->
+<!-- +bridge:tuwunel -->
+Matrix
+<!-- -bridge -->
+<!-- +formation:k8s -->
+Kubernetes
+<!-- -formation -->
+";
+        assert_eq!(
+            filter_tags(input, &team_ctx(), CommentSyntax::Html),
+            "Common\nMatrix\n"
+        );
+    }
+
+    #[test]
+    fn boolean_combinations() {
+        let input = "\
+# +bridge:tuwunel | bridge:rocketchat
+self-hosted: true
+# -bridge
+# +brain & !formation:k8s
+local-brain: true
+# -brain
+# +!(brain)
+ralph-only: true
+# -brain
+";
+        assert_eq!(
+            filter_tags(input, &team_ctx(), CommentSyntax::Hash),
+            "self-hosted: true\nlocal-brain: true\n"
+        );
+    }
+
+    #[test]
+    fn nested_blocks_require_every_condition() {
+        let input = "\
+<!-- +agent:claude-code -->
+Claude
+<!-- +brain -->
+Claude brain
+<!-- -brain -->
+<!-- -agent -->
+<!-- +agent:gemini-cli -->
+<!-- +brain -->
+Gemini brain
+<!-- -brain -->
+<!-- -agent -->
+";
+        assert_eq!(
+            filter_tags(input, &team_ctx(), CommentSyntax::Html),
+            "Claude\nClaude brain\n"
+        );
+    }
+
+    #[test]
+    fn unknown_dimensions_are_deferred() {
+        let input = "\
+<!-- +agent:gemini-cli -->
+Gemini
+<!-- -agent -->
+<!-- +formation:k8s -->
+Kubernetes
+<!-- -formation -->
+<!-- +agent:claude-code & formation:local -->
+Claude local
+<!-- -agent -->
+";
+        let partial = filter_tags(input, &TagContext::for_agent("claude-code"), CommentSyntax::Html);
+        assert_eq!(
+            partial,
+            "\
+<!-- +formation:k8s -->
+Kubernetes
+<!-- -formation -->
+<!-- +agent:claude-code & formation:local -->
+Claude local
+<!-- -agent -->
+"
+        );
+        // A later pass with the formation known resolves what was deferred.
+        assert_eq!(
+            filter_tags(&partial, &team_ctx(), CommentSyntax::Html),
+            "Claude local\n"
+        );
+    }
+
+    #[test]
+    fn false_conjunction_decided_despite_unknown_dimension() {
+        let input = "# +agent:gemini-cli & formation:k8s\nx\n# -agent\n";
+        assert_eq!(
+            filter_tags(input, &TagContext::for_agent("claude-code"), CommentSyntax::Hash),
+            ""
+        );
+    }
+
+    #[test]
+    fn non_tag_comments_untouched() {
+        let input = "# +1 for this\n<!-- + not a tag -->\n# - list item\n# -verbose\n";
+        assert_eq!(filter_tags(input, &team_ctx(), CommentSyntax::Hash), input);
+        assert!(tags_are_balanced(input, CommentSyntax::Hash));
+    }
+
+    #[test]
+    fn collect_tag_conditions_reports_all_dimensions() {
+        let input = "\
+<!-- +agent:claude-code -->
+<!-- -agent -->
+<!-- +bridge:tuwunel | !brain -->
+<!-- -bridge -->
+";
+        let atoms: Vec<String> = collect_tag_conditions(input, CommentSyntax::Html)
+            .into_iter()
+            .collect();
+        assert_eq!(atoms, vec!["agent:claude-code", "brain", "bridge:tuwunel"]);
+        let agents: Vec<String> = collect_agent_names(input, CommentSyntax::Html)
+            .into_iter()
+            .collect();
+        assert_eq!(agents, vec!["claude-code"]);
+    }
 }
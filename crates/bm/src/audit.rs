@@ -0,0 +1,467 @@
+//! Append-only audit log of operator and agent actions.
+//!
+//! Every action that changes a team — hiring, firing, archiving, restoring,
+//! starting, stopping, pausing and resuming members, exporting credentials,
+//! console file writes, daemon API calls and agent knowledge proposals —
+//! appends one JSON line to
+//! `~/.botminter/audit-<team>.jsonl`:
+//!
+//! ```json
+//! {"seq":3,"ts":"2026-03-24T10:00:00+00:00","actor":{"kind":"cli","name":"alice"},
+//!  "action":"fire","target":"dev-bob","params":{"keep_app":false},
+//!  "outcome":"ok","prev_hash":"9c1e…","hash":"51d0…"}
+//! ```
+//!
+//! Entries are hash-chained: `hash` is the SHA-256 of the entry without its
+//! `hash` field, and `prev_hash` is the hash of the previous entry (64 zeros
+//! for the first). Editing, removing or reordering a line breaks the chain,
+//! which [`verify`] reports. Parameter values whose names look like secrets
+//! are replaced with [`REDACTED`] before they are written.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use fs2::FileExt;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use crate::config;
+
+/// `prev_hash` of the first entry in a log.
+pub const GENESIS_HASH: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+/// Replacement for redacted parameter values.
+pub const REDACTED: &str = "[redacted]";
+
+/// Parameter names containing any of these are redacted.
+const SECRET_MARKERS: &[&str] = &["secret", "token", "password", "private_key", "api_key"];
+
+/// HTTP header carrying the calling actor on daemon API requests.
+pub const ACTOR_HEADER: &str = "x-bm-actor";
+
+/// Audit log file: `<config_dir>/audit-<team>.jsonl`.
+pub fn log_path(config_dir: &Path, team_name: &str) -> PathBuf {
+    config_dir.join(format!("audit-{}.jsonl", team_name))
+}
+
+// ── Actors ───────────────────────────────────────────────────────────
+
+/// Where an action came from.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ActorKind {
+    /// An operator running `bm`.
+    Cli,
+    /// The web console.
+    Console,
+    /// A daemon API client that did not identify itself.
+    Api,
+    /// A member's coding agent running `bm-agent`.
+    Agent,
+}
+
+impl ActorKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ActorKind::Cli => "cli",
+            ActorKind::Console => "console",
+            ActorKind::Api => "api",
+            ActorKind::Agent => "agent",
+        }
+    }
+}
+
+/// Who performed an action: a kind plus a name (OS user, member, ...).
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct Actor {
+    pub kind: ActorKind,
+    pub name: String,
+}
+
+impl Actor {
+    /// The operator running this process, named after `$USER`.
+    pub fn cli() -> Self {
+        Self {
+            kind: ActorKind::Cli,
+            name: std::env::var("USER").unwrap_or_else(|_| "unknown".to_string()),
+        }
+    }
+
+    /// The web console. It has no login, so the name is fixed.
+    pub fn console() -> Self {
+        Self {
+            kind: ActorKind::Console,
+            name: "console".to_string(),
+        }
+    }
+
+    /// A member's agent.
+    pub fn agent(member: &str) -> Self {
+        Self {
+            kind: ActorKind::Agent,
+            name: member.to_string(),
+        }
+    }
+
+    /// Parses `<kind>:<name>`, the form actors are displayed in.
+    pub fn parse(value: &str) -> Option<Self> {
+        let (kind, name) = value.split_once(':')?;
+        let kind = match kind {
+            "cli" => ActorKind::Cli,
+            "console" => ActorKind::Console,
+            "api" => ActorKind::Api,
+            "agent" => ActorKind::Agent,
+            _ => return None,
+        };
+        (!name.is_empty()).then(|| Self {
+            kind,
+            name: name.to_string(),
+        })
+    }
+
+    /// Parses an [`ACTOR_HEADER`] value (`<kind>:<name>`). Missing or
+    /// malformed values yield an anonymous API actor.
+    pub fn from_header(value: Option<&str>) -> Self {
+        value.and_then(Self::parse).unwrap_or_else(|| Self {
+            kind: ActorKind::Api,
+            name: "anonymous".to_string(),
+        })
+    }
+}
+
+impl fmt::Display for Actor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.kind.label(), self.name)
+    }
+}
+
+// ── Entries ──────────────────────────────────────────────────────────
+
+/// Whether the action succeeded.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Ok,
+    Error,
+}
+
+/// An action about to be written to the audit log.
+#[derive(Debug, Clone)]
+pub struct Record {
+    actor: Actor,
+    action: String,
+    target: Option<String>,
+    params: BTreeMap<String, Value>,
+    error: Option<String>,
+}
+
+impl Record {
+    pub fn new(actor: Actor, action: &str) -> Self {
+        Self {
+            actor,
+            action: action.to_string(),
+            target: None,
+            params: BTreeMap::new(),
+            error: None,
+        }
+    }
+
+    /// The member, file or other object the action applies to.
+    pub fn target(mut self, target: Option<&str>) -> Self {
+        self.target = target.map(str::to_string);
+        self
+    }
+
+    /// Adds a parameter. `None` values are left out; secret-looking names
+    /// are redacted.
+    pub fn param(mut self, name: &str, value: impl Into<Value>) -> Self {
+        let value = value.into();
+        if value.is_null() {
+            return self;
+        }
+        let value = if is_secret(name) {
+            Value::String(REDACTED.to_string())
+        } else {
+            value
+        };
+        self.params.insert(name.to_string(), value);
+        self
+    }
+
+    /// Sets the outcome from the action's result.
+    pub fn outcome<T>(mut self, result: &Result<T>) -> Self {
+        self.error = result.as_ref().err().map(|e| format!("{:#}", e));
+        self
+    }
+
+    /// Marks the action as failed with `message`.
+    pub fn failed(mut self, message: impl Into<String>) -> Self {
+        self.error = Some(message.into());
+        self
+    }
+}
+
+fn is_secret(name: &str) -> bool {
+    let name = name.to_ascii_lowercase();
+    SECRET_MARKERS.iter().any(|m| name.contains(m))
+}
+
+/// A line of the audit log.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct AuditEntry {
+    /// Position in the log, starting at 1.
+    pub seq: u64,
+    pub ts: String,
+    pub actor: Actor,
+    /// Dotted action name, e.g. `hire`, `credentials.export`, `members.start`.
+    pub action: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target: Option<String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub params: BTreeMap<String, Value>,
+    pub outcome: Outcome,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    pub prev_hash: String,
+    #[serde(default)]
+    pub hash: String,
+}
+
+impl AuditEntry {
+    /// SHA-256 (hex) of the entry with `hash` left out.
+    pub fn compute_hash(&self) -> String {
+        let mut value = serde_json::to_value(self).unwrap_or(Value::Null);
+        if let Some(obj) = value.as_object_mut() {
+            obj.remove("hash");
+        }
+        hex::encode(Sha256::digest(value.to_string().as_bytes()))
+    }
+}
+
+// ── Reading and writing ──────────────────────────────────────────────
+
+/// Appends `record` to the log at `path`, chaining it to the last entry.
+/// Concurrent writers (CLI, daemon, console) are serialized with an
+/// exclusive file lock.
+pub fn append(path: &Path, record: Record) -> Result<AuditEntry> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+    let file = OpenOptions::new()
+        .create(true)
+        .read(true)
+        .append(true)
+        .open(path)
+        .with_context(|| format!("Failed to open audit log {}", path.display()))?;
+
+    file.lock_exclusive()?;
+    let result = append_locked(&file, path, record);
+    file.unlock()?;
+
+    result
+}
+
+fn append_locked(mut file: &File, path: &Path, record: Record) -> Result<AuditEntry> {
+    let last = last_line(file)?
+        .map(|line| {
+            serde_json::from_str::<AuditEntry>(&line)
+                .with_context(|| format!("Last entry of {} is not valid JSON", path.display()))
+        })
+        .transpose()?;
+
+    let mut entry = AuditEntry {
+        seq: last.as_ref().map_or(1, |e| e.seq + 1),
+        ts: chrono::Utc::now().to_rfc3339(),
+        actor: record.actor,
+        action: record.action,
+        target: record.target,
+        params: record.params,
+        outcome: if record.error.is_some() {
+            Outcome::Error
+        } else {
+            Outcome::Ok
+        },
+        error: record.error,
+        prev_hash: last.map_or_else(|| GENESIS_HASH.to_string(), |e| e.hash),
+        hash: String::new(),
+    };
+    entry.hash = entry.compute_hash();
+
+    writeln!(file, "{}", serde_json::to_string(&entry)?)?;
+    file.sync_data()?;
+    Ok(entry)
+}
+
+fn last_line(file: &File) -> Result<Option<String>> {
+    let mut last = None;
+    for line in BufReader::new(file).lines() {
+        let line = line?;
+        if !line.trim().is_empty() {
+            last = Some(line);
+        }
+    }
+    Ok(last)
+}
+
+/// Loads all entries, oldest first. A missing log is empty; a malformed
+/// line is an error, since the log is never rewritten.
+pub fn load(path: &Path) -> Result<Vec<AuditEntry>> {
+    let file = match File::open(path) {
+        Ok(f) => f,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => {
+            return Err(e).with_context(|| format!("Failed to open audit log {}", path.display()))
+        }
+    };
+    let mut entries = Vec::new();
+    for (i, line) in BufReader::new(file).lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let entry = serde_json::from_str(&line).with_context(|| {
+            format!("Line {} of {} is not a valid entry", i + 1, path.display())
+        })?;
+        entries.push(entry);
+    }
+    Ok(entries)
+}
+
+/// Checks the hash chain of `entries`. Returns the number of entries, or
+/// an error naming the first entry that doesn't fit.
+pub fn verify(entries: &[AuditEntry]) -> Result<usize> {
+    let mut prev_hash = GENESIS_HASH;
+    for (i, entry) in entries.iter().enumerate() {
+        let expected_seq = i as u64 + 1;
+        if entry.seq != expected_seq {
+            bail!(
+                "Entry {} has sequence number {} (entries removed or reordered)",
+                expected_seq,
+                entry.seq
+            );
+        }
+        if entry.prev_hash != prev_hash {
+            bail!(
+                "Entry {} does not link to the previous entry (entries removed or reordered)",
+                entry.seq
+            );
+        }
+        if entry.compute_hash() != entry.hash {
+            bail!("Entry {} was modified after it was written", entry.seq);
+        }
+        prev_hash = &entry.hash;
+    }
+    Ok(entries.len())
+}
+
+/// Records an action taken through `bm`. The team is resolved the same way
+/// the command resolved it; if that fails, the command failed before it
+/// touched a team and nothing is recorded.
+pub fn record_cli(team_flag: Option<&str>, record: Record) {
+    let Ok(cfg) = config::load() else {
+        return;
+    };
+    if let Ok(team) = config::resolve_team(&cfg, team_flag) {
+        record_for_team(&team.name, record);
+    }
+}
+
+/// Appends `record` to the team's log under `~/.botminter`. Write failures
+/// are reported but never fail the action being recorded.
+pub fn record_for_team(team_name: &str, record: Record) {
+    let result = config::config_dir().and_then(|dir| append(&log_path(&dir, team_name), record));
+    if let Err(e) = result {
+        eprintln!("Warning: failed to write audit log: {:#}", e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(action: &str) -> Record {
+        Record::new(Actor::agent("dev-bob"), action)
+            .target(Some("dev-bob"))
+            .param("force", true)
+    }
+
+    #[test]
+    fn append_chains_entries_and_verifies() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = log_path(tmp.path(), "team");
+
+        let first = append(&path, record("members.start")).unwrap();
+        let second = append(
+            &path,
+            record("members.stop").outcome::<()>(&Err(anyhow::anyhow!("not running"))),
+        )
+        .unwrap();
+
+        assert_eq!(first.seq, 1);
+        assert_eq!(first.prev_hash, GENESIS_HASH);
+        assert_eq!(second.seq, 2);
+        assert_eq!(second.prev_hash, first.hash);
+        assert_eq!(second.outcome, Outcome::Error);
+        assert_eq!(second.error.as_deref(), Some("not running"));
+
+        let entries = load(&path).unwrap();
+        assert_eq!(entries, vec![first, second]);
+        assert_eq!(verify(&entries).unwrap(), 2);
+    }
+
+    #[test]
+    fn verify_detects_edits_and_removals() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = log_path(tmp.path(), "team");
+        for action in ["hire", "start", "fire"] {
+            append(&path, record(action)).unwrap();
+        }
+        let entries = load(&path).unwrap();
+
+        let mut edited = entries.clone();
+        edited[1].target = Some("someone-else".to_string());
+        let err = verify(&edited).unwrap_err().to_string();
+        assert!(err.contains("Entry 2 was modified"), "{}", err);
+
+        let removed = vec![entries[0].clone(), entries[2].clone()];
+        let err = verify(&removed).unwrap_err().to_string();
+        assert!(err.contains("sequence number 3"), "{}", err);
+
+        let mut resequenced = removed;
+        resequenced[1].seq = 2;
+        resequenced[1].hash = resequenced[1].compute_hash();
+        let err = verify(&resequenced).unwrap_err().to_string();
+        assert!(err.contains("does not link"), "{}", err);
+    }
+
+    #[test]
+    fn params_redact_secrets_and_skip_none() {
+        let r = Record::new(Actor::cli(), "hire")
+            .param("bot_token", "xoxb-123")
+            .param("private_key_file", "/tmp/key.pem")
+            .param("app_id", "42")
+            .param("client_id", None::<String>);
+
+        assert_eq!(r.params["bot_token"], REDACTED);
+        assert_eq!(r.params["private_key_file"], REDACTED);
+        assert_eq!(r.params["app_id"], "42");
+        assert!(!r.params.contains_key("client_id"));
+    }
+
+    #[test]
+    fn actor_header_round_trips() {
+        let agent = Actor::agent("dev-bob");
+        assert_eq!(Actor::from_header(Some(agent.to_string().as_str())), agent);
+        assert_eq!(Actor::from_header(None).kind, ActorKind::Api);
+        assert_eq!(Actor::from_header(Some("robot:x")).name, "anonymous");
+        assert_eq!(Actor::from_header(Some("cli:")).name, "anonymous");
+        assert_eq!(Actor::parse("agent:dev-bob"), Some(agent));
+        assert_eq!(Actor::parse("dev-bob"), None);
+    }
+}
@@ -13,6 +13,10 @@ const SIGNIFICANT_TOPICS: &[&str] = &[
     "build.blocked",
     "task.close",
     "LOOP_COMPLETE",
+    // Prompts injected by the daemon's scheduler (see `schedule.yml`)
+    "schedule.trigger",
+    // Failed executable invariants reported by the daemon after `task.close`
+    "invariant.violation",
 ];
 
 /// A single event parsed from a Ralph JSONL event file.
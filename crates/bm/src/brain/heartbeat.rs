@@ -5,18 +5,22 @@ use tokio::sync::mpsc;
 use tokio::time::{self, Duration, MissedTickBehavior};
 
 use super::types::BrainMessage;
+use crate::schedule::ResolvedSchedule;
 
 /// Configuration for the heartbeat timer.
 #[derive(Debug, Clone)]
 pub struct HeartbeatConfig {
     /// Interval between heartbeat prompts. Set to `Duration::ZERO` to disable.
     pub interval: Duration,
+    /// Member schedule whose quiet hours suppress heartbeats.
+    pub schedule: Option<ResolvedSchedule>,
 }
 
 impl Default for HeartbeatConfig {
     fn default() -> Self {
         Self {
             interval: Duration::from_secs(60),
+            schedule: None,
         }
     }
 }
@@ -26,9 +30,28 @@ impl HeartbeatConfig {
     pub fn from_secs(secs: u64) -> Self {
         Self {
             interval: Duration::from_secs(secs),
+            schedule: None,
         }
     }
 
+    /// Create a heartbeat config from the member's schedule: `heartbeat_secs`
+    /// overrides the default interval and quiet hours silence the timer.
+    pub fn from_schedule(schedule: Option<ResolvedSchedule>) -> Self {
+        let interval = schedule
+            .as_ref()
+            .and_then(|s| s.heartbeat_secs)
+            .map(Duration::from_secs)
+            .unwrap_or_else(|| Self::default().interval);
+        Self { interval, schedule }
+    }
+
+    /// Whether heartbeats are suppressed right now (quiet hours).
+    pub fn is_quiet(&self) -> bool {
+        self.schedule
+            .as_ref()
+            .is_some_and(|s| s.is_quiet(chrono::Utc::now()))
+    }
+
     /// Whether the heartbeat is disabled (interval is zero).
     pub fn is_disabled(&self) -> bool {
         self.interval.is_zero()
@@ -183,6 +206,10 @@ impl Heartbeat {
                         tracing::debug!("Skipping heartbeat — previous still pending");
                         continue;
                     }
+                    if self.config.is_quiet() {
+                        tracing::debug!("Skipping heartbeat — quiet hours");
+                        continue;
+                    }
 
                     let msg = BrainMessage::heartbeat();
                     match self.input_tx.send(msg).await {
@@ -218,6 +245,29 @@ mod tests {
         assert!(config.is_disabled());
     }
 
+    #[test]
+    fn config_from_schedule_uses_heartbeat_secs() {
+        let cfg: crate::schedule::ScheduleConfig =
+            serde_yml::from_str("default:\n  heartbeat_secs: 300\n").unwrap();
+        let sched = cfg.resolve("any", "any-one").unwrap();
+        let config = HeartbeatConfig::from_schedule(sched);
+        assert_eq!(config.interval, Duration::from_secs(300));
+        assert!(!config.is_quiet());
+
+        let config = HeartbeatConfig::from_schedule(None);
+        assert_eq!(config.interval, Duration::from_secs(60));
+    }
+
+    #[test]
+    fn config_quiet_all_day_is_quiet() {
+        let cfg: crate::schedule::ScheduleConfig = serde_yml::from_str(
+            "default:\n  quiet_hours:\n    - { start: \"00:00\", end: \"00:00\" }\n",
+        )
+        .unwrap();
+        let config = HeartbeatConfig::from_schedule(cfg.resolve("any", "any-one").unwrap());
+        assert!(config.is_quiet());
+    }
+
     #[test]
     fn config_from_secs_nonzero() {
         let config = HeartbeatConfig::from_secs(30);
@@ -274,6 +324,7 @@ mod tests {
         let (tx, mut rx) = mpsc::channel(16);
         let config = HeartbeatConfig {
             interval: Duration::from_millis(50),
+            ..Default::default()
         };
         let (heartbeat, shutdown, _pending) = Heartbeat::new(config, tx);
 
@@ -301,6 +352,7 @@ mod tests {
         let (tx, mut rx) = mpsc::channel(16);
         let config = HeartbeatConfig {
             interval: Duration::from_millis(30),
+            ..Default::default()
         };
         let (heartbeat, shutdown, pending) = Heartbeat::new(config, tx);
 
@@ -335,6 +387,7 @@ mod tests {
         let (tx, rx) = mpsc::channel(16);
         let config = HeartbeatConfig {
             interval: Duration::from_millis(30),
+            ..Default::default()
         };
         let (heartbeat, _shutdown, _pending) = Heartbeat::new(config, tx);
 
@@ -350,6 +403,7 @@ mod tests {
         let (tx, mut rx) = mpsc::channel(16);
         let config = HeartbeatConfig {
             interval: Duration::from_millis(30),
+            ..Default::default()
         };
         let (heartbeat, shutdown, pending) = Heartbeat::new(config, tx);
 
@@ -13,7 +13,7 @@ pub use heartbeat::{
 };
 pub use multiplexer::{
     Multiplexer, MultiplexerConfig, MultiplexerError, MultiplexerInput, MultiplexerOutput,
-    MultiplexerShutdown,
+    MultiplexerPause, MultiplexerShutdown,
 };
 pub use prompt_template::{
     parse_github_repo, read_member_name, read_member_role, render_brain_prompt,
@@ -41,6 +41,10 @@ const HEARTBEAT_SUPPRESSION_SECS: u64 = 30;
 /// incoming messages are queued. When the response completes, the queue is
 /// drained by priority order — human messages first, then loop events, then
 /// heartbeat.
+///
+/// While paused (see [`MultiplexerPause`]), the in-flight prompt finishes but
+/// nothing new is sent: messages stay queued and heartbeats are dropped until
+/// intake resumes.
 pub struct Multiplexer {
     config: MultiplexerConfig,
     /// Receives messages from all input sources (bridge, event watcher, heartbeat).
@@ -49,6 +53,10 @@ pub struct Multiplexer {
     output_tx: mpsc::Sender<BridgeOutput>,
     /// Receives shutdown signal.
     shutdown_rx: mpsc::Receiver<()>,
+    /// Receives pause (`true`) and resume (`false`) requests.
+    pause_rx: mpsc::Receiver<bool>,
+    /// Kept so [`Multiplexer::pause_handle`] can hand out senders.
+    pause_tx: mpsc::Sender<bool>,
     /// Timestamp of the last TurnComplete, used for heartbeat suppression.
     last_turn_completed: Option<Instant>,
 }
@@ -109,6 +117,24 @@ impl MultiplexerShutdown {
     }
 }
 
+/// Handle for pausing and resuming the multiplexer's intake.
+#[derive(Clone)]
+pub struct MultiplexerPause {
+    tx: mpsc::Sender<bool>,
+}
+
+impl MultiplexerPause {
+    /// Stop sending prompts once the in-flight one completes.
+    pub async fn pause(&self) {
+        let _ = self.tx.send(true).await;
+    }
+
+    /// Resume sending prompts, starting with the queued ones.
+    pub async fn resume(&self) {
+        let _ = self.tx.send(false).await;
+    }
+}
+
 /// Errors from the multiplexer.
 #[derive(Debug)]
 pub enum MultiplexerError {
@@ -148,12 +174,15 @@ impl Multiplexer {
         let (input_tx, input_rx) = mpsc::channel(64);
         let (output_tx, output_rx) = mpsc::channel(256);
         let (shutdown_tx, shutdown_rx) = mpsc::channel(1);
+        let (pause_tx, pause_rx) = mpsc::channel(4);
 
         let mux = Multiplexer {
             config,
             input_rx,
             output_tx,
             shutdown_rx,
+            pause_rx,
+            pause_tx,
             last_turn_completed: None,
         };
 
@@ -164,6 +193,13 @@ impl Multiplexer {
         (mux, input, output, shutdown)
     }
 
+    /// Returns a handle for pausing and resuming intake.
+    pub fn pause_handle(&self) -> MultiplexerPause {
+        MultiplexerPause {
+            tx: self.pause_tx.clone(),
+        }
+    }
+
     /// Run the multiplexer event loop.
     ///
     /// This is the core async loop that:
@@ -214,6 +250,7 @@ impl Multiplexer {
 
         let mut queue = PromptQueue::new();
         let mut prompt_in_flight = false;
+        let mut paused = false;
 
         loop {
             tokio::select! {
@@ -228,10 +265,35 @@ impl Multiplexer {
                     return Ok(());
                 }
 
+                // Pause or resume intake
+                Some(pause) = self.pause_rx.recv() => {
+                    if pause == paused {
+                        continue;
+                    }
+                    paused = pause;
+                    if paused {
+                        tracing::info!(queue_len = queue.len(), "Brain intake paused");
+                    } else {
+                        tracing::info!(queue_len = queue.len(), "Brain intake resumed");
+                    }
+                    if !paused && !prompt_in_flight {
+                        if let Some(next_msg) = queue.pop() {
+                            let prompt = next_msg.to_prompt_with_envelope(&envelopes);
+                            client.prompt(&session_id, &prompt).await?;
+                            prompt_in_flight = true;
+                        }
+                    }
+                }
+
                 // Receive new messages from input sources
                 msg = self.input_rx.recv() => {
                     match msg {
                         Some(message) => {
+                            // Nobody is listening for heartbeats while paused
+                            if paused && message.priority == Priority::Heartbeat {
+                                continue;
+                            }
+
                             // Suppress heartbeats that arrive too soon after a turn
                             if message.priority == Priority::Heartbeat {
                                 if let Some(last) = self.last_turn_completed {
@@ -246,11 +308,12 @@ impl Multiplexer {
                                 }
                             }
 
-                            if prompt_in_flight {
-                                // Queue the message while a prompt is in-flight
+                            if prompt_in_flight || paused {
+                                // Queue the message while a prompt is in-flight or intake is paused
                                 tracing::debug!(
                                     priority = %message.priority,
-                                    "Queuing message (prompt in-flight)"
+                                    paused,
+                                    "Queuing message"
                                 );
                                 queue.push(message);
                             } else {
@@ -293,7 +356,10 @@ impl Multiplexer {
                             prompt_in_flight = false;
                             self.last_turn_completed = Some(Instant::now());
 
-                            // Drain the queue by priority
+                            // Drain the queue by priority (held while paused)
+                            if paused {
+                                continue;
+                            }
                             if let Some(next_msg) = queue.pop() {
                                 let prompt = next_msg.to_prompt_with_envelope(&envelopes);
                                 tracing::debug!(
@@ -379,6 +445,17 @@ mod tests {
         assert!(result.is_none());
     }
 
+    #[tokio::test]
+    async fn pause_handle_send_after_mux_dropped_is_noop() {
+        let config = test_config();
+        let (mux, _input, _output, _shutdown) = Multiplexer::new(config);
+        let pause = mux.pause_handle();
+        drop(mux);
+        // Like shutdown, pausing a multiplexer that is gone must not panic
+        pause.pause().await;
+        pause.resume().await;
+    }
+
     #[test]
     fn multiplexer_error_display() {
         let err = MultiplexerError::Shutdown;
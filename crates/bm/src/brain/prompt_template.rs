@@ -1,26 +1,213 @@
 use std::fs;
 use std::path::Path;
 
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
+use minijinja::{Environment, UndefinedBehavior};
+use serde::Serialize;
+
+use crate::profile::{self, ProjectDef, StatusDef};
+
+/// Name the brain template is registered under (shows up in render errors).
+const TEMPLATE_NAME: &str = "system-prompt.md";
+
+/// Top-level variables available to brain prompt templates.
+const KNOWN_VARS: &[&str] = &[
+    "member_name",
+    "team_name",
+    "role",
+    "gh_org",
+    "gh_repo",
+    "members",
+    "projects",
+    "statuses",
+    "views",
+    "bridge",
+    "knowledge",
+];
+
+/// Global functions minijinja provides; not reported as unknown variables.
+const BUILTIN_GLOBALS: &[&str] = &["range", "dict", "namespace", "debug"];
 
 /// Template variables for rendering the brain system prompt.
-#[derive(Debug, Clone)]
+///
+/// Templates use Jinja syntax (minijinja): `{{ var }}`, `{% if %}`,
+/// `{% for %}`, and `{% include "partial.md" %}` relative to the team repo's
+/// `brain/` directory.
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct BrainPromptVars {
     pub member_name: String,
     pub team_name: String,
     pub role: String,
     pub gh_org: String,
     pub gh_repo: String,
+    /// Every hired member, including this one.
+    pub members: Vec<TeammateVar>,
+    pub projects: Vec<ProjectDef>,
+    pub statuses: Vec<StatusDef>,
+    pub views: Vec<ViewVar>,
+    /// The team's selected bridge, if any.
+    pub bridge: Option<BridgeVar>,
+    /// Knowledge files visible to this member, relative to the team repo.
+    pub knowledge: Vec<String>,
+}
+
+/// A team member as seen from a brain prompt.
+#[derive(Debug, Clone, Serialize)]
+pub struct TeammateVar {
+    /// Member directory name (e.g. `engineer-bob`).
+    pub name: String,
+    pub display_name: String,
+    pub role: String,
+    /// True for the member the prompt is rendered for.
+    pub is_self: bool,
+}
+
+/// A board view with its statuses expanded.
+#[derive(Debug, Clone, Serialize)]
+pub struct ViewVar {
+    pub name: String,
+    pub statuses: Vec<String>,
+}
+
+/// The team's chat bridge.
+#[derive(Debug, Clone, Serialize)]
+pub struct BridgeVar {
+    pub name: String,
+    pub display_name: String,
+    #[serde(rename = "type")]
+    pub bridge_type: String,
+}
+
+impl BrainPromptVars {
+    /// Gathers template variables for a member from the team repo: roster,
+    /// projects, statuses, views, bridge, and knowledge files.
+    pub fn gather(
+        team_repo: &Path,
+        member_dir_name: &str,
+        team_name: &str,
+        github_repo: Option<&str>,
+    ) -> Self {
+        let (gh_org, gh_repo) = github_repo
+            .and_then(parse_github_repo)
+            .map(|(org, repo)| (org.to_string(), repo.to_string()))
+            .unwrap_or_default();
+
+        let summary = profile::gather_team_summary(team_repo);
+        let members = summary
+            .members
+            .iter()
+            .map(|(name, role)| TeammateVar {
+                name: name.clone(),
+                display_name: read_member_name(team_repo, name),
+                role: role.clone(),
+                is_self: name == member_dir_name,
+            })
+            .collect();
+
+        let manifest = profile::read_team_repo_manifest(team_repo).ok();
+        let (statuses, views, bridge) = match manifest {
+            Some(m) => {
+                let views = m
+                    .views
+                    .iter()
+                    .map(|v| ViewVar {
+                        name: v.name.clone(),
+                        statuses: v.resolve_statuses(&m.statuses),
+                    })
+                    .collect();
+                let bridge = m.bridge.as_ref().and_then(|selected| {
+                    m.bridges.iter().find(|b| &b.name == selected).map(|b| BridgeVar {
+                        name: b.name.clone(),
+                        display_name: b.display_name.clone(),
+                        bridge_type: b.bridge_type.clone(),
+                    })
+                });
+                (m.statuses, views, bridge)
+            }
+            None => (Vec::new(), Vec::new(), None),
+        };
+
+        BrainPromptVars {
+            member_name: read_member_name(team_repo, member_dir_name),
+            team_name: team_name.to_string(),
+            role: read_member_role(team_repo, member_dir_name).unwrap_or_default(),
+            gh_org,
+            gh_repo,
+            members,
+            knowledge: knowledge_files(team_repo, member_dir_name, &summary.projects),
+            projects: summary.projects,
+            statuses,
+            views,
+            bridge,
+        }
+    }
+}
+
+/// Knowledge files in the scopes a member resolves: team, each project,
+/// the member, and member+project.
+fn knowledge_files(team_repo: &Path, member_dir_name: &str, projects: &[ProjectDef]) -> Vec<String> {
+    let member_base = format!("members/{}", member_dir_name);
+    let mut scopes = vec![String::new()];
+    scopes.extend(projects.iter().map(|p| format!("projects/{}", p.name)));
+    scopes.push(member_base.clone());
+    scopes.extend(projects.iter().map(|p| format!("{}/projects/{}", member_base, p.name)));
+
+    let mut files = Vec::new();
+    for scope in scopes {
+        let base = team_repo.join(&scope);
+        for file in profile::list_scope_files(&base, "knowledge") {
+            files.push(if scope.is_empty() {
+                format!("knowledge/{}", file)
+            } else {
+                format!("{}/knowledge/{}", scope, file)
+            });
+        }
+    }
+    files
 }
 
-/// Renders a brain system prompt template by replacing `{{var}}` placeholders.
-pub fn render_brain_prompt(template: &str, vars: &BrainPromptVars) -> String {
-    template
-        .replace("{{member_name}}", &vars.member_name)
-        .replace("{{team_name}}", &vars.team_name)
-        .replace("{{role}}", &vars.role)
-        .replace("{{gh_org}}", &vars.gh_org)
-        .replace("{{gh_repo}}", &vars.gh_repo)
+fn environment<'a>(include_dir: Option<&Path>) -> Environment<'a> {
+    let mut env = Environment::new();
+    env.set_undefined_behavior(UndefinedBehavior::Strict);
+    env.set_keep_trailing_newline(true);
+    env.set_trim_blocks(true);
+    env.set_lstrip_blocks(true);
+    if let Some(dir) = include_dir {
+        env.set_loader(minijinja::path_loader(dir));
+    }
+    env
+}
+
+/// Renders a brain system prompt template.
+///
+/// Fails on syntax errors and on variables the context doesn't provide —
+/// including ones in branches that aren't taken — so template mistakes
+/// surface at `bm teams sync` rather than as a silently broken prompt.
+pub fn render_brain_prompt(template: &str, vars: &BrainPromptVars) -> Result<String> {
+    render_with(template, vars, None)
+}
+
+fn render_with(template: &str, vars: &BrainPromptVars, include_dir: Option<&Path>) -> Result<String> {
+    let mut env = environment(include_dir);
+    env.add_template_owned(TEMPLATE_NAME, template.to_string())
+        .context("Invalid brain prompt template")?;
+    let tmpl = env.get_template(TEMPLATE_NAME)?;
+
+    let mut unknown: Vec<String> = tmpl
+        .undeclared_variables(false)
+        .into_iter()
+        .filter(|v| !KNOWN_VARS.contains(&v.as_str()) && !BUILTIN_GLOBALS.contains(&v.as_str()))
+        .collect();
+    if !unknown.is_empty() {
+        unknown.sort();
+        bail!(
+            "Unknown variable(s) in brain prompt template: {}. Available: {}",
+            unknown.join(", "),
+            KNOWN_VARS.join(", ")
+        );
+    }
+
+    tmpl.render(vars).context("Failed to render brain prompt template")
 }
 
 /// Reads the brain system prompt template from a team repo, renders it with
@@ -41,7 +228,8 @@ pub fn surface_brain_prompt(
     let template = fs::read_to_string(&template_path)
         .with_context(|| format!("Failed to read brain template at {}", template_path.display()))?;
 
-    let rendered = render_brain_prompt(&template, vars);
+    let rendered = render_with(&template, vars, Some(&team_repo.join("brain")))
+        .with_context(|| format!("Failed to render {}", template_path.display()))?;
 
     let output_path = ws_root.join("brain-prompt.md");
     fs::write(&output_path, rendered)
@@ -117,13 +305,14 @@ mod tests {
             role: "superman".into(),
             gh_org: "myorg".into(),
             gh_repo: "team-repo".into(),
+            ..Default::default()
         }
     }
 
     #[test]
     fn render_replaces_all_variables() {
         let template = "You are {{member_name}} on {{team_name}}, role={{role}}, org={{gh_org}}, repo={{gh_repo}}.";
-        let result = render_brain_prompt(template, &sample_vars());
+        let result = render_brain_prompt(template, &sample_vars()).unwrap();
         assert_eq!(
             result,
             "You are alice on alpha-team, role=superman, org=myorg, repo=team-repo."
@@ -133,30 +322,149 @@ mod tests {
     #[test]
     fn render_handles_multiple_occurrences() {
         let template = "{{member_name}} is {{member_name}}.";
-        let result = render_brain_prompt(template, &sample_vars());
+        let result = render_brain_prompt(template, &sample_vars()).unwrap();
         assert_eq!(result, "alice is alice.");
     }
 
     #[test]
     fn render_preserves_text_without_variables() {
         let template = "No variables here.";
-        let result = render_brain_prompt(template, &sample_vars());
+        let result = render_brain_prompt(template, &sample_vars()).unwrap();
         assert_eq!(result, "No variables here.");
     }
 
     #[test]
     fn render_handles_empty_template() {
-        let result = render_brain_prompt("", &sample_vars());
+        let result = render_brain_prompt("", &sample_vars()).unwrap();
         assert_eq!(result, "");
     }
 
     #[test]
     fn render_handles_adjacent_variables() {
         let template = "{{member_name}}{{team_name}}";
-        let result = render_brain_prompt(template, &sample_vars());
+        let result = render_brain_prompt(template, &sample_vars()).unwrap();
         assert_eq!(result, "alicealpha-team");
     }
 
+    #[test]
+    fn render_supports_conditionals_and_loops() {
+        let mut vars = sample_vars();
+        vars.members = vec![
+            TeammateVar {
+                name: "superman-alice".into(),
+                display_name: "alice".into(),
+                role: "superman".into(),
+                is_self: true,
+            },
+            TeammateVar {
+                name: "reviewer-bob".into(),
+                display_name: "bob".into(),
+                role: "reviewer".into(),
+                is_self: false,
+            },
+        ];
+        let template = "{% for m in members if not m.is_self %}- {{ m.display_name }} ({{ m.role }})\n{% endfor %}\
+                        {% if bridge %}chat{% else %}no bridge{% endif %}";
+        let result = render_brain_prompt(template, &vars).unwrap();
+        assert_eq!(result, "- bob (reviewer)\nno bridge");
+    }
+
+    #[test]
+    fn render_rejects_unknown_variable() {
+        let err = render_brain_prompt("Hi {{ member_nmae }}", &sample_vars()).unwrap_err();
+        let msg = format!("{:#}", err);
+        assert!(msg.contains("member_nmae"), "got: {msg}");
+    }
+
+    #[test]
+    fn render_rejects_unknown_variable_in_untaken_branch() {
+        let template = "{% if false %}{{ teammates }}{% endif %}";
+        assert!(render_brain_prompt(template, &sample_vars()).is_err());
+    }
+
+    #[test]
+    fn render_rejects_unknown_attribute() {
+        let template = "{% for p in projects %}{{ p.url }}{% endfor %}";
+        let mut vars = sample_vars();
+        vars.projects = vec![ProjectDef {
+            name: "widget".into(),
+            fork_url: "https://github.com/org/widget".into(),
+        }];
+        assert!(render_brain_prompt(template, &vars).is_err());
+    }
+
+    #[test]
+    fn render_keeps_trailing_newline() {
+        let result = render_brain_prompt("{{ member_name }}\n", &sample_vars()).unwrap();
+        assert_eq!(result, "alice\n");
+    }
+
+    #[test]
+    fn surface_brain_prompt_resolves_includes() {
+        let tmp = tempfile::tempdir().unwrap();
+        let team_repo = tmp.path().join("team");
+        let ws_root = tmp.path().join("workspace");
+        fs::create_dir_all(team_repo.join("brain/partials")).unwrap();
+        fs::create_dir_all(&ws_root).unwrap();
+
+        fs::write(
+            team_repo.join("brain/system-prompt.md"),
+            "Intro\n{% include \"partials/team.md\" %}",
+        )
+        .unwrap();
+        fs::write(team_repo.join("brain/partials/team.md"), "Team: {{ team_name }}").unwrap();
+
+        surface_brain_prompt(&team_repo, &ws_root, &sample_vars()).unwrap();
+        assert_eq!(
+            fs::read_to_string(ws_root.join("brain-prompt.md")).unwrap(),
+            "Intro\nTeam: alpha-team"
+        );
+    }
+
+    #[test]
+    fn gather_collects_roster_projects_and_knowledge() {
+        let tmp = tempfile::tempdir().unwrap();
+        let repo = tmp.path();
+        fs::write(
+            repo.join("botminter.yml"),
+            r#"name: scrum
+display_name: Scrum
+description: d
+version: "1"
+schema_version: "1.0"
+statuses:
+  - { name: "dev:todo", description: Todo }
+  - { name: done, description: Done }
+views:
+  - { name: Dev, prefixes: [dev], also_include: [done] }
+projects:
+  - { name: widget, fork_url: "https://github.com/org/widget" }
+"#,
+        )
+        .unwrap();
+        for m in ["superman-alice", "reviewer-bob"] {
+            fs::create_dir_all(repo.join("members").join(m)).unwrap();
+        }
+        fs::create_dir_all(repo.join("knowledge")).unwrap();
+        fs::write(repo.join("knowledge/commits.md"), "x").unwrap();
+        fs::create_dir_all(repo.join("projects/widget/knowledge")).unwrap();
+        fs::write(repo.join("projects/widget/knowledge/api.md"), "x").unwrap();
+        fs::create_dir_all(repo.join("members/reviewer-bob/knowledge")).unwrap();
+        fs::write(repo.join("members/reviewer-bob/knowledge/notes.md"), "x").unwrap();
+
+        let vars = BrainPromptVars::gather(repo, "superman-alice", "alpha", Some("org/team"));
+        assert_eq!(vars.gh_org, "org");
+        assert_eq!(vars.members.len(), 2);
+        assert!(vars.members.iter().any(|m| m.name == "superman-alice" && m.is_self));
+        assert_eq!(vars.projects[0].name, "widget");
+        assert_eq!(vars.views[0].statuses, vec!["dev:todo", "done"]);
+        assert_eq!(
+            vars.knowledge,
+            vec!["knowledge/commits.md", "projects/widget/knowledge/api.md"],
+            "other members' knowledge is not listed"
+        );
+    }
+
     #[test]
     fn parse_github_repo_valid() {
         let (org, repo) = parse_github_repo("myorg/team-repo").unwrap();
@@ -66,6 +66,10 @@ pub struct BridgeIdentitySpec {
 pub struct BridgeRoomSpec {
     pub create: String,
     pub list: String,
+    /// Optional recipe posting a message to a room (`send <room-id> <message>`).
+    /// The daemon uses it for operator alerts.
+    #[serde(default)]
+    pub send: Option<String>,
 }
 
 /// Persisted bridge state at {workzone}/{team}/bridge-state.json.
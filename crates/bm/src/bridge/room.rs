@@ -146,6 +146,18 @@ impl Bridge {
 
         Ok(None)
     }
+
+    /// Posts a message to a room via the bridge's send recipe.
+    ///
+    /// Returns `Ok(false)` if the bridge declares no send recipe.
+    pub fn send_message(&self, room_id: &str, message: &str) -> Result<bool> {
+        let Some(send_recipe) = self.manifest.spec.room.as_ref().and_then(|r| r.send.clone())
+        else {
+            return Ok(false);
+        };
+        self.invoke_recipe(&send_recipe, &[room_id, message])?;
+        Ok(true)
+    }
 }
 
 #[cfg(test)]
@@ -194,4 +206,13 @@ mod tests {
         let rooms = result.unwrap();
         assert!(!rooms.is_empty());
     }
+
+    #[test]
+    fn send_message_uses_send_recipe() {
+        let (bridge, _tmp) = stub_bridge();
+
+        let sent = bridge.send_message("stub-room-id", "token expiring").unwrap();
+
+        assert!(sent);
+    }
 }
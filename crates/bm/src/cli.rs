@@ -55,6 +55,53 @@ pub enum Command {
         /// Import App credentials from a YAML file (for machine migration)
         #[arg(long)]
         credentials_file: Option<String>,
+
+        /// Forge hosting the team repo: github (default), gitea or forgejo
+        #[arg(long)]
+        forge: Option<String>,
+
+        /// Base URL of the Gitea/Forgejo instance (required with --forge gitea|forgejo)
+        #[arg(long)]
+        forge_url: Option<String>,
+
+        /// GitHub Enterprise Server hostname (default: github.com)
+        #[arg(long)]
+        github_host: Option<String>,
+
+        /// REST API base URL for --github-host (default: https://<host>/api/v3)
+        #[arg(long)]
+        github_api_base: Option<String>,
+    },
+
+    /// Create or reconcile a team from a declarative spec file
+    Apply {
+        /// Path to the team spec YAML
+        #[arg(short, long)]
+        file: String,
+
+        /// Print the plan without making changes
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Fire members not declared in the spec
+        #[arg(long)]
+        prune: bool,
+
+        /// Skip interactive confirmation for destructive plans
+        #[arg(short, long)]
+        yes: bool,
+
+        /// Run `bm teams sync --all` after applying
+        #[arg(long)]
+        sync: bool,
+
+        /// Skip GitHub API calls (for testing)
+        #[arg(long, hide = true)]
+        skip_github: bool,
+
+        /// Override workzone directory (new teams only)
+        #[arg(long)]
+        workzone: Option<String>,
     },
 
     /// Hire a member into a role
@@ -94,6 +141,10 @@ pub enum Command {
         /// Save created App credentials to a file
         #[arg(long)]
         save_credentials: Option<String>,
+
+        /// Access token of the member's bot user (Gitea/Forgejo teams, instead of a GitHub App)
+        #[arg(long, conflicts_with = "reuse_app")]
+        bot_token: Option<String>,
     },
 
     /// Fire a member (stop, uninstall App, remove credentials and directories)
@@ -163,6 +214,34 @@ pub enum Command {
         all: bool,
     },
 
+    /// Pause members without tearing down their loops (all, or a specific one)
+    Pause {
+        /// Optional member to pause (pauses all if omitted)
+        member: Option<String>,
+
+        /// Team to operate on
+        #[arg(short, long)]
+        team: Option<String>,
+
+        /// Freeze Ralph members immediately instead of after the current iteration
+        #[arg(long)]
+        now: bool,
+
+        /// Seconds to wait for the current iteration to finish
+        #[arg(long, default_value = "900")]
+        timeout: u64,
+    },
+
+    /// Resume paused members (all, or a specific one)
+    Resume {
+        /// Optional member to resume (resumes all if omitted)
+        member: Option<String>,
+
+        /// Team to operate on
+        #[arg(short, long)]
+        team: Option<String>,
+    },
+
     /// Status dashboard
     Status {
         /// Team to operate on
@@ -237,12 +316,18 @@ pub enum Command {
         command: ProjectsCommand,
     },
 
-    /// Credential management (export/import for machine migration)
+    /// Credential management (export for machine migration, App key rotation)
     Credentials {
         #[command(subcommand)]
         command: CredentialsCommand,
     },
 
+    /// Query, verify and export the team's audit log
+    Audit {
+        #[command(subcommand)]
+        command: AuditCommand,
+    },
+
     /// Knowledge and invariant management
     Knowledge {
         #[command(subcommand)]
@@ -257,6 +342,12 @@ pub enum Command {
         scope: Option<String>,
     },
 
+    /// Executable invariant checks
+    Invariants {
+        #[command(subcommand)]
+        command: InvariantsCommand,
+    },
+
     /// Bridge service management
     Bridge {
         #[command(subcommand)]
@@ -291,6 +382,10 @@ pub enum Command {
         /// Bind address for the HTTP server
         #[arg(long, default_value = "0.0.0.0")]
         bind: String,
+
+        /// Launch all members once the server is listening
+        #[arg(long)]
+        start_members: bool,
     },
 
     /// Internal: run the brain multiplexer event loop (not user-facing)
@@ -329,6 +424,12 @@ pub enum Command {
         team: Option<String>,
     },
 
+    /// Model Context Protocol server for coding agents
+    Mcp {
+        #[command(subcommand)]
+        command: McpCommand,
+    },
+
     /// Debugging and diagnostic commands
     Debug {
         #[command(subcommand)]
@@ -498,6 +599,62 @@ pub enum MembersCommand {
         #[arg(short, long)]
         team: Option<String>,
     },
+
+    /// Merge profile updates into a member's customised files
+    Refresh {
+        /// Member name (e.g., architect-01)
+        member: Option<String>,
+
+        /// Refresh every hired member
+        #[arg(long)]
+        all: bool,
+
+        /// Show what would change without writing or committing
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Team to operate on
+        #[arg(short, long)]
+        team: Option<String>,
+    },
+
+    /// Archive a member (stop it and keep its App, credentials and workspace for a later restore)
+    Archive {
+        /// Member name (e.g., architect-01)
+        member: String,
+
+        /// Why the member is being archived (recorded in the archive metadata)
+        #[arg(long)]
+        reason: Option<String>,
+
+        /// Team to operate on
+        #[arg(short, long)]
+        team: Option<String>,
+    },
+
+    /// Restore an archived member and re-provision its credentials and bridge identity
+    Restore {
+        /// Archived member name (e.g., architect-01)
+        member: String,
+
+        /// Team to operate on
+        #[arg(short, long)]
+        team: Option<String>,
+    },
+
+    /// Check member GitHub Apps for permission, event and repository drift
+    Doctor {
+        /// Member name (checks every member when omitted)
+        member: Option<String>,
+
+        /// Grant missing repository access without prompting
+        #[arg(long)]
+        fix: bool,
+
+        /// Team to operate on
+        #[arg(short, long)]
+        team: Option<String>,
+    },
 }
 
 #[derive(Subcommand)]
@@ -531,6 +688,44 @@ pub enum ProfilesCommand {
         #[arg(long)]
         force: bool,
     },
+
+    /// Create a profile from a live team's repo
+    Create {
+        /// Name of the new profile (defaults to the team name)
+        name: Option<String>,
+
+        /// Team whose repo to export
+        #[arg(long)]
+        from_team: String,
+
+        /// Restore `+agent:` tags so the profile stays usable with other coding agents
+        #[arg(long)]
+        agent_tags: bool,
+
+        /// Replace an existing profile with the same name
+        #[arg(long)]
+        force: bool,
+    },
+
+    /// Install profiles from a git repository (URL or local path)
+    Add {
+        /// Repository URL or path, optionally pinned with @<branch|tag|commit|version>
+        source: String,
+
+        /// Replace profiles of the same name installed by hand or from another source
+        #[arg(long)]
+        force: bool,
+    },
+
+    /// Fetch and install newer versions of profiles added from git
+    Update {
+        /// Only update this profile
+        profile: Option<String>,
+
+        /// Report available updates and affected teams without installing
+        #[arg(long)]
+        check: bool,
+    },
 }
 
 #[derive(Subcommand)]
@@ -582,6 +777,73 @@ pub enum CredentialsCommand {
         #[arg(short, long)]
         team: Option<String>,
     },
+
+    /// Replace a member's GitHub App private key with a newly generated one
+    Rotate {
+        /// Member whose App key to rotate
+        member: String,
+
+        /// New private key PEM downloaded from the App settings page
+        /// (prompted for interactively when omitted)
+        #[arg(long)]
+        key_file: Option<String>,
+
+        /// Team to operate on
+        #[arg(short, long)]
+        team: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum AuditCommand {
+    /// List audit log entries, newest first
+    List {
+        /// Only entries by this actor (`cli`, `agent:dev-bob`, ...)
+        #[arg(long)]
+        actor: Option<String>,
+
+        /// Only entries for this action (`fire`, `members.start`, ...)
+        #[arg(long)]
+        action: Option<String>,
+
+        /// Only entries at or after this time (RFC 3339 or YYYY-MM-DD)
+        #[arg(long)]
+        since: Option<String>,
+
+        /// Number of entries to show
+        #[arg(short = 'n', long, default_value = "20")]
+        limit: usize,
+
+        /// Team to operate on
+        #[arg(short, long)]
+        team: Option<String>,
+    },
+
+    /// Check the hash chain for edited, removed or reordered entries
+    Verify {
+        /// Team to operate on
+        #[arg(short, long)]
+        team: Option<String>,
+    },
+
+    /// Export the audit log (jsonl keeps hashes, so the export can be verified)
+    Export {
+        /// Output format: jsonl, json or csv
+        #[arg(long, default_value = "jsonl")]
+        format: String,
+
+        /// Output file path (stdout when omitted)
+        #[arg(short, long)]
+        output: Option<String>,
+
+        /// Only entries at or after this time (RFC 3339 or YYYY-MM-DD)
+        #[arg(long)]
+        since: Option<String>,
+
+        /// Team to operate on
+        #[arg(short, long)]
+        team: Option<String>,
+    },
 }
 
 #[derive(Subcommand)]
@@ -606,6 +868,152 @@ pub enum KnowledgeCommand {
         #[arg(short, long)]
         team: Option<String>,
     },
+
+    /// Show the knowledge and invariants a member's agent actually sees
+    Resolve {
+        /// Member directory name (e.g., architect-alice)
+        member: String,
+
+        /// Include project and member-project scopes for this project
+        #[arg(short, long)]
+        project: Option<String>,
+
+        /// Team to operate on
+        #[arg(short, long)]
+        team: Option<String>,
+    },
+
+    /// Add a knowledge/invariant file through a pull request
+    Add {
+        /// File name (e.g., commit-convention.md)
+        name: String,
+
+        /// Target scope: team, project, member, or member-project
+        #[arg(long, default_value = "team")]
+        scope: String,
+
+        /// Project for project and member-project scopes
+        #[arg(short, long)]
+        project: Option<String>,
+
+        /// Member for member and member-project scopes
+        #[arg(long)]
+        member: Option<String>,
+
+        /// Add an invariant instead of knowledge
+        #[arg(long)]
+        invariant: bool,
+
+        /// Read the content from a file instead of opening $EDITOR
+        #[arg(long)]
+        from: Option<String>,
+
+        /// Pull request title (defaults to a summary of the change)
+        #[arg(short, long)]
+        message: Option<String>,
+
+        /// Team to operate on
+        #[arg(short, long)]
+        team: Option<String>,
+    },
+
+    /// Edit a knowledge/invariant file in $EDITOR and open a pull request
+    Edit {
+        /// Path to the file (relative to team repo root)
+        path: String,
+
+        /// Pull request title (defaults to a summary of the change)
+        #[arg(short, long)]
+        message: Option<String>,
+
+        /// Team to operate on
+        #[arg(short, long)]
+        team: Option<String>,
+    },
+
+    /// Move a knowledge/invariant file to another scope through a pull request
+    Move {
+        /// Path to the file (relative to team repo root)
+        path: String,
+
+        /// Destination scope: team, project, member, or member-project
+        #[arg(long)]
+        scope: String,
+
+        /// Project for project and member-project scopes
+        #[arg(short, long)]
+        project: Option<String>,
+
+        /// Member for member and member-project scopes
+        #[arg(long)]
+        member: Option<String>,
+
+        /// Pull request title (defaults to a summary of the change)
+        #[arg(short, long)]
+        message: Option<String>,
+
+        /// Team to operate on
+        #[arg(short, long)]
+        team: Option<String>,
+    },
+
+    /// Search knowledge, invariants, prompts and hats across the team repo
+    Search {
+        /// Search terms (all must match)
+        #[arg(required = true)]
+        query: Vec<String>,
+
+        /// Only files in this scope: team, project, member, or member-project
+        #[arg(long)]
+        scope: Option<String>,
+
+        /// Only files belonging to this member
+        #[arg(long)]
+        member: Option<String>,
+
+        /// Only files belonging to this project
+        #[arg(short, long)]
+        project: Option<String>,
+
+        /// Only this kind of file: knowledge, invariant, prompt, or hats
+        #[arg(long)]
+        kind: Option<String>,
+
+        /// Maximum number of results
+        #[arg(long, default_value_t = 20)]
+        limit: usize,
+
+        /// Team to operate on
+        #[arg(short, long)]
+        team: Option<String>,
+    },
+
+    /// Remove a knowledge/invariant file through a pull request
+    Rm {
+        /// Path to the file (relative to team repo root)
+        path: String,
+
+        /// Pull request title (defaults to a summary of the change)
+        #[arg(short, long)]
+        message: Option<String>,
+
+        /// Team to operate on
+        #[arg(short, long)]
+        team: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum InvariantsCommand {
+    /// Run invariant checks against member workspaces and report violations
+    Check {
+        /// Member to check (default: all members with a workspace)
+        member: Option<String>,
+
+        /// Team to operate on
+        #[arg(short, long)]
+        team: Option<String>,
+    },
 }
 
 #[derive(Subcommand)]
@@ -631,6 +1039,11 @@ pub enum DaemonCommand {
         /// Bind address for the HTTP server
         #[arg(long, default_value = "0.0.0.0")]
         bind: String,
+
+        /// Public URL of the daemon's /webhook endpoint. Creates or updates
+        /// the team repo's webhook to point at it
+        #[arg(long)]
+        webhook_url: Option<String>,
     },
 
     /// Stop the running daemon
@@ -646,6 +1059,84 @@ pub enum DaemonCommand {
         #[arg(short, long)]
         team: Option<String>,
     },
+
+    /// Install systemd user units so the daemon (and bridge) start at boot
+    Install {
+        /// Team to operate on
+        #[arg(short, long)]
+        team: Option<String>,
+
+        /// Daemon mode: webhook or poll
+        #[arg(long, default_value = "poll")]
+        mode: String,
+
+        /// HTTP listener port for webhook mode
+        #[arg(long, default_value = "8484")]
+        port: u16,
+
+        /// Polling interval in seconds for poll mode
+        #[arg(long, default_value = "60")]
+        interval: u64,
+
+        /// Bind address for the HTTP server
+        #[arg(long, default_value = "127.0.0.1")]
+        bind: String,
+
+        /// Also install a unit for the team's bridge
+        #[arg(long)]
+        bridge: bool,
+
+        /// Enable the units without starting them now
+        #[arg(long)]
+        no_start: bool,
+    },
+
+    /// Disable and remove the team's systemd user units
+    Uninstall {
+        /// Team to operate on
+        #[arg(short, long)]
+        team: Option<String>,
+    },
+
+    /// Inspect and replay webhook deliveries received by the daemon
+    Events {
+        #[command(subcommand)]
+        command: DaemonEventsCommand,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum DaemonEventsCommand {
+    /// List recent webhook deliveries, newest first
+    List {
+        /// Team to operate on
+        #[arg(short, long)]
+        team: Option<String>,
+
+        /// Number of deliveries to show
+        #[arg(short = 'n', long, default_value = "20")]
+        limit: usize,
+    },
+
+    /// Show a delivery's headers, payload, validation result and action
+    Show {
+        /// Delivery ID (or a unique prefix of it)
+        id: String,
+
+        /// Team to operate on
+        #[arg(short, long)]
+        team: Option<String>,
+    },
+
+    /// Route a stored delivery again without GitHub redelivering it
+    Replay {
+        /// Delivery ID (or a unique prefix of it)
+        id: String,
+
+        /// Team to operate on
+        #[arg(short, long)]
+        team: Option<String>,
+    },
 }
 
 #[derive(Subcommand)]
@@ -667,6 +1158,35 @@ pub enum DebugCommand {
         #[arg(long, default_value = "30")]
         entries: usize,
     },
+
+    /// Show daemon logs (journald when the daemon runs as a systemd unit)
+    DaemonLogs {
+        /// Team to operate on
+        #[arg(short, long)]
+        team: Option<String>,
+
+        /// Number of lines to show
+        #[arg(short = 'n', long, default_value = "50")]
+        lines: usize,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum McpCommand {
+    /// Serve botminter tools over MCP on stdin/stdout
+    Serve {
+        /// Tool set: read-only (queries) or operator (adds loop start, inbox write, teams sync)
+        #[arg(long, default_value = "read-only")]
+        mode: String,
+
+        /// Pin the server to this team (tools then refuse other teams)
+        #[arg(short, long)]
+        team: Option<String>,
+
+        /// Who operator tool calls are audited as, e.g. `agent:chief-ann` (default: you)
+        #[arg(long)]
+        actor: Option<String>,
+    },
 }
 
 #[derive(Subcommand)]
@@ -0,0 +1,210 @@
+use std::io::IsTerminal;
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+
+use crate::audit::{self, Actor, Record};
+use crate::config;
+use crate::formation;
+use crate::git;
+use crate::member_lifecycle::{self, FireParams, HireParams};
+use crate::profile;
+use crate::team_spec::{self, ApplyAction, TeamState};
+
+/// Options for `bm apply`.
+pub struct ApplyOptions<'a> {
+    pub file: &'a str,
+    pub dry_run: bool,
+    pub prune: bool,
+    pub yes: bool,
+    pub sync: bool,
+    pub skip_github: bool,
+    pub workzone: Option<String>,
+}
+
+/// Handles `bm apply -f <file> [--dry-run] [--prune] [--yes] [--sync]`.
+pub fn run(opts: ApplyOptions<'_>) -> Result<()> {
+    super::ensure_profiles(false)?;
+    let spec = team_spec::load_spec(Path::new(opts.file))?;
+
+    let cfg = config::load_or_default();
+    let existing = cfg.teams.iter().find(|t| t.name == spec.name);
+    let state = existing.map(TeamState::read).transpose()?;
+
+    // Validate against the team repo's manifest when the team exists,
+    // otherwise against the profile on disk.
+    let (manifest, formations) = match existing {
+        Some(team) => {
+            let team_repo = team.path.join("team");
+            (
+                profile::read_team_repo_manifest(&team_repo)?,
+                formation::list_formations(&team_repo)?,
+            )
+        }
+        None => {
+            let manifest = profile::read_manifest(&spec.profile)?;
+            let profile_dir = profile::profiles_dir()?.join(&spec.profile);
+            (manifest, formation::list_formations(&profile_dir)?)
+        }
+    };
+    team_spec::validate_spec(&spec, &manifest, &formations)?;
+
+    let plan = team_spec::plan(&spec, state.as_ref(), opts.prune)?;
+
+    if plan.is_empty() {
+        println!("Team '{}' matches {}. Nothing to do.", spec.name, opts.file);
+        return Ok(());
+    }
+
+    println!("Plan for team '{}':", spec.name);
+    for action in &plan.actions {
+        println!("  {}", action);
+    }
+
+    if opts.dry_run {
+        println!("\nDry run — no changes made.");
+        return Ok(());
+    }
+
+    if plan.is_destructive() && !opts.yes {
+        if !std::io::stdin().is_terminal() {
+            bail!("Plan fires members. Use --yes to confirm: bm apply -f {} --prune --yes", opts.file);
+        }
+        let confirm: bool = cliclack::confirm(
+            "This plan fires members not declared in the spec. Continue?",
+        )
+        .initial_value(false)
+        .interact()?;
+        if !confirm {
+            println!("Aborted.");
+            return Ok(());
+        }
+    }
+
+    println!();
+    let mut hired = Vec::new();
+    for action in &plan.actions {
+        apply_action(action, &spec, &opts)?;
+        if let ApplyAction::Hire { role, name } = action {
+            hired.push((role.clone(), name.clone()));
+        }
+        println!("✓ {}", action);
+    }
+
+    if opts.sync {
+        let skip_remote = opts.skip_github;
+        super::teams::sync(!skip_remote, spec.bridge.is_some() && !skip_remote, false, Some(&spec.name))?;
+    }
+
+    println!("\nApplied {} change(s) to team '{}'.", plan.actions.len(), spec.name);
+    if !hired.is_empty() && !opts.skip_github {
+        println!("New members have no GitHub App identity yet. Create one per member with:");
+        for (role, name) in &hired {
+            println!("  bm hire {} --name {} -t {}", role, name, spec.name);
+        }
+    }
+    if !opts.sync {
+        println!("Run `bm teams sync --all -t {}` to push and provision workspaces.", spec.name);
+    }
+    if let Some(ref f) = spec.formation {
+        println!("Start members with `bm start -t {} --formation {}`.", spec.name, f);
+    }
+
+    Ok(())
+}
+
+/// Executes a single plan action. Config is re-read per action because
+/// earlier actions (team creation, coding agent) mutate it.
+fn apply_action(action: &ApplyAction, spec: &team_spec::TeamSpec, opts: &ApplyOptions<'_>) -> Result<()> {
+    if let ApplyAction::CreateTeam { .. } = action {
+        let gh = spec.github.as_ref().context("Spec has no github section")?;
+        let board = gh
+            .project_board
+            .clone()
+            .unwrap_or_else(|| format!("{} Board", spec.name));
+        return super::init::run_non_interactive(
+            Some(spec.profile.clone()),
+            Some(spec.name.clone()),
+            Some(gh.org.clone()),
+            Some(gh.repo.clone()),
+            None,
+            Some(board),
+            spec.bridge.clone(),
+            opts.skip_github,
+            opts.workzone.clone(),
+            None,
+            None,
+            None,
+            gh.host.clone(),
+            gh.api_base.clone(),
+        );
+    }
+
+    let mut cfg = config::load()?;
+    let team = config::resolve_team(&cfg, Some(&spec.name))?.clone();
+    let team_repo = team.path.join("team");
+
+    match action {
+        ApplyAction::CreateTeam { .. } => unreachable!("handled above"),
+        ApplyAction::SetCodingAgent { agent } => {
+            if let Some(entry) = cfg.teams.iter_mut().find(|t| t.name == team.name) {
+                entry.coding_agent = Some(agent.clone());
+            }
+            config::save(&cfg)?;
+        }
+        ApplyAction::SetBridge { bridge } => {
+            let manifest = profile::read_team_repo_manifest(&team_repo)?;
+            profile::record_bridge_in_manifest(&team_repo, bridge, &manifest.bridges)?;
+            git::run_git(&team_repo, &["add", "botminter.yml"])?;
+            git::run_git(&team_repo, &["commit", "-m", &format!("feat: select bridge {}", bridge)])?;
+        }
+        ApplyAction::AddProject { url, .. } => {
+            let github_repo = if opts.skip_github { "" } else { team.github_repo.as_str() };
+            git::add_project(&git::GitHubHost::for_team(&team), &team_repo, url, github_repo)?;
+        }
+        ApplyAction::Hire { role, name } => {
+            let result = member_lifecycle::hire_member(&HireParams {
+                team: &team,
+                role,
+                name: Some(name),
+                app_credentials: None,
+                save_credentials_path: None,
+            });
+            let member_dir = match &result {
+                Ok(r) => r.member_dir_name.clone(),
+                Err(_) => format!("{}-{}", role, name),
+            };
+            let record = Record::new(Actor::cli(), "hire")
+                .target(Some(&member_dir))
+                .param("role", role.as_str())
+                .param("spec", opts.file)
+                .outcome(&result);
+            audit::record_for_team(&team.name, record);
+            result?;
+        }
+        ApplyAction::Fire { member } => {
+            let local_formation = formation::create_local_formation(&team.name)?;
+            let result = member_lifecycle::fire_member(
+                &FireParams {
+                    team: &team,
+                    config: &cfg,
+                    member,
+                    keep_app: false,
+                    delete_repo: false,
+                },
+                &*local_formation,
+            );
+            let record = Record::new(Actor::cli(), "fire")
+                .target(Some(member.as_str()))
+                .param("spec", opts.file)
+                .outcome(&result);
+            audit::record_for_team(&team.name, record);
+            let result = result?;
+            for e in &result.errors {
+                eprintln!("  Warning [{}]: {}", e.step, e.error);
+            }
+        }
+    }
+
+    Ok(())
+}
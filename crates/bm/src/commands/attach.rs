@@ -104,6 +104,8 @@ mod tests {
             project_number: None,
             bridge_lifecycle: Default::default(),
             vm: vm.map(String::from),
+            profile_source: None,
+            forge: None,
         }
     }
 
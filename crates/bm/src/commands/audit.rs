@@ -0,0 +1,232 @@
+use std::fs;
+
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, NaiveDate, Utc};
+use comfy_table::{
+    modifiers::UTF8_ROUND_CORNERS, presets::UTF8_FULL_CONDENSED, ContentArrangement, Table,
+};
+
+use crate::audit::{self, AuditEntry, Outcome};
+use crate::config;
+use crate::daemon::DaemonPaths;
+
+/// Handles `bm audit list`.
+pub fn list(
+    actor: Option<&str>,
+    action: Option<&str>,
+    since: Option<&str>,
+    limit: usize,
+    team_flag: Option<&str>,
+) -> Result<()> {
+    let (team_name, entries) = load_team_log(team_flag)?;
+    let since = since.map(parse_since).transpose()?;
+
+    let matching: Vec<&AuditEntry> = entries
+        .iter()
+        .rev()
+        .filter(|e| actor.is_none_or(|a| actor_matches(e, a)))
+        .filter(|e| action.is_none_or(|a| e.action == a))
+        .filter(|e| since.is_none_or(|s| after(e, &s)))
+        .collect();
+
+    if matching.is_empty() {
+        println!("No audit entries recorded for team '{}'.", team_name);
+        return Ok(());
+    }
+
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL_CONDENSED)
+        .apply_modifier(UTF8_ROUND_CORNERS)
+        .set_content_arrangement(ContentArrangement::DynamicFullWidth)
+        .set_header(vec!["#", "Time", "Actor", "Action", "Target", "Outcome"]);
+
+    for e in matching.iter().take(limit) {
+        let outcome = match (&e.outcome, &e.error) {
+            (Outcome::Error, Some(err)) => format!("error: {}", err),
+            (Outcome::Error, None) => "error".to_string(),
+            (Outcome::Ok, _) => "ok".to_string(),
+        };
+        table.add_row(vec![
+            e.seq.to_string(),
+            format_timestamp(&e.ts),
+            e.actor.to_string(),
+            e.action.clone(),
+            e.target.clone().unwrap_or_else(|| "-".to_string()),
+            outcome,
+        ]);
+    }
+
+    println!("{table}");
+    if matching.len() > limit {
+        println!(
+            "Showing {} of {} entries. Use -n to show more.",
+            limit,
+            matching.len()
+        );
+    }
+    Ok(())
+}
+
+/// Handles `bm audit verify`.
+pub fn verify(team_flag: Option<&str>) -> Result<()> {
+    let (team_name, entries) = load_team_log(team_flag)?;
+    let count = audit::verify(&entries)
+        .with_context(|| format!("Audit log for team '{}' failed verification", team_name))?;
+    println!(
+        "Audit log for team '{}' is intact ({} entries).",
+        team_name, count
+    );
+    Ok(())
+}
+
+/// Handles `bm audit export`.
+pub fn export(
+    format: &str,
+    output: Option<&str>,
+    since: Option<&str>,
+    team_flag: Option<&str>,
+) -> Result<()> {
+    let (team_name, entries) = load_team_log(team_flag)?;
+    let since = since.map(parse_since).transpose()?;
+    let entries: Vec<&AuditEntry> = entries
+        .iter()
+        .filter(|e| since.is_none_or(|s| after(e, &s)))
+        .collect();
+
+    let contents = match format {
+        "jsonl" => entries
+            .iter()
+            .map(|e| serde_json::to_string(e).map(|line| line + "\n"))
+            .collect::<serde_json::Result<String>>()?,
+        "json" => serde_json::to_string_pretty(&entries)? + "\n",
+        "csv" => to_csv(&entries),
+        other => bail!(
+            "Unknown export format '{}' (expected jsonl, json or csv)",
+            other
+        ),
+    };
+
+    match output {
+        Some(path) => {
+            fs::write(path, contents).with_context(|| format!("Failed to write {}", path))?;
+            eprintln!(
+                "Exported {} audit entries for team '{}' to {}",
+                entries.len(),
+                team_name,
+                path
+            );
+        }
+        None => print!("{}", contents),
+    }
+    Ok(())
+}
+
+fn load_team_log(team_flag: Option<&str>) -> Result<(String, Vec<AuditEntry>)> {
+    let cfg = config::load()?;
+    let team = config::resolve_team(&cfg, team_flag)?;
+    let entries = audit::load(&DaemonPaths::new(&team.name)?.audit_log())?;
+    Ok((team.name.clone(), entries))
+}
+
+/// `--actor` matches the kind (`cli`) or kind and name (`agent:dev-bob`).
+fn actor_matches(entry: &AuditEntry, filter: &str) -> bool {
+    entry.actor.kind.label() == filter || entry.actor.to_string() == filter
+}
+
+fn after(entry: &AuditEntry, since: &DateTime<Utc>) -> bool {
+    DateTime::parse_from_rfc3339(&entry.ts).is_ok_and(|ts| ts >= *since)
+}
+
+/// Parses `--since` as an RFC 3339 timestamp or a `YYYY-MM-DD` date (UTC midnight).
+fn parse_since(value: &str) -> Result<DateTime<Utc>> {
+    if let Ok(ts) = DateTime::parse_from_rfc3339(value) {
+        return Ok(ts.with_timezone(&Utc));
+    }
+    match NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        Ok(date) => Ok(date.and_hms_opt(0, 0, 0).unwrap_or_default().and_utc()),
+        Err(_) => bail!(
+            "Invalid --since '{}' (expected RFC 3339, e.g. 2026-03-24T10:00:00Z, or YYYY-MM-DD)",
+            value
+        ),
+    }
+}
+
+fn to_csv(entries: &[&AuditEntry]) -> String {
+    let mut out = String::from("seq,ts,actor,action,target,params,outcome,error,prev_hash,hash\n");
+    for e in entries {
+        let params = if e.params.is_empty() {
+            String::new()
+        } else {
+            serde_json::to_string(&e.params).unwrap_or_default()
+        };
+        let outcome = match e.outcome {
+            Outcome::Ok => "ok",
+            Outcome::Error => "error",
+        };
+        let fields = [
+            e.seq.to_string(),
+            e.ts.clone(),
+            e.actor.to_string(),
+            e.action.clone(),
+            e.target.clone().unwrap_or_default(),
+            params,
+            outcome.to_string(),
+            e.error.clone().unwrap_or_default(),
+            e.prev_hash.clone(),
+            e.hash.clone(),
+        ];
+        let row: Vec<String> = fields.iter().map(|f| csv_field(f)).collect();
+        out.push_str(&row.join(","));
+        out.push('\n');
+    }
+    out
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn format_timestamp(ts: &str) -> String {
+    if let Ok(dt) = DateTime::parse_from_rfc3339(ts) {
+        dt.format("%Y-%m-%d %H:%M:%S UTC").to_string()
+    } else {
+        ts.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audit::{Actor, Record};
+
+    #[test]
+    fn parse_since_accepts_rfc3339_and_dates() {
+        let ts = parse_since("2026-03-24T10:00:00+02:00").unwrap();
+        assert_eq!(ts.to_rfc3339(), "2026-03-24T08:00:00+00:00");
+        let date = parse_since("2026-03-24").unwrap();
+        assert_eq!(date.to_rfc3339(), "2026-03-24T00:00:00+00:00");
+        assert!(parse_since("yesterday").is_err());
+    }
+
+    #[test]
+    fn csv_quotes_fields_and_keeps_params_as_json() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = audit::log_path(tmp.path(), "team");
+        let record = Record::new(Actor::agent("dev-bob"), "files.write")
+            .target(Some("knowledge/a, b.md"))
+            .param("bytes", 12);
+        let entry = audit::append(&path, record).unwrap();
+
+        let csv = to_csv(&[&entry]);
+        let row = csv.lines().nth(1).unwrap();
+        assert!(row.starts_with("1,"), "{}", row);
+        assert!(row.contains(",agent:dev-bob,files.write,\"knowledge/a, b.md\","));
+        assert!(row.contains(",\"{\"\"bytes\"\":12}\",ok,,"), "{}", row);
+        assert!(row.ends_with(&entry.hash));
+    }
+}
@@ -7,6 +7,8 @@ use crate::brain::{
     bridge_adapter::{self, MatrixBridgeConfig, MatrixBridgeReader, MatrixBridgeWriter},
     EventWatcher, EventWatcherConfig, Heartbeat, HeartbeatConfig, Multiplexer, MultiplexerConfig,
 };
+use crate::profile;
+use crate::schedule::{self, ResolvedSchedule};
 
 /// Runs the brain multiplexer event loop.
 ///
@@ -107,7 +109,7 @@ async fn run_brain(
     });
 
     // Spawn heartbeat
-    let heartbeat_config = HeartbeatConfig::default();
+    let heartbeat_config = HeartbeatConfig::from_schedule(resolve_schedule(&workspace));
     let (heartbeat, heartbeat_shutdown, _pending) =
         Heartbeat::new(heartbeat_config, heartbeat_sender);
     let heartbeat_handle = tokio::spawn(async move {
@@ -131,6 +133,33 @@ async fn run_brain(
         }
     });
 
+    // SIGUSR1 pauses intake and SIGUSR2 resumes it (sent by `bm pause` / `bm resume`)
+    let pause = mux.pause_handle();
+    tokio::spawn(async move {
+        use tokio::signal::unix::{signal, SignalKind};
+        let (mut pause_sig, mut resume_sig) =
+            match (signal(SignalKind::user_defined1()), signal(SignalKind::user_defined2())) {
+                (Ok(p), Ok(r)) => (p, r),
+                (Err(e), _) | (_, Err(e)) => {
+                    tracing::error!("Failed to register pause/resume handlers: {e}");
+                    return;
+                }
+            };
+        loop {
+            tokio::select! {
+                Some(()) = pause_sig.recv() => {
+                    tracing::info!("Received SIGUSR1, pausing brain");
+                    pause.pause().await;
+                }
+                Some(()) = resume_sig.recv() => {
+                    tracing::info!("Received SIGUSR2, resuming brain");
+                    pause.resume().await;
+                }
+                else => break,
+            }
+        }
+    });
+
     // Run the multiplexer (blocks until shutdown)
     let result = mux.run().await;
 
@@ -184,6 +213,24 @@ fn resolve_bridge_config(workspace: &std::path::Path) -> Option<MatrixBridgeConf
     })
 }
 
+/// Resolves this member's schedule from `schedule.yml` in the team repo
+/// (`BM_TEAM_REPO`). The member name is the workspace directory name.
+fn resolve_schedule(workspace: &std::path::Path) -> Option<ResolvedSchedule> {
+    let team_repo = PathBuf::from(std::env::var("BM_TEAM_REPO").ok()?);
+    let member = workspace.file_name()?.to_string_lossy().to_string();
+    let role = profile::read_member_role(&team_repo.join("members"), &member);
+    let resolved = schedule::load(&team_repo)
+        .and_then(|cfg| cfg.map(|c| c.resolve(&role, &member)).transpose())
+        .map(Option::flatten);
+    match resolved {
+        Ok(s) => s,
+        Err(e) => {
+            tracing::warn!("Ignoring schedule: {e:#}");
+            None
+        }
+    }
+}
+
 /// Keys that `collect_env_vars` forwards to the ACP child process.
 const ENV_VAR_ALLOWLIST: &[&str] = &[
     // Essential system
@@ -8,6 +8,7 @@ use clap_complete::{ArgValueCandidates, Shell};
 
 use crate::cli::Cli;
 use crate::config::{self, BotminterConfig, TeamEntry};
+use crate::daemon;
 use crate::formation;
 use crate::profile;
 
@@ -80,6 +81,18 @@ impl CompletionContext {
             .and_then(|repo| formation::list_formations(repo).ok())
             .unwrap_or_default()
     }
+
+    /// Stored webhook delivery IDs of the default team, newest first.
+    fn delivery_ids(&self) -> Vec<String> {
+        let (Some(team), Ok(dir)) = (self.team.as_ref(), config::config_dir()) else {
+            return Vec::new();
+        };
+        daemon::load_deliveries(&daemon::deliveries_path(&dir, &team.name))
+            .into_iter()
+            .rev()
+            .map(|d| d.id)
+            .collect()
+    }
 }
 
 /// Build a `clap::Command` with dynamic completion values attached.
@@ -96,6 +109,7 @@ pub fn build_cli_with_completions() -> clap::Command {
     let members = ctx.member_names();
     let projects = ctx.project_names();
     let formations = ctx.formation_names();
+    let deliveries = ctx.delivery_ids();
 
     let bridges: Vec<String> = {
         // Collect bridge names from all profiles
@@ -115,18 +129,28 @@ pub fn build_cli_with_completions() -> clap::Command {
     };
 
     let daemon_modes: Vec<String> = vec!["webhook".into(), "poll".into()];
+    let mcp_modes: Vec<String> = vec!["read-only".into(), "operator".into()];
+    let audit_formats: Vec<String> = vec!["jsonl".into(), "json".into(), "csv".into()];
+    let forges: Vec<String> = vec!["github".into(), "gitea".into(), "forgejo".into()];
     let knowledge_scopes: Vec<String> = vec![
         "team".into(),
         "project".into(),
         "member".into(),
         "member-project".into(),
     ];
+    let search_kinds: Vec<String> = vec![
+        "knowledge".into(),
+        "invariant".into(),
+        "prompt".into(),
+        "hats".into(),
+    ];
 
     Cli::command()
         // ── init ──────────────────────────────────────────────
         .mut_subcommand("init", |c| {
             c.mut_arg("profile", |a| a.add(make(profiles.clone())))
                 .mut_arg("bridge", |a| a.add(make(bridges)))
+                .mut_arg("forge", |a| a.add(make(forges)))
         })
         // ── hire ──────────────────────────────────────────────
         .mut_subcommand("hire", |c| {
@@ -180,7 +204,11 @@ pub fn build_cli_with_completions() -> clap::Command {
                 s.mut_arg("team", |a| a.add(make(teams.clone())))
             })
             .mut_subcommand("show", |s| {
-                s.mut_arg("member", |a| a.add(make(members)))
+                s.mut_arg("member", |a| a.add(make(members.clone())))
+                    .mut_arg("team", |a| a.add(make(teams.clone())))
+            })
+            .mut_subcommand("refresh", |s| {
+                s.mut_arg("member", |a| a.add(make(members.clone())))
                     .mut_arg("team", |a| a.add(make(teams.clone())))
             })
         })
@@ -193,8 +221,14 @@ pub fn build_cli_with_completions() -> clap::Command {
         // ── profiles ──────────────────────────────────────────
         .mut_subcommand("profiles", |c| {
             c.mut_subcommand("describe", |s| {
+                s.mut_arg("profile", |a| a.add(make(profiles.clone())))
+            })
+            .mut_subcommand("update", |s| {
                 s.mut_arg("profile", |a| a.add(make(profiles)))
             })
+            .mut_subcommand("create", |s| {
+                s.mut_arg("from_team", |a| a.add(make(teams.clone())))
+            })
         })
         // ── projects ──────────────────────────────────────────
         .mut_subcommand("projects", |c| {
@@ -202,7 +236,7 @@ pub fn build_cli_with_completions() -> clap::Command {
                 s.mut_arg("team", |a| a.add(make(teams.clone())))
             })
             .mut_subcommand("show", |s| {
-                s.mut_arg("project", |a| a.add(make(projects)))
+                s.mut_arg("project", |a| a.add(make(projects.clone())))
                     .mut_arg("team", |a| a.add(make(teams.clone())))
             })
             .mut_subcommand("add", |s| {
@@ -218,11 +252,48 @@ pub fn build_cli_with_completions() -> clap::Command {
                 .mut_arg("scope", |a| a.add(make(knowledge_scopes.clone())))
                 .mut_subcommand("list", |s| {
                     s.mut_arg("team", |a| a.add(make(teams.clone())))
-                        .mut_arg("scope", |a| a.add(make(knowledge_scopes)))
+                        .mut_arg("scope", |a| a.add(make(knowledge_scopes.clone())))
                 })
                 .mut_subcommand("show", |s| {
                     s.mut_arg("team", |a| a.add(make(teams.clone())))
                 })
+                .mut_subcommand("resolve", |s| {
+                    s.mut_arg("member", |a| a.add(make(members.clone())))
+                        .mut_arg("project", |a| a.add(make(projects.clone())))
+                        .mut_arg("team", |a| a.add(make(teams.clone())))
+                })
+                .mut_subcommand("add", |s| {
+                    s.mut_arg("scope", |a| a.add(make(knowledge_scopes.clone())))
+                        .mut_arg("project", |a| a.add(make(projects.clone())))
+                        .mut_arg("member", |a| a.add(make(members.clone())))
+                        .mut_arg("team", |a| a.add(make(teams.clone())))
+                })
+                .mut_subcommand("edit", |s| {
+                    s.mut_arg("team", |a| a.add(make(teams.clone())))
+                })
+                .mut_subcommand("move", |s| {
+                    s.mut_arg("scope", |a| a.add(make(knowledge_scopes.clone())))
+                        .mut_arg("project", |a| a.add(make(projects.clone())))
+                        .mut_arg("member", |a| a.add(make(members.clone())))
+                        .mut_arg("team", |a| a.add(make(teams.clone())))
+                })
+                .mut_subcommand("rm", |s| {
+                    s.mut_arg("team", |a| a.add(make(teams.clone())))
+                })
+                .mut_subcommand("search", |s| {
+                    s.mut_arg("scope", |a| a.add(make(knowledge_scopes)))
+                        .mut_arg("project", |a| a.add(make(projects)))
+                        .mut_arg("member", |a| a.add(make(members.clone())))
+                        .mut_arg("kind", |a| a.add(make(search_kinds)))
+                        .mut_arg("team", |a| a.add(make(teams.clone())))
+                })
+        })
+        // ── invariants ────────────────────────────────────────
+        .mut_subcommand("invariants", |c| {
+            c.mut_subcommand("check", |s| {
+                s.mut_arg("member", |a| a.add(make(members)))
+                    .mut_arg("team", |a| a.add(make(teams.clone())))
+            })
         })
         // ── minty ─────────────────────────────────────────────
         .mut_subcommand("minty", |c| {
@@ -234,6 +305,26 @@ pub fn build_cli_with_completions() -> clap::Command {
                 s.mut_arg("team", |a| a.add(make(teams.clone())))
             })
         })
+        // ── audit ─────────────────────────────────────────────
+        .mut_subcommand("audit", |c| {
+            c.mut_subcommand("list", |s| {
+                s.mut_arg("team", |a| a.add(make(teams.clone())))
+            })
+            .mut_subcommand("verify", |s| {
+                s.mut_arg("team", |a| a.add(make(teams.clone())))
+            })
+            .mut_subcommand("export", |s| {
+                s.mut_arg("team", |a| a.add(make(teams.clone())))
+                    .mut_arg("format", |a| a.add(make(audit_formats)))
+            })
+        })
+        // ── mcp ───────────────────────────────────────────────
+        .mut_subcommand("mcp", |c| {
+            c.mut_subcommand("serve", |s| {
+                s.mut_arg("team", |a| a.add(make(teams.clone())))
+                    .mut_arg("mode", |a| a.add(make(mcp_modes)))
+            })
+        })
         // ── daemon ────────────────────────────────────────────
         .mut_subcommand("daemon", |c| {
             c.mut_subcommand("start", |s| {
@@ -246,6 +337,19 @@ pub fn build_cli_with_completions() -> clap::Command {
             .mut_subcommand("status", |s| {
                 s.mut_arg("team", |a| a.add(make(teams.clone())))
             })
+            .mut_subcommand("events", |s| {
+                s.mut_subcommand("list", |e| {
+                    e.mut_arg("team", |a| a.add(make(teams.clone())))
+                })
+                .mut_subcommand("show", |e| {
+                    e.mut_arg("id", |a| a.add(make(deliveries.clone())))
+                        .mut_arg("team", |a| a.add(make(teams.clone())))
+                })
+                .mut_subcommand("replay", |e| {
+                    e.mut_arg("id", |a| a.add(make(deliveries)))
+                        .mut_arg("team", |a| a.add(make(teams.clone())))
+                })
+            })
         })
 }
 
@@ -323,6 +427,7 @@ mod tests {
         assert!(ctx.member_names().is_empty());
         assert!(ctx.project_names().is_empty());
         assert!(ctx.formation_names().is_empty());
+        assert!(ctx.delivery_ids().is_empty());
     }
 
     #[test]
@@ -356,6 +461,8 @@ mod tests {
                         project_number: None,
                         bridge_lifecycle: Default::default(),
                         vm: None,
+                        profile_source: None,
+                        forge: None,
                     },
                     TeamEntry {
                         name: "beta".into(),
@@ -367,6 +474,8 @@ mod tests {
                         project_number: None,
                         bridge_lifecycle: Default::default(),
                         vm: None,
+                        profile_source: None,
+                        forge: None,
                     },
                 ],
                 keyring_collection: None,
@@ -394,6 +503,8 @@ mod tests {
                 project_number: None,
                 bridge_lifecycle: Default::default(),
                 vm: None,
+                profile_source: None,
+                forge: None,
             }),
             team_repo: None,
         };
@@ -467,9 +578,10 @@ projects:
     #[test]
     fn all_commands_covered_by_completions() {
         use crate::cli::{
-            BridgeCommand, BridgeIdentityCommand, BridgeRoomCommand, Command, CredentialsCommand,
-            DaemonCommand, DebugCommand, EnvCommand, KnowledgeCommand, MembersCommand,
-            ProfilesCommand, ProjectsCommand, RolesCommand, RuntimeCommand, TeamsCommand,
+            AuditCommand, BridgeCommand, BridgeIdentityCommand, BridgeRoomCommand, Command,
+            CredentialsCommand, DaemonCommand, DaemonEventsCommand, DebugCommand, EnvCommand,
+            InvariantsCommand, KnowledgeCommand, McpCommand, MembersCommand, ProfilesCommand,
+            ProjectsCommand, RolesCommand, RuntimeCommand, TeamsCommand,
         };
 
         // This exhaustive match ensures that if a new Command variant is
@@ -478,12 +590,15 @@ projects:
         fn _assert_variant_exists(cmd: &Command) {
             match cmd {
                 Command::Init { .. } => {}
+                Command::Apply { .. } => {}
                 Command::Hire { .. } => {}
                 Command::Fire { .. } => {}
                 Command::Chat { .. } => {}
                 Command::Minty { .. } => {}
                 Command::Start { .. } => {}
                 Command::Stop { .. } => {}
+                Command::Pause { .. } => {}
+                Command::Resume { .. } => {}
                 Command::Status { .. } => {}
                 Command::Teams { command } => match command {
                     TeamsCommand::List => {}
@@ -501,6 +616,10 @@ projects:
                 Command::Members { command } => match command {
                     MembersCommand::List { .. } => {}
                     MembersCommand::Show { .. } => {}
+                    MembersCommand::Refresh { .. } => {}
+                    MembersCommand::Archive { .. } => {}
+                    MembersCommand::Restore { .. } => {}
+                    MembersCommand::Doctor { .. } => {}
                 },
                 Command::Roles { command } => match command {
                     RolesCommand::List { .. } => {}
@@ -509,6 +628,9 @@ projects:
                     ProfilesCommand::List => {}
                     ProfilesCommand::Describe { .. } => {}
                     ProfilesCommand::Init { .. } => {}
+                    ProfilesCommand::Create { .. } => {}
+                    ProfilesCommand::Add { .. } => {}
+                    ProfilesCommand::Update { .. } => {}
                 },
                 Command::Projects { command } => match command {
                     ProjectsCommand::List { .. } => {}
@@ -518,12 +640,27 @@ projects:
                 },
                 Command::Credentials { command } => match command {
                     CredentialsCommand::Export { .. } => {}
+                    CredentialsCommand::Rotate { .. } => {}
+                },
+                Command::Audit { command } => match command {
+                    AuditCommand::List { .. } => {}
+                    AuditCommand::Verify { .. } => {}
+                    AuditCommand::Export { .. } => {}
                 },
                 Command::Knowledge { command, .. } => match command {
                     Some(KnowledgeCommand::List { .. }) => {}
                     Some(KnowledgeCommand::Show { .. }) => {}
+                    Some(KnowledgeCommand::Resolve { .. }) => {}
+                    Some(KnowledgeCommand::Add { .. }) => {}
+                    Some(KnowledgeCommand::Edit { .. }) => {}
+                    Some(KnowledgeCommand::Move { .. }) => {}
+                    Some(KnowledgeCommand::Rm { .. }) => {}
+                    Some(KnowledgeCommand::Search { .. }) => {}
                     None => {}
                 },
+                Command::Invariants { command } => match command {
+                    InvariantsCommand::Check { .. } => {}
+                },
                 Command::Bridge { command } => match command {
                     BridgeCommand::Start { .. } => {}
                     BridgeCommand::Stop { .. } => {}
@@ -545,12 +682,23 @@ projects:
                     DaemonCommand::Start { .. } => {}
                     DaemonCommand::Stop { .. } => {}
                     DaemonCommand::Status { .. } => {}
+                    DaemonCommand::Install { .. } => {}
+                    DaemonCommand::Uninstall { .. } => {}
+                    DaemonCommand::Events { command } => match command {
+                        DaemonEventsCommand::List { .. } => {}
+                        DaemonEventsCommand::Show { .. } => {}
+                        DaemonEventsCommand::Replay { .. } => {}
+                    },
                 },
                 Command::DaemonRun { .. } => {}
                 Command::BrainRun { .. } => {}
                 Command::Attach { .. } => {}
+                Command::Mcp { command } => match command {
+                    McpCommand::Serve { .. } => {}
+                },
                 Command::Debug { command } => match command {
                     DebugCommand::BrainLogs { .. } => {}
+                    DebugCommand::DaemonLogs { .. } => {}
                 },
                 Command::Completions { .. } => {}
             }
@@ -569,6 +717,7 @@ projects:
         assert!(cmd.find_subcommand("projects").is_some());
         assert!(cmd.find_subcommand("daemon").is_some());
         assert!(cmd.find_subcommand("knowledge").is_some());
+        assert!(cmd.find_subcommand("invariants").is_some());
         assert!(cmd.find_subcommand("teams").is_some());
         assert!(cmd.find_subcommand("runtime").is_some());
     }
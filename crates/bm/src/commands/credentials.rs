@@ -1,12 +1,15 @@
 use std::fs;
+use std::io::IsTerminal;
 use std::os::unix::fs::PermissionsExt;
 
 use anyhow::{bail, Context, Result};
 
 use crate::bridge;
 use crate::config;
-use crate::formation::{self, CredentialDomain};
-use crate::git::manifest_flow::credential_keys;
+use crate::daemon::{DaemonClient, ReloadCredentialsRequest};
+use crate::formation::{self, CredentialDomain, KeyValueCredentialStore};
+use crate::git::manifest_flow::{self, credential_keys};
+use crate::git::{app_auth, GitHubHost};
 use crate::workspace;
 
 /// Handles `bm credentials export -o <file> [-t team]`.
@@ -122,11 +125,181 @@ pub fn export(output: &str, team_flag: Option<&str>) -> Result<()> {
     Ok(())
 }
 
+/// Handles `bm credentials rotate <member> [--key-file <pem>] [-t team]`.
+///
+/// GitHub has no API for creating App private keys, so the new key is
+/// generated on the App's settings page and handed to us as a PEM file.
+/// The key is verified by minting an installation token before it replaces
+/// the old one in the credential store; a running daemon is then asked to
+/// reload it so the member's token refresh signs with the new key.
+pub fn rotate(member: &str, key_file: Option<&str>, team_flag: Option<&str>) -> Result<()> {
+    let cfg = config::load()?;
+    let team = config::resolve_team(&cfg, team_flag)?;
+
+    let members = workspace::list_member_dirs(&team.path.join("team").join("members"))?;
+    if !members.iter().any(|m| m == member) {
+        bail!("Member '{}' not found in team '{}'.", member, team.name);
+    }
+
+    let formation = formation::create_local_formation(&team.name)?;
+    let store = formation.credential_store(CredentialDomain::GitHubApp {
+        team_name: team.name.clone(),
+        member_name: member.to_string(),
+    })?;
+
+    let client_id = store.retrieve(&credential_keys::client_id(member))?;
+    let old_key = store.retrieve(&credential_keys::private_key(member))?;
+    let installation_id = store.retrieve(&credential_keys::installation_id(member))?;
+    let (Some(client_id), Some(old_key), Some(installation_id)) =
+        (client_id, old_key, installation_id)
+    else {
+        bail!("Member '{}' has no GitHub App credentials to rotate.", member);
+    };
+    let installation_id: u64 = installation_id
+        .parse()
+        .context("Stored installation ID is not a number")?;
+
+    let host = GitHubHost::for_team(team);
+    let org = team.github_repo.split('/').next().unwrap_or_default();
+    let slug = app_auth::generate_jwt(&client_id, &old_key)
+        .and_then(|jwt| app_auth::get_app_slug(&host.api_base, &jwt))
+        .unwrap_or_else(|_| manifest_flow::app_name_to_slug(&format!("{}-{}", team.name, member)));
+    let settings_url = app_settings_url(&host, org, &slug);
+
+    let key_path = match key_file {
+        Some(path) => path.to_string(),
+        None => prompt_for_key_file(member, &settings_url)?,
+    };
+    let new_key = fs::read_to_string(&key_path)
+        .with_context(|| format!("Failed to read private key: {key_path}"))?;
+    if new_key.trim() == old_key.trim() {
+        bail!(
+            "{} is the key '{}' already uses. Generate a new key at {}",
+            key_path,
+            member,
+            settings_url
+        );
+    }
+
+    // Prove GitHub accepts the key before the old one is replaced.
+    let jwt = app_auth::generate_jwt(&client_id, &new_key)
+        .with_context(|| format!("{key_path} is not a usable App private key"))?;
+    app_auth::exchange_for_installation_token(&host.api_base, &jwt, installation_id)
+        .with_context(|| {
+            format!("GitHub rejected the new key. Is {key_path} from the '{slug}' App?")
+        })?;
+
+    swap_private_key(store.as_ref(), member, &new_key, &chrono::Utc::now().to_rfc3339())?;
+    println!("Rotated the GitHub App private key for '{}'.", member);
+
+    match DaemonClient::connect(&team.name) {
+        Ok(client) => {
+            let resp = client.reload_credentials(&ReloadCredentialsRequest {
+                member: member.to_string(),
+            })?;
+            if !resp.reloaded {
+                println!("Daemon is not refreshing a token for '{}'; nothing to reload.", member);
+            } else if resp.ok {
+                println!(
+                    "Daemon reloaded the key; new token valid until {}.",
+                    resp.expires_at.as_deref().unwrap_or("unknown")
+                );
+            } else {
+                bail!(
+                    "Daemon could not refresh '{}' with the new key: {}",
+                    member,
+                    resp.error.unwrap_or_default()
+                );
+            }
+        }
+        Err(_) => {
+            println!("No daemon running; the new key is used the next time '{}' starts.", member);
+        }
+    }
+
+    let reminder = format!(
+        "Delete the old key under 'Private keys' at {settings_url} — it stays valid until \
+         deleted. Keep only the newest key, then remove {key_path} from disk."
+    );
+    if std::io::stdin().is_terminal() {
+        cliclack::log::warning(&reminder)?;
+        let deleted = cliclack::confirm("Have you deleted the old private key?")
+            .initial_value(false)
+            .interact()?;
+        if !deleted {
+            eprintln!("Warning: the old private key for '{}' is still valid.", member);
+        }
+    } else {
+        println!("{reminder}");
+    }
+
+    Ok(())
+}
+
+/// Browser URL of an organization-owned App's settings page.
+fn app_settings_url(host: &GitHubHost, org: &str, slug: &str) -> String {
+    format!("{}/organizations/{}/settings/apps/{}", host.web_base(), org, slug)
+}
+
+/// Walks the operator through generating a key in the browser and returns
+/// the path of the downloaded PEM.
+fn prompt_for_key_file(member: &str, settings_url: &str) -> Result<String> {
+    if !std::io::stdin().is_terminal() {
+        bail!(
+            "No --key-file given. Generate a private key at {} and pass it with --key-file.",
+            settings_url
+        );
+    }
+    cliclack::log::info(format!(
+        "Generate a new private key for {member}'s GitHub App:\n\
+         \n\
+           {settings_url}\n\
+         \n\
+         Under 'Private keys', click 'Generate a private key'.\n\
+         GitHub downloads a .pem file."
+    ))?;
+    let path: String = cliclack::input("Path to the downloaded .pem file").interact()?;
+    Ok(path.trim().to_string())
+}
+
+/// Replaces a member's private key and records when it was rotated.
+///
+/// The store writes one key at a time, so the new value is read back and
+/// the old key restored if the write did not land.
+fn swap_private_key(
+    store: &dyn KeyValueCredentialStore,
+    member: &str,
+    new_key: &str,
+    rotated_at: &str,
+) -> Result<()> {
+    let key = credential_keys::private_key(member);
+    let old_key = store.retrieve(&key)?;
+
+    let stored = store
+        .store(&key, new_key)
+        .and_then(|_| store.retrieve(&key));
+    if !matches!(stored, Ok(Some(ref v)) if v == new_key) {
+        if let Some(old) = old_key {
+            store
+                .store(&key, &old)
+                .context("Failed to restore the previous private key")?;
+        }
+        match stored {
+            Err(e) => return Err(e.context("Failed to store the new private key")),
+            Ok(_) => bail!("New private key did not persist in the credential store"),
+        }
+    }
+
+    store
+        .store(&credential_keys::private_key_rotated_at(member), rotated_at)
+        .context("Failed to record key rotation time")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::formation::{InMemoryKeyValueCredentialStore, KeyValueCredentialStore};
-    use crate::git::manifest_flow::{self, PreGeneratedCredentials};
+    use crate::formation::InMemoryKeyValueCredentialStore;
+    use crate::git::manifest_flow::PreGeneratedCredentials;
 
     #[test]
     fn export_yaml_format_matches_design() {
@@ -208,4 +381,37 @@ mod tests {
         let meta = fs::metadata(path).unwrap();
         assert_eq!(meta.permissions().mode() & 0o777, 0o600);
     }
+
+    #[test]
+    fn swap_private_key_replaces_key_and_records_rotation() {
+        let store = InMemoryKeyValueCredentialStore::new();
+        store
+            .store(&credential_keys::private_key("member-a"), "old-pem")
+            .unwrap();
+
+        swap_private_key(&store, "member-a", "new-pem", "2026-01-01T00:00:00+00:00").unwrap();
+
+        assert_eq!(
+            store.retrieve(&credential_keys::private_key("member-a")).unwrap(),
+            Some("new-pem".to_string())
+        );
+        assert_eq!(
+            store
+                .retrieve(&credential_keys::private_key_rotated_at("member-a"))
+                .unwrap(),
+            Some("2026-01-01T00:00:00+00:00".to_string())
+        );
+    }
+
+    #[test]
+    fn app_settings_url_uses_team_host() {
+        assert_eq!(
+            app_settings_url(&GitHubHost::default(), "acme", "my-team-superman"),
+            "https://github.com/organizations/acme/settings/apps/my-team-superman"
+        );
+        assert_eq!(
+            app_settings_url(&GitHubHost::new(Some("ghe.local"), None), "acme", "bot"),
+            "https://ghe.local/organizations/acme/settings/apps/bot"
+        );
+    }
 }
@@ -1,7 +1,11 @@
-use anyhow::Result;
+use anyhow::{bail, Context, Result};
+use comfy_table::{
+    modifiers::UTF8_ROUND_CORNERS, presets::UTF8_FULL_CONDENSED, ContentArrangement, Table,
+};
 
 use crate::config;
-use crate::daemon::{self, DaemonStatusInfo};
+use crate::daemon::{self, systemd, DaemonClient, DaemonPaths, DaemonStatusInfo};
+use crate::forge;
 
 /// Handles `bm daemon start`.
 pub fn start(
@@ -10,16 +14,37 @@ pub fn start(
     port: u16,
     interval: u64,
     bind: &str,
+    webhook_url: Option<&str>,
 ) -> Result<()> {
     let cfg = config::load()?;
     let team = config::resolve_team(&cfg, team_flag)?;
     let team_repo = team.path.join("team");
 
+    if let Some(url) = webhook_url {
+        if mode != "webhook" {
+            bail!("--webhook-url only applies to webhook mode");
+        }
+        let token = forge::require_token(team.forge.as_ref())?;
+        forge::for_team(team, &token)?.ensure_webhook(
+            &team.github_repo,
+            url,
+            team.credentials.webhook_secret.as_deref(),
+        )?;
+        eprintln!("Webhook on {} delivers to {}", team.github_repo, url);
+    }
+
     eprintln!(
         "Starting daemon for team '{}' in {} mode...",
         team.name, mode
     );
 
+    if systemd::is_installed(&team.name) {
+        eprintln!(
+            "Using systemd unit {} (mode and port come from the unit).",
+            systemd::daemon_unit_name(&team.name)
+        );
+    }
+
     let result = daemon::start_daemon(&team.name, &team_repo, mode, port, interval, bind)?;
 
     println!("Daemon started (PID {})", result.pid);
@@ -27,6 +52,83 @@ pub fn start(
     Ok(())
 }
 
+/// Handles `bm daemon install`.
+pub fn install(
+    team_flag: Option<&str>,
+    mode: &str,
+    port: u16,
+    interval: u64,
+    bind: &str,
+    bridge: bool,
+    no_start: bool,
+) -> Result<()> {
+    let cfg = config::load()?;
+    let team = config::resolve_team(&cfg, team_flag)?;
+    let team_repo = team.path.join("team");
+
+    if mode != "webhook" && mode != "poll" {
+        bail!("Invalid daemon mode '{}'. Use 'webhook' or 'poll'.", mode);
+    }
+    if bridge && crate::bridge::discover(&team_repo, &team.name)?.is_none() {
+        bail!("Team '{}' has no bridge configured. Omit --bridge.", team.name);
+    }
+
+    // Hand over from a PID-file daemon to the unit
+    if let DaemonStatusInfo::Running { unit: None, pid, .. } = daemon::query_status(&team.name)? {
+        eprintln!("Stopping running daemon (PID {}) before handing over to systemd...", pid);
+        daemon::stop_daemon(&team.name)?;
+    }
+
+    let exe = std::env::current_exe().context("Failed to determine bm executable path")?;
+    let path_env = std::env::var("PATH").ok();
+    let params = systemd::DaemonUnitParams {
+        team: &team.name,
+        exe: &exe,
+        mode,
+        port,
+        interval,
+        bind,
+        path_env: path_env.as_deref(),
+        with_bridge: bridge,
+    };
+    let result = systemd::install(&params, !no_start)?;
+
+    for unit in &result.units {
+        println!("Installed {}", unit.display());
+    }
+    if result.started {
+        println!("Enabled and started. Members start with the daemon.");
+    } else {
+        println!(
+            "Enabled. Start now with `systemctl --user start {}`.",
+            systemd::daemon_unit_name(&team.name)
+        );
+    }
+    if !result.linger {
+        println!(
+            "Note: lingering is off — units stop at logout and only start at login. \
+             Run `loginctl enable-linger` to start them at boot."
+        );
+    }
+    Ok(())
+}
+
+/// Handles `bm daemon uninstall`.
+pub fn uninstall(team_flag: Option<&str>) -> Result<()> {
+    let cfg = config::load()?;
+    let team = config::resolve_team(&cfg, team_flag)?;
+
+    let removed = systemd::uninstall(&team.name)?;
+    if removed.is_empty() {
+        println!("No systemd units installed for team '{}'.", team.name);
+    } else {
+        for name in &removed {
+            println!("Removed {}", name);
+        }
+    }
+    Ok(())
+}
+
 /// Handles `bm daemon stop`.
 pub fn stop(team_flag: Option<&str>) -> Result<()> {
     let cfg = config::load()?;
@@ -46,8 +148,11 @@ pub fn status(team_flag: Option<&str>) -> Result<()> {
     let info = daemon::query_status(&team.name)?;
 
     match info {
-        DaemonStatusInfo::Running { pid, config } => {
+        DaemonStatusInfo::Running { pid, config, unit } => {
             println!("Daemon: running (PID {})", pid);
+            if let Some(unit) = unit {
+                println!("Unit: {} (systemd)", unit);
+            }
             if let Some(daemon_cfg) = config {
                 match daemon_cfg.mode.as_str() {
                     "webhook" => println!("Mode: webhook (port {})", daemon_cfg.port),
@@ -65,15 +170,136 @@ pub fn status(team_flag: Option<&str>) -> Result<()> {
         }
         DaemonStatusInfo::NotRunning { reason } => {
             println!("Daemon: {}", reason);
+            if systemd::is_installed(&team.name) {
+                println!("Unit: {} (systemd, inactive)", systemd::daemon_unit_name(&team.name));
+            }
         }
     }
 
     Ok(())
 }
 
+/// Handles `bm daemon events list`.
+pub fn events_list(team_flag: Option<&str>, limit: usize) -> Result<()> {
+    let cfg = config::load()?;
+    let team = config::resolve_team(&cfg, team_flag)?;
+    let deliveries = daemon::load_deliveries(&DaemonPaths::new(&team.name)?.deliveries());
+
+    if deliveries.is_empty() {
+        println!("No webhook deliveries recorded for team '{}'.", team.name);
+        return Ok(());
+    }
+
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL_CONDENSED)
+        .apply_modifier(UTF8_ROUND_CORNERS)
+        .set_content_arrangement(ContentArrangement::DynamicFullWidth)
+        .set_header(vec!["ID", "Received", "Event", "Validation", "Action"]);
+
+    for d in deliveries.iter().rev().take(limit) {
+        let action = match d.replays.len() {
+            0 => d.action.label().to_string(),
+            n => format!("{} (replayed {}x)", d.action.label(), n),
+        };
+        table.add_row(vec![
+            d.id.as_str(),
+            &format_timestamp(&d.received_at),
+            d.summary.as_deref().or(d.event.as_deref()).unwrap_or("-"),
+            d.validation.label(),
+            &action,
+        ]);
+    }
+
+    println!("{table}");
+    if deliveries.len() > limit {
+        println!(
+            "Showing {} of {} deliveries. Use -n to show more.",
+            limit,
+            deliveries.len()
+        );
+    }
+    Ok(())
+}
+
+/// Handles `bm daemon events show`.
+pub fn events_show(id: &str, team_flag: Option<&str>) -> Result<()> {
+    let cfg = config::load()?;
+    let team = config::resolve_team(&cfg, team_flag)?;
+    let path = DaemonPaths::new(&team.name)?.deliveries();
+    let Some(d) = daemon::find_delivery(&path, id) else {
+        bail!(
+            "No stored delivery matches '{}' for team '{}'.\n  Hint: run `bm daemon events list` to see recorded deliveries",
+            id,
+            team.name
+        );
+    };
+
+    println!("Delivery: {}", d.id);
+    println!("Received: {}", format_timestamp(&d.received_at));
+    println!("Event: {}", d.event.as_deref().unwrap_or("-"));
+    if let Some(summary) = &d.summary {
+        println!("Summary: {}", summary);
+    }
+    println!("Validation: {}", d.validation.label());
+    println!("Action: {}", d.action.label());
+    for r in &d.replays {
+        println!(
+            "Replayed: {} ({})",
+            format_timestamp(&r.replayed_at),
+            r.action.label()
+        );
+    }
+
+    println!("\nHeaders:");
+    for (name, value) in &d.headers {
+        println!("  {}: {}", name, value);
+    }
+
+    println!("\nPayload:");
+    match d.payload.as_deref() {
+        None if !d.validation.accepted() => println!("  (not stored for rejected deliveries)"),
+        None => println!("  (not stored, over {} bytes)", daemon::MAX_PAYLOAD_BYTES),
+        Some(payload) => match serde_json::from_str::<serde_json::Value>(payload) {
+            Ok(json) => println!("{}", serde_json::to_string_pretty(&json)?),
+            Err(_) => println!("{}", payload),
+        },
+    }
+    Ok(())
+}
+
+/// Handles `bm daemon events replay`.
+pub fn events_replay(id: &str, team_flag: Option<&str>) -> Result<()> {
+    let cfg = config::load()?;
+    let team = config::resolve_team(&cfg, team_flag)?;
+
+    let client = DaemonClient::connect(&team.name).with_context(|| {
+        format!(
+            "Replay runs inside the daemon.\n  Hint: start it with `bm daemon start -t {}`",
+            team.name
+        )
+    })?;
+    let resp = client.replay_delivery(id)?;
+
+    println!(
+        "Replayed delivery {} ({}): {}",
+        resp.id.as_deref().unwrap_or(id),
+        resp.event.as_deref().unwrap_or("no event"),
+        resp.action.as_deref().unwrap_or("-")
+    );
+    Ok(())
+}
+
 /// Handles the hidden `bm daemon-run` command.
-pub fn run_daemon(team: &str, mode: &str, port: u16, interval: u64, bind: &str) -> Result<()> {
-    daemon::run_daemon(team, mode, port, interval, bind)
+pub fn run_daemon(
+    team: &str,
+    mode: &str,
+    port: u16,
+    interval: u64,
+    bind: &str,
+    start_members: bool,
+) -> Result<()> {
+    daemon::run_daemon(team, mode, port, interval, bind, start_members)
 }
 
 /// Formats an ISO 8601 timestamp for display.
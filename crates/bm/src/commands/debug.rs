@@ -6,6 +6,7 @@ use std::path::{Path, PathBuf};
 use anyhow::{bail, Result};
 
 use crate::config;
+use crate::daemon::{self, systemd};
 use crate::state;
 
 /// Show brain member logs: stderr + LLM conversation.
@@ -51,6 +52,49 @@ pub fn brain_logs(
     Ok(())
 }
 
+/// Show daemon logs.
+///
+/// Reads journald when the daemon runs as a systemd user unit, otherwise
+/// tails `~/.botminter/logs/daemon-<team>.log`.
+pub fn daemon_logs(team_flag: Option<&str>, lines: usize) -> Result<()> {
+    let cfg = config::load()?;
+    let team = config::resolve_team(&cfg, team_flag)?;
+
+    if systemd::is_installed(&team.name) {
+        let unit = systemd::daemon_unit_name(&team.name);
+        println!("── journald: {} (last {} lines) ──", unit, lines);
+        print!("{}", systemd::journal(&unit, lines)?);
+        return Ok(());
+    }
+
+    let log_path = daemon::DaemonPaths::new(&team.name)?.log()?;
+    println!("── {} (last {} lines) ──", log_path.display(), lines);
+    if !log_path.exists() {
+        println!("  (no daemon log yet — start it with `bm daemon start`)");
+        return Ok(());
+    }
+    for line in tail_file(&log_path, lines)? {
+        println!("{}", line);
+    }
+    Ok(())
+}
+
+/// Returns the last `max_lines` lines of a file.
+fn tail_file(path: &Path, max_lines: usize) -> Result<Vec<String>> {
+    if max_lines == 0 {
+        return Ok(Vec::new());
+    }
+    let file = fs::File::open(path)?;
+    let mut tail: VecDeque<String> = VecDeque::with_capacity(max_lines);
+    for line in BufReader::new(file).lines().map_while(Result::ok) {
+        if tail.len() == max_lines {
+            tail.pop_front();
+        }
+        tail.push_back(line);
+    }
+    Ok(tail.into())
+}
+
 /// Display the last N lines of brain-stderr.log.
 fn show_brain_stderr(workspace: &Path, max_lines: usize) {
     let log_path = workspace.join("brain-stderr.log");
@@ -325,6 +369,15 @@ fn truncate(s: &str, max_len: usize) -> String {
 mod tests {
     use super::*;
 
+    #[test]
+    fn tail_file_keeps_last_lines() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("daemon.log");
+        fs::write(&path, "one\ntwo\nthree\nfour\n").unwrap();
+        assert_eq!(tail_file(&path, 2).unwrap(), vec!["three", "four"]);
+        assert_eq!(tail_file(&path, 10).unwrap().len(), 4);
+    }
+
     #[test]
     fn claude_project_dir_path_derivation() {
         // Verify the path transformation logic
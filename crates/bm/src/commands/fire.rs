@@ -88,9 +88,11 @@ pub fn run(member: &str, team_flag: Option<&str>, keep_app: bool, yes: bool, del
         let org = team.github_repo.split('/').next().unwrap_or("YOUR_ORG");
         println!(
             "\nNote: The GitHub App itself cannot be deleted via API.\n\
-             To delete it, visit: https://github.com/organizations/{}/settings/apps\n\
+             To delete it, visit: {}/organizations/{}/settings/apps\n\
              Find the App associated with '{}' and click 'Delete'.",
-            org, member
+            crate::git::GitHubHost::for_team(team).web_base(),
+            org,
+            member
         );
     }
 
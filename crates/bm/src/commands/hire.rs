@@ -4,8 +4,8 @@ use std::io::IsTerminal as _;
 use anyhow::{Context, Result};
 
 use crate::bridge::{self, CredentialStore};
-use crate::config;
-use crate::git::manifest_flow;
+use crate::config::{self, ForgeKind};
+use crate::git::{self, manifest_flow};
 use crate::member_lifecycle::{self, AppCredentials, HireParams};
 
 /// GitHub App credential flags from the CLI.
@@ -16,19 +16,27 @@ pub struct AppCredentialFlags<'a> {
     pub private_key_file: Option<&'a str>,
     pub installation_id: Option<&'a str>,
     pub save_credentials: Option<&'a str>,
+    /// Bot user token for Gitea/Forgejo teams.
+    pub bot_token: Option<&'a str>,
 }
 
 /// Handles `bm hire <role> [--name <name>] [-t team] [--reuse-app ...]`.
+///
+/// Returns the hired member's directory name (e.g. `architect-03`).
 pub fn run(
     role: &str,
     name: Option<&str>,
     team_flag: Option<&str>,
     app_flags: AppCredentialFlags<'_>,
-) -> Result<()> {
+) -> Result<String> {
     super::ensure_profiles(false)?;
     let cfg = config::load()?;
     let team = config::resolve_team(&cfg, team_flag)?;
 
+    if app_flags.bot_token.is_some() && team.forge_kind() != ForgeKind::Gitea {
+        anyhow::bail!("--bot-token is only supported for Gitea/Forgejo teams");
+    }
+
     // Resolve App credentials from CLI flags (if --reuse-app)
     let app_credentials = if app_flags.reuse_app {
         let app_id = app_flags.app_id.context("--reuse-app requires --app-id")?;
@@ -66,7 +74,20 @@ pub fn run(
         println!("Hired {} as {} in team '{}'.", role, result.member_name, team.name);
     }
 
-    if result.app_credentials_stored {
+    if team.forge_kind() == ForgeKind::Gitea {
+        match app_flags.bot_token {
+            Some(token) => {
+                let login =
+                    member_lifecycle::setup_bot_credentials(team, &result.member_dir_name, token)?;
+                println!("Bot token for '{}' stored for {}.", login, result.member_dir_name);
+            }
+            None => println!(
+                "No --bot-token given. {} will use your own forge credentials until you re-run \
+                 `bm hire {} --name {} --bot-token <token>`.",
+                result.member_dir_name, role, result.member_name
+            ),
+        }
+    } else if result.app_credentials_stored {
         println!("GitHub App credentials stored for {}.", result.member_dir_name);
     } else if !team.github_repo.is_empty() && !app_flags.reuse_app {
         // No --reuse-app and team has a GitHub repo: run the interactive manifest flow
@@ -80,7 +101,7 @@ pub fn run(
     // Prompt for bridge token if team has an external bridge configured
     prompt_bridge_token(&team.path.join("team"), team, &cfg, &result.member_dir_name)?;
 
-    Ok(())
+    Ok(result.member_dir_name)
 }
 
 /// Runs the interactive manifest flow to create a GitHub App for a member,
@@ -90,12 +111,13 @@ fn run_manifest_flow_for_member(
     member_name: &str,
     save_credentials: Option<&str>,
 ) -> Result<()> {
-    let org = manifest_flow::resolve_org_from_repo(&team.github_repo)?;
+    let host = git::GitHubHost::for_team(team);
+    let org = manifest_flow::resolve_org_from_repo(&host, &team.github_repo)?;
     let app_name = format!("{}-{}", team.name, member_name);
     let slug = manifest_flow::app_name_to_slug(&app_name);
 
     // Check for name collision before starting the browser flow
-    match manifest_flow::check_name_collision(&slug) {
+    match manifest_flow::check_name_collision(&host, &slug) {
         Ok(true) => {
             anyhow::bail!(
                 "A GitHub App named '{}' already exists.\n\
@@ -135,14 +157,18 @@ fn run_manifest_flow_for_member(
         true
     };
 
-    let team_repo_url = format!("https://github.com/{}", team.github_repo);
+    let team_repo_url = host.repo_url(&team.github_repo);
 
     let mut server = manifest_flow::prepare_manifest_flow(&manifest_flow::ManifestFlowParams {
         app_name: app_name.clone(),
         org,
         team_repo_url,
-        github_api_base: std::env::var("BM_GITHUB_API_BASE").ok(),
-        github_web_base: std::env::var("BM_GITHUB_WEB_BASE").ok(),
+        github_api_base: std::env::var("BM_GITHUB_API_BASE")
+            .ok()
+            .or(Some(host.api_base.clone())),
+        github_web_base: std::env::var("BM_GITHUB_WEB_BASE")
+            .ok()
+            .or(Some(host.web_base())),
     })?;
 
     let flow_result = if browser_available {
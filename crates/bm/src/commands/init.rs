@@ -4,12 +4,14 @@ use std::path::Path;
 use anyhow::{bail, Context, Result};
 
 use crate::bridge;
-use crate::config;
+use crate::config::{self, ForgeConfig, ForgeKind};
+use crate::forge::{self, Forge, StatusTracking};
 use crate::formation;
 use crate::git;
 use crate::git::manifest_flow;
 use crate::member_lifecycle::{self, AppCredentials};
 use crate::profile;
+use crate::workspace::{RemoteRepoOps, RemoteRepoState};
 
 /// Whether to create a new GitHub Project board or use an existing one.
 enum ProjectChoice {
@@ -62,6 +64,13 @@ fn next_steps_message(team_name: &str, team_dir: &Path, team_repo: &Path, bridge
 /// Skips all interactive prompts. When `skip_github` is true, also skips
 /// GitHub API calls (token validation, label bootstrap, project board creation),
 /// making it suitable for automated testing without network access.
+///
+/// `forge` selects where the team repo lives (`github`, or `gitea`/`forgejo`
+/// with `forge_url`). On Gitea/Forgejo, status is tracked with `status/*`
+/// labels instead of a project board.
+///
+/// `github_host`/`github_api_base` point a GitHub team at a GitHub Enterprise
+/// Server instance; they are recorded on the team entry.
 #[allow(clippy::too_many_arguments)]
 pub fn run_non_interactive(
     profile_name: Option<String>,
@@ -74,6 +83,10 @@ pub fn run_non_interactive(
     skip_github: bool,
     workzone_override: Option<String>,
     credentials_file: Option<String>,
+    forge_name: Option<String>,
+    forge_url: Option<String>,
+    github_host: Option<String>,
+    github_api_base: Option<String>,
 ) -> Result<()> {
     let selected_profile =
         profile_name.ok_or_else(|| anyhow::anyhow!("--profile is required with --non-interactive"))?;
@@ -90,9 +103,15 @@ pub fn run_non_interactive(
         bail!("Team name cannot contain '/' or spaces");
     }
 
+    let forge_cfg = parse_forge_flags(forge_name.as_deref(), forge_url.as_deref())?;
+    if forge_cfg.is_some() && (github_host.is_some() || github_api_base.is_some()) {
+        bail!("--github-host and --github-api-base only apply to GitHub teams");
+    }
+    let host = git::GitHubHost::new(github_host.as_deref(), github_api_base.as_deref());
+
     super::ensure_profiles(false)?;
 
-    if !skip_github {
+    if !skip_github && forge_cfg.is_none() {
         config::check_prerequisites()?;
     } else if which::which("git").is_err() {
         bail!("Missing required tool: git — https://git-scm.com/");
@@ -132,15 +151,33 @@ pub fn run_non_interactive(
         None
     };
 
-    if !skip_github {
-        let token = git::detect_token_non_interactive()?;
-        git::validate_token(&token)?;
-        // Validate that the org is actually a GitHub Organization (not a personal account).
-        // resolve_org_from_repo does `GET /users/{owner}` and checks `.type == "Organization"`.
-        git::manifest_flow::resolve_org_from_repo(
-            &format!("{}/{}", github_org, repo_name),
-        )?;
-    }
+    let team_forge: Option<Box<dyn Forge>> = if skip_github {
+        None
+    } else {
+        let team_forge = match &forge_cfg {
+            Some(f) => {
+                let token = forge::require_token(Some(f))?;
+                let team_forge = forge::for_config(Some(f), host.clone(), &token, None)?;
+                team_forge
+                    .current_user()
+                    .with_context(|| format!("Token for {} was rejected", f.url))?;
+                team_forge
+            }
+            None => {
+                let token = git::detect_token_non_interactive(&host)?;
+                git::validate_token(&host, &token)?;
+                // Validate that the org is actually a GitHub Organization (not a personal
+                // account). resolve_org_from_repo does `GET /users/{owner}` and checks
+                // `.type == "Organization"`.
+                git::manifest_flow::resolve_org_from_repo(
+                    &host,
+                    &format!("{}/{}", github_org, repo_name),
+                )?;
+                forge::for_config(None, host.clone(), &token, None)?
+            }
+        };
+        Some(team_forge)
+    };
 
     eprintln!(
         "Creating team '{}' with profile '{}' at {}",
@@ -152,10 +189,9 @@ pub fn run_non_interactive(
     fs::create_dir_all(&team_dir)
         .with_context(|| format!("Failed to create team directory at {}", team_dir.display()))?;
 
-    let is_new_repo = if skip_github {
-        true
-    } else {
-        !git::repo_exists(&github_repo)?
+    let is_new_repo = match &team_forge {
+        None => true,
+        Some(f) => f.repo_state(&github_repo)? == RemoteRepoState::NotFound,
     };
 
     let team_repo = team_dir.join("team");
@@ -172,54 +208,66 @@ pub fn run_non_interactive(
             None,
         )?;
 
-        if !skip_github {
-            git::create_repo_and_push(&team_repo, &github_repo)?;
+        match &team_forge {
+            // gh creates the remote and waits for GitHub to propagate the repo
+            Some(f) if f.kind() == ForgeKind::GitHub => {
+                git::create_repo_and_push(&host, &team_repo, &github_repo)?;
+            }
+            Some(f) => {
+                f.create_repo(&github_repo)?;
+                git::run_git(
+                    &team_repo,
+                    &["remote", "add", "origin", &f.clone_url(&github_repo)],
+                )?;
+                f.push_repo(&team_repo)?;
+            }
+            None => {}
         }
     } else {
         eprintln!("Repository '{}' already exists — cloning it.", github_repo);
-        git::clone_repo(&team_dir, &github_repo)?;
+        match &team_forge {
+            Some(f) if f.kind() != ForgeKind::GitHub => {
+                f.clone_repo(&github_repo, &team_repo, false)?
+            }
+            _ => git::clone_repo(&host, &team_dir, &github_repo)?,
+        }
     }
 
     formation::register_team(
         &team_name, &team_dir, &selected_profile, &github_repo, &workzone,
     )?;
 
-    if !skip_github {
-        if let Err(e) = git::bootstrap_labels(&github_repo, &manifest.labels) {
-            bail!(
-                "Failed to bootstrap labels: {}. Run `bm init` interactively for recovery steps.",
-                e
-            );
+    if let Some(ref f) = forge_cfg {
+        let mut cfg = config::load()?;
+        if let Some(entry) = cfg.teams.iter_mut().find(|t| t.name == team_name) {
+            entry.forge = Some(f.clone());
         }
+        config::save(&cfg)?;
+    } else {
+        save_github_host(&team_name, &host)?;
+    }
 
-        let owner = github_repo.split('/').next().unwrap_or(&github_org);
-        let board_title = github_project_board
-            .ok_or_else(|| anyhow::anyhow!("--github-project-board is required with --non-interactive"))?;
-
-        // Find existing board by title, or create one
-        let project_number = {
-            let projects = git::list_projects(owner)?;
-            if let Some((number, _)) = projects.iter().find(|(_, t)| t == &board_title) {
-                eprintln!("Using existing project board '{}' (#{})", board_title, number);
-                git::sync_project_status_field(owner, *number, &manifest.statuses)?;
-                *number
-            } else {
-                match git::create_project(owner, &board_title, &manifest.statuses) {
-                    Ok(n) => n,
-                    Err(e) => {
-                        bail!(
-                            "Failed to create GitHub Project: {}. Run `bm projects sync` to retry.",
-                            e
-                        );
-                    }
-                }
-            }
+    if let Some(ref f) = team_forge {
+        if let Err(e) = f.ensure_labels(&github_repo, &manifest.labels) {
+            bail!("Failed to bootstrap labels: {}. Re-run `bm init` to retry.", e);
+        }
+
+        // Only GitHub tracks status on a (named) project board
+        let board_title = match f.kind() {
+            ForgeKind::GitHub => github_project_board.ok_or_else(|| {
+                anyhow::anyhow!("--github-project-board is required with --non-interactive")
+            })?,
+            ForgeKind::Gitea => String::new(),
         };
+        let tracking = f
+            .setup_status_tracking(&github_repo, &board_title, &manifest.statuses)
+            .context("Failed to set up status tracking. Run `bm projects sync` to retry.")?;
 
-        {
+        if let StatusTracking::ProjectBoard { number } = tracking {
+            eprintln!("Using project board '{}' (#{})", board_title, number);
             let mut cfg = config::load()?;
             if let Some(entry) = cfg.teams.iter_mut().find(|t| t.name == team_name) {
-                entry.project_number = Some(project_number);
+                entry.project_number = Some(number);
             }
             config::save(&cfg)?;
         }
@@ -235,8 +283,46 @@ pub fn run_non_interactive(
     Ok(())
 }
 
-/// Runs the `bm init` interactive wizard.
-pub fn run() -> Result<()> {
+/// Validates `--forge`/`--forge-url`. Returns `None` for GitHub.
+fn parse_forge_flags(forge: Option<&str>, forge_url: Option<&str>) -> Result<Option<ForgeConfig>> {
+    match forge {
+        None | Some("github") => {
+            if forge_url.is_some() {
+                bail!("--forge-url requires --forge gitea or --forge forgejo");
+            }
+            Ok(None)
+        }
+        Some("gitea") | Some("forgejo") => {
+            let url = forge_url.ok_or_else(|| {
+                anyhow::anyhow!("--forge-url is required with --forge {}", forge.unwrap_or_default())
+            })?;
+            Ok(Some(ForgeConfig {
+                kind: ForgeKind::Gitea,
+                url: forge::normalize_forge_url(url)?,
+                api_base: None,
+            }))
+        }
+        Some(other) => bail!("Unknown forge '{}'. Supported: github, gitea, forgejo", other),
+    }
+}
+
+/// Records a GHES host as the team's forge. No-op for github.com teams
+/// without an API override, so their config stays unchanged.
+fn save_github_host(team_name: &str, host: &git::GitHubHost) -> Result<()> {
+    let Some(forge) = host.forge_config() else {
+        return Ok(());
+    };
+    let mut cfg = config::load()?;
+    if let Some(entry) = cfg.teams.iter_mut().find(|t| t.name == team_name) {
+        entry.forge = Some(forge);
+    }
+    config::save(&cfg)
+}
+
+/// Runs the `bm init` interactive wizard against github.com, or the GHES
+/// instance given by `--github-host`/`--github-api-base`.
+pub fn run(github_host: Option<String>, github_api_base: Option<String>) -> Result<()> {
+    let host = git::GitHubHost::new(github_host.as_deref(), github_api_base.as_deref());
     super::ensure_profiles(false)?;
     config::check_prerequisites()?;
 
@@ -321,16 +407,16 @@ pub fn run() -> Result<()> {
         .map(|s: &str| s.to_string())?;
 
     // GitHub integration — require existing `gh auth` session (no manual PAT prompt)
-    let token = git::detect_token_non_interactive()
+    let token = git::detect_token_non_interactive(&host)
         .context("GitHub App identity requires an authenticated `gh` session.\nRun `gh auth login` first.")?;
-    let token_info = git::validate_token(&token)?;
-    cliclack::log::info(format!("Authenticated as: {}", token_info.login))?;
+    let token_info = git::validate_token(&host, &token)?;
+    cliclack::log::info(format!("Authenticated as: {} on {}", token_info.login, host.host))?;
 
-    let github_org = select_github_org()?;
-    let (github_repo, is_new_repo) = select_or_create_repo(&github_org, &team_name)?;
+    let github_org = select_github_org(&host)?;
+    let (github_repo, is_new_repo) = select_or_create_repo(&host, &github_org, &team_name)?;
 
     let github_owner = github_repo.split('/').next().unwrap_or(&github_org);
-    let project_choice = select_or_create_project(github_owner, &team_name)?;
+    let project_choice = select_or_create_project(&host, github_owner, &team_name)?;
 
     let manifest = profile::read_manifest(&selected_profile)?;
 
@@ -362,7 +448,7 @@ pub fn run() -> Result<()> {
     let (members_to_hire, projects_to_add) = if is_new_repo {
         let role_names: Vec<String> = manifest.roles.iter().map(|r| r.name.clone()).collect();
         let members = collect_members(&role_names)?;
-        let projects = collect_projects(Some(&github_org))?;
+        let projects = collect_projects(&host, Some(&github_org))?;
         (members, projects)
     } else {
         cliclack::log::info(
@@ -426,20 +512,21 @@ pub fn run() -> Result<()> {
         )?;
 
         spinner.start("Creating GitHub repository...");
-        git::create_repo_and_push(&team_repo, &github_repo)?;
+        git::create_repo_and_push(&host, &team_repo, &github_repo)?;
     } else {
         spinner.start("Cloning existing repository...");
-        git::clone_repo(&team_dir, &github_repo)?;
+        git::clone_repo(&host, &team_dir, &github_repo)?;
     }
 
     spinner.start("Registering team...");
     formation::register_team(
         &team_name, &team_dir, &selected_profile, &github_repo, &workzone,
     )?;
+    save_github_host(&team_name, &host)?;
 
     // Bootstrap labels
     spinner.start("Bootstrapping labels...");
-    if let Err(e) = git::bootstrap_labels(&github_repo, &manifest.labels) {
+    if let Err(e) = git::bootstrap_labels(&host, &github_repo, &manifest.labels) {
         spinner.stop("Label bootstrap failed");
         let label_cmds: Vec<String> = manifest
             .labels.iter()
@@ -462,7 +549,7 @@ pub fn run() -> Result<()> {
         ProjectChoice::CreateNew => {
             spinner.start("Creating GitHub Project board...");
             let board_title = format!("{} Board", team_name);
-            match git::create_project(owner, &board_title, &manifest.statuses) {
+            match git::create_project(&host, owner, &board_title, &manifest.statuses) {
                 Ok(n) => {
                     spinner.stop("GitHub Project board created");
                     n
@@ -483,7 +570,7 @@ pub fn run() -> Result<()> {
         }
         ProjectChoice::UseExisting(n) => {
             spinner.start("Syncing project board statuses...");
-            git::sync_project_status_field(owner, n, &manifest.statuses)?;
+            git::sync_project_status_field(&host, owner, n, &manifest.statuses)?;
             spinner.stop("Project board statuses synced");
             n
         }
@@ -499,7 +586,7 @@ pub fn run() -> Result<()> {
     }
 
     if !manifest.views.is_empty() {
-        let project_url = format!("https://github.com/orgs/{}/projects/{}", owner, project_number);
+        let project_url = host.project_url(owner, project_number);
         cliclack::log::info(format!("Board: {}", project_url))?;
     }
 
@@ -507,14 +594,14 @@ pub fn run() -> Result<()> {
     if !members_to_hire.is_empty() && !github_repo.is_empty() {
         let cfg = config::load()?;
         if let Some(team) = cfg.teams.iter().find(|t| t.name == team_name) {
-            let team_repo_url = format!("https://github.com/{}", github_repo);
+            let team_repo_url = host.repo_url(&github_repo);
             for (role, name) in &members_to_hire {
                 let member_dir_name = format!("{role}-{name}");
                 let app_name = format!("{}-{}", team_name, member_dir_name);
                 let slug = manifest_flow::app_name_to_slug(&app_name);
 
                 // Check name collision
-                match manifest_flow::check_name_collision(&slug) {
+                match manifest_flow::check_name_collision(&host, &slug) {
                     Ok(true) => {
                         eprintln!(
                             "Warning: App name '{}' is already taken. \
@@ -551,8 +638,12 @@ pub fn run() -> Result<()> {
                         app_name: app_name.clone(),
                         org: github_org.clone(),
                         team_repo_url: team_repo_url.clone(),
-                        github_api_base: std::env::var("BM_GITHUB_API_BASE").ok(),
-                        github_web_base: std::env::var("BM_GITHUB_WEB_BASE").ok(),
+                        github_api_base: std::env::var("BM_GITHUB_API_BASE")
+                            .ok()
+                            .or(Some(host.api_base.clone())),
+                        github_web_base: std::env::var("BM_GITHUB_WEB_BASE")
+                            .ok()
+                            .or(Some(host.web_base())),
                     },
                 ) {
                     Ok(s) => s,
@@ -658,14 +749,15 @@ pub fn run() -> Result<()> {
 ///
 /// Personal accounts are excluded — GitHub App identity requires an
 /// organization for `organization_projects` permissions.
-fn select_github_org() -> Result<String> {
-    let orgs = git::list_user_orgs()?;
+fn select_github_org(host: &git::GitHubHost) -> Result<String> {
+    let orgs = git::list_user_orgs(host)?;
 
     if orgs.is_empty() {
         bail!(
             "GitHub App identity requires an organization, but no organizations were found.\n\
-             Create a GitHub organization first: https://github.com/organizations/plan\n\
-             Then re-run `bm init`."
+             Create a GitHub organization first: {}/organizations/plan\n\
+             Then re-run `bm init`.",
+            host.web_base()
         );
     }
 
@@ -696,7 +788,7 @@ fn select_github_org() -> Result<String> {
             })
             .interact()?;
         // Validate via GitHub API that the entered name is actually an Organization
-        git::manifest_flow::validate_is_org(&org)?;
+        git::manifest_flow::validate_is_org(host, &org)?;
         Ok(org)
     } else {
         Ok(selected.to_string())
@@ -704,8 +796,8 @@ fn select_github_org() -> Result<String> {
 }
 
 /// Lists repos for an org/user, lets the user select or create. Returns `(owner/repo, is_new)`.
-fn select_or_create_repo(owner: &str, team_name: &str) -> Result<(String, bool)> {
-    let repos = git::list_repos(owner)?;
+fn select_or_create_repo(host: &git::GitHubHost, owner: &str, team_name: &str) -> Result<(String, bool)> {
+    let repos = git::list_repos(host, owner)?;
     let default_name = format!("{}-team", team_name);
     let create_label = format!("Create new repo ({})", default_name);
 
@@ -737,8 +829,8 @@ fn select_or_create_repo(owner: &str, team_name: &str) -> Result<(String, bool)>
 }
 
 /// Lists GitHub Projects, lets the user select or create a new one.
-fn select_or_create_project(owner: &str, team_name: &str) -> Result<ProjectChoice> {
-    let projects = git::list_projects(owner)?;
+fn select_or_create_project(host: &git::GitHubHost, owner: &str, team_name: &str) -> Result<ProjectChoice> {
+    let projects = git::list_projects(host, owner)?;
     let default_title = format!("{} Board", team_name);
     let create_label = format!("Create new board ({})", default_title);
 
@@ -820,7 +912,7 @@ fn collect_members(roles: &[String]) -> Result<Vec<(String, String)>> {
 }
 
 /// Collect projects to add during init (optional).
-fn collect_projects(org: Option<&str>) -> Result<Vec<(String, String)>> {
+fn collect_projects(host: &git::GitHubHost, org: Option<&str>) -> Result<Vec<(String, String)>> {
     let add_projects: bool = cliclack::confirm("Add projects now?")
         .initial_value(false)
         .interact()?;
@@ -832,7 +924,7 @@ fn collect_projects(org: Option<&str>) -> Result<Vec<(String, String)>> {
     let mut projects = Vec::new();
     loop {
         let url = if let Some(org) = org {
-            select_project_repo(org)?
+            select_project_repo(host, org)?
         } else {
             prompt_project_url()?
         };
@@ -871,8 +963,8 @@ fn prompt_project_url() -> Result<String> {
 }
 
 /// Lists repos for an org/user and lets the user select one as a project fork.
-fn select_project_repo(org: &str) -> Result<String> {
-    let repos = git::list_repos(org)?;
+fn select_project_repo(host: &git::GitHubHost, org: &str) -> Result<String> {
+    let repos = git::list_repos(host, org)?;
 
     if repos.is_empty() {
         cliclack::log::warning(format!("No repos found in '{}'. Enter URL manually.", org))?;
@@ -889,7 +981,7 @@ fn select_project_repo(org: &str) -> Result<String> {
         .filter_mode()
         .interact()?;
 
-    Ok(format!("https://github.com/{}/{}.git", org, selected))
+    Ok(host.clone_url(&format!("{}/{}", org, selected)))
 }
 
 // ── Credential import ─────────────────────────────────────────────
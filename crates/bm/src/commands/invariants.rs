@@ -0,0 +1,84 @@
+use anyhow::{bail, Result};
+
+use crate::config;
+use crate::invariants::{self, CheckReport};
+use crate::profile;
+use crate::workspace;
+
+/// Handles `bm invariants check [member] [-t team]`.
+pub fn check(member: Option<&str>, team_flag: Option<&str>) -> Result<()> {
+    let cfg = config::load()?;
+    let team = config::resolve_team(&cfg, team_flag)?;
+    let team_repo = team.path.join("team");
+
+    let team_schema = profile::read_team_schema(&team_repo)?;
+    profile::require_current_schema(&team.name, &team_schema)?;
+
+    let members_dir = team_repo.join("members");
+    let members = match member {
+        Some(m) => {
+            if !members_dir.join(m).is_dir() {
+                bail!(
+                    "Member '{}' not found. Available members: {}",
+                    m,
+                    profile::list_subdirs(&members_dir).join(", ")
+                );
+            }
+            vec![m.to_string()]
+        }
+        None => workspace::list_member_dirs(&members_dir)?,
+    };
+
+    let team_ws_base = cfg.workzone.join(&team.name);
+    let mut total = 0;
+    for m in &members {
+        let Some(ws) = workspace::find_workspace(&team_ws_base, m) else {
+            println!("{}: no workspace (run `bm teams sync`), skipped", m);
+            continue;
+        };
+        let report = invariants::check_member(&team_repo, &ws, m, None)?;
+        display_report(&report);
+        total += report.violations.len();
+    }
+
+    if total > 0 {
+        bail!("{} invariant violation(s)", total);
+    }
+    Ok(())
+}
+
+fn display_report(report: &CheckReport) {
+    if report.checks_run == 0 {
+        println!("{}: no executable invariants", report.member);
+        return;
+    }
+    if report.violations.is_empty() {
+        println!(
+            "{}: {} {}",
+            report.member,
+            console::style("ok").green(),
+            plural(report.checks_run, "check")
+        );
+        return;
+    }
+    println!(
+        "{}: {} in {}",
+        report.member,
+        console::style(plural(report.violations.len(), "violation")).red(),
+        plural(report.checks_run, "check")
+    );
+    for v in &report.violations {
+        println!("  {} [{}] {}", v.invariant, v.target, v.rule);
+        for line in v.message.lines() {
+            println!("    {}", line);
+        }
+    }
+}
+
+fn plural(n: usize, noun: &str) -> String {
+    if n == 1 {
+        format!("1 {}", noun)
+    } else {
+        format!("{} {}s", n, noun)
+    }
+}
@@ -1,8 +1,15 @@
 use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
 
 use anyhow::{bail, Context, Result};
 
 use crate::config;
+use crate::forge;
+use crate::knowledge::{
+    self, DocKind, KnowledgeEdit, KnowledgeKind, Placement, Proposal, ResolvedFile, Scope,
+    SearchFilter,
+};
 use crate::profile;
 
 /// Handles `bm knowledge list [-t team] [--scope <scope>]`.
@@ -71,33 +78,307 @@ pub fn show(path: &str, team_flag: Option<&str>) -> Result<()> {
 
     let team_schema = profile::read_team_schema(&team_repo)?;
     profile::require_current_schema(&team.name, &team_schema)?;
-    profile::validate_knowledge_path(path)?;
-
-    let file_path = team_repo.join(path);
-
-    let canonical_repo = team_repo
-        .canonicalize()
-        .context("Failed to resolve team repo path")?;
-    if file_path.exists() {
-        let canonical_file = file_path
-            .canonicalize()
-            .context("Failed to resolve file path")?;
-        if !canonical_file.starts_with(&canonical_repo) {
-            bail!("Path resolves outside the team repo");
+
+    let contents = profile::read_knowledge_file(&team_repo, path)?;
+    print!("{}", contents);
+
+    Ok(())
+}
+
+/// Handles `bm knowledge resolve <member> [-p project] [-t team]`.
+pub fn resolve(member: &str, project: Option<&str>, team_flag: Option<&str>) -> Result<()> {
+    let cfg = config::load()?;
+    let team = config::resolve_team(&cfg, team_flag)?;
+    let team_repo = team.path.join("team");
+
+    let team_schema = profile::read_team_schema(&team_repo)?;
+    profile::require_current_schema(&team.name, &team_schema)?;
+
+    let resolution = knowledge::resolve_knowledge(&team_repo, member, project)?;
+
+    match project {
+        Some(p) => println!("Member: {} (project: {})", member, p),
+        None => println!("Member: {}", member),
+    }
+    println!();
+
+    for kind in [KnowledgeKind::Knowledge, KnowledgeKind::Invariants] {
+        display_resolved(kind, resolution.files(kind));
+    }
+
+    Ok(())
+}
+
+/// Displays effective files in resolution order, then the ones shadowed by them.
+fn display_resolved(kind: KnowledgeKind, files: &[ResolvedFile]) {
+    println!("{}:", capitalize(kind.dir()));
+    let effective: Vec<_> = files.iter().filter(|f| f.is_effective()).collect();
+    if effective.is_empty() {
+        println!("  (none)");
+    }
+    for (i, file) in effective.iter().enumerate() {
+        println!("  {}. {:<16} {}", i + 1, file.scope.label(), file.path);
+        for replaced in &file.overrides {
+            println!("     overrides {}", replaced);
         }
     }
 
-    if !file_path.exists() {
-        bail!("File not found: {}", path);
+    let shadowed: Vec<_> = files.iter().filter(|f| !f.is_effective()).collect();
+    if !shadowed.is_empty() {
+        println!("  Shadowed:");
+        for file in shadowed {
+            println!(
+                "    {} (shadowed by {})",
+                file.path,
+                file.shadowed_by.as_deref().unwrap_or_default()
+            );
+        }
     }
+    println!();
+}
 
-    let contents = fs::read_to_string(&file_path)
-        .with_context(|| format!("Failed to read {}", path))?;
-    print!("{}", contents);
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(c) => c.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Handles `bm knowledge add <name> [--scope S] [-p project] [--member M] [--invariant]`.
+#[allow(clippy::too_many_arguments)]
+pub fn add(
+    name: &str,
+    scope: &str,
+    project: Option<&str>,
+    member: Option<&str>,
+    invariant: bool,
+    from: Option<&str>,
+    message: Option<&str>,
+    team_flag: Option<&str>,
+) -> Result<()> {
+    let team_repo = load_team_repo(team_flag)?;
+
+    let placement = Placement {
+        scope: scope.parse::<Scope>()?,
+        kind: if invariant {
+            KnowledgeKind::Invariants
+        } else {
+            KnowledgeKind::Knowledge
+        },
+        project,
+        member,
+    };
+    let path = placement.file_path(name)?;
+    placement.check_exists(&team_repo)?;
+
+    let content = match from {
+        Some(file) => {
+            fs::read_to_string(file).with_context(|| format!("Failed to read {}", file))?
+        }
+        None => edit_in_editor(&path, "")?,
+    };
+    if content.trim().is_empty() {
+        bail!("{} would be empty, nothing to add", path);
+    }
+
+    submit(
+        team_flag,
+        &team_repo,
+        message.map(String::from).unwrap_or_else(|| format!("knowledge: add {}", path)),
+        "bm knowledge add",
+        KnowledgeEdit::Write {
+            path,
+            content,
+            overwrite: false,
+        },
+    )
+}
+
+/// Handles `bm knowledge edit <path> [-t team]`.
+pub fn edit(path: &str, message: Option<&str>, team_flag: Option<&str>) -> Result<()> {
+    let team_repo = load_team_repo(team_flag)?;
+
+    let current = knowledge::read_base_file(&team_repo, path)?;
+    let content = edit_in_editor(path, &current)?;
+    if content == current {
+        println!("No changes to {}, nothing to propose.", path);
+        return Ok(());
+    }
+
+    submit(
+        team_flag,
+        &team_repo,
+        message.map(String::from).unwrap_or_else(|| format!("knowledge: update {}", path)),
+        "bm knowledge edit",
+        KnowledgeEdit::Write {
+            path: path.to_string(),
+            content,
+            overwrite: true,
+        },
+    )
+}
+
+/// Handles `bm knowledge move <path> --scope S [-p project] [--member M]`.
+pub fn move_file(
+    path: &str,
+    scope: &str,
+    project: Option<&str>,
+    member: Option<&str>,
+    message: Option<&str>,
+    team_flag: Option<&str>,
+) -> Result<()> {
+    let team_repo = load_team_repo(team_flag)?;
+
+    knowledge::validate_authoring_path(path)?;
+    let kind = knowledge::kind_of_path(path)
+        .with_context(|| format!("Cannot tell the scope of {}", path))?;
+    let name = path.rsplit('/').next().unwrap_or(path);
+    let placement = Placement {
+        scope: scope.parse::<Scope>()?,
+        kind,
+        project,
+        member,
+    };
+    let to = placement.file_path(name)?;
+    placement.check_exists(&team_repo)?;
+    if to == path {
+        bail!("{} is already in the {} scope", path, placement.scope.label());
+    }
+
+    submit(
+        team_flag,
+        &team_repo,
+        message.map(String::from).unwrap_or_else(|| format!("knowledge: move {} to {}", path, to)),
+        "bm knowledge move",
+        KnowledgeEdit::Move {
+            from: path.to_string(),
+            to,
+        },
+    )
+}
+
+/// Handles `bm knowledge rm <path> [-t team]`.
+pub fn remove(path: &str, message: Option<&str>, team_flag: Option<&str>) -> Result<()> {
+    let team_repo = load_team_repo(team_flag)?;
+
+    knowledge::validate_authoring_path(path)?;
+
+    submit(
+        team_flag,
+        &team_repo,
+        message.map(String::from).unwrap_or_else(|| format!("knowledge: remove {}", path)),
+        "bm knowledge rm",
+        KnowledgeEdit::Remove {
+            path: path.to_string(),
+        },
+    )
+}
+
+/// Handles `bm knowledge search <query> [--scope S] [--member M] [-p project] [--kind K]`.
+pub fn search(
+    query: &str,
+    scope: Option<&str>,
+    member: Option<&str>,
+    project: Option<&str>,
+    kind: Option<&str>,
+    limit: usize,
+    team_flag: Option<&str>,
+) -> Result<()> {
+    let team_repo = load_team_repo(team_flag)?;
+
+    let filter = SearchFilter {
+        scope: scope.map(str::parse::<Scope>).transpose()?,
+        member: member.map(String::from),
+        project: project.map(String::from),
+        kind: kind.map(str::parse::<DocKind>).transpose()?,
+    };
+    let hits = knowledge::search_team(&team_repo, query, &filter, limit)?;
+
+    if hits.is_empty() {
+        println!("No matches for \"{}\".", query);
+        return Ok(());
+    }
+    for hit in &hits {
+        println!("{} ({}, {})", hit.path, hit.scope.label(), hit.kind.label());
+        println!(
+            "  {}: {}",
+            hit.line,
+            hit.highlighted(|t| console::style(t).bold().yellow().to_string())
+        );
+    }
+    println!();
+    println!("{} result(s)", hits.len());
 
     Ok(())
 }
 
+/// Resolves the team and checks its schema. Returns the team repo path.
+fn load_team_repo(team_flag: Option<&str>) -> Result<PathBuf> {
+    let cfg = config::load()?;
+    let team = config::resolve_team(&cfg, team_flag)?;
+    let team_repo = team.path.join("team");
+
+    let team_schema = profile::read_team_schema(&team_repo)?;
+    profile::require_current_schema(&team.name, &team_schema)?;
+
+    Ok(team_repo)
+}
+
+/// Pushes a single-edit proposal and reports the pull request.
+fn submit(
+    team_flag: Option<&str>,
+    team_repo: &Path,
+    title: String,
+    command: &str,
+    edit: KnowledgeEdit,
+) -> Result<()> {
+    let cfg = config::load()?;
+    let team = config::resolve_team(&cfg, team_flag)?;
+    let token = forge::require_token(team.forge.as_ref())?;
+    let team_forge = forge::for_team(team, &token)?;
+
+    let proposal = Proposal {
+        title,
+        body: format!("Opened with `{}`.", command),
+        edits: vec![edit],
+    };
+    let (pushed, url) = knowledge::open_pull_request(
+        team_forge.as_ref(),
+        &team.github_repo,
+        team_repo,
+        &proposal,
+    )?;
+    println!("Pushed branch {} (base {}).", pushed.branch, pushed.base);
+    println!("Opened pull request: {}", url);
+    Ok(())
+}
+
+/// Opens `$VISUAL`/`$EDITOR` (default `vi`) on a temporary copy of `initial`
+/// and returns the saved content.
+fn edit_in_editor(path: &str, initial: &str) -> Result<String> {
+    let name = path.rsplit('/').next().unwrap_or("knowledge.md");
+    let tmp = tempfile::tempdir().context("Failed to create a temporary directory")?;
+    let file = tmp.path().join(name);
+    fs::write(&file, initial).context("Failed to write temporary file")?;
+
+    let editor = std::env::var("VISUAL")
+        .or_else(|_| std::env::var("EDITOR"))
+        .unwrap_or_else(|_| "vi".to_string());
+    let mut parts = editor.split_whitespace();
+    let program = parts.next().unwrap_or("vi");
+    let status = Command::new(program)
+        .args(parts)
+        .arg(&file)
+        .status()
+        .with_context(|| format!("Failed to launch editor '{}'", editor))?;
+    if !status.success() {
+        bail!("Editor '{}' exited with {}", editor, status);
+    }
+
+    fs::read_to_string(&file).context("Failed to read edited file")
+}
+
 /// Handles `bm knowledge [-t team]` (bare — launches interactive Claude session).
 pub fn interactive(team_flag: Option<&str>, _scope: Option<&str>) -> Result<()> {
     let cfg = config::load()?;
@@ -0,0 +1,25 @@
+use anyhow::{Context, Result};
+
+use crate::audit::Actor;
+use crate::mcp::{self, McpMode, ToolContext};
+
+/// Handles `bm mcp serve [--mode read-only|operator] [-t team] [--actor kind:name]`.
+///
+/// Speaks MCP over stdin/stdout until the client closes stdin. Stdout carries
+/// protocol messages only; nothing else may be printed to it.
+pub fn serve(mode: &str, team_flag: Option<&str>, actor: Option<&str>) -> Result<()> {
+    let actor = match actor {
+        Some(a) => Actor::parse(a).with_context(|| {
+            format!("Invalid actor '{}'. Use <kind>:<name>, e.g. agent:chief-ann.", a)
+        })?,
+        None => Actor::cli(),
+    };
+    let ctx = ToolContext {
+        mode: McpMode::parse(mode)?,
+        team: team_flag.map(String::from),
+        actor,
+    };
+    let stdin = std::io::stdin();
+    let stdout = std::io::stdout();
+    mcp::serve(stdin.lock(), stdout.lock(), &ctx)
+}
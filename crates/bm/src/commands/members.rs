@@ -1,9 +1,15 @@
+use std::io::IsTerminal;
+
 use anyhow::{bail, Result};
 use comfy_table::{
     ContentArrangement, modifiers::UTF8_ROUND_CORNERS, presets::UTF8_FULL_CONDENSED, Table,
 };
 
-use crate::config;
+use crate::config::{self, ForgeKind};
+use crate::formation;
+use crate::git::app_drift::{self, Drift, MemberDrift};
+use crate::git::GitHubHost;
+use crate::member_lifecycle::{self, ArchiveParams, RestoreParams};
 use crate::profile;
 use crate::state::{self, MemberStatus};
 use crate::workspace;
@@ -15,7 +21,8 @@ pub fn list(team_flag: Option<&str>) -> Result<()> {
     let team_repo = team.path.join("team");
 
     let member_dirs = profile::discover_member_dirs(&team_repo);
-    if member_dirs.is_empty() {
+    let archived = member_lifecycle::list_archived(&team_repo);
+    if member_dirs.is_empty() && archived.is_empty() {
         println!("No members hired yet. Run `bm hire <role>` to hire a member.");
         return Ok(());
     }
@@ -36,7 +43,117 @@ pub fn list(team_flag: Option<&str>) -> Result<()> {
         table.add_row(vec![member.as_str(), role.as_str(), status.label()]);
     }
 
-    println!("{table}");
+    if !member_dirs.is_empty() {
+        println!("{table}");
+    }
+
+    if !archived.is_empty() {
+        println!("\nArchived (restore with `bm members restore <member>`):");
+        for (name, meta) in &archived {
+            match meta {
+                Some(meta) => {
+                    let date = meta.archived_at.split('T').next().unwrap_or(&meta.archived_at);
+                    match &meta.reason {
+                        Some(reason) => println!("  {} ({}, archived {}): {}", name, meta.role, date, reason),
+                        None => println!("  {} ({}, archived {})", name, meta.role, date),
+                    }
+                }
+                None => println!("  {}", name),
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Handles `bm members archive <member> [--reason] [-t team]`.
+///
+/// Stops the member and moves it to `members/.archived/`, keeping its App,
+/// credentials and workspace so it can be restored later.
+pub fn archive(member: &str, reason: Option<&str>, team_flag: Option<&str>) -> Result<()> {
+    let cfg = config::load()?;
+    let team = config::resolve_team(&cfg, team_flag)?;
+
+    let local_formation = formation::create_local_formation(&team.name)?;
+    let result = member_lifecycle::archive_member(
+        &ArchiveParams {
+            team,
+            config: &cfg,
+            member,
+            reason,
+        },
+        &*local_formation,
+    )?;
+
+    let mut succeeded = vec!["Moved member to members/.archived/"];
+    if result.stopped {
+        succeeded.insert(0, "Stopped member");
+    }
+    if result.bridge_identity_removed {
+        succeeded.push("Released bridge identity");
+    }
+
+    println!("\nArchived '{}' in team '{}'.", member, team.name);
+    println!("  Succeeded: {}", succeeded.join(", "));
+    for e in &result.errors {
+        eprintln!("  Failed [{}]: {}", e.step, e.error);
+    }
+    println!(
+        "  Kept: GitHub App installation, App credentials, workspace and workspace repo\n\
+         \nRestore with: bm members restore {}",
+        member
+    );
+
+    if !result.errors.is_empty() {
+        bail!("Some archive steps failed. Check the errors above; the member is archived.");
+    }
+    Ok(())
+}
+
+/// Handles `bm members restore <member> [-t team]`.
+pub fn restore(member: &str, team_flag: Option<&str>) -> Result<()> {
+    let cfg = config::load()?;
+    let team = config::resolve_team(&cfg, team_flag)?;
+
+    let result = member_lifecycle::restore_member(&RestoreParams {
+        team,
+        config: &cfg,
+        member,
+    })?;
+
+    let mut succeeded = vec!["Moved member back to members/"];
+    if result.app_verified {
+        succeeded.push("Verified GitHub App credentials");
+    }
+    if result.bridge_identity_provisioned {
+        succeeded.push("Provisioned bridge identity");
+    }
+
+    println!(
+        "\nRestored '{}' in team '{}' (archived {}).",
+        member, team.name, result.meta.archived_at
+    );
+    println!("  Succeeded: {}", succeeded.join(", "));
+    for e in &result.errors {
+        eprintln!("  Failed [{}]: {}", e.step, e.error);
+    }
+    if result.app_credentials_missing {
+        let role = &result.meta.role;
+        let suffix = member
+            .strip_prefix(role.as_str())
+            .and_then(|rest| rest.strip_prefix('-'))
+            .unwrap_or(member);
+        eprintln!(
+            "  No GitHub App credentials found for '{}'. Re-attach its App with\n    \
+             bm hire {} --name {} -t {} --reuse-app --app-id <id> --client-id <id> \
+             --private-key-file <pem> --installation-id <id>",
+            member, role, suffix, team.name
+        );
+    }
+    println!("\nNext: run `bm teams sync` to refresh the workspace, then `bm start {}`.", member);
+
+    if !result.errors.is_empty() {
+        bail!("Some restore steps failed. Fix the errors above, then run `bm teams sync`.");
+    }
     Ok(())
 }
 
@@ -61,9 +178,12 @@ pub fn show(member: &str, team_flag: Option<&str>) -> Result<()> {
     let runtime_state = state::load().unwrap_or_default();
     let status = state::resolve_member_status(&runtime_state, &team.name, member);
     match &status {
-        MemberStatus::Running { pid, started_at, brain_mode } => {
+        MemberStatus::Running { pid, started_at, brain_mode, paused_at } => {
             let label = if *brain_mode { "brain" } else { "running" };
             println!("Status: {}\nPID: {}\nStarted: {}", label, pid, started_at);
+            if let Some(paused_at) = paused_at {
+                println!("Paused: {} (resume with `bm resume {}`)", paused_at, member);
+            }
         }
         MemberStatus::Crashed { pid, started_at } => {
             println!("Status: crashed\nPID: {}\nStarted: {}", pid, started_at);
@@ -106,6 +226,227 @@ pub fn show(member: &str, team_flag: Option<&str>) -> Result<()> {
     Ok(())
 }
 
+/// Handles `bm members refresh [<member>] [--all] [--dry-run] [-t team]`.
+///
+/// Three-way merges profile changes to each member's role skeleton into the
+/// member's customised files. Members that merge cleanly are committed to the
+/// team repo; members with conflicts are left with conflict markers for the
+/// operator to resolve and commit.
+pub fn refresh(member: Option<&str>, all: bool, dry_run: bool, team_flag: Option<&str>) -> Result<()> {
+    super::ensure_profiles(false)?;
+
+    let cfg = config::load()?;
+    let team = config::resolve_team(&cfg, team_flag)?;
+    let team_repo = team.path.join("team");
+
+    let manifest = profile::read_team_repo_manifest(&team_repo)?;
+    profile::check_schema_version(&team.profile, &manifest.schema_version)?;
+    let coding_agent = profile::resolve_coding_agent(team, &manifest)?;
+
+    let targets = match (member, all) {
+        (Some(_), true) => bail!("Pass either a member name or --all, not both."),
+        (Some(m), false) => vec![m.to_string()],
+        (None, true) => profile::discover_member_dirs(&team_repo),
+        (None, false) => bail!("Specify a member to refresh, or --all to refresh every member."),
+    };
+    if targets.is_empty() {
+        println!("No members hired yet. Run `bm hire <role>` to hire a member.");
+        return Ok(());
+    }
+
+    let mut clean = Vec::new();
+    let mut changed = Vec::new();
+    let mut conflicted = Vec::new();
+    let mut failed = 0;
+
+    for target in &targets {
+        let result = match profile::refresh_member(
+            &team_repo,
+            &team.profile,
+            target,
+            &coding_agent,
+            dry_run,
+        ) {
+            Ok(r) => r,
+            Err(e) if all => {
+                eprintln!("{}: {:#}", target, e);
+                failed += 1;
+                continue;
+            }
+            Err(e) => return Err(e),
+        };
+        display_refresh(&result);
+        if result.conflicts() > 0 {
+            conflicted.push(result.member_dir_name);
+        } else {
+            // Even an unchanged member gets its skeleton snapshot rewritten
+            // (and created, for members hired before snapshots existed).
+            if !result.files.is_empty() {
+                changed.push(result.member_dir_name.clone());
+            }
+            clean.push(result.member_dir_name);
+        }
+    }
+
+    println!();
+    if dry_run {
+        println!("Dry run — no files were changed.");
+    } else {
+        if profile::commit_refresh(&team_repo, &clean, &team.profile)? && !changed.is_empty() {
+            println!("Committed refresh of {} to the team repo.", changed.join(", "));
+        }
+        if !conflicted.is_empty() {
+            let paths: Vec<String> = conflicted.iter().map(|m| format!("members/{}", m)).collect();
+            println!(
+                "Conflicts left in {}. Resolve the conflict markers, then run:\n  \
+                 git -C {repo} add {} && \
+                 git -C {repo} commit -m \"chore: refresh from profile {}\"",
+                conflicted.join(", "),
+                paths.join(" "),
+                team.profile,
+                repo = team_repo.display(),
+            );
+        }
+        if !changed.is_empty() || !conflicted.is_empty() {
+            println!("Run `bm teams sync` to propagate the changes to workspaces.");
+        }
+    }
+
+    if failed > 0 {
+        bail!("{} member(s) could not be refreshed", failed);
+    }
+    Ok(())
+}
+
+/// Handles `bm members doctor [member] [--fix] [-t team]`.
+///
+/// Compares each member's GitHub App with the permissions, events and repos
+/// the team needs. Missing repo access can be granted from here; the rest
+/// needs an organization owner in the GitHub UI, so the command prints where.
+pub fn doctor(member: Option<&str>, fix: bool, team_flag: Option<&str>) -> Result<()> {
+    let cfg = config::load()?;
+    let team = config::resolve_team(&cfg, team_flag)?;
+    if team.forge_kind() != ForgeKind::GitHub {
+        println!("Team '{}' is not on GitHub; its members have no GitHub App to check.", team.name);
+        return Ok(());
+    }
+    let team_repo = team.path.join("team");
+
+    let targets = match member {
+        Some(m) => {
+            if !team_repo.join("members").join(m).is_dir() {
+                bail!(
+                    "Member '{}' not found in team '{}'. Run `bm members list` to see hired members.",
+                    m, team.name
+                );
+            }
+            vec![m.to_string()]
+        }
+        None => profile::discover_member_dirs(&team_repo),
+    };
+    if targets.is_empty() {
+        println!("No members hired yet. Run `bm hire <role>` to hire a member.");
+        return Ok(());
+    }
+
+    let host = GitHubHost::for_team(team);
+    let interactive = std::io::stdin().is_terminal();
+    let mut remaining = 0;
+    let mut failed = 0;
+
+    for target in &targets {
+        let found = match app_drift::check_member(team, target) {
+            Ok(Some(found)) => found,
+            Ok(None) => {
+                println!("{}: no GitHub App, skipped", target);
+                continue;
+            }
+            Err(e) => {
+                eprintln!("{}: {:#}", target, e);
+                failed += 1;
+                continue;
+            }
+        };
+        if found.drift.is_empty() {
+            println!("{}: {}", target, console::style("ok").green());
+            continue;
+        }
+
+        println!(
+            "{}: {}",
+            target,
+            console::style(format!("{} drift finding(s)", found.drift.len())).red()
+        );
+        for drift in &found.drift {
+            println!("  {}", drift);
+            if let Drift::MissingRepo(repo) = drift {
+                if repair_repo(&host, &found, repo, fix, interactive) {
+                    println!("    granted {} access to {}", found.slug, repo);
+                    continue;
+                }
+            }
+            println!("    fix: {}", found.repair_url(&host, drift));
+            remaining += 1;
+        }
+    }
+
+    if failed > 0 {
+        bail!("{} member(s) could not be checked", failed);
+    }
+    if remaining > 0 {
+        bail!("{} drift finding(s) remain", remaining);
+    }
+    Ok(())
+}
+
+/// Grants the installation access to a repo with `--fix`, or after asking
+/// on a terminal. Returns true if access was granted.
+fn repair_repo(
+    host: &GitHubHost,
+    found: &MemberDrift,
+    repo: &str,
+    fix: bool,
+    interactive: bool,
+) -> bool {
+    let approved = fix
+        || (interactive
+            && cliclack::confirm(format!("Grant {} access to {}?", found.slug, repo))
+                .initial_value(true)
+                .interact()
+                .unwrap_or(false));
+    if !approved {
+        return false;
+    }
+    match app_drift::grant_repo(host, found.installation_id, repo) {
+        Ok(()) => true,
+        Err(e) => {
+            eprintln!("    could not grant access: {:#}", e);
+            false
+        }
+    }
+}
+
+/// Prints the per-file outcome of a member refresh.
+fn display_refresh(result: &profile::RefreshResult) {
+    if result.files.is_empty() {
+        println!("{}: up to date", result.member_dir_name);
+        return;
+    }
+    let base = match result.base_source {
+        profile::BaseSource::Snapshot => "",
+        profile::BaseSource::HireCommit => " (base: hire commit)",
+    };
+    println!("{}{}:", result.member_dir_name, base);
+    for file in &result.files {
+        println!("  {:<18} {}", file.action.label(), file.path);
+        for hunk in &file.conflict_hunks {
+            for line in hunk.lines() {
+                println!("      {}", line);
+            }
+        }
+    }
+}
+
 /// Displays a labeled list of files, or "none" if empty.
 fn display_file_list(label: &str, files: &[String]) {
     println!();
@@ -1,8 +1,10 @@
 use std::fs;
+use std::path::{Path, PathBuf};
 
 use anyhow::{bail, Context, Result};
 
 use crate::config;
+use crate::mcp::{self, McpMode};
 use crate::profile;
 use crate::profile::CodingAgentDef;
 
@@ -40,12 +42,17 @@ pub fn run(team_flag: Option<&str>, autonomous: bool) -> Result<()> {
         )
     })?;
 
+    let mcp_config = register_mcp_server(&minty_dir, &agent, team_flag)?;
+
     // Launch coding agent via exec (replaces this process)
     use std::os::unix::process::CommandExt;
     let mut cmd = std::process::Command::new(&agent.binary);
     cmd.current_dir(&minty_dir)
         .arg(prompt_flag)
         .arg(&prompt_path);
+    if let (Some(flag), Some(path)) = (agent.mcp_config_flag.as_deref(), &mcp_config) {
+        cmd.arg(flag).arg(path);
+    }
     if autonomous {
         if let Some(flag) = agent.skip_permissions_flag.as_deref() {
             cmd.arg(flag);
@@ -56,6 +63,22 @@ pub fn run(team_flag: Option<&str>, autonomous: bool) -> Result<()> {
     bail!("Failed to launch {}: {}", agent.binary, err);
 }
 
+/// Registers `bm mcp serve` (operator mode) in Minty's MCP config so Minty
+/// gets structured team tools. Returns the config path, or `None` when the
+/// coding agent has no MCP config file.
+fn register_mcp_server(
+    minty_dir: &Path,
+    agent: &CodingAgentDef,
+    team_flag: Option<&str>,
+) -> Result<Option<PathBuf>> {
+    let Some(ref config_file) = agent.mcp_config_file else {
+        return Ok(None);
+    };
+    let path = minty_dir.join(config_file);
+    mcp::register_server(&path, &mcp::server_command(), McpMode::Operator, team_flag, None)?;
+    Ok(Some(path))
+}
+
 /// Resolves the coding agent definition from team config or profile defaults.
 fn resolve_coding_agent(team_flag: Option<&str>) -> Result<CodingAgentDef> {
     if let Some(team_name) = team_flag {
@@ -114,6 +137,35 @@ mod tests {
         assert!(minty_dir.join(".claude/skills/hire-guide/SKILL.md").exists());
     }
 
+    #[test]
+    fn register_mcp_server_writes_agent_config() {
+        let tmp = tempfile::tempdir().unwrap();
+        let mut agent = CodingAgentDef {
+            name: "claude-code".into(),
+            display_name: "Claude Code".into(),
+            context_file: "CLAUDE.md".into(),
+            agent_dir: ".claude".into(),
+            binary: "claude".into(),
+            system_prompt_flag: None,
+            skip_permissions_flag: None,
+            mcp_config_file: Some(".mcp.json".into()),
+            mcp_config_flag: Some("--mcp-config".into()),
+            mcp_mode: None,
+        };
+
+        let path = register_mcp_server(tmp.path(), &agent, Some("alpha"))
+            .unwrap()
+            .unwrap();
+        let json: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        let args = json["mcpServers"]["botminter"]["args"].as_array().unwrap();
+        assert!(args.contains(&serde_json::json!("operator")));
+        assert!(args.contains(&serde_json::json!("alpha")));
+
+        agent.mcp_config_file = None;
+        assert!(register_mcp_server(tmp.path(), &agent, None).unwrap().is_none());
+    }
+
     #[test]
     fn resolve_agent_team_not_found_errors() {
         let result = resolve_coding_agent(Some("nonexistent-team"));
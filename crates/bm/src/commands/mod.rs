@@ -1,4 +1,6 @@
+pub mod apply;
 pub mod attach;
+pub mod audit;
 pub mod bootstrap;
 pub mod brain_run;
 pub mod bridge;
@@ -11,12 +13,16 @@ pub mod daemon;
 pub mod fire;
 pub mod hire;
 pub mod init;
+pub mod invariants;
 pub mod knowledge;
+pub mod mcp;
 pub mod members;
 pub mod minty;
+pub mod pause;
 pub mod profiles;
 pub mod profiles_init;
 pub mod projects;
+pub mod resume;
 pub mod roles;
 pub mod start;
 pub mod status;
@@ -0,0 +1,73 @@
+use std::time::Duration;
+
+use anyhow::{bail, Result};
+
+use crate::config;
+use crate::formation;
+use crate::team::Team;
+
+/// Handles `bm pause [member] [-t team] [--now] [--timeout <secs>]`.
+pub fn run(
+    team_flag: Option<&str>,
+    member_filter: Option<&str>,
+    now: bool,
+    timeout: u64,
+) -> Result<()> {
+    let cfg = config::load()?;
+    let team = config::resolve_team(&cfg, team_flag)?;
+
+    let local_formation = formation::create_local_formation(&team.name)?;
+    let team_api = Team::new(team, local_formation);
+
+    if !now {
+        eprintln!(
+            "Waiting for running iterations to finish (up to {}s)...",
+            timeout
+        );
+    }
+    let result = team_api.pause(member_filter, now, Duration::from_secs(timeout))?;
+
+    if result.no_members_running {
+        match member_filter {
+            Some(target) => println!(
+                "Member '{}' is not running for team '{}'.",
+                target, team.name
+            ),
+            None => println!("No members running for team '{}'.", team.name),
+        }
+        println!("The daemon won't launch paused members until `bm resume`.");
+        return Ok(());
+    }
+
+    for m in &result.paused {
+        if m.already_paused {
+            eprintln!("Pausing {}... already paused", m.name);
+        } else if m.brain_mode {
+            eprintln!(
+                "Pausing {} (brain)... done, bridge messages are queued",
+                m.name
+            );
+        } else if m.immediate {
+            eprintln!("Pausing {} (now)... done, frozen mid-iteration", m.name);
+        } else {
+            eprintln!(
+                "Pausing {}... done, held after its current iteration",
+                m.name
+            );
+        }
+    }
+    for m in &result.errors {
+        eprintln!("Pausing {}... failed: {}", m.name, m.error);
+    }
+
+    println!(
+        "\nPaused {} member(s), {} error(s). Resume with `bm resume`.",
+        result.paused.len(),
+        result.errors.len()
+    );
+
+    if !result.errors.is_empty() {
+        bail!("Some members could not be paused.");
+    }
+    Ok(())
+}
@@ -1,6 +1,7 @@
-use anyhow::Result;
+use anyhow::{bail, Result};
 use comfy_table::{ContentArrangement, Table, presets::UTF8_FULL_CONDENSED, modifiers::UTF8_ROUND_CORNERS};
 
+use crate::config;
 use crate::profile;
 
 /// Handles `bm profiles list` — displays a table of all embedded profiles.
@@ -31,16 +32,32 @@ pub fn list() -> Result<()> {
 }
 
 /// Handles `bm profiles describe <profile>` — shows full profile details.
-/// When `show_tags` is true, appends a summary of agent-tagged files.
+/// When `show_tags` is true, appends a summary of files with conditional tags
+/// (coding agent, bridge, formation, brain, ...).
 pub fn describe(name: &str, show_tags: bool) -> Result<()> {
     super::ensure_profiles(false)?;
     let manifest = profile::read_manifest(name)?;
+    // Provenance is only shown for profiles that extend another.
+    let inherited = profile::resolve_profile(name).ok().filter(|r| r.extends());
+    let origin = |list: &str, item: &str| {
+        inherited
+            .as_ref()
+            .and_then(|r| r.origin(list, item))
+            .map(|layer| format!(" [{}]", layer))
+            .unwrap_or_default()
+    };
 
     println!("Profile: {}", manifest.name);
     println!("Display Name: {}", manifest.display_name);
     println!("Version: {}", manifest.version);
     println!("Schema: {}", manifest.schema_version);
     println!("Description: {}", manifest.description);
+    if let Some(src) = profile::installed_source(name) {
+        println!("Source: {} ({})", src.spec(), short_commit(&src.commit));
+    }
+    if let Some(resolved) = &inherited {
+        println!("Extends: {}", resolved.chain.join(" → "));
+    }
 
     println!();
     println!("Available Roles:");
@@ -56,13 +73,21 @@ pub fn describe(name: &str, show_tags: bool) -> Result<()> {
         let desc = role_descriptions
             .get(role.as_str())
             .unwrap_or(&"");
-        println!("  {:<20} {}", role, desc);
+        println!("  {:<20} {}{}", role, desc, origin("roles", role));
+    }
+
+    if inherited.is_some() {
+        println!();
+        println!("Statuses ({}):", manifest.statuses.len());
+        for status in &manifest.statuses {
+            println!("  {:<30} {}{}", status.name, status.description, origin("statuses", &status.name));
+        }
     }
 
     println!();
     println!("Labels ({}):", manifest.labels.len());
     for label in &manifest.labels {
-        println!("  {:<30} {}", label.name, label.description);
+        println!("  {:<30} {}{}", label.name, label.description, origin("labels", &label.name));
     }
 
     if !manifest.coding_agents.is_empty() {
@@ -88,22 +113,264 @@ pub fn describe(name: &str, show_tags: bool) -> Result<()> {
         println!();
         println!("Bridges ({}):", manifest.bridges.len());
         for bridge in &manifest.bridges {
-            println!("  {:<20} {} [{}] — {}", bridge.name, bridge.display_name, bridge.bridge_type, bridge.description);
+            println!(
+                "  {:<20} {} [{}] — {}{}",
+                bridge.name, bridge.display_name, bridge.bridge_type, bridge.description,
+                origin("bridges", &bridge.name)
+            );
+        }
+    }
+
+    if let Some(resolved) = &inherited {
+        let overridden = resolved.overridden_files();
+        println!();
+        println!("Files:");
+        for (layer, count) in resolved.file_counts() {
+            let overrides = overridden.iter().filter(|(_, l)| *l == layer).count();
+            if overrides > 0 {
+                println!("  {:<20} {} files ({} overridden)", layer, count, overrides);
+            } else {
+                println!("  {:<20} {} files", layer, count);
+            }
+        }
+        for (path, layer) in &overridden {
+            println!("  overrides: {} [{}]", path, layer);
         }
     }
 
     if show_tags {
-        let tagged_files = profile::scan_agent_tags(name)?;
+        let tagged_files = profile::scan_profile_tags(name)?;
+        let agent_of = |c: &String| c.strip_prefix("agent:").map(String::from);
+
         println!();
         if tagged_files.is_empty() {
+            println!("Tag Dimensions: none");
+        } else {
+            let mut dimensions: std::collections::BTreeMap<&str, Vec<&str>> =
+                std::collections::BTreeMap::new();
+            for condition in tagged_files.iter().flat_map(|(_, c)| c) {
+                let (dim, value) = condition
+                    .split_once(':')
+                    .map_or((condition.as_str(), None), |(d, v)| (d, Some(v)));
+                let values = dimensions.entry(dim).or_default();
+                if let Some(v) = value {
+                    if !values.contains(&v) {
+                        values.push(v);
+                    }
+                }
+            }
+            let summary: Vec<String> = dimensions
+                .iter()
+                .map(|(dim, values)| {
+                    if values.is_empty() {
+                        dim.to_string()
+                    } else {
+                        format!("{} ({})", dim, values.join(", "))
+                    }
+                })
+                .collect();
+            println!("Tag Dimensions: {}", summary.join(", "));
+        }
+
+        let agent_files: Vec<(&String, Vec<String>)> = tagged_files
+            .iter()
+            .map(|(path, c)| (path, c.iter().filter_map(agent_of).collect::<Vec<_>>()))
+            .filter(|(_, agents)| !agents.is_empty())
+            .collect();
+        if agent_files.is_empty() {
             println!("Coding-Agent Dependent Files: none");
         } else {
-            println!("Coding-Agent Dependent Files ({} files):", tagged_files.len());
-            for (path, agents) in &tagged_files {
+            println!("Coding-Agent Dependent Files ({} files):", agent_files.len());
+            for (path, agents) in &agent_files {
                 println!("  {} ({})", path, agents.join(", "));
             }
         }
+
+        let other_files: Vec<&(String, Vec<String>)> = tagged_files
+            .iter()
+            .filter(|(_, c)| c.iter().any(|c| agent_of(c).is_none()))
+            .collect();
+        if !other_files.is_empty() {
+            println!("Other Conditional Files ({} files):", other_files.len());
+            for (path, conditions) in other_files {
+                println!("  {} ({})", path, conditions.join(", "));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Handles `bm profiles create [<name>] --from-team <team> [--agent-tags] [--force]`
+/// — reverse-extracts a team repo into a new profile on disk.
+pub fn create(name: Option<&str>, from_team: &str, agent_tags: bool, force: bool) -> Result<()> {
+    super::ensure_profiles(false)?;
+    let cfg = config::load()?;
+    let team = config::resolve_team(&cfg, Some(from_team))?;
+    let team_repo = team.path.join("team");
+
+    let manifest = profile::read_team_repo_manifest(&team_repo)?;
+    let coding_agent = profile::resolve_coding_agent(team, &manifest)?;
+
+    let name = name.unwrap_or(&team.name);
+    if name.is_empty() || name.contains(['/', '\\']) || name.starts_with('.') {
+        bail!("Invalid profile name '{}'", name);
+    }
+    let profiles = profile::profiles_dir()?;
+    let target = profiles.join(name);
+    if target.exists() {
+        if !force {
+            bail!(
+                "Profile '{}' already exists at {}. Use --force to replace it.",
+                name,
+                target.display()
+            );
+        }
+        std::fs::remove_dir_all(&target)?;
+    }
+
+    let source_dir = profiles.join(&team.profile);
+    let summary = profile::export_team_profile(
+        &profile::ExportParams {
+            team_repo: &team_repo,
+            team_name: &team.name,
+            coding_agent: &coding_agent,
+            source_profile_dir: source_dir.is_dir().then_some(source_dir.as_path()),
+            profile_name: name,
+            agent_tags,
+        },
+        &target,
+    )?;
+
+    println!("Created profile '{}' from team '{}' at {}", name, team.name, target.display());
+    println!();
+    println!("Roles:");
+    for (role, origin) in &summary.roles {
+        let from = match origin {
+            profile::RoleOrigin::Member(m) => format!("from member {}", m),
+            profile::RoleOrigin::SourceProfile => format!("from profile {}", team.profile),
+        };
+        println!("  {:<20} {}", role, from);
+    }
+    println!();
+    println!(
+        "{} files ({} unchanged from '{}'{})",
+        summary.files,
+        summary.restored,
+        team.profile,
+        if agent_tags {
+            format!(", {} re-tagged", summary.tagged)
+        } else {
+            String::new()
+        }
+    );
+    println!("Use it with `bm init --profile {}`.", name);
+    Ok(())
+}
+
+/// Handles `bm profiles add <url>[@ref] [--force]` — installs profiles from a
+/// git repository and records the source for `bm profiles update`.
+pub fn add(source: &str, force: bool) -> Result<()> {
+    super::ensure_profiles(false)?;
+    let paths = profile::SourcePaths::resolve()?;
+    let added = profile::add_source(&paths, source, force)?;
+
+    for src in &added {
+        println!(
+            "Installed profile '{}' v{} from {} ({})",
+            src.profile,
+            src.version,
+            src.spec(),
+            short_commit(&src.commit)
+        );
+    }
+    if let [only] = added.as_slice() {
+        println!("Use it with `bm init --profile {}`.", only.profile);
+    }
+    Ok(())
+}
+
+/// Handles `bm profiles update [<profile>] [--check]` — fetches profile
+/// sources, installs newer versions and lists the teams built from them.
+pub fn update(profile_name: Option<&str>, check: bool) -> Result<()> {
+    let paths = profile::SourcePaths::resolve()?;
+    let profile::SourceUpdates { updates, failed } =
+        profile::update_sources(&paths, profile_name, check)?;
+    if updates.is_empty() && failed.is_empty() {
+        println!("No profiles installed from git sources. Add one with `bm profiles add <url>`.");
+        return Ok(());
+    }
+
+    let teams = config::load_or_default().teams;
+    for u in &updates {
+        let name = &u.source.profile;
+        if !u.changed() {
+            println!("{}: up to date (v{}, {})", name, u.source.version, short_commit(&u.source.commit));
+            if !u.tracks_branch && !u.newer_tags.is_empty() {
+                println!(
+                    "  Pinned to '{}'. Newer versions: {}",
+                    u.source.git_ref.as_deref().unwrap_or_default(),
+                    u.newer_tags.join(", ")
+                );
+                println!(
+                    "  Pin a newer one with `bm profiles add {}@<version>`.",
+                    u.source.url
+                );
+            }
+            continue;
+        }
+
+        let verb = if u.applied { "updated" } else { "update available" };
+        println!(
+            "{}: {} v{} ({}) → v{} ({})",
+            name,
+            verb,
+            u.source.version,
+            short_commit(&u.source.commit),
+            u.to_version,
+            short_commit(&u.to_commit)
+        );
+        if let Some((old, new)) = &u.schema_change {
+            println!(
+                "  Schema changes from {} to {}: teams on schema {} will be rejected until migrated.",
+                old, new, old
+            );
+        }
+
+        let affected: Vec<_> = teams.iter().filter(|t| &t.profile == name).collect();
+        if affected.is_empty() {
+            println!("  No teams use this profile.");
+            continue;
+        }
+        println!("  Affected teams:");
+        for team in &affected {
+            let built_from = match &team.profile_source {
+                Some(src) => format!("built from v{} ({})", src.version, short_commit(&src.commit)),
+                None => "source not recorded".to_string(),
+            };
+            println!("    {:<20} {}", team.name, built_from);
+        }
+        if u.applied {
+            println!(
+                "  Run `bm members refresh --all -t <team>` and `bm teams sync -t <team>` to roll it out."
+            );
+        }
     }
 
+    for (name, error) in &failed {
+        eprintln!("{}: update failed: {}", name, error);
+    }
+
+    if check && updates.iter().any(|u| u.changed()) {
+        println!();
+        println!("Run `bm profiles update` to install.");
+    }
+    if !failed.is_empty() {
+        bail!("{} profile(s) could not be updated", failed.len());
+    }
     Ok(())
 }
+
+fn short_commit(commit: &str) -> &str {
+    &commit[..commit.len().min(7)]
+}
@@ -68,7 +68,9 @@ pub fn add(url: &str, team_flag: Option<&str>) -> Result<()> {
     let cfg = config::load()?;
     let team = config::resolve_team(&cfg, team_flag)?;
     let team_repo = team.path.join("team");
+    let host = git::GitHubHost::for_team(team);
     let name = git::add_project(
+        &host,
         &team_repo,
         url,
         &team.github_repo,
@@ -76,7 +78,7 @@ pub fn add(url: &str, team_flag: Option<&str>) -> Result<()> {
     println!("Added project '{}' to team '{}'.", name, team.name);
 
     // Install all hired members' Apps on the new project repo (Req 17).
-    if let Some(owner_repo) = manifest_flow::fork_url_to_owner_repo(url) {
+    if let Some(owner_repo) = manifest_flow::fork_url_to_owner_repo(&host, url) {
         install_member_apps_on_repo(team, &team_repo, &owner_repo);
     }
 
@@ -161,7 +163,13 @@ fn install_member_apps_on_repo(
         };
 
         eprintln!("Installing {member}'s App on {owner_repo}...");
-        if let Err(e) = manifest_flow::ensure_app_on_repos(&installation_id, &client_id, &private_key, &[owner_repo]) {
+        if let Err(e) = manifest_flow::ensure_app_on_repos(
+            &git::GitHubHost::for_team(team),
+            &installation_id,
+            &client_id,
+            &private_key,
+            &[owner_repo],
+        ) {
             eprintln!("Warning: failed to install {member}'s App on {owner_repo}: {e}");
         }
     }
@@ -0,0 +1,51 @@
+use anyhow::{bail, Result};
+
+use crate::config;
+use crate::formation;
+use crate::team::Team;
+
+/// Handles `bm resume [member] [-t team]`.
+pub fn run(team_flag: Option<&str>, member_filter: Option<&str>) -> Result<()> {
+    let cfg = config::load()?;
+    let team = config::resolve_team(&cfg, team_flag)?;
+
+    let local_formation = formation::create_local_formation(&team.name)?;
+    let team_api = Team::new(team, local_formation);
+
+    let result = team_api.resume(member_filter)?;
+
+    if result.no_members_running {
+        match member_filter {
+            Some(target) => println!(
+                "Member '{}' is not running for team '{}'.",
+                target, team.name
+            ),
+            None => println!("No members running for team '{}'.", team.name),
+        }
+        return Ok(());
+    }
+
+    let mut resumed = 0;
+    for m in &result.resumed {
+        if m.was_paused {
+            resumed += 1;
+            eprintln!("Resuming {}... done", m.name);
+        } else if member_filter.is_some() {
+            eprintln!("Resuming {}... not paused", m.name);
+        }
+    }
+    for m in &result.errors {
+        eprintln!("Resuming {}... failed: {}", m.name, m.error);
+    }
+
+    println!(
+        "\nResumed {} member(s), {} error(s).",
+        resumed,
+        result.errors.len()
+    );
+
+    if !result.errors.is_empty() {
+        bail!("Some members could not be resumed.");
+    }
+    Ok(())
+}
@@ -4,6 +4,8 @@ use comfy_table::{
 };
 
 use crate::config;
+use crate::daemon::{TokenHealth, TokenState};
+use crate::formation;
 use crate::state::{self, MemberStatus};
 
 /// Handles `bm status [-t team] [-v]`.
@@ -37,6 +39,9 @@ pub fn run(team_flag: Option<&str>, verbose: bool) -> Result<()> {
             ),
             _ => println!("Daemon: running (PID {})", d.pid),
         }
+        if let Some(unit) = &d.unit {
+            println!("Daemon unit: {} (systemd)", unit);
+        }
         if d.port > 0 {
             println!("Console: http://localhost:{}", d.port);
         }
@@ -54,12 +59,18 @@ pub fn run(team_flag: Option<&str>, verbose: bool) -> Result<()> {
         .load_preset(UTF8_FULL_CONDENSED)
         .apply_modifier(UTF8_ROUND_CORNERS)
         .set_content_arrangement(ContentArrangement::DynamicFullWidth)
-        .set_header(vec!["Member", "Role", "Status", "Branch", "Started", "PID"]);
+        .set_header(vec!["Member", "Role", "Status", "Branch", "Started", "PID", "Token"]);
 
+    let now = chrono::Utc::now();
     for m in &info.members {
         let (label, started, pid_str) = match &m.status {
-            MemberStatus::Running { pid, started_at, brain_mode } => {
-                let status = if *brain_mode { "brain" } else { "running" };
+            MemberStatus::Running { pid, started_at, brain_mode, paused_at } => {
+                let status = match (paused_at, brain_mode) {
+                    (Some(_), true) => "brain (paused)",
+                    (Some(_), false) => "paused",
+                    (None, true) => "brain",
+                    (None, false) => "running",
+                };
                 (status, format_timestamp(started_at), pid.to_string())
             }
             MemberStatus::Crashed { pid, started_at } => {
@@ -74,10 +85,37 @@ pub fn run(team_flag: Option<&str>, verbose: bool) -> Result<()> {
             &m.branch,
             &started,
             &pid_str,
+            &format_token(m.token_health.as_ref(), now),
         ]);
     }
     println!("{table}");
 
+    // Paused members: say since when and how to resume
+    for m in &info.members {
+        if let MemberStatus::Running { paused_at: Some(paused_at), .. } = &m.status {
+            println!(
+                "{} paused since {}. Resume with `bm resume {}`.",
+                m.name,
+                format_timestamp(paused_at),
+                m.name
+            );
+        }
+    }
+
+    // Token problems: show why refreshes fail
+    for m in &info.members {
+        let Some(health) = &m.token_health else { continue };
+        if health.state(now) == TokenState::Healthy {
+            continue;
+        }
+        if let Some(err) = &health.last_error {
+            println!(
+                "Token refresh for {} failing ({} attempts): {}",
+                m.name, health.consecutive_failures, err
+            );
+        }
+    }
+
     // Bridge
     if let Some(b) = &info.bridge {
         println!();
@@ -111,6 +149,16 @@ pub fn run(team_flag: Option<&str>, verbose: bool) -> Result<()> {
         }
         for ri in &v.ralph_sections {
             println!("\n── {} (PID {}) ──", ri.member, ri.pid);
+            if let Some(u) = &ri.usage {
+                println!("\n  Resources ({}.scope):", u.unit);
+                println!("    Memory: {}", format_usage(u.memory_current, u.memory_max, formation::format_bytes));
+                println!(
+                    "    CPU: {} used{}",
+                    format_cpu_time(u.cpu_usage_usec),
+                    u.cpu_quota_pct.map(|p| format!(", quota {}%", p)).unwrap_or_default()
+                );
+                println!("    Tasks: {}", format_usage(u.tasks_current, u.tasks_max, |n| n.to_string()));
+            }
             for (label, output) in &ri.sections {
                 println!("\n  {}:", label);
                 for line in output.lines() {
@@ -123,7 +171,46 @@ pub fn run(team_flag: Option<&str>, verbose: bool) -> Result<()> {
     Ok(())
 }
 
+/// Formats a cgroup counter against its limit ("1.2G / 4.0G", "12 / max").
+fn format_usage(current: u64, max: Option<u64>, fmt: impl Fn(u64) -> String) -> String {
+    match max {
+        Some(m) => format!("{} / {}", fmt(current), fmt(m)),
+        None => format!("{} / max", fmt(current)),
+    }
+}
+
+/// Formats cumulative CPU time in microseconds as "1h02m03s" / "2m03s" / "3.4s".
+fn format_cpu_time(usec: u64) -> String {
+    let secs = usec / 1_000_000;
+    if secs >= 3600 {
+        format!("{}h{:02}m{:02}s", secs / 3600, (secs % 3600) / 60, secs % 60)
+    } else if secs >= 60 {
+        format!("{}m{:02}s", secs / 60, secs % 60)
+    } else {
+        format!("{:.1}s", usec as f64 / 1_000_000.0)
+    }
+}
+
 /// Formats an ISO 8601 timestamp for display, stripping sub-seconds.
+/// Formats a member's token health for the status table.
+fn format_token(health: Option<&TokenHealth>, now: chrono::DateTime<chrono::Utc>) -> String {
+    let Some(health) = health else {
+        return "—".to_string();
+    };
+    let state = health.state(now);
+    match state {
+        TokenState::Failing => format!("{} ({}x)", state.label(), health.consecutive_failures),
+        TokenState::Expiring => {
+            let mins = health
+                .expiry()
+                .map(|exp| (exp - now).num_minutes().max(0))
+                .unwrap_or(0);
+            format!("{} ({}m left)", state.label(), mins)
+        }
+        _ => state.label().to_string(),
+    }
+}
+
 fn format_timestamp(ts: &str) -> String {
     if let Ok(dt) = chrono::DateTime::parse_from_rfc3339(ts) {
         dt.format("%Y-%m-%d %H:%M:%S").to_string()
@@ -161,4 +248,36 @@ mod tests {
         let result = format_timestamp("");
         assert_eq!(result, "");
     }
+
+    // ── token health ──────────────────────────────────────────────
+
+    #[test]
+    fn format_token_states() {
+        let now = chrono::Utc::now();
+        let health = |mins: i64, failures: u32| TokenHealth {
+            expires_at: Some((now + chrono::Duration::minutes(mins)).to_rfc3339()),
+            consecutive_failures: failures,
+            ..Default::default()
+        };
+        assert_eq!(format_token(None, now), "—");
+        assert_eq!(format_token(Some(&health(40, 0)), now), "healthy");
+        assert_eq!(format_token(Some(&health(40, 3)), now), "failing (3x)");
+        assert!(format_token(Some(&health(8, 3)), now).starts_with("expiring ("));
+        assert_eq!(format_token(Some(&health(-5, 3)), now), "expired");
+    }
+
+    // ── resource usage ────────────────────────────────────────────
+
+    #[test]
+    fn format_usage_with_and_without_limit() {
+        assert_eq!(format_usage(3, Some(512), |n| n.to_string()), "3 / 512");
+        assert_eq!(format_usage(3, None, |n| n.to_string()), "3 / max");
+    }
+
+    #[test]
+    fn format_cpu_time_units() {
+        assert_eq!(format_cpu_time(3_400_000), "3.4s");
+        assert_eq!(format_cpu_time(123_000_000), "2m03s");
+        assert_eq!(format_cpu_time(3_723_000_000), "1h02m03s");
+    }
 }
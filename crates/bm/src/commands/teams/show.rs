@@ -24,6 +24,18 @@ pub fn show(name: Option<&str>, team_flag: Option<&str>) -> Result<()> {
             println!("Profile Source: {}", profile_source.display());
         }
     }
+    if let Some(src) = &team.profile_source {
+        let spec = match &src.git_ref {
+            Some(r) => format!("{}@{}", src.url, r),
+            None => src.url.clone(),
+        };
+        println!(
+            "Built From: {} v{} ({})",
+            spec,
+            src.version,
+            &src.commit[..src.commit.len().min(7)]
+        );
+    }
 
     let manifest_path = team_repo.join("botminter.yml");
     if let Ok(contents) = std::fs::read_to_string(&manifest_path) {
@@ -46,7 +58,8 @@ pub fn show(name: Option<&str>, team_flag: Option<&str>) -> Result<()> {
     }
     if let Some(number) = team.project_number {
         let owner = team.github_repo.split('/').next().unwrap_or(&team.github_repo);
-        println!("Board: https://github.com/orgs/{}/projects/{}", owner, number);
+        let host = crate::git::GitHubHost::for_team(team);
+        println!("Board: {}", host.project_url(owner, number));
     }
     println!("Path: {}", team.path.display());
     println!("Default: {}", if is_default { "yes" } else { "no" });
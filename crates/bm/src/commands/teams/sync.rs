@@ -22,6 +22,7 @@ pub fn sync(repos: bool, bridge_flag: bool, verbose: bool, team_flag: Option<&st
 
     let gh = if team.github_repo.is_empty() { None } else { Some(team.github_repo.as_str()) };
 
+    let github_host = crate::git::GitHubHost::for_team(team);
     let params = workspace::TeamSyncParams {
         team_repo: &team_repo,
         team_path: &team.path,
@@ -29,6 +30,8 @@ pub fn sync(repos: bool, bridge_flag: bool, verbose: bool, team_flag: Option<&st
         manifest: &manifest,
         coding_agent,
         github_repo: gh,
+        forge: team.forge.as_ref(),
+        github_host: &github_host,
         repos,
         verbose,
         bridge_flag,
@@ -100,6 +103,15 @@ fn display_sync_event(event: &TeamSyncEvent) {
         TeamSyncEvent::WorkspaceCreateFailed { name, error } => eprintln!("Error: {}: {}", name, error),
         TeamSyncEvent::RobotInjected { member, enabled } => println!("  RObot.enabled = {} for {}", enabled, member),
         TeamSyncEvent::BrainPromptSurfaced { member } => println!("  Brain prompt surfaced for {}", member),
+        TeamSyncEvent::BrainPromptFailed { member, error } => eprintln!("Error: {}: brain prompt: {}", member, error),
+        TeamSyncEvent::McpServerRegistered { member } => println!("  MCP server registered for {}", member),
+        TeamSyncEvent::McpServerFailed { member, error } => eprintln!("Warning: {}: MCP server not registered: {}", member, error),
+        TeamSyncEvent::SearchIndexUpdated { indexed, removed } => {
+            if *indexed > 0 || *removed > 0 {
+                println!("Search index: {} file(s) indexed, {} removed", indexed, removed);
+            }
+        }
+        TeamSyncEvent::SearchIndexFailed { error } => eprintln!("Warning: search index not updated: {}", error),
     }
 }
 
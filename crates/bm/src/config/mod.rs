@@ -51,6 +51,57 @@ pub struct TeamEntry {
     /// Optional Lima VM name this team is linked to.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub vm: Option<String>,
+    /// Git source and version of the profile this team was built from.
+    /// `None` for teams built from embedded or hand-copied profiles.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile_source: Option<TeamProfileSource>,
+    /// Forge hosting the team's repos. `None` means github.com.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub forge: Option<ForgeConfig>,
+}
+
+impl TeamEntry {
+    /// The forge kind hosting this team's repos.
+    pub fn forge_kind(&self) -> ForgeKind {
+        self.forge.as_ref().map_or(ForgeKind::GitHub, |f| f.kind)
+    }
+}
+
+/// Which forge implementation serves a team.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum ForgeKind {
+    #[default]
+    GitHub,
+    /// Gitea or Forgejo (API-compatible).
+    #[serde(alias = "forgejo")]
+    Gitea,
+}
+
+/// A self-hosted forge: Gitea/Forgejo (recorded by `bm init --forge`) or a
+/// GitHub Enterprise Server host (recorded by `bm init --github-host`).
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct ForgeConfig {
+    pub kind: ForgeKind,
+    /// Base URL of the forge, e.g. `https://forgejo.example.com`.
+    pub url: String,
+    /// REST API base of a GitHub host, when it isn't `{url}/api/v3`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_base: Option<String>,
+}
+
+/// Where a team's profile came from (recorded by `bm init`).
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct TeamProfileSource {
+    /// Git URL or local path of the profile source.
+    pub url: String,
+    /// Branch, tag or commit the source is pinned to (`None` = default branch).
+    #[serde(rename = "ref", default, skip_serializing_if = "Option::is_none")]
+    pub git_ref: Option<String>,
+    /// `version` from the profile's `botminter.yml`.
+    pub version: String,
+    /// Commit the profile was installed from.
+    pub commit: String,
 }
 
 /// Controls bridge lifecycle relative to member start/stop.
@@ -298,6 +349,8 @@ mod tests {
                 project_number: None,
                 bridge_lifecycle: Default::default(),
                 vm: None,
+                profile_source: None,
+                forge: None,
             }],
             vms: Vec::new(),
             keyring_collection: None,
@@ -313,6 +366,22 @@ mod tests {
         assert!(loaded.teams[0].credentials.telegram_bot_token.is_none());
     }
 
+    #[test]
+    fn forge_config_parses_and_defaults_to_github() {
+        let yaml = "kind: forgejo\nurl: https://forgejo.example.com\n";
+        let forge: ForgeConfig = serde_yml::from_str(yaml).unwrap();
+        assert_eq!(forge.kind, ForgeKind::Gitea);
+        assert_eq!(serde_yml::to_string(&forge.kind).unwrap().trim(), "gitea");
+
+        let mut team: TeamEntry = serde_yml::from_str(
+            "name: t\npath: /tmp/t\nprofile: scrum\ngithub_repo: org/t\ncredentials: {}\n",
+        )
+        .unwrap();
+        assert_eq!(team.forge_kind(), ForgeKind::GitHub);
+        team.forge = Some(forge);
+        assert_eq!(team.forge_kind(), ForgeKind::Gitea);
+    }
+
     #[test]
     fn load_missing_config_errors() {
         let tmp = tempfile::tempdir().unwrap();
@@ -359,6 +428,8 @@ mod tests {
                     project_number: None,
                     bridge_lifecycle: Default::default(),
                 vm: None,
+                profile_source: None,
+                forge: None,
                 },
                 TeamEntry {
                     name: "other".to_string(),
@@ -370,6 +441,8 @@ mod tests {
                     project_number: None,
                     bridge_lifecycle: Default::default(),
                 vm: None,
+                profile_source: None,
+                forge: None,
                 },
             ],
             vms: Vec::new(),
@@ -397,6 +470,8 @@ mod tests {
                 project_number: None,
                 bridge_lifecycle: Default::default(),
                 vm: None,
+                profile_source: None,
+                forge: None,
             }],
             vms: Vec::new(),
             keyring_collection: None,
@@ -468,6 +543,8 @@ mod tests {
                 project_number: None,
                 bridge_lifecycle: Default::default(),
                 vm: None,
+                profile_source: None,
+                forge: None,
             }],
             vms: Vec::new(),
             keyring_collection: None,
@@ -1,16 +1,21 @@
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
-use axum::extract::State;
-use axum::http::StatusCode;
+use axum::extract::{Path as AxumPath, State};
+use axum::http::{HeaderMap, StatusCode};
 use axum::response::IntoResponse;
 use axum::Json;
 use serde::{Deserialize, Serialize};
 
 use anyhow::Context;
 
+use super::config::DaemonPaths;
+use super::deliveries;
+use super::event_kind::EventKind;
 use super::log::daemon_log;
-use super::run::DaemonState;
+use super::run::{parse_payload, route_event, AppCredentialsMap, DaemonState};
+use super::token_health;
+use crate::audit::{self, Actor, Record};
 use crate::formation::{self, CredentialDomain};
 use crate::git::app_auth;
 use crate::git::manifest_flow::credential_keys;
@@ -35,6 +40,13 @@ pub struct StopMembersRequest {
     pub force: bool,
 }
 
+/// Request body for `POST /api/members/credentials/reload`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ReloadCredentialsRequest {
+    /// Member whose App credentials changed in the credential store.
+    pub member: String,
+}
+
 // ── Response types ───────────────────────────────────────────────────
 
 /// Response for `POST /api/members/start`.
@@ -94,6 +106,47 @@ pub struct MemberStatusInfo {
     pub workspace: Option<String>,
     pub brain_mode: bool,
     pub started_at: Option<String>,
+    /// App token health, for members whose token the daemon refreshes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token_health: Option<TokenHealthInfo>,
+}
+
+/// Token health of a member as reported by `GET /api/members`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TokenHealthInfo {
+    /// `healthy`, `failing`, `expiring` or `expired`.
+    pub state: String,
+    pub last_refresh: Option<String>,
+    pub expires_at: Option<String>,
+    pub consecutive_failures: u32,
+    pub last_error: Option<String>,
+}
+
+impl TokenHealthInfo {
+    pub fn from_health(
+        health: &token_health::TokenHealth,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> Self {
+        Self {
+            state: health.state(now).label().to_string(),
+            last_refresh: health.last_refresh.clone(),
+            expires_at: health.expires_at.clone(),
+            consecutive_failures: health.consecutive_failures,
+            last_error: health.last_error.clone(),
+        }
+    }
+}
+
+/// Response for `POST /api/members/credentials/reload`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ReloadCredentialsResponse {
+    pub ok: bool,
+    /// False when the daemon is not refreshing this member's token
+    /// (member not running, or started outside the daemon).
+    pub reloaded: bool,
+    /// Expiry of the installation token minted with the reloaded key.
+    pub expires_at: Option<String>,
+    pub error: Option<String>,
 }
 
 /// Response for `GET /api/health`.
@@ -125,6 +178,18 @@ pub struct StartLoopResponse {
     pub error: Option<String>,
 }
 
+/// Response for `POST /api/deliveries/{id}/replay`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ReplayDeliveryResponse {
+    pub ok: bool,
+    /// Full ID of the replayed delivery.
+    pub id: Option<String>,
+    pub event: Option<String>,
+    /// What routing did on replay (`launched members`, `ignored`, ...).
+    pub action: Option<String>,
+    pub error: Option<String>,
+}
+
 /// Error response body.
 #[derive(Debug, Serialize)]
 struct ErrorResponse {
@@ -137,6 +202,7 @@ struct ErrorResponse {
 /// POST /api/members/start — launches team members.
 pub(super) async fn start_members_handler(
     State(state): State<DaemonState>,
+    headers: HeaderMap,
     Json(req): Json<StartMembersRequest>,
 ) -> impl IntoResponse {
     let paths = Arc::clone(&state.paths);
@@ -149,6 +215,8 @@ pub(super) async fn start_members_handler(
             req.member.as_deref().unwrap_or("all")
         ),
     );
+    let record =
+        Record::new(request_actor(&headers), "members.start").target(req.member.as_deref());
 
     let cfg = Arc::clone(&state.config);
     let team_entry = Arc::clone(&state.team_entry);
@@ -178,7 +246,7 @@ pub(super) async fn start_members_handler(
 
             for member in &start_result.launched {
                 if let Ok(cached) = cache_app_credentials(
-                    &team_name_for_cache,
+                    &state.team_entry,
                     &member.name,
                     &member.pid,
                 ) {
@@ -186,7 +254,7 @@ pub(super) async fn start_members_handler(
                     app_creds
                         .lock()
                         .unwrap()
-                        .insert(member_name.clone(), cached.clone());
+                        .insert(member_name.clone(), cached);
 
                     daemon_log(
                         &paths,
@@ -194,13 +262,24 @@ pub(super) async fn start_members_handler(
                         &format!("Cached App credentials for member '{}'", member_name),
                     );
 
-                    // Spawn background refresh task (50-minute interval — Req 11)
-                    let refresh_creds = cached;
+                    // The launch just delivered a fresh installation token.
+                    token_health::record_success(
+                        &paths.token_health(),
+                        &member_name,
+                        chrono::Utc::now()
+                            + chrono::Duration::minutes(token_health::INSTALLATION_TOKEN_TTL_MINS),
+                    );
+
+                    // Spawn background refresh task (50-minute interval — Req 11).
+                    // The loop reads the shared cache each cycle so a rotated
+                    // key takes effect without restarting the member.
+                    let refresh_creds = Arc::clone(&app_creds);
                     let refresh_shutdown = Arc::clone(&shutdown_for_refresh);
                     let refresh_paths = Arc::clone(&paths_for_refresh);
                     let refresh_team = team_name_for_cache.clone();
                     tokio::spawn(async move {
                         run_token_refresh_loop(
+                            member_name,
                             refresh_creds,
                             &refresh_team,
                             &refresh_paths,
@@ -212,6 +291,21 @@ pub(super) async fn start_members_handler(
             }
 
             let has_errors = !start_result.errors.is_empty();
+            let launched: Vec<&str> = start_result
+                .launched
+                .iter()
+                .map(|m| m.name.as_str())
+                .collect();
+            let mut record = record.param("launched", launched);
+            if has_errors {
+                let errors: Vec<String> = start_result
+                    .errors
+                    .iter()
+                    .map(|m| format!("{}: {}", m.name, m.error))
+                    .collect();
+                record = record.failed(errors.join("; "));
+            }
+            record_audit(&paths, record);
             let resp = StartMembersResponse {
                 ok: !has_errors,
                 launched: start_result
@@ -244,6 +338,7 @@ pub(super) async fn start_members_handler(
         }
         Ok(Err(e)) => {
             daemon_log(&paths, "ERROR", &format!("API start failed: {}", e));
+            record_audit(&paths, record.failed(e.to_string()));
             let resp = ErrorResponse {
                 ok: false,
                 error: e.to_string(),
@@ -256,6 +351,7 @@ pub(super) async fn start_members_handler(
         }
         Err(e) => {
             daemon_log(&paths, "ERROR", &format!("API start panicked: {}", e));
+            record_audit(&paths, record.failed("internal error"));
             let resp = ErrorResponse {
                 ok: false,
                 error: "internal error".to_string(),
@@ -273,10 +369,11 @@ pub(super) async fn start_members_handler(
 /// cached credentials for the refresh loop. Returns Err if the member
 /// has no App credentials (not an error — just means legacy auth).
 fn cache_app_credentials(
-    team_name: &str,
+    team: &crate::config::TeamEntry,
     member_name: &str,
     _pid: &u32,
 ) -> anyhow::Result<formation::AppCredentialsCached> {
+    let team_name = team.name.as_str();
     let formation = formation::local::create_local_formation(team_name)?;
     let store = formation.credential_store(CredentialDomain::GitHubApp {
         team_name: team_name.to_string(),
@@ -311,14 +408,20 @@ fn cache_app_credentials(
         private_key,
         installation_id,
         workspace,
+        api_base: crate::git::GitHubHost::for_team(team).api_base,
     })
 }
 
 /// Background task: refreshes installation tokens every 50 minutes.
 /// On failure, retries with exponential backoff. The existing token
 /// remains valid until its 1-hour expiry (Req 11).
+///
+/// Credentials are looked up in the shared cache on every attempt, so a
+/// key reloaded via `POST /api/members/credentials/reload` is picked up by
+/// the next refresh. The loop ends when the member leaves the cache.
 async fn run_token_refresh_loop(
-    creds: formation::AppCredentialsCached,
+    member_name: String,
+    app_creds: AppCredentialsMap,
     team_name: &str,
     paths: &super::config::DaemonPaths,
     shutdown: &std::sync::atomic::AtomicBool,
@@ -338,20 +441,20 @@ async fn run_token_refresh_loop(
             daemon_log(
                 paths,
                 "INFO",
-                &format!("Token refresh loop stopping for member '{}'", creds.member_name),
+                &format!("Token refresh loop stopping for member '{}'", member_name),
             );
             break;
         }
 
         // Check if the member process is still alive
-        let state_key = format!("{}/{}", team_name, creds.member_name);
+        let state_key = format!("{}/{}", team_name, member_name);
         if !is_member_alive(&state_key) {
             daemon_log(
                 paths,
                 "INFO",
                 &format!(
                     "Member '{}' no longer running, stopping refresh loop",
-                    creds.member_name
+                    member_name
                 ),
             );
             break;
@@ -361,25 +464,46 @@ async fn run_token_refresh_loop(
         // Tokens expire after 60 minutes. We refresh at 50 minutes, leaving
         // a 10-minute window for retries before the token becomes invalid.
         loop {
+            let Some(creds) = app_creds.lock().unwrap().get(&member_name).cloned() else {
+                daemon_log(
+                    paths,
+                    "INFO",
+                    &format!(
+                        "No cached credentials for '{}', stopping refresh loop",
+                        member_name
+                    ),
+                );
+                return;
+            };
             let refresh_result = attempt_token_refresh(&creds, team_name).await;
 
             match refresh_result {
-                Ok(Ok(())) => {
+                Ok(Ok(expires_at)) => {
+                    token_health::record_success(
+                        &paths.token_health(),
+                        &member_name,
+                        expires_at,
+                    );
                     daemon_log(
                         paths,
                         "INFO",
-                        &format!("Refreshed token for member '{}'", creds.member_name),
+                        &format!("Refreshed token for member '{}'", member_name),
                     );
                     backoff = std::time::Duration::from_secs(10);
                     break; // Success — resume outer 50-min cycle
                 }
                 Ok(Err(e)) => {
+                    token_health::record_failure(
+                        &paths.token_health(),
+                        &member_name,
+                        &format!("{:#}", e),
+                    );
                     daemon_log(
                         paths,
                         "ERROR",
                         &format!(
                             "Token refresh failed for '{}': {}. Retrying in {}s. Existing token valid until expiry.",
-                            creds.member_name,
+                            member_name,
                             e,
                             backoff.as_secs()
                         ),
@@ -394,7 +518,7 @@ async fn run_token_refresh_loop(
                             "INFO",
                             &format!(
                                 "Stopping retry loop for '{}' (shutdown or member exited)",
-                                creds.member_name
+                                member_name
                             ),
                         );
                         return; // Exit the entire refresh function
@@ -404,7 +528,7 @@ async fn run_token_refresh_loop(
                     daemon_log(
                         paths,
                         "ERROR",
-                        &format!("Token refresh task panicked for '{}': {}", creds.member_name, e),
+                        &format!("Token refresh task panicked for '{}': {}", member_name, e),
                     );
                     return; // Unrecoverable — exit
                 }
@@ -426,22 +550,23 @@ fn is_member_alive(state_key: &str) -> bool {
 }
 
 /// Attempt a single token refresh: JWT generation + exchange + atomic write.
+/// Returns the new token's expiry.
 async fn attempt_token_refresh(
     creds: &formation::AppCredentialsCached,
     team_name: &str,
-) -> Result<anyhow::Result<()>, tokio::task::JoinError> {
+) -> Result<anyhow::Result<chrono::DateTime<chrono::Utc>>, tokio::task::JoinError> {
     tokio::task::spawn_blocking({
         let creds = creds.clone();
         let team_name = team_name.to_string();
-        move || -> anyhow::Result<()> {
+        move || -> anyhow::Result<chrono::DateTime<chrono::Utc>> {
             let jwt = app_auth::generate_jwt(&creds.client_id, &creds.private_key)?;
             let inst_token =
-                app_auth::exchange_for_installation_token(&jwt, creds.installation_id)?;
+                app_auth::exchange_for_installation_token(&creds.api_base, &jwt, creds.installation_id)?;
 
             let formation = formation::local::create_local_formation(&team_name)?;
             formation.refresh_token(&creds.member_name, &creds.workspace, &inst_token.token)?;
 
-            Ok(())
+            Ok(inst_token.expires_at)
         }
     })
     .await
@@ -450,6 +575,7 @@ async fn attempt_token_refresh(
 /// POST /api/members/stop — stops team members.
 pub(super) async fn stop_members_handler(
     State(state): State<DaemonState>,
+    headers: HeaderMap,
     Json(req): Json<StopMembersRequest>,
 ) -> impl IntoResponse {
     let paths = Arc::clone(&state.paths);
@@ -463,9 +589,13 @@ pub(super) async fn stop_members_handler(
             req.force
         ),
     );
+    let record = Record::new(request_actor(&headers), "members.stop")
+        .target(req.member.as_deref())
+        .param("force", req.force);
 
     let cfg = Arc::clone(&state.config);
     let team_entry = Arc::clone(&state.team_entry);
+    let health_path = paths.token_health();
 
     let result = tokio::task::spawn_blocking(move || {
         formation::stop_local_members(
@@ -479,7 +609,26 @@ pub(super) async fn stop_members_handler(
 
     match result {
         Ok(Ok(stop_result)) => {
+            for m in &stop_result.stopped {
+                token_health::forget(&health_path, &m.name);
+                state.app_credentials.lock().unwrap().remove(&m.name);
+            }
             let has_errors = !stop_result.errors.is_empty();
+            let stopped: Vec<&str> = stop_result
+                .stopped
+                .iter()
+                .map(|m| m.name.as_str())
+                .collect();
+            let mut record = record.param("stopped", stopped);
+            if has_errors {
+                let errors: Vec<String> = stop_result
+                    .errors
+                    .iter()
+                    .map(|m| format!("{}: {}", m.name, m.error))
+                    .collect();
+                record = record.failed(errors.join("; "));
+            }
+            record_audit(&paths, record);
             let resp = StopMembersResponse {
                 ok: !has_errors,
                 stopped: stop_result
@@ -504,6 +653,7 @@ pub(super) async fn stop_members_handler(
         }
         Ok(Err(e)) => {
             daemon_log(&paths, "ERROR", &format!("API stop failed: {}", e));
+            record_audit(&paths, record.failed(e.to_string()));
             let resp = ErrorResponse {
                 ok: false,
                 error: e.to_string(),
@@ -516,6 +666,7 @@ pub(super) async fn stop_members_handler(
         }
         Err(e) => {
             daemon_log(&paths, "ERROR", &format!("API stop panicked: {}", e));
+            record_audit(&paths, record.failed("internal error"));
             let resp = ErrorResponse {
                 ok: false,
                 error: "internal error".to_string(),
@@ -534,10 +685,13 @@ pub(super) async fn list_members_handler(
     State(state): State<DaemonState>,
 ) -> impl IntoResponse {
     let team_name = state.team_name.clone();
+    let health_path = state.paths.token_health();
 
     let result = tokio::task::spawn_blocking(move || {
         let runtime_state = state::load()?;
         let team_prefix = format!("{}/", team_name);
+        let health = token_health::load_token_health(&health_path);
+        let now = chrono::Utc::now();
 
         let members: Vec<MemberStatusInfo> = runtime_state
             .members
@@ -549,6 +703,9 @@ pub(super) async fn list_members_handler(
                     .unwrap_or(key)
                     .to_string();
                 let alive = state::is_alive(rt.pid);
+                let token_health = health
+                    .get(&name)
+                    .map(|h| TokenHealthInfo::from_health(h, now));
                 MemberStatusInfo {
                     name,
                     status: if alive {
@@ -564,6 +721,7 @@ pub(super) async fn list_members_handler(
                     workspace: Some(rt.workspace.to_string_lossy().to_string()),
                     brain_mode: rt.brain_mode,
                     started_at: Some(rt.started_at.clone()),
+                    token_health,
                 }
             })
             .collect();
@@ -602,6 +760,190 @@ pub(super) async fn list_members_handler(
     }
 }
 
+/// POST /api/members/credentials/reload — re-reads a member's App
+/// credentials from the credential store after a key rotation.
+///
+/// Replaces the cached credentials used by the refresh loop and mints a
+/// token with them right away, so a bad key surfaces now rather than at
+/// the next 50-minute refresh.
+pub(super) async fn reload_credentials_handler(
+    State(state): State<DaemonState>,
+    Json(req): Json<ReloadCredentialsRequest>,
+) -> impl IntoResponse {
+    let paths = Arc::clone(&state.paths);
+    daemon_log(
+        &paths,
+        "INFO",
+        &format!("API: reload credentials for member '{}'", req.member),
+    );
+
+    if !state.app_credentials.lock().unwrap().contains_key(&req.member) {
+        let resp = ReloadCredentialsResponse {
+            ok: true,
+            reloaded: false,
+            expires_at: None,
+            error: None,
+        };
+        return (StatusCode::OK, Json(serde_json::to_value(resp).unwrap())).into_response();
+    }
+
+    let team_entry = Arc::clone(&state.team_entry);
+    let member = req.member.clone();
+    let cached = tokio::task::spawn_blocking(move || {
+        cache_app_credentials(&team_entry, &member, &0)
+    })
+    .await;
+
+    let creds = match cached {
+        Ok(Ok(creds)) => creds,
+        Ok(Err(e)) => {
+            daemon_log(
+                &paths,
+                "ERROR",
+                &format!("Credential reload failed for '{}': {:#}", req.member, e),
+            );
+            let resp = ReloadCredentialsResponse {
+                ok: false,
+                reloaded: false,
+                expires_at: None,
+                error: Some(format!("{:#}", e)),
+            };
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::to_value(resp).unwrap()),
+            )
+                .into_response();
+        }
+        Err(e) => {
+            daemon_log(&paths, "ERROR", &format!("API reload panicked: {}", e));
+            let resp = ErrorResponse {
+                ok: false,
+                error: "internal error".to_string(),
+            };
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::to_value(resp).unwrap()),
+            )
+                .into_response();
+        }
+    };
+
+    state
+        .app_credentials
+        .lock()
+        .unwrap()
+        .insert(req.member.clone(), creds.clone());
+
+    let resp = match attempt_token_refresh(&creds, &state.team_name).await {
+        Ok(Ok(expires_at)) => {
+            token_health::record_success(&paths.token_health(), &req.member, expires_at);
+            daemon_log(
+                &paths,
+                "INFO",
+                &format!("Reloaded credentials and refreshed token for '{}'", req.member),
+            );
+            ReloadCredentialsResponse {
+                ok: true,
+                reloaded: true,
+                expires_at: Some(expires_at.to_rfc3339()),
+                error: None,
+            }
+        }
+        Ok(Err(e)) => {
+            token_health::record_failure(&paths.token_health(), &req.member, &format!("{:#}", e));
+            daemon_log(
+                &paths,
+                "ERROR",
+                &format!("Token refresh with reloaded key failed for '{}': {}", req.member, e),
+            );
+            ReloadCredentialsResponse {
+                ok: false,
+                reloaded: true,
+                expires_at: None,
+                error: Some(format!("{:#}", e)),
+            }
+        }
+        Err(e) => ReloadCredentialsResponse {
+            ok: false,
+            reloaded: true,
+            expires_at: None,
+            error: Some(format!("token refresh task panicked: {}", e)),
+        },
+    };
+    (StatusCode::OK, Json(serde_json::to_value(resp).unwrap())).into_response()
+}
+
+/// POST /api/deliveries/{id}/replay — routes a stored webhook delivery
+/// again, as if the forge had redelivered it.
+///
+/// Only deliveries that passed validation can be replayed, so the endpoint
+/// can't be used to push unsigned payloads through routing.
+pub(super) async fn replay_delivery_handler(
+    State(state): State<DaemonState>,
+    AxumPath(id): AxumPath<String>,
+) -> impl IntoResponse {
+    let path = state.paths.deliveries();
+    let failed = |status: StatusCode, error: String| {
+        let resp = ReplayDeliveryResponse {
+            ok: false,
+            id: None,
+            event: None,
+            action: None,
+            error: Some(error),
+        };
+        (status, Json(serde_json::to_value(resp).unwrap())).into_response()
+    };
+
+    let Some(delivery) = deliveries::find_delivery(&path, &id) else {
+        return failed(
+            StatusCode::NOT_FOUND,
+            format!("No stored delivery matches '{}'", id),
+        );
+    };
+    if !delivery.validation.accepted() {
+        return failed(
+            StatusCode::CONFLICT,
+            format!(
+                "Delivery {} was rejected ({}); only accepted deliveries can be replayed",
+                delivery.id,
+                delivery.validation.label()
+            ),
+        );
+    }
+
+    let Some(payload) = delivery.payload.as_deref() else {
+        return failed(
+            StatusCode::CONFLICT,
+            format!(
+                "Delivery {} has no stored payload (bodies over {} bytes are not kept)",
+                delivery.id,
+                deliveries::MAX_PAYLOAD_BYTES
+            ),
+        );
+    };
+
+    daemon_log(
+        &state.paths,
+        "INFO",
+        &format!("API: replay delivery {}", delivery.id),
+    );
+    let event = delivery
+        .event
+        .as_deref()
+        .map(|t| EventKind::parse(t, &parse_payload(payload.as_bytes())));
+    let action = route_event(&state, event.as_ref());
+    deliveries::record_replay(&path, &delivery.id, action);
+
+    let resp = ReplayDeliveryResponse {
+        ok: true,
+        id: Some(delivery.id),
+        event: delivery.event,
+        action: Some(action.label().to_string()),
+        error: None,
+    };
+    (StatusCode::OK, Json(serde_json::to_value(resp).unwrap())).into_response()
+}
+
 /// GET /api/health — enhanced health check with daemon metadata.
 pub(super) async fn health_check_handler(
     State(state): State<DaemonState>,
@@ -647,6 +989,7 @@ pub(super) async fn health_check_handler(
 /// POST /api/loops/start — spawns a Ralph loop in a member's workspace.
 pub(super) async fn start_loop_handler(
     State(state): State<DaemonState>,
+    headers: HeaderMap,
     Json(req): Json<StartLoopRequest>,
 ) -> impl IntoResponse {
     let paths = Arc::clone(&state.paths);
@@ -660,6 +1003,9 @@ pub(super) async fn start_loop_handler(
             req.prompt.len()
         ),
     );
+    let record = Record::new(request_actor(&headers), "loops.start")
+        .target(req.member.as_deref())
+        .param("prompt_bytes", req.prompt.len());
 
     let cfg = Arc::clone(&state.config);
     let team_entry = Arc::clone(&state.team_entry);
@@ -672,10 +1018,12 @@ pub(super) async fn start_loop_handler(
 
     match result {
         Ok(Ok(resp)) => {
+            record_audit(&paths, record.param("loop_id", resp.loop_id.clone()));
             (StatusCode::OK, Json(serde_json::to_value(resp).unwrap())).into_response()
         }
         Ok(Err(e)) => {
             daemon_log(&paths, "ERROR", &format!("API start loop failed: {}", e));
+            record_audit(&paths, record.failed(e.to_string()));
             let resp = StartLoopResponse {
                 ok: false,
                 loop_id: None,
@@ -690,6 +1038,7 @@ pub(super) async fn start_loop_handler(
         }
         Err(e) => {
             daemon_log(&paths, "ERROR", &format!("API start loop panicked: {}", e));
+            record_audit(&paths, record.failed("internal error"));
             let resp = StartLoopResponse {
                 ok: false,
                 loop_id: None,
@@ -705,6 +1054,29 @@ pub(super) async fn start_loop_handler(
     }
 }
 
+/// The caller named in the [`audit::ACTOR_HEADER`] header.
+fn request_actor(headers: &HeaderMap) -> Actor {
+    Actor::from_header(
+        headers
+            .get(audit::ACTOR_HEADER)
+            .and_then(|v| v.to_str().ok()),
+    )
+}
+
+/// Appends an API call to the team's audit log without blocking the handler.
+fn record_audit(paths: &Arc<DaemonPaths>, record: Record) {
+    let paths = Arc::clone(paths);
+    tokio::task::spawn_blocking(move || {
+        if let Err(e) = audit::append(&paths.audit_log(), record) {
+            daemon_log(
+                &paths,
+                "WARN",
+                &format!("Failed to write audit log: {:#}", e),
+            );
+        }
+    });
+}
+
 /// Blocking implementation for loop spawning.
 fn start_loop_blocking(
     team_name: &str,
@@ -745,9 +1117,11 @@ fn start_loop_blocking(
             member_name: String::new(),
         },
     )?;
+    let github_host = crate::git::GitHubHost::for_team(team_entry);
     let gh_config_dir = match crate::formation::start_members::resolve_app_credentials_and_deliver(
         app_cred_store.as_ref(),
         local_formation.as_ref(),
+        &github_host,
         &member_name,
         &ws,
     ) {
@@ -767,10 +1141,12 @@ fn start_loop_blocking(
         .stdin(std::process::Stdio::null())
         .stdout(std::process::Stdio::null())
         .stderr(std::process::Stdio::null());
+    github_host.apply_host(&mut cmd);
 
     if let Some(config_dir) = gh_config_dir {
         cmd.env("GH_CONFIG_DIR", config_dir);
         cmd.env_remove("GH_TOKEN");
+        cmd.env_remove("GH_ENTERPRISE_TOKEN");
         cmd.env_remove("GITHUB_TOKEN");
     }
 
@@ -875,6 +1251,13 @@ mod tests {
                     workspace: Some("/tmp/ws/alice".to_string()),
                     brain_mode: false,
                     started_at: Some("2026-03-24T10:00:00Z".to_string()),
+                    token_health: Some(TokenHealthInfo {
+                        state: "failing".to_string(),
+                        last_refresh: Some("2026-03-24T10:00:00Z".to_string()),
+                        expires_at: Some("2026-03-24T11:00:00Z".to_string()),
+                        consecutive_failures: 2,
+                        last_error: Some("502 Bad Gateway".to_string()),
+                    }),
                 },
                 MemberStatusInfo {
                     name: "bob".to_string(),
@@ -883,6 +1266,7 @@ mod tests {
                     workspace: Some("/tmp/ws/bob".to_string()),
                     brain_mode: true,
                     started_at: Some("2026-03-24T10:05:00Z".to_string()),
+                    token_health: None,
                 },
             ],
         };
@@ -890,6 +1274,9 @@ mod tests {
         assert_eq!(json["members"][0]["name"], "alice");
         assert_eq!(json["members"][0]["status"], "running");
         assert_eq!(json["members"][1]["brain_mode"], true);
+        assert_eq!(json["members"][0]["token_health"]["state"], "failing");
+        assert_eq!(json["members"][0]["token_health"]["consecutive_failures"], 2);
+        assert!(json["members"][1].get("token_health").is_none());
     }
 
     #[test]
@@ -1020,4 +1407,23 @@ mod tests {
         assert_eq!(parsed["prompt"], "Fix the tests");
         assert_eq!(parsed["member"], "alice");
     }
+
+    #[test]
+    fn reload_credentials_round_trip() {
+        let req: ReloadCredentialsRequest =
+            serde_json::from_str(r#"{"member": "alice"}"#).unwrap();
+        assert_eq!(req.member, "alice");
+
+        let resp = ReloadCredentialsResponse {
+            ok: true,
+            reloaded: false,
+            expires_at: None,
+            error: None,
+        };
+        let json = serde_json::to_value(&resp).unwrap();
+        assert_eq!(json["reloaded"], false);
+        let parsed: ReloadCredentialsResponse = serde_json::from_value(json).unwrap();
+        assert!(parsed.ok);
+        assert!(parsed.expires_at.is_none());
+    }
 }
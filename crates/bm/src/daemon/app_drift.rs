@@ -0,0 +1,148 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use super::config::DaemonPaths;
+use super::log::daemon_log;
+use super::token_health::send_bridge_alert;
+use crate::config::{self, ForgeKind};
+use crate::git::app_drift::{self, MemberDrift};
+use crate::workspace;
+
+/// How often member Apps are compared against the team's requirements.
+const CHECK_INTERVAL_SECS: u64 = 60 * 60;
+
+/// Background task: checks every member App for permission, event and
+/// repository drift, and alerts the operator when a member's findings change.
+pub(super) async fn run_app_drift_loop(
+    team_name: &str,
+    paths: &DaemonPaths,
+    shutdown: &Arc<AtomicBool>,
+) {
+    let mut ticker = tokio::time::interval(tokio::time::Duration::from_secs(CHECK_INTERVAL_SECS));
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+    // Findings last reported per member, so unchanged drift alerts once.
+    let mut reported: HashMap<String, Vec<String>> = HashMap::new();
+
+    loop {
+        ticker.tick().await;
+        if shutdown.load(Ordering::SeqCst) {
+            break;
+        }
+
+        let team = team_name.to_string();
+        let tick_paths = paths.clone();
+        let mut seen = std::mem::take(&mut reported);
+        let result = tokio::task::spawn_blocking(move || {
+            check_and_alert(&team, &tick_paths, &mut seen);
+            seen
+        })
+        .await;
+
+        match result {
+            Ok(seen) => reported = seen,
+            Err(e) => daemon_log(paths, "ERROR", &format!("App drift check panicked: {}", e)),
+        }
+    }
+}
+
+fn check_and_alert(
+    team_name: &str,
+    paths: &DaemonPaths,
+    reported: &mut HashMap<String, Vec<String>>,
+) {
+    let cfg = match config::load() {
+        Ok(c) => c,
+        Err(e) => {
+            daemon_log(paths, "ERROR", &format!("App drift check: {:#}", e));
+            return;
+        }
+    };
+    let team = match config::resolve_team(&cfg, Some(team_name)) {
+        Ok(t) => t,
+        Err(e) => {
+            daemon_log(paths, "ERROR", &format!("App drift check: {:#}", e));
+            return;
+        }
+    };
+    if team.forge_kind() != ForgeKind::GitHub {
+        return;
+    }
+    let members = match workspace::list_member_dirs(&team.path.join("team").join("members")) {
+        Ok(m) => m,
+        Err(e) => {
+            daemon_log(paths, "ERROR", &format!("App drift check: {:#}", e));
+            return;
+        }
+    };
+
+    for member in &members {
+        let found = match app_drift::check_member(team, member) {
+            Ok(Some(found)) => found,
+            Ok(None) => continue,
+            Err(e) => {
+                daemon_log(
+                    paths,
+                    "ERROR",
+                    &format!("{}: App drift check failed: {:#}", member, e),
+                );
+                continue;
+            }
+        };
+
+        let findings: Vec<String> = found.drift.iter().map(|d| d.to_string()).collect();
+        if reported.get(member) == Some(&findings) {
+            continue;
+        }
+        if findings.is_empty() {
+            if reported.remove(member).is_some() {
+                daemon_log(paths, "INFO", &format!("{}: App drift resolved", member));
+            }
+            continue;
+        }
+
+        let message = alert_message(team_name, &found);
+        daemon_log(paths, "WARN", &message);
+        if let Err(e) = send_bridge_alert(team_name, &message) {
+            daemon_log(
+                paths,
+                "ERROR",
+                &format!("Failed to send App drift alert through the bridge: {:#}", e),
+            );
+        }
+        reported.insert(member.clone(), findings);
+    }
+}
+
+/// Operator-facing alert text for a member's drifted App.
+fn alert_message(team_name: &str, found: &MemberDrift) -> String {
+    let findings: Vec<String> = found.drift.iter().map(|d| d.to_string()).collect();
+    format!(
+        "[botminter] GitHub App of {}/{} has drifted: {}. Run `bm members doctor -t {}` to repair.",
+        team_name,
+        found.member,
+        findings.join("; "),
+        team_name
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::git::app_drift::Drift;
+
+    #[test]
+    fn alert_message_lists_findings() {
+        let found = MemberDrift {
+            member: "superman".into(),
+            slug: "team-superman".into(),
+            account: "acme".into(),
+            installation_id: 1,
+            drift: vec![Drift::MissingRepo("acme/app".into()), Drift::Suspended],
+        };
+        let msg = alert_message("team", &found);
+        assert!(msg.contains("team/superman"));
+        assert!(msg.contains("installation cannot access acme/app; installation is suspended"));
+        assert!(msg.contains("bm members doctor -t team"));
+    }
+}
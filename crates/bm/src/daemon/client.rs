@@ -4,19 +4,24 @@ use std::time::Duration;
 use anyhow::{bail, Context, Result};
 
 use super::api::{
-    HealthResponse, MembersStatusResponse, StartLoopRequest, StartLoopResponse,
-    StartMembersRequest, StartMembersResponse, StopMembersRequest, StopMembersResponse,
+    HealthResponse, MembersStatusResponse, ReloadCredentialsRequest, ReloadCredentialsResponse,
+    ReplayDeliveryResponse, StartLoopRequest, StartLoopResponse, StartMembersRequest,
+    StartMembersResponse, StopMembersRequest, StopMembersResponse,
 };
 use super::config::{DaemonConfig, DaemonPaths};
+use crate::audit::{self, Actor};
 use crate::state;
 
 /// HTTP client for communicating with a running daemon.
 ///
 /// Created via [`DaemonClient::connect`], which discovers the daemon's
 /// address from its config file and verifies the process is alive.
+/// Requests that change state name the caller in the `x-bm-actor` header
+/// so the daemon can attribute them in the audit log.
 pub struct DaemonClient {
     base_url: String,
     client: reqwest::blocking::Client,
+    actor: Actor,
 }
 
 impl DaemonClient {
@@ -46,7 +51,17 @@ impl DaemonClient {
             .build()
             .context("Failed to build HTTP client")?;
 
-        Ok(Self { base_url, client })
+        Ok(Self {
+            base_url,
+            client,
+            actor: Actor::cli(),
+        })
+    }
+
+    /// Attributes requests to `actor` instead of the operator running `bm`.
+    pub fn with_actor(mut self, actor: Actor) -> Self {
+        self.actor = actor;
+        self
     }
 
     /// Returns the base URL this client is connected to.
@@ -60,6 +75,7 @@ impl DaemonClient {
         let resp = self
             .client
             .post(&url)
+            .header(audit::ACTOR_HEADER, self.actor.to_string())
             .json(req)
             .send()
             .with_context(|| format!("Failed to connect to daemon at {}", url))?;
@@ -80,6 +96,7 @@ impl DaemonClient {
         let resp = self
             .client
             .post(&url)
+            .header(audit::ACTOR_HEADER, self.actor.to_string())
             .json(req)
             .send()
             .with_context(|| format!("Failed to connect to daemon at {}", url))?;
@@ -113,12 +130,37 @@ impl DaemonClient {
             .context("Failed to parse members response")
     }
 
+    /// POST /api/members/credentials/reload — pick up a rotated App key.
+    pub fn reload_credentials(
+        &self,
+        req: &ReloadCredentialsRequest,
+    ) -> Result<ReloadCredentialsResponse> {
+        let url = format!("{}/api/members/credentials/reload", self.base_url);
+        let resp = self
+            .client
+            .post(&url)
+            .header(audit::ACTOR_HEADER, self.actor.to_string())
+            .json(req)
+            .send()
+            .with_context(|| format!("Failed to connect to daemon at {}", url))?;
+
+        let status = resp.status();
+        if !status.is_success() {
+            let body = resp.text().unwrap_or_default();
+            bail!("Daemon returned {} for credential reload: {}", status, body);
+        }
+
+        resp.json::<ReloadCredentialsResponse>()
+            .context("Failed to parse credential reload response")
+    }
+
     /// POST /api/loops/start — start a Ralph loop in a member's workspace.
     pub fn start_loop(&self, req: &StartLoopRequest) -> Result<StartLoopResponse> {
         let url = format!("{}/api/loops/start", self.base_url);
         let resp = self
             .client
             .post(&url)
+            .header(audit::ACTOR_HEADER, self.actor.to_string())
             .json(req)
             .send()
             .with_context(|| format!("Failed to connect to daemon at {}", url))?;
@@ -133,6 +175,30 @@ impl DaemonClient {
             .context("Failed to parse start loop response")
     }
 
+    /// POST /api/deliveries/{id}/replay — route a stored webhook delivery again.
+    pub fn replay_delivery(&self, id: &str) -> Result<ReplayDeliveryResponse> {
+        let url = format!("{}/api/deliveries/{}/replay", self.base_url, id);
+        let resp = self
+            .client
+            .post(&url)
+            .header(audit::ACTOR_HEADER, self.actor.to_string())
+            .send()
+            .with_context(|| format!("Failed to connect to daemon at {}", url))?;
+
+        let status = resp.status();
+        if !status.is_success() {
+            let body = resp.text().unwrap_or_default();
+            let error = serde_json::from_str::<ReplayDeliveryResponse>(&body)
+                .ok()
+                .and_then(|r| r.error)
+                .unwrap_or(body);
+            bail!("Daemon returned {} for delivery replay: {}", status, error);
+        }
+
+        resp.json::<ReplayDeliveryResponse>()
+            .context("Failed to parse delivery replay response")
+    }
+
     /// GET /api/health — daemon health check.
     pub fn health(&self) -> Result<HealthResponse> {
         let url = format!("{}/api/health", self.base_url);
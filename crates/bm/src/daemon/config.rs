@@ -69,6 +69,21 @@ impl DaemonPaths {
             .join(format!("daemon-{}-poll.json", self.team_name))
     }
 
+    /// Token health file path: `~/.botminter/daemon-<team>-tokens.json`
+    pub fn token_health(&self) -> PathBuf {
+        super::token_health::token_health_path(&self.config_dir, &self.team_name)
+    }
+
+    /// Webhook delivery log path: `~/.botminter/daemon-<team>-deliveries.jsonl`
+    pub fn deliveries(&self) -> PathBuf {
+        super::deliveries::deliveries_path(&self.config_dir, &self.team_name)
+    }
+
+    /// Team audit log path: `~/.botminter/audit-<team>.jsonl`
+    pub fn audit_log(&self) -> PathBuf {
+        crate::audit::log_path(&self.config_dir, &self.team_name)
+    }
+
     /// Log file path: `~/.botminter/logs/daemon-<team>.log`
     pub fn log(&self) -> Result<PathBuf> {
         let logs_dir = self.config_dir.join("logs");
@@ -0,0 +1,414 @@
+use std::collections::BTreeMap;
+use std::fs::{self, OpenOptions};
+use std::io::Write as _;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use axum::http::HeaderMap;
+use serde::{Deserialize, Serialize};
+
+/// Number of deliveries kept per team; older ones are dropped.
+pub const MAX_DELIVERIES: usize = 200;
+
+/// Size the delivery log may grow to before it is compacted to half of it,
+/// dropping the oldest deliveries.
+pub const MAX_LOG_BYTES: u64 = 8 * 1024 * 1024;
+
+/// Larger request bodies are not stored; the delivery is still recorded.
+pub const MAX_PAYLOAD_BYTES: usize = 1024 * 1024;
+
+/// Headers never written to the delivery log.
+const REDACTED_HEADERS: &[&str] = &["authorization", "cookie", "proxy-authorization"];
+
+/// Serializes appends and compaction of the delivery log across handlers.
+static FILE_LOCK: Mutex<()> = Mutex::new(());
+
+/// Result of checking a webhook delivery before routing it.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Validation {
+    /// Signature matched the team's webhook secret.
+    Verified,
+    /// No webhook secret is configured, so the signature was not checked.
+    Unsigned,
+    /// Signature missing or not matching the webhook secret.
+    BadSignature,
+    /// Sent by a different GitHub host than the team's.
+    WrongHost,
+    /// Body is not UTF-8.
+    InvalidBody,
+}
+
+impl Validation {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Validation::Verified => "verified",
+            Validation::Unsigned => "unsigned",
+            Validation::BadSignature => "bad signature",
+            Validation::WrongHost => "wrong host",
+            Validation::InvalidBody => "invalid body",
+        }
+    }
+
+    /// Whether the delivery passed validation and was routed.
+    pub fn accepted(&self) -> bool {
+        matches!(self, Validation::Verified | Validation::Unsigned)
+    }
+}
+
+/// What the daemon did with a delivery.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum DeliveryAction {
+    /// Relevant event: members were launched.
+    LaunchedMembers,
+    /// Event type that doesn't trigger members.
+    Ignored,
+    /// No event header on the delivery.
+    NoEvent,
+    /// Failed validation, not routed.
+    Rejected,
+}
+
+impl DeliveryAction {
+    pub fn label(&self) -> &'static str {
+        match self {
+            DeliveryAction::LaunchedMembers => "launched members",
+            DeliveryAction::Ignored => "ignored",
+            DeliveryAction::NoEvent => "no event",
+            DeliveryAction::Rejected => "rejected",
+        }
+    }
+}
+
+/// A re-run of a stored delivery through routing.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct Replay {
+    pub replayed_at: String,
+    pub action: DeliveryAction,
+}
+
+/// A webhook delivery as received by the daemon.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct Delivery {
+    /// Forge delivery ID (`X-GitHub-Delivery`, `X-Gitea-Delivery`), or a
+    /// generated one when the header is missing.
+    pub id: String,
+    pub received_at: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub event: Option<String>,
+    /// One-line description of the parsed event, e.g. `check_run 'ci' failure`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    /// Request headers, lowercased, credentials removed.
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+    /// Request body. Not stored for rejected deliveries, which can't be
+    /// replayed, or for bodies over [`MAX_PAYLOAD_BYTES`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payload: Option<String>,
+    pub validation: Validation,
+    pub action: DeliveryAction,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub replays: Vec<Replay>,
+}
+
+impl Delivery {
+    /// Captures a delivery from the request. `delivery_id` is the forge's
+    /// delivery header value, if any.
+    pub fn new(
+        delivery_id: Option<&str>,
+        event: Option<&str>,
+        headers: &HeaderMap,
+        body: &[u8],
+        validation: Validation,
+        action: DeliveryAction,
+    ) -> Self {
+        let id = match delivery_id {
+            Some(id) if !id.is_empty() => id.to_string(),
+            _ => format!("local-{}", uuid::Uuid::new_v4()),
+        };
+        let headers = headers
+            .iter()
+            .filter(|(name, _)| !REDACTED_HEADERS.contains(&name.as_str()))
+            .map(|(name, value)| {
+                (
+                    name.as_str().to_string(),
+                    String::from_utf8_lossy(value.as_bytes()).into_owned(),
+                )
+            })
+            .collect();
+        Self {
+            id,
+            received_at: chrono::Utc::now().to_rfc3339(),
+            event: event.map(str::to_string),
+            summary: None,
+            headers,
+            payload: (validation.accepted() && body.len() <= MAX_PAYLOAD_BYTES)
+                .then(|| String::from_utf8_lossy(body).into_owned()),
+            validation,
+            action,
+            replays: Vec::new(),
+        }
+    }
+}
+
+/// A replay, appended to the log as its own line and folded into the
+/// delivery it belongs to when the log is read.
+#[derive(Debug, Serialize, Deserialize)]
+struct ReplayLine {
+    replay_of: String,
+    #[serde(flatten)]
+    replay: Replay,
+}
+
+/// Delivery log file: `<config_dir>/daemon-<team>-deliveries.jsonl`, one
+/// delivery or replay per line.
+pub fn deliveries_path(config_dir: &Path, team_name: &str) -> PathBuf {
+    config_dir.join(format!("daemon-{}-deliveries.jsonl", team_name))
+}
+
+/// Loads the newest [`MAX_DELIVERIES`] stored deliveries, oldest first.
+/// Returns an empty list if the file is missing; unreadable lines are
+/// skipped.
+pub fn load_deliveries(path: &Path) -> Vec<Delivery> {
+    let Ok(contents) = fs::read_to_string(path) else {
+        return Vec::new();
+    };
+    let mut deliveries: Vec<Delivery> = Vec::new();
+    for line in contents.lines().filter(|l| !l.trim().is_empty()) {
+        if let Ok(r) = serde_json::from_str::<ReplayLine>(line) {
+            if let Some(d) = deliveries.iter_mut().rev().find(|d| d.id == r.replay_of) {
+                d.replays.push(r.replay);
+            }
+        } else if let Ok(d) = serde_json::from_str::<Delivery>(line) {
+            deliveries.push(d);
+        }
+    }
+    let excess = deliveries.len().saturating_sub(MAX_DELIVERIES);
+    deliveries.drain(..excess);
+    deliveries
+}
+
+/// Finds a stored delivery by ID. A unique ID prefix also matches.
+pub fn find_delivery(path: &Path, id: &str) -> Option<Delivery> {
+    let deliveries = load_deliveries(path);
+    if let Some(d) = deliveries.iter().find(|d| d.id == id) {
+        return Some(d.clone());
+    }
+    let mut matches = deliveries.into_iter().filter(|d| d.id.starts_with(id));
+    match (matches.next(), matches.next()) {
+        (Some(d), None) => Some(d),
+        _ => None,
+    }
+}
+
+/// Appends one line to the log, compacting it once it outgrows
+/// [`MAX_LOG_BYTES`]. Silently ignores write errors.
+fn append(path: &Path, line: &impl Serialize) {
+    let _guard = FILE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let Ok(mut line) = serde_json::to_string(line) else {
+        return;
+    };
+    line.push('\n');
+    let written = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .and_then(|mut f| f.write_all(line.as_bytes()));
+    if written.is_err() {
+        return;
+    }
+    if fs::metadata(path).is_ok_and(|m| m.len() > MAX_LOG_BYTES) {
+        compact(path);
+    }
+}
+
+/// Rewrites the log atomically with the newest deliveries that fit in half
+/// of [`MAX_LOG_BYTES`], replays folded in.
+fn compact(path: &Path) {
+    let mut lines: Vec<String> = load_deliveries(path)
+        .iter()
+        .filter_map(|d| serde_json::to_string(d).ok())
+        .collect();
+    let mut size: u64 = lines.iter().map(|l| l.len() as u64 + 1).sum();
+    let mut oldest_kept = 0;
+    while size > MAX_LOG_BYTES / 2 && oldest_kept < lines.len() {
+        size -= lines[oldest_kept].len() as u64 + 1;
+        oldest_kept += 1;
+    }
+    lines.drain(..oldest_kept);
+
+    let mut contents = lines.join("\n");
+    if !contents.is_empty() {
+        contents.push('\n');
+    }
+    let tmp_path = path.with_extension("jsonl.tmp");
+    if fs::write(&tmp_path, contents).is_ok() {
+        let _ = fs::rename(&tmp_path, path);
+    }
+}
+
+/// Appends a delivery to the log.
+pub fn record_delivery(path: &Path, delivery: Delivery) {
+    append(path, &delivery);
+}
+
+/// Records a replay of the delivery with `id`. It is dropped when read back
+/// if the delivery is no longer stored.
+pub fn record_replay(path: &Path, id: &str, action: DeliveryAction) {
+    append(
+        path,
+        &ReplayLine {
+            replay_of: id.to_string(),
+            replay: Replay {
+                replayed_at: chrono::Utc::now().to_rfc3339(),
+                action,
+            },
+        },
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn delivery(id: &str) -> Delivery {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-github-event", "issues".parse().unwrap());
+        headers.insert("authorization", "token secret".parse().unwrap());
+        Delivery::new(
+            Some(id),
+            Some("issues"),
+            &headers,
+            br#"{"action":"opened"}"#,
+            Validation::Verified,
+            DeliveryAction::LaunchedMembers,
+        )
+    }
+
+    #[test]
+    fn new_delivery_redacts_credentials_and_generates_missing_ids() {
+        let d = delivery("abc");
+        assert_eq!(d.id, "abc");
+        assert_eq!(d.headers.get("x-github-event").unwrap(), "issues");
+        assert!(!d.headers.contains_key("authorization"));
+
+        let d = Delivery::new(
+            None,
+            None,
+            &HeaderMap::new(),
+            b"{}",
+            Validation::Unsigned,
+            DeliveryAction::NoEvent,
+        );
+        assert!(d.id.starts_with("local-"));
+    }
+
+    #[test]
+    fn rejected_and_oversized_payloads_are_not_stored() {
+        let d = Delivery::new(
+            Some("r"),
+            Some("issues"),
+            &HeaderMap::new(),
+            b"{}",
+            Validation::BadSignature,
+            DeliveryAction::Rejected,
+        );
+        assert_eq!(d.payload, None);
+
+        let big = vec![b'x'; MAX_PAYLOAD_BYTES + 1];
+        let d = Delivery::new(
+            Some("b"),
+            Some("issues"),
+            &HeaderMap::new(),
+            &big,
+            Validation::Verified,
+            DeliveryAction::LaunchedMembers,
+        );
+        assert_eq!(d.payload, None);
+        assert_eq!(delivery("ok").payload.as_deref(), Some(r#"{"action":"opened"}"#));
+    }
+
+    #[test]
+    fn record_keeps_the_newest_deliveries() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = deliveries_path(tmp.path(), "team");
+        for i in 0..MAX_DELIVERIES + 5 {
+            record_delivery(&path, delivery(&format!("d-{}", i)));
+        }
+
+        let stored = load_deliveries(&path);
+        assert_eq!(stored.len(), MAX_DELIVERIES);
+        assert_eq!(stored[0].id, "d-5");
+        assert_eq!(
+            stored.last().unwrap().id,
+            format!("d-{}", MAX_DELIVERIES + 4)
+        );
+    }
+
+    #[test]
+    fn find_by_id_or_unique_prefix_and_record_replay() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = deliveries_path(tmp.path(), "team");
+        record_delivery(&path, delivery("72d3162e-1"));
+        record_delivery(&path, delivery("72d3162e-2"));
+        record_delivery(&path, delivery("9f00"));
+
+        assert_eq!(find_delivery(&path, "9f").unwrap().id, "9f00");
+        assert_eq!(find_delivery(&path, "72d3162e-2").unwrap().id, "72d3162e-2");
+        assert!(find_delivery(&path, "72d3").is_none(), "ambiguous prefix");
+        assert!(find_delivery(&path, "nope").is_none());
+
+        record_replay(&path, "9f00", DeliveryAction::Ignored);
+        let d = find_delivery(&path, "9f00").unwrap();
+        assert_eq!(d.replays.len(), 1);
+        assert_eq!(d.replays[0].action, DeliveryAction::Ignored);
+
+        // Replays are appended, not rewritten into the delivery's line
+        let contents = fs::read_to_string(&path).unwrap();
+        assert_eq!(contents.lines().count(), 4);
+        assert!(contents.lines().last().unwrap().contains(r#""replay_of":"9f00""#));
+    }
+
+    #[test]
+    fn log_is_compacted_once_it_outgrows_the_byte_cap() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = deliveries_path(tmp.path(), "team");
+        let body = vec![b'x'; MAX_PAYLOAD_BYTES];
+        let count = (MAX_LOG_BYTES as usize / MAX_PAYLOAD_BYTES) + 2;
+        for i in 0..count {
+            let d = Delivery::new(
+                Some(&format!("d-{}", i)),
+                Some("push"),
+                &HeaderMap::new(),
+                &body,
+                Validation::Verified,
+                DeliveryAction::Ignored,
+            );
+            record_delivery(&path, d);
+        }
+
+        assert!(fs::metadata(&path).unwrap().len() <= MAX_LOG_BYTES);
+        let stored = load_deliveries(&path);
+        assert!(stored.len() < count);
+        assert_eq!(stored.last().unwrap().id, format!("d-{}", count - 1));
+    }
+
+    #[test]
+    fn unreadable_lines_are_skipped() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = deliveries_path(tmp.path(), "team");
+        record_delivery(&path, delivery("a"));
+        OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .unwrap()
+            .write_all(b"{not json\n")
+            .unwrap();
+        record_delivery(&path, delivery("b"));
+
+        let ids: Vec<String> = load_deliveries(&path).into_iter().map(|d| d.id).collect();
+        assert_eq!(ids, vec!["a", "b"]);
+    }
+}
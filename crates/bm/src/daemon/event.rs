@@ -3,12 +3,26 @@ use std::process::Command;
 use anyhow::{bail, Context, Result};
 use serde::Deserialize;
 
-use crate::config;
+use crate::config::{self, ForgeKind};
+use crate::forge::{self, ActivityEntry, GiteaForge};
+use crate::git::GitHubHost;
 
 use super::config::PollState;
-
-/// GitHub event types that trigger member launches.
-const RELEVANT_EVENTS: &[&str] = &["issues", "issue_comment", "pull_request"];
+use super::event_kind::EventKind;
+
+/// GitHub event types that can trigger member launches. Whether a given
+/// event does depends on its payload, see [`EventKind::should_wake`].
+const RELEVANT_EVENTS: &[&str] = &[
+    "issues",
+    "issue_comment",
+    "pull_request",
+    "pull_request_review",
+    "pull_request_review_comment",
+    "check_suite",
+    "check_run",
+    "workflow_run",
+    "projects_v2_item",
+];
 
 /// A GitHub event from the events API.
 #[derive(Debug, Deserialize)]
@@ -16,9 +30,18 @@ pub struct GitHubEvent {
     pub id: String,
     #[serde(rename = "type")]
     pub event_type: String,
+    #[serde(default)]
+    pub payload: serde_json::Value,
 }
 
-/// Checks if an event type is relevant for triggering member launches.
+impl GitHubEvent {
+    /// The event parsed into its typed form.
+    pub fn kind(&self) -> EventKind {
+        EventKind::parse(&self.event_type, &self.payload)
+    }
+}
+
+/// Checks if an event type is one that can trigger member launches.
 pub fn is_relevant_event(event_type: &str) -> bool {
     // The events API uses PascalCase type names, webhook headers use snake_case
     let normalized = event_type.to_lowercase();
@@ -30,21 +53,22 @@ pub fn is_relevant_event(event_type: &str) -> bool {
     })
 }
 
-/// Polls the GitHub events API for new events.
+/// Polls the GitHub events API of `host` for new events.
 pub fn poll_github_events(
+    host: &GitHubHost,
     github_repo: &str,
     poll_state: &PollState,
 ) -> Result<Vec<GitHubEvent>> {
-    let output = Command::new("gh")
-        .args([
-            "api",
-            &format!("repos/{}/events", github_repo),
-            "--paginate",
-            "--jq",
-            "[.[] | {id: .id, type: .type}]",
-        ])
-        .output()
-        .context("Failed to run gh api command")?;
+    let mut cmd = Command::new("gh");
+    cmd.args([
+        "api",
+        &format!("repos/{}/events", github_repo),
+        "--paginate",
+        "--jq",
+        "[.[] | {id: .id, type: .type, payload: .payload}]",
+    ]);
+    host.apply_detected_token(&mut cmd);
+    let output = cmd.output().context("Failed to run gh api command")?;
 
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
@@ -71,14 +95,99 @@ pub fn poll_github_events(
     }
 }
 
-/// Resolves the GitHub repo (owner/name) for a team.
-pub fn resolve_github_repo(team_name: &str) -> Result<String> {
+/// Polls a Gitea/Forgejo repo's activity feed for new events, mapped onto
+/// webhook event names so [`EventKind`] parsing applies unchanged.
+pub fn poll_gitea_events(
+    forge_url: &str,
+    repo: &str,
+    poll_state: &PollState,
+) -> Result<Vec<GitHubEvent>> {
+    let token = forge::require_token(Some(&config::ForgeConfig {
+        kind: ForgeKind::Gitea,
+        url: forge_url.to_string(),
+        api_base: None,
+    }))?;
+    let feed = GiteaForge::new(forge_url, &token)?.activity_feed(repo)?;
+    Ok(gitea_feed_events(feed, poll_state))
+}
+
+fn gitea_feed_events(feed: Vec<ActivityEntry>, poll_state: &PollState) -> Vec<GitHubEvent> {
+    feed.into_iter()
+        .map(|a| GitHubEvent {
+            id: a.id.to_string(),
+            event_type: gitea_op_event(&a.op_type).to_string(),
+            payload: serde_json::Value::Null,
+        })
+        .take_while(|e| poll_state.last_event_id.as_ref() != Some(&e.id))
+        .collect()
+}
+
+/// Maps a Gitea activity `op_type` to the webhook event it corresponds to.
+fn gitea_op_event(op_type: &str) -> &str {
+    match op_type {
+        "create_issue" | "close_issue" | "reopen_issue" => "issues",
+        "comment_issue" | "comment_pull" => "issue_comment",
+        "create_pull_request"
+        | "merge_pull_request"
+        | "close_pull_request"
+        | "reopen_pull_request"
+        | "approve_pull_request"
+        | "reject_pull_request"
+        | "auto_merge_pull_request" => "pull_request",
+        other => other,
+    }
+}
+
+/// Polls the team's forge for new events.
+pub fn poll_team_events(team_name: &str, poll_state: &PollState) -> Result<Vec<GitHubEvent>> {
     let cfg = config::load()?;
     let team = config::resolve_team(&cfg, Some(team_name))?;
     if team.github_repo.is_empty() {
-        bail!("No GitHub repo configured for team '{}'", team_name);
+        bail!("No repo configured for team '{}'", team_name);
+    }
+    match &team.forge {
+        Some(f) if f.kind == ForgeKind::Gitea => {
+            poll_gitea_events(&f.url, &team.github_repo, poll_state)
+        }
+        _ => poll_github_events(&GitHubHost::for_team(team), &team.github_repo, poll_state),
+    }
+}
+
+/// Header carrying the event name on webhook deliveries from `kind`.
+pub fn event_header(kind: ForgeKind) -> &'static str {
+    match kind {
+        ForgeKind::GitHub => "x-github-event",
+        ForgeKind::Gitea => "x-gitea-event",
+    }
+}
+
+/// Header carrying the unique delivery ID on webhook deliveries from `kind`.
+pub fn delivery_header(kind: ForgeKind) -> &'static str {
+    match kind {
+        ForgeKind::GitHub => "x-github-delivery",
+        ForgeKind::Gitea => "x-gitea-delivery",
+    }
+}
+
+/// Header carrying the payload signature on webhook deliveries from `kind`.
+pub fn signature_header(kind: ForgeKind) -> &'static str {
+    match kind {
+        ForgeKind::GitHub => "x-hub-signature-256",
+        ForgeKind::Gitea => "x-gitea-signature",
+    }
+}
+
+/// Validates a webhook signature in the format `kind` sends.
+pub fn validate_forge_signature(
+    kind: ForgeKind,
+    secret: &str,
+    body: &str,
+    signature_header: Option<&str>,
+) -> bool {
+    match kind {
+        ForgeKind::GitHub => validate_webhook_signature(secret, body, signature_header),
+        ForgeKind::Gitea => validate_gitea_signature(secret, body, signature_header),
     }
-    Ok(team.github_repo.clone())
 }
 
 /// Validates a GitHub webhook signature using HMAC-SHA256.
@@ -115,6 +224,34 @@ pub fn validate_webhook_signature(
     mac.verify_slice(&expected).is_ok()
 }
 
+/// Validates a Gitea/Forgejo webhook signature: plain hex HMAC-SHA256,
+/// without GitHub's `sha256=` prefix.
+pub fn validate_gitea_signature(
+    secret: &str,
+    body: &str,
+    signature_header: Option<&str>,
+) -> bool {
+    match signature_header {
+        Some(sig) if !sig.starts_with("sha256=") => {
+            validate_webhook_signature(secret, body, Some(&format!("sha256={}", sig)))
+        }
+        _ => false,
+    }
+}
+
+/// Header GHES adds to webhook deliveries, naming the sending instance.
+pub const ENTERPRISE_HOST_HEADER: &str = "x-github-enterprise-host";
+
+/// Checks that a GitHub delivery came from the team's host: GHES teams
+/// require a matching `X-GitHub-Enterprise-Host`, github.com teams reject
+/// deliveries that carry one.
+pub fn delivery_matches_host(host: &GitHubHost, enterprise_host_header: Option<&str>) -> bool {
+    match enterprise_host_header {
+        Some(sender) => host.is_enterprise() && sender.eq_ignore_ascii_case(&host.host),
+        None => !host.is_enterprise(),
+    }
+}
+
 /// Loads the webhook secret from the team's credentials.
 pub fn load_webhook_secret(team_name: &str) -> Option<String> {
     let cfg = config::load().ok()?;
@@ -140,6 +277,16 @@ mod tests {
         assert!(is_relevant_event("PullRequestEvent"));
     }
 
+    #[test]
+    fn relevant_event_types_review_ci_and_board() {
+        assert!(is_relevant_event("pull_request_review"));
+        assert!(is_relevant_event("PullRequestReviewCommentEvent"));
+        assert!(is_relevant_event("check_suite"));
+        assert!(is_relevant_event("check_run"));
+        assert!(is_relevant_event("workflow_run"));
+        assert!(is_relevant_event("projects_v2_item"));
+    }
+
     #[test]
     fn irrelevant_event_types() {
         assert!(!is_relevant_event("push"));
@@ -201,6 +348,59 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn gitea_signature_is_plain_hex() {
+        use hmac::{Hmac, Mac};
+        use sha2::Sha256;
+
+        let secret = "mysecret";
+        let body = r#"{"action":"opened"}"#;
+        let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).unwrap();
+        mac.update(body.as_bytes());
+        let hex_sig = hex::encode(mac.finalize().into_bytes());
+
+        assert!(validate_gitea_signature(secret, body, Some(&hex_sig)));
+        assert!(!validate_gitea_signature(
+            secret,
+            body,
+            Some(&format!("sha256={}", hex_sig))
+        ));
+        assert!(!validate_gitea_signature(secret, body, None));
+        assert!(validate_forge_signature(ForgeKind::Gitea, secret, body, Some(&hex_sig)));
+        assert!(!validate_forge_signature(ForgeKind::GitHub, secret, body, Some(&hex_sig)));
+    }
+
+    #[test]
+    fn gitea_feed_maps_ops_and_stops_at_last_seen() {
+        let feed = vec![
+            ActivityEntry { id: 12, op_type: "comment_issue".into() },
+            ActivityEntry { id: 11, op_type: "merge_pull_request".into() },
+            ActivityEntry { id: 10, op_type: "create_issue".into() },
+            ActivityEntry { id: 9, op_type: "create_issue".into() },
+        ];
+        let poll_state = PollState {
+            last_event_id: Some("10".into()),
+            ..Default::default()
+        };
+        let events = gitea_feed_events(feed, &poll_state);
+        let types: Vec<&str> = events.iter().map(|e| e.event_type.as_str()).collect();
+        assert_eq!(types, vec!["issue_comment", "pull_request"]);
+        assert!(events.iter().all(|e| is_relevant_event(&e.event_type)));
+        assert!(!is_relevant_event(gitea_op_event("commit_repo")));
+    }
+
+    #[test]
+    fn deliveries_must_come_from_team_host() {
+        let ghes = GitHubHost::new(Some("github.acme.corp"), None);
+        assert!(delivery_matches_host(&ghes, Some("GitHub.Acme.Corp")));
+        assert!(!delivery_matches_host(&ghes, Some("ghe.other.corp")));
+        assert!(!delivery_matches_host(&ghes, None));
+
+        let dotcom = GitHubHost::default();
+        assert!(delivery_matches_host(&dotcom, None));
+        assert!(!delivery_matches_host(&dotcom, Some("github.acme.corp")));
+    }
+
     #[test]
     fn github_event_deser() {
         let json =
@@ -212,5 +412,24 @@ mod tests {
         assert_eq!(events[0].event_type, "IssuesEvent");
         assert_eq!(events[1].id, "12346");
         assert_eq!(events[1].event_type, "PushEvent");
+        assert!(events[0].kind().should_wake());
+        assert!(!events[1].kind().should_wake());
+    }
+
+    #[test]
+    fn github_event_kind_uses_payload() {
+        let json = serde_json::json!([{
+            "id": "1",
+            "type": "PullRequestReviewEvent",
+            "payload": {
+                "action": "created",
+                "review": { "state": "changes_requested" },
+                "pull_request": { "number": 3 }
+            }
+        }]);
+        let events: Vec<GitHubEvent> = serde_json::from_value(json).unwrap();
+        let kind = events[0].kind();
+        assert!(kind.should_wake());
+        assert_eq!(kind.to_string(), "pull_request_review changes_requested #3");
     }
 }
@@ -0,0 +1,503 @@
+use std::fmt;
+
+use serde_json::Value;
+
+/// CI conclusions that wake members.
+const FAILED_CONCLUSIONS: &[&str] = &["failure", "timed_out", "startup_failure", "action_required"];
+
+/// A forge event parsed into the fields routing acts on.
+///
+/// Built from a webhook delivery (event header + payload) or a poll result
+/// (Events API type + payload); both naming styles are accepted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EventKind {
+    Issue {
+        action: String,
+        number: Option<u64>,
+    },
+    IssueComment {
+        action: String,
+        number: Option<u64>,
+    },
+    PullRequest {
+        action: String,
+        number: Option<u64>,
+    },
+    PullRequestReview {
+        action: String,
+        number: Option<u64>,
+        /// `approved`, `changes_requested` or `commented`.
+        state: String,
+    },
+    PullRequestReviewComment {
+        action: String,
+        number: Option<u64>,
+    },
+    CheckSuite {
+        action: String,
+        conclusion: Option<String>,
+        head_branch: Option<String>,
+        pull_requests: Vec<u64>,
+    },
+    CheckRun {
+        action: String,
+        name: String,
+        conclusion: Option<String>,
+        pull_requests: Vec<u64>,
+    },
+    WorkflowRun {
+        action: String,
+        name: String,
+        conclusion: Option<String>,
+        head_branch: Option<String>,
+    },
+    /// A Projects v2 board item; `field`, `from` and `to` describe an
+    /// edited field value such as the Status column.
+    ProjectItem {
+        action: String,
+        /// Node ID of the board the item is on (`PVT_…`).
+        project_node_id: Option<String>,
+        content_node_id: Option<String>,
+        field: Option<String>,
+        from: Option<String>,
+        to: Option<String>,
+    },
+    /// Any other event type.
+    Other {
+        event_type: String,
+    },
+}
+
+impl EventKind {
+    /// Parses an event. `payload` may be `Value::Null` when only the type is
+    /// known; fields are then empty.
+    pub fn parse(event_type: &str, payload: &Value) -> Self {
+        let action = str_field(&payload["action"]).unwrap_or_default();
+        match canonical_name(event_type).as_str() {
+            "issues" => EventKind::Issue {
+                action,
+                number: number(payload),
+            },
+            "issuecomment" => EventKind::IssueComment {
+                action,
+                number: number(payload),
+            },
+            "pullrequest" => EventKind::PullRequest {
+                action,
+                number: number(payload),
+            },
+            "pullrequestreview" => EventKind::PullRequestReview {
+                action,
+                number: number(payload),
+                state: str_field(&payload["review"]["state"])
+                    .unwrap_or_default()
+                    .to_lowercase(),
+            },
+            "pullrequestreviewcomment" => EventKind::PullRequestReviewComment {
+                action,
+                number: number(payload),
+            },
+            "checksuite" => {
+                let suite = &payload["check_suite"];
+                EventKind::CheckSuite {
+                    action,
+                    conclusion: str_field(&suite["conclusion"]),
+                    head_branch: str_field(&suite["head_branch"]),
+                    pull_requests: pull_request_numbers(&suite["pull_requests"]),
+                }
+            }
+            "checkrun" => {
+                let run = &payload["check_run"];
+                EventKind::CheckRun {
+                    action,
+                    name: str_field(&run["name"]).unwrap_or_default(),
+                    conclusion: str_field(&run["conclusion"]),
+                    pull_requests: pull_request_numbers(&run["pull_requests"]),
+                }
+            }
+            "workflowrun" => {
+                let run = &payload["workflow_run"];
+                EventKind::WorkflowRun {
+                    action,
+                    name: str_field(&run["name"]).unwrap_or_default(),
+                    conclusion: str_field(&run["conclusion"]),
+                    head_branch: str_field(&run["head_branch"]),
+                }
+            }
+            "projectsv2item" => {
+                let item = &payload["projects_v2_item"];
+                let change = &payload["changes"]["field_value"];
+                EventKind::ProjectItem {
+                    action,
+                    project_node_id: str_field(&item["project_node_id"]),
+                    content_node_id: str_field(&item["content_node_id"]),
+                    field: str_field(&change["field_name"]),
+                    from: option_name(&change["from"]),
+                    to: option_name(&change["to"]),
+                }
+            }
+            _ => EventKind::Other {
+                event_type: event_type.to_string(),
+            },
+        }
+    }
+
+    /// Whether the event should wake members.
+    ///
+    /// Issue and pull request activity always does. Reviews wake members
+    /// when submitted, CI events when they complete with a failure, and
+    /// board items when added or when a field (e.g. Status) changes.
+    pub fn should_wake(&self) -> bool {
+        match self {
+            EventKind::Issue { .. }
+            | EventKind::IssueComment { .. }
+            | EventKind::PullRequest { .. }
+            | EventKind::PullRequestReviewComment { .. } => true,
+            // The Events API reports submitted reviews as `created`.
+            EventKind::PullRequestReview { action, .. } => {
+                action == "submitted" || action == "created"
+            }
+            EventKind::CheckSuite {
+                action, conclusion, ..
+            }
+            | EventKind::CheckRun {
+                action, conclusion, ..
+            }
+            | EventKind::WorkflowRun {
+                action, conclusion, ..
+            } => action == "completed" && is_failure(conclusion.as_deref()),
+            EventKind::ProjectItem { action, field, .. } => match action.as_str() {
+                "created" | "restored" | "converted" => true,
+                "edited" => field.is_some(),
+                _ => false,
+            },
+            EventKind::Other { .. } => false,
+        }
+    }
+
+    /// Whether the event should wake the members of a team whose board is
+    /// `project`. Board item events only count when they come from the
+    /// team's own board; organization webhooks deliver them for every
+    /// project of the owner.
+    pub fn should_wake_for(&self, project: &ProjectFilter) -> bool {
+        if !self.should_wake() {
+            return false;
+        }
+        let EventKind::ProjectItem { project_node_id, .. } = self else {
+            return true;
+        };
+        match project {
+            ProjectFilter::Board(id) => project_node_id.as_deref() == Some(id.as_str()),
+            ProjectFilter::NoBoard => false,
+            ProjectFilter::Any => true,
+        }
+    }
+}
+
+/// Which board's item events wake a team's members.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProjectFilter {
+    /// The team's board, by node ID.
+    Board(String),
+    /// The team has no board; item events never wake members.
+    NoBoard,
+    /// The board's node ID couldn't be looked up; every item event wakes
+    /// members.
+    Any,
+}
+
+/// One-line description for logs and the delivery log, e.g.
+/// `check_run 'ci / test' failure (#42)`.
+impl fmt::Display for EventKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EventKind::Issue { action, number } => {
+                write!(f, "issues {}{}", action, on_number(*number))
+            }
+            EventKind::IssueComment { action, number } => {
+                write!(f, "issue_comment {}{}", action, on_number(*number))
+            }
+            EventKind::PullRequest { action, number } => {
+                write!(f, "pull_request {}{}", action, on_number(*number))
+            }
+            EventKind::PullRequestReview {
+                action,
+                number,
+                state,
+            } => {
+                let what = if state.is_empty() { action } else { state };
+                write!(f, "pull_request_review {}{}", what, on_number(*number))
+            }
+            EventKind::PullRequestReviewComment { action, number } => {
+                write!(
+                    f,
+                    "pull_request_review_comment {}{}",
+                    action,
+                    on_number(*number)
+                )
+            }
+            EventKind::CheckSuite {
+                action,
+                conclusion,
+                head_branch,
+                pull_requests,
+            } => {
+                write!(f, "check_suite {}", conclusion.as_ref().unwrap_or(action))?;
+                if let Some(branch) = head_branch {
+                    write!(f, " on {}", branch)?;
+                }
+                write!(f, "{}", on_numbers(pull_requests))
+            }
+            EventKind::CheckRun {
+                action,
+                name,
+                conclusion,
+                pull_requests,
+            } => write!(
+                f,
+                "check_run '{}' {}{}",
+                name,
+                conclusion.as_ref().unwrap_or(action),
+                on_numbers(pull_requests)
+            ),
+            EventKind::WorkflowRun {
+                action,
+                name,
+                conclusion,
+                head_branch,
+            } => {
+                write!(
+                    f,
+                    "workflow_run '{}' {}",
+                    name,
+                    conclusion.as_ref().unwrap_or(action)
+                )?;
+                if let Some(branch) = head_branch {
+                    write!(f, " on {}", branch)?;
+                }
+                Ok(())
+            }
+            EventKind::ProjectItem {
+                action,
+                field,
+                from,
+                to,
+                ..
+            } => match field {
+                Some(field) => write!(
+                    f,
+                    "projects_v2_item {}: {} → {}",
+                    field,
+                    from.as_deref().unwrap_or("(none)"),
+                    to.as_deref().unwrap_or("(none)")
+                ),
+                None => write!(f, "projects_v2_item {}", action),
+            },
+            EventKind::Other { event_type } => write!(f, "{}", event_type),
+        }
+    }
+}
+
+/// Reduces webhook names (`pull_request_review`) and Events API types
+/// (`PullRequestReviewEvent`) to the same key (`pullrequestreview`).
+fn canonical_name(event_type: &str) -> String {
+    let name = event_type.to_lowercase().replace('_', "");
+    match name.strip_suffix("event") {
+        Some(stripped) => stripped.to_string(),
+        None => name,
+    }
+}
+
+fn is_failure(conclusion: Option<&str>) -> bool {
+    conclusion.is_some_and(|c| FAILED_CONCLUSIONS.contains(&c))
+}
+
+fn str_field(v: &Value) -> Option<String> {
+    v.as_str().map(str::to_string)
+}
+
+/// Issue or pull request number of an issue/PR/review payload.
+fn number(payload: &Value) -> Option<u64> {
+    payload["issue"]["number"]
+        .as_u64()
+        .or_else(|| payload["pull_request"]["number"].as_u64())
+        .or_else(|| payload["number"].as_u64())
+}
+
+fn pull_request_numbers(v: &Value) -> Vec<u64> {
+    v.as_array()
+        .map(|prs| prs.iter().filter_map(|pr| pr["number"].as_u64()).collect())
+        .unwrap_or_default()
+}
+
+/// A Projects v2 field value: single-select options come as `{ "name": .. }`,
+/// text and number fields as plain values.
+fn option_name(v: &Value) -> Option<String> {
+    match v {
+        Value::Object(o) => o.get("name").and_then(Value::as_str).map(str::to_string),
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        _ => None,
+    }
+}
+
+fn on_number(number: Option<u64>) -> String {
+    number.map(|n| format!(" #{}", n)).unwrap_or_default()
+}
+
+fn on_numbers(numbers: &[u64]) -> String {
+    if numbers.is_empty() {
+        return String::new();
+    }
+    let list: Vec<String> = numbers.iter().map(|n| format!("#{}", n)).collect();
+    format!(" ({})", list.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn parses_webhook_and_events_api_names_alike() {
+        let payload = json!({
+            "action": "submitted",
+            "review": { "state": "APPROVED" },
+            "pull_request": { "number": 7 }
+        });
+        let webhook = EventKind::parse("pull_request_review", &payload);
+        assert_eq!(
+            webhook,
+            EventKind::parse("PullRequestReviewEvent", &payload)
+        );
+        assert_eq!(
+            webhook,
+            EventKind::PullRequestReview {
+                action: "submitted".into(),
+                number: Some(7),
+                state: "approved".into(),
+            }
+        );
+        assert!(webhook.should_wake());
+        assert_eq!(webhook.to_string(), "pull_request_review approved #7");
+    }
+
+    #[test]
+    fn issue_activity_wakes_without_payload() {
+        for name in ["issues", "IssueCommentEvent", "pull_request"] {
+            assert!(
+                EventKind::parse(name, &Value::Null).should_wake(),
+                "{}",
+                name
+            );
+        }
+        assert!(!EventKind::parse("push", &Value::Null).should_wake());
+        assert!(!EventKind::parse("PushEvent", &Value::Null).should_wake());
+    }
+
+    #[test]
+    fn ci_wakes_only_on_completed_failures() {
+        let failed = EventKind::parse(
+            "check_run",
+            &json!({
+                "action": "completed",
+                "check_run": {
+                    "name": "ci / test",
+                    "conclusion": "failure",
+                    "pull_requests": [{ "number": 42 }]
+                }
+            }),
+        );
+        assert!(failed.should_wake());
+        assert_eq!(failed.to_string(), "check_run 'ci / test' failure (#42)");
+
+        let passed = EventKind::parse(
+            "check_suite",
+            &json!({
+                "action": "completed",
+                "check_suite": { "conclusion": "success", "head_branch": "main" }
+            }),
+        );
+        assert!(!passed.should_wake());
+        assert_eq!(passed.to_string(), "check_suite success on main");
+
+        let running = EventKind::parse(
+            "workflow_run",
+            &json!({ "action": "requested", "workflow_run": { "name": "CI", "conclusion": null } }),
+        );
+        assert!(!running.should_wake());
+
+        let timed_out = EventKind::parse(
+            "workflow_run",
+            &json!({
+                "action": "completed",
+                "workflow_run": { "name": "CI", "conclusion": "timed_out", "head_branch": "feat" }
+            }),
+        );
+        assert!(timed_out.should_wake());
+        assert_eq!(timed_out.to_string(), "workflow_run 'CI' timed_out on feat");
+    }
+
+    #[test]
+    fn project_item_status_change() {
+        let moved = EventKind::parse(
+            "projects_v2_item",
+            &json!({
+                "action": "edited",
+                "projects_v2_item": {
+                    "project_node_id": "PVT_team",
+                    "content_node_id": "I_kwDO"
+                },
+                "changes": {
+                    "field_value": {
+                        "field_name": "Status",
+                        "field_type": "single_select",
+                        "from": { "name": "dev:in-progress" },
+                        "to": { "name": "qe:verify" }
+                    }
+                }
+            }),
+        );
+        assert_eq!(
+            moved,
+            EventKind::ProjectItem {
+                action: "edited".into(),
+                project_node_id: Some("PVT_team".into()),
+                content_node_id: Some("I_kwDO".into()),
+                field: Some("Status".into()),
+                from: Some("dev:in-progress".into()),
+                to: Some("qe:verify".into()),
+            }
+        );
+        assert!(moved.should_wake());
+        assert_eq!(
+            moved.to_string(),
+            "projects_v2_item Status: dev:in-progress → qe:verify"
+        );
+
+        let reordered = EventKind::parse("projects_v2_item", &json!({ "action": "reordered" }));
+        assert!(!reordered.should_wake());
+    }
+
+    #[test]
+    fn project_items_wake_only_for_the_team_board() {
+        let item = |board: &str| {
+            EventKind::parse(
+                "projects_v2_item",
+                &json!({
+                    "action": "created",
+                    "projects_v2_item": { "project_node_id": board }
+                }),
+            )
+        };
+        let team = ProjectFilter::Board("PVT_team".into());
+        assert!(item("PVT_team").should_wake_for(&team));
+        assert!(!item("PVT_other").should_wake_for(&team));
+        assert!(!item("PVT_team").should_wake_for(&ProjectFilter::NoBoard));
+        assert!(item("PVT_other").should_wake_for(&ProjectFilter::Any));
+
+        // Other events are not affected by the board
+        let issue = EventKind::parse("issues", &json!({ "action": "opened" }));
+        assert!(issue.should_wake_for(&ProjectFilter::NoBoard));
+    }
+}
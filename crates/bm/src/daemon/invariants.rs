@@ -0,0 +1,292 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{Read as _, Seek as _, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use anyhow::Result;
+
+use super::config::DaemonPaths;
+use super::log::daemon_log;
+use super::scheduler::append_brain_event;
+use crate::brain::inbox;
+use crate::config;
+use crate::formation;
+use crate::invariants::{self, Violation, TASK_CLOSE};
+use crate::workspace;
+
+/// How often member event files are scanned for `task.close`.
+const TICK_SECS: u64 = 5;
+
+/// Event topic the brain's event watcher picks up for failed checks.
+const VIOLATION_TOPIC: &str = "invariant.violation";
+
+/// State carried between ticks.
+#[derive(Default)]
+struct WatchState {
+    /// Read position per loop event file.
+    offsets: HashMap<PathBuf, u64>,
+    /// Set after the first scan. Files seen on the first scan start at their
+    /// end so a daemon restart doesn't re-check old closes; files that show
+    /// up later are read from the start.
+    primed: bool,
+    /// Last load error, logged once until it changes.
+    last_error: Option<String>,
+}
+
+/// Runs invariant checks marked `triggers: [task.close]` whenever a member's
+/// loop closes a task, and reports violations back to the member.
+pub(super) async fn run_invariants_loop(
+    team_name: &str,
+    paths: &DaemonPaths,
+    shutdown: &Arc<AtomicBool>,
+) {
+    let mut ticker = tokio::time::interval(tokio::time::Duration::from_secs(TICK_SECS));
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+    let mut watch_state = WatchState::default();
+
+    loop {
+        ticker.tick().await;
+        if shutdown.load(Ordering::SeqCst) {
+            break;
+        }
+
+        // Checks run commands and walk workspaces — blocking work.
+        let team = team_name.to_string();
+        let tick_paths = paths.clone();
+        let mut st = std::mem::take(&mut watch_state);
+        let result = tokio::task::spawn_blocking(move || {
+            tick(&team, &tick_paths, &mut st);
+            st
+        })
+        .await;
+
+        match result {
+            Ok(st) => watch_state = st,
+            Err(e) => daemon_log(
+                paths,
+                "ERROR",
+                &format!("Invariant checker panicked: {}", e),
+            ),
+        }
+    }
+}
+
+fn tick(team_name: &str, paths: &DaemonPaths, st: &mut WatchState) {
+    let cfg = match config::load() {
+        Ok(c) => c,
+        Err(e) => return log_load_error(paths, st, format!("{:#}", e)),
+    };
+    let team = match config::resolve_team(&cfg, Some(team_name)) {
+        Ok(t) => t,
+        Err(e) => return log_load_error(paths, st, format!("{:#}", e)),
+    };
+    let team_repo = team.path.join("team");
+    let members = match workspace::list_member_dirs(&team_repo.join("members")) {
+        Ok(m) => m,
+        Err(e) => return log_load_error(paths, st, format!("{:#}", e)),
+    };
+    st.last_error = None;
+
+    let team_ws_base = cfg.workzone.join(&team.name);
+    for member in &members {
+        let Some(ws) = workspace::find_workspace(&team_ws_base, member) else {
+            continue;
+        };
+        if count_new_closes(&ws, st) == 0 {
+            continue;
+        }
+
+        let report = match invariants::check_member(&team_repo, &ws, member, Some(TASK_CLOSE)) {
+            Ok(r) => r,
+            Err(e) => {
+                daemon_log(
+                    paths,
+                    "ERROR",
+                    &format!("{}: invariant check failed: {:#}", member, e),
+                );
+                continue;
+            }
+        };
+        if report.checks_run == 0 {
+            continue;
+        }
+        if report.violations.is_empty() {
+            daemon_log(
+                paths,
+                "INFO",
+                &format!(
+                    "{}: {} invariant check(s) passed after {}",
+                    member, report.checks_run, TASK_CLOSE
+                ),
+            );
+            continue;
+        }
+
+        daemon_log(
+            paths,
+            "WARN",
+            &format!(
+                "{}: {} invariant violation(s) after {}",
+                member,
+                report.violations.len(),
+                TASK_CLOSE
+            ),
+        );
+        let brain_mode = formation::is_brain_member(&ws);
+        if let Err(e) = deliver_violations(&ws, brain_mode, &report.violations) {
+            daemon_log(
+                paths,
+                "ERROR",
+                &format!("{}: failed to report invariant violations: {:#}", member, e),
+            );
+        }
+    }
+    st.primed = true;
+}
+
+fn log_load_error(paths: &DaemonPaths, st: &mut WatchState, err: String) {
+    if st.last_error.as_deref() != Some(err.as_str()) {
+        daemon_log(paths, "ERROR", &format!("Invariant checker: {}", err));
+        st.last_error = Some(err);
+    }
+}
+
+/// Reads new lines from the workspace's loop event files and counts
+/// `task.close` events. Partial trailing lines are left for the next tick.
+fn count_new_closes(ws: &Path, st: &mut WatchState) -> usize {
+    let Ok(entries) = std::fs::read_dir(ws.join(".ralph")) else {
+        return 0;
+    };
+    let mut closes = 0;
+    for entry in entries.flatten() {
+        let name = entry.file_name().to_string_lossy().to_string();
+        if !name.starts_with("events-") || !name.ends_with(".jsonl") {
+            continue;
+        }
+        let path = entry.path();
+        let len = match entry.metadata() {
+            Ok(m) => m.len(),
+            Err(_) => continue,
+        };
+        let primed = st.primed;
+        let offset = st
+            .offsets
+            .entry(path.clone())
+            .or_insert(if primed { 0 } else { len });
+        // Truncated or replaced file: start over.
+        if len < *offset {
+            *offset = 0;
+        }
+        if len == *offset {
+            continue;
+        }
+        match read_complete_lines(&path, *offset) {
+            Ok((text, consumed)) => {
+                *offset += consumed;
+                closes += text.lines().filter(|l| is_task_close(l)).count();
+            }
+            Err(_) => continue,
+        }
+    }
+    closes
+}
+
+/// Reads from `offset` up to the last newline. Returns the text and the
+/// number of bytes consumed.
+fn read_complete_lines(path: &Path, offset: u64) -> Result<(String, u64)> {
+    let mut f = File::open(path)?;
+    f.seek(SeekFrom::Start(offset))?;
+    let mut buf = Vec::new();
+    f.read_to_end(&mut buf)?;
+    let end = buf.iter().rposition(|&b| b == b'\n').map_or(0, |i| i + 1);
+    buf.truncate(end);
+    Ok((String::from_utf8_lossy(&buf).to_string(), end as u64))
+}
+
+fn is_task_close(line: &str) -> bool {
+    serde_json::from_str::<serde_json::Value>(line)
+        .ok()
+        .and_then(|v| v.get("topic").and_then(|t| t.as_str()).map(String::from))
+        .is_some_and(|topic| topic == TASK_CLOSE)
+}
+
+/// Hands violations to the member: an `invariant.violation` event for brain
+/// members, a loop inbox message for Ralph members.
+fn deliver_violations(ws: &Path, brain_mode: bool, violations: &[Violation]) -> Result<()> {
+    let prompt = invariants::violation_prompt(TASK_CLOSE, violations);
+    if brain_mode {
+        append_brain_event(ws, "events-invariants.jsonl", VIOLATION_TOPIC, &prompt)
+    } else {
+        inbox::write_message(&inbox::inbox_path(ws), "invariants", &prompt)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::io::Write as _;
+
+    fn append(path: &Path, line: &str) {
+        let mut f = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .unwrap();
+        write!(f, "{}", line).unwrap();
+    }
+
+    #[test]
+    fn counts_only_new_task_close_events() {
+        let ws = tempfile::tempdir().unwrap();
+        let ralph = ws.path().join(".ralph");
+        fs::create_dir_all(&ralph).unwrap();
+        let events = ralph.join("events-20260101-000000.jsonl");
+        append(&events, "{\"topic\":\"task.close\",\"ts\":\"t\"}\n");
+
+        let mut st = WatchState::default();
+        // First scan starts at the end of existing files.
+        assert_eq!(count_new_closes(ws.path(), &mut st), 0);
+        st.primed = true;
+
+        append(&events, "{\"topic\":\"build.done\",\"ts\":\"t\"}\n");
+        append(&events, "{\"topic\":\"task.close\",\"ts\":\"t\"}\n");
+        append(&events, "{\"topic\":\"task.cl");
+        assert_eq!(count_new_closes(ws.path(), &mut st), 1);
+
+        // The partial line completes on the next tick.
+        append(&events, "ose\",\"ts\":\"t\"}\n");
+        assert_eq!(count_new_closes(ws.path(), &mut st), 1);
+        assert_eq!(count_new_closes(ws.path(), &mut st), 0);
+
+        // Files created after the first scan are read from the start.
+        append(
+            &ralph.join("events-20260101-010000.jsonl"),
+            "{\"topic\":\"task.close\",\"ts\":\"t\"}\n",
+        );
+        assert_eq!(count_new_closes(ws.path(), &mut st), 1);
+    }
+
+    #[test]
+    fn delivers_to_inbox_or_brain_events() {
+        let violations = vec![Violation {
+            invariant: "invariants/no-debug.md".into(),
+            target: "projects/api".into(),
+            rule: "forbid_pattern".into(),
+            message: "'dbg!(' found at src/lib.rs:1".into(),
+        }];
+
+        let ws = tempfile::tempdir().unwrap();
+        deliver_violations(ws.path(), false, &violations).unwrap();
+        let inbox = fs::read_to_string(inbox::inbox_path(ws.path())).unwrap();
+        assert!(inbox.contains("invariants/no-debug.md"), "{}", inbox);
+
+        deliver_violations(ws.path(), true, &violations).unwrap();
+        let events = fs::read_to_string(ws.path().join(".ralph/events-invariants.jsonl")).unwrap();
+        let event: serde_json::Value = serde_json::from_str(events.trim()).unwrap();
+        assert_eq!(event["topic"], VIOLATION_TOPIC);
+        assert!(event["payload"].as_str().unwrap().contains("src/lib.rs:1"));
+    }
+}
@@ -11,6 +11,7 @@ use crate::profile;
 use crate::state;
 
 use super::config::{read_team_schema, DaemonConfig, DaemonPaths};
+use super::systemd;
 
 /// Result of a successful daemon start.
 pub struct DaemonStartResult {
@@ -20,9 +21,11 @@ pub struct DaemonStartResult {
 /// Status information about a daemon.
 pub enum DaemonStatusInfo {
     /// Daemon is running with the given PID and optional config.
+    /// `unit` is set when the daemon is supervised by a systemd user unit.
     Running {
         pid: u32,
         config: Option<DaemonConfig>,
+        unit: Option<String>,
     },
     /// Daemon is not running.
     NotRunning {
@@ -49,6 +52,11 @@ pub fn start_daemon(
 
     let paths = DaemonPaths::new(team_name)?;
 
+    // systemd-supervised daemon: mode/port/interval come from the unit
+    if systemd::is_installed(team_name) {
+        return start_daemon_unit(team_name, &paths);
+    }
+
     // Check if already running
     let pid_file = paths.pid();
     if pid_file.exists() {
@@ -154,12 +162,64 @@ pub fn start_daemon(
     Ok(DaemonStartResult { pid })
 }
 
+/// Starts the team's systemd daemon unit and waits for the daemon to write
+/// its config file.
+fn start_daemon_unit(team_name: &str, paths: &DaemonPaths) -> Result<DaemonStartResult> {
+    let unit = systemd::daemon_unit_name(team_name);
+    if systemd::unit_state(&unit)?.is_active() {
+        bail!("Daemon already running for team '{}' (systemd unit {})", team_name, unit);
+    }
+
+    // Remove a stale config so we wait for the fresh one
+    let cfg_path = paths.config();
+    let _ = fs::remove_file(&cfg_path);
+
+    systemd::systemctl(&["start", &unit])?;
+
+    let max_wait = Duration::from_secs(10);
+    let start_time = std::time::Instant::now();
+    loop {
+        if let Some(cfg) = read_config(&cfg_path) {
+            return Ok(DaemonStartResult { pid: cfg.pid });
+        }
+        if !systemd::unit_state(&unit)?.is_active() {
+            bail!(
+                "Daemon unit {} failed to start. Check logs with `bm debug daemon-logs -t {}`",
+                unit,
+                team_name
+            );
+        }
+        if start_time.elapsed() > max_wait {
+            bail!(
+                "Daemon did not write config within {:?}. Check logs with `bm debug daemon-logs -t {}`",
+                max_wait,
+                team_name
+            );
+        }
+        thread::sleep(Duration::from_millis(100));
+    }
+}
+
 /// Stops a running daemon for the given team.
 ///
 /// Reads the PID file, sends SIGTERM, waits up to 30 seconds, escalates to
-/// SIGKILL if needed, then cleans up PID/config/poll-state files.
+/// SIGKILL if needed, then cleans up PID/config/poll-state/token-health files. When a
+/// systemd unit is installed and active, stops the unit instead.
 pub fn stop_daemon(team_name: &str) -> Result<()> {
     let paths = DaemonPaths::new(team_name)?;
+
+    if systemd::is_installed(team_name) {
+        let unit = systemd::daemon_unit_name(team_name);
+        if systemd::unit_state(&unit)?.is_active() {
+            systemd::systemctl(&["stop", &unit])?;
+            let _ = fs::remove_file(paths.config());
+            let _ = fs::remove_file(paths.poll_state());
+    let _ = fs::remove_file(paths.token_health());
+            let _ = fs::remove_file(paths.token_health());
+            return Ok(());
+        }
+    }
+
     let pid_file = paths.pid();
 
     if !pid_file.exists() {
@@ -204,6 +264,21 @@ pub fn stop_daemon(team_name: &str) -> Result<()> {
 /// its PID, and its configuration. Also cleans up stale PID files.
 pub fn query_status(team_name: &str) -> Result<DaemonStatusInfo> {
     let paths = DaemonPaths::new(team_name)?;
+
+    // A systemd-supervised daemon has no PID file — ask systemd.
+    if systemd::is_installed(team_name) {
+        let unit = systemd::daemon_unit_name(team_name);
+        if let Ok(state) = systemd::unit_state(&unit) {
+            if let (true, Some(pid)) = (state.is_active(), state.main_pid) {
+                return Ok(DaemonStatusInfo::Running {
+                    pid,
+                    config: read_config(&paths.config()),
+                    unit: Some(unit),
+                });
+            }
+        }
+    }
+
     let pid_file = paths.pid();
 
     if !pid_file.exists() {
@@ -243,5 +318,11 @@ pub fn query_status(team_name: &str) -> Result<DaemonStatusInfo> {
         None
     };
 
-    Ok(DaemonStatusInfo::Running { pid, config })
+    Ok(DaemonStatusInfo::Running { pid, config, unit: None })
+}
+
+/// Reads the daemon config file, if present and parseable.
+fn read_config(path: &Path) -> Option<DaemonConfig> {
+    let contents = fs::read_to_string(path).ok()?;
+    serde_json::from_str(&contents).ok()
 }
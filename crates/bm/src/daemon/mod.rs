@@ -1,21 +1,37 @@
 mod api;
+mod app_drift;
 mod client;
 mod config;
+mod deliveries;
 mod event;
+mod event_kind;
+mod invariants;
 mod lifecycle;
 mod log;
 mod process;
 mod run;
+mod scheduler;
+mod token_health;
+pub mod systemd;
 
 pub use self::api::{
-    HealthResponse, MemberStatusInfo, MembersStatusResponse, StartLoopRequest,
-    StartLoopResponse, StartMembersRequest, StartMembersResponse, StopMembersRequest,
-    StopMembersResponse,
+    HealthResponse, MemberStatusInfo, MembersStatusResponse, ReloadCredentialsRequest,
+    ReloadCredentialsResponse, ReplayDeliveryResponse, StartLoopRequest, StartLoopResponse,
+    StartMembersRequest, StartMembersResponse, StopMembersRequest, StopMembersResponse,
+    TokenHealthInfo,
 };
 pub use self::client::DaemonClient;
 pub use self::config::{DaemonConfig, DaemonPaths, PollState};
+pub use self::deliveries::{
+    deliveries_path, find_delivery, load_deliveries, Delivery, DeliveryAction, Validation,
+    MAX_PAYLOAD_BYTES,
+};
 pub use self::event::{is_relevant_event, validate_webhook_signature, GitHubEvent};
+pub use self::event_kind::EventKind;
 pub use self::lifecycle::{
     query_status, start_daemon, stop_daemon, DaemonStartResult, DaemonStatusInfo,
 };
 pub use self::run::run_daemon;
+pub use self::token_health::{
+    load_token_health, token_health_path, TokenHealth, TokenHealthMap, TokenState,
+};
@@ -4,9 +4,12 @@ use std::sync::Arc;
 use anyhow::Result;
 
 use crate::config;
+use crate::state;
+use crate::workspace;
 
 use super::config::DaemonPaths;
 use super::log::daemon_log;
+use super::scheduler::off_duty_members;
 
 /// Launches all team members using the single entry point (`start_local_members`).
 ///
@@ -24,18 +27,58 @@ pub fn launch_members_oneshot(
     let team = config::resolve_team(&cfg, Some(team_name))?;
     let team_repo = team.path.join("team");
 
+    // Members outside working hours or inside quiet hours wait for the
+    // scheduler instead of being woken by events.
+    let team_ws_base = cfg.workzone.join(&team.name);
+    let off_duty = match off_duty_members(&team_repo, &team_ws_base, chrono::Utc::now()) {
+        Ok(m) => m,
+        Err(e) => {
+            daemon_log(paths, "WARN", &format!("Ignoring schedule: {:#}", e));
+            Vec::new()
+        }
+    };
+
+    // Members held by `bm pause` stay down until `bm resume`.
+    let runtime_state = state::load().unwrap_or_default();
+    let any_paused = runtime_state
+        .paused
+        .keys()
+        .any(|key| key.starts_with(&format!("{}/", team.name)));
+
     // The daemon IS the formation's internal runtime — it calls start_local_members
     // directly, not formation.start_members() (which would HTTP-call back into this
     // daemon, creating a circular loop).
-    let result = crate::formation::start_local_members(
-        team,
-        &cfg,
-        &team_repo,
-        None,   // all members
-        true,   // no_bridge — daemon doesn't manage bridge lifecycle
-        None,   // no formation override
-    )?;
+    if off_duty.is_empty() && !any_paused {
+        let result = crate::formation::start_local_members(
+            team,
+            &cfg,
+            &team_repo,
+            None,   // all members
+            true,   // no_bridge — daemon doesn't manage bridge lifecycle
+            None,   // no formation override
+        )?;
+        return Ok(log_start_result(paths, &result));
+    }
 
+    let mut launched = 0;
+    for member in workspace::list_member_dirs(&team_repo.join("members"))? {
+        if off_duty.contains(&member) {
+            daemon_log(paths, "INFO", &format!("{}: off duty (schedule), not launching", member));
+            continue;
+        }
+        if runtime_state.is_paused(&team.name, &member) {
+            daemon_log(paths, "INFO", &format!("{}: paused, not launching", member));
+            continue;
+        }
+        let result =
+            crate::formation::start_local_members(team, &cfg, &team_repo, Some(&member), true, None)?;
+        launched += log_start_result(paths, &result);
+    }
+    Ok(launched)
+}
+
+/// Logs per-member outcomes and returns the number launched.
+fn log_start_result(paths: &DaemonPaths, result: &crate::formation::StartResult) -> u32 {
     for m in &result.launched {
         daemon_log(paths, "INFO", &format!("{}: launched (PID {})", m.name, m.pid));
     }
@@ -45,8 +88,7 @@ pub fn launch_members_oneshot(
     for m in &result.errors {
         daemon_log(paths, "ERROR", &format!("{}: {}", m.name, m.error));
     }
-
-    Ok(result.launched.len() as u32)
+    result.launched.len() as u32
 }
 
 /// Launches members one-shot with logging.
@@ -13,18 +13,28 @@ use axum::Router;
 use tower_http::cors::{AllowOrigin, CorsLayer};
 
 use super::api;
+use super::app_drift::run_app_drift_loop;
 use super::config::{load_poll_state, save_poll_state, DaemonConfig, DaemonPaths};
+use super::deliveries::{record_delivery, Delivery, DeliveryAction, Validation};
 use super::event::{
-    is_relevant_event, load_webhook_secret, poll_github_events, resolve_github_repo,
-    validate_webhook_signature,
+    delivery_header, delivery_matches_host, event_header, load_webhook_secret, poll_team_events,
+    signature_header, validate_forge_signature, ENTERPRISE_HOST_HEADER,
 };
+use super::event_kind::{EventKind, ProjectFilter};
+use super::invariants::run_invariants_loop;
+use super::token_health::run_token_alert_loop;
 use super::log::daemon_log;
 use super::process::handle_member_launch;
+use super::scheduler::run_scheduler_loop;
 use crate::config as app_config;
 use crate::formation::AppCredentialsCached;
+use crate::git::{self, GitHubHost};
 use crate::web::state::WebState;
 use crate::web::web_router;
 
+/// App credentials of started members, keyed by member name.
+pub(super) type AppCredentialsMap = Arc<Mutex<HashMap<String, AppCredentialsCached>>>;
+
 /// Shared state for axum handlers.
 #[derive(Clone)]
 pub(super) struct DaemonState {
@@ -41,17 +51,23 @@ pub(super) struct DaemonState {
     pub(super) team_entry: Arc<app_config::TeamEntry>,
     /// In-memory cache of App credentials for members that have been started.
     /// Used by the background refresh loop to re-sign JWTs without re-reading keyring.
-    pub(super) app_credentials: Arc<Mutex<HashMap<String, AppCredentialsCached>>>,
+    pub(super) app_credentials: AppCredentialsMap,
+    /// Board whose item events wake members, resolved once at startup.
+    pub(super) project: ProjectFilter,
 }
 
 /// Runs the daemon event loop. Called by the hidden `bm daemon-run` command.
 /// This function does not return until the daemon is signaled to stop.
+///
+/// With `start_members`, launches all members once the server is listening —
+/// used by the systemd unit so the team comes back after a reboot.
 pub fn run_daemon(
     team_name: &str,
     mode: &str,
     port: u16,
     interval: u64,
     bind: &str,
+    start_members: bool,
 ) -> Result<()> {
     // Resolve the isolated keyring D-Bus address BEFORE creating the tokio
     // runtime. `with_keyring_dbus` in credential.rs swaps DBUS_SESSION_BUS_ADDRESS
@@ -65,7 +81,7 @@ pub fn run_daemon(
     }
 
     let rt = tokio::runtime::Runtime::new().context("Failed to create tokio runtime")?;
-    rt.block_on(run_daemon_async(team_name, mode, port, interval, bind))
+    rt.block_on(run_daemon_async(team_name, mode, port, interval, bind, start_members))
 }
 
 async fn run_daemon_async(
@@ -74,6 +90,7 @@ async fn run_daemon_async(
     port: u16,
     interval: u64,
     bind: &str,
+    start_members: bool,
 ) -> Result<()> {
     // NOTE: Do NOT set SIGCHLD=SIG_IGN here. While it prevents zombie children,
     // it also breaks Command::output() (used by gh api in poll mode) because
@@ -93,6 +110,13 @@ async fn run_daemon_async(
     let team_entry = app_config::resolve_team(&cfg, Some(team_name))
         .context("Daemon failed to resolve team at startup")?
         .clone();
+    let project = {
+        let team = team_entry.clone();
+        let paths = Arc::clone(&paths);
+        tokio::task::spawn_blocking(move || resolve_project_filter(&team, &paths))
+            .await
+            .unwrap_or(ProjectFilter::Any)
+    };
 
     let state = DaemonState {
         team_name: team_name.to_string(),
@@ -104,6 +128,7 @@ async fn run_daemon_async(
         config: Arc::new(cfg),
         team_entry: Arc::new(team_entry),
         app_credentials: Arc::new(Mutex::new(HashMap::new())),
+        project: project.clone(),
     };
 
     // Resolve config path for the web API (console routes)
@@ -137,7 +162,16 @@ async fn run_daemon_async(
         .route("/api/members/start", post(api::start_members_handler))
         .route("/api/members/stop", post(api::stop_members_handler))
         .route("/api/members", get(api::list_members_handler))
+        .route(
+            "/api/members/credentials/reload",
+            post(api::reload_credentials_handler),
+        )
         .route("/api/health", get(api::health_check_handler))
+        // Webhook delivery log
+        .route(
+            "/api/deliveries/{id}/replay",
+            post(api::replay_delivery_handler),
+        )
         // Loop management API
         .route("/api/loops/start", post(api::start_loop_handler))
         .with_state(state.clone())
@@ -150,10 +184,42 @@ async fn run_daemon_async(
         let poll_paths = Arc::clone(&paths);
         let poll_shutdown = Arc::clone(&shutdown);
         tokio::spawn(async move {
-            run_poll_loop(&poll_team, &poll_paths, interval, &poll_shutdown).await;
+            run_poll_loop(&poll_team, &poll_paths, interval, &project, &poll_shutdown).await;
         });
     }
 
+    // Schedules (working hours, quiet hours, triggers) from the team repo
+    let sched_team = team_name.to_string();
+    let sched_paths = Arc::clone(&paths);
+    let sched_shutdown = Arc::clone(&shutdown);
+    tokio::spawn(async move {
+        run_scheduler_loop(&sched_team, &sched_paths, &sched_shutdown).await;
+    });
+
+    // Executable invariants triggered by loop events
+    let inv_team = team_name.to_string();
+    let inv_paths = Arc::clone(&paths);
+    let inv_shutdown = Arc::clone(&shutdown);
+    tokio::spawn(async move {
+        run_invariants_loop(&inv_team, &inv_paths, &inv_shutdown).await;
+    });
+
+    // Operator alerts for member App tokens close to expiry
+    let alert_team = team_name.to_string();
+    let alert_paths = Arc::clone(&paths);
+    let alert_shutdown = Arc::clone(&shutdown);
+    tokio::spawn(async move {
+        run_token_alert_loop(&alert_team, &alert_paths, &alert_shutdown).await;
+    });
+
+    // Member App permission, event and repository drift
+    let drift_team = team_name.to_string();
+    let drift_paths = Arc::clone(&paths);
+    let drift_shutdown = Arc::clone(&shutdown);
+    tokio::spawn(async move {
+        run_app_drift_loop(&drift_team, &drift_paths, &drift_shutdown).await;
+    });
+
     let addr: SocketAddr = format!("{}:{}", bind, port)
         .parse()
         .with_context(|| format!("Invalid bind address: {}:{}", bind, port))?;
@@ -205,6 +271,16 @@ async fn run_daemon_async(
         &format!("Daemon config written (port={})", actual_addr.port()),
     );
 
+    if start_members {
+        daemon_log(&paths, "INFO", "Starting members at daemon startup");
+        let launch_team = team_name.to_string();
+        let launch_paths = Arc::clone(&paths);
+        let launch_shutdown = Arc::clone(&shutdown);
+        tokio::task::spawn_blocking(move || {
+            handle_member_launch(&launch_team, &launch_paths, &launch_shutdown);
+        });
+    }
+
     let shutdown_flag = Arc::clone(&shutdown);
     axum::serve(listener, app)
         .with_graceful_shutdown(shutdown_signal(shutdown_flag))
@@ -254,61 +330,141 @@ async fn shutdown_signal(shutdown: Arc<AtomicBool>) {
 }
 
 /// Axum handler for POST /webhook.
+///
+/// Every delivery is recorded in the team's delivery log with its
+/// validation result and the action taken, so it can be inspected and
+/// replayed with `bm daemon events`.
 async fn webhook_handler(
     State(state): State<DaemonState>,
     headers: HeaderMap,
     body: Bytes,
 ) -> impl IntoResponse {
-    let body_str = match std::str::from_utf8(&body) {
-        Ok(s) => s.to_string(),
+    let forge_kind = state.team_entry.forge_kind();
+    let delivery_id = headers
+        .get(delivery_header(forge_kind))
+        .and_then(|v| v.to_str().ok());
+    let event_type = headers
+        .get(event_header(forge_kind))
+        .and_then(|v| v.to_str().ok());
+
+    let validation = validate_delivery(&state, &headers, &body);
+    let event = event_type.map(|t| EventKind::parse(t, &parse_payload(&body)));
+    let action = if validation.accepted() {
+        route_event(&state, event.as_ref())
+    } else {
+        DeliveryAction::Rejected
+    };
+
+    let mut delivery = Delivery::new(delivery_id, event_type, &headers, &body, validation, action);
+    delivery.summary = event.map(|e| e.to_string());
+    let path = state.paths.deliveries();
+    tokio::task::spawn_blocking(move || record_delivery(&path, delivery));
+
+    match validation {
+        Validation::Verified | Validation::Unsigned => StatusCode::OK,
+        Validation::InvalidBody => StatusCode::BAD_REQUEST,
+        Validation::BadSignature | Validation::WrongHost => StatusCode::FORBIDDEN,
+    }
+}
+
+/// Parses a delivery body as JSON; `Null` when it isn't.
+pub(super) fn parse_payload(body: &[u8]) -> serde_json::Value {
+    serde_json::from_slice(body).unwrap_or(serde_json::Value::Null)
+}
+
+/// Checks a delivery's body, sending host and signature.
+fn validate_delivery(state: &DaemonState, headers: &HeaderMap, body: &[u8]) -> Validation {
+    let body_str = match std::str::from_utf8(body) {
+        Ok(s) => s,
         Err(_) => {
             daemon_log(&state.paths, "ERROR", "Failed to read request body as UTF-8");
-            return StatusCode::BAD_REQUEST;
+            return Validation::InvalidBody;
         }
     };
 
-    // Validate signature if webhook secret is configured
-    if let Some(ref secret) = state.webhook_secret {
-        let sig_header = headers
-            .get("x-hub-signature-256")
-            .and_then(|v| v.to_str().ok())
-            .map(|s| s.to_string());
-
-        if !validate_webhook_signature(secret, &body_str, sig_header.as_deref()) {
-            daemon_log(&state.paths, "WARN", "Webhook signature validation failed");
-            return StatusCode::FORBIDDEN;
-        }
-    }
-
-    // Parse event type from header
-    let event_type = headers
-        .get("x-github-event")
-        .and_then(|v| v.to_str().ok())
-        .map(|s| s.to_string());
+    let forge_kind = state.team_entry.forge_kind();
 
-    if let Some(event_type) = event_type {
-        if is_relevant_event(&event_type) {
+    if forge_kind == app_config::ForgeKind::GitHub {
+        let sender = headers
+            .get(ENTERPRISE_HOST_HEADER)
+            .and_then(|v| v.to_str().ok());
+        let host = GitHubHost::for_team(&state.team_entry);
+        if !delivery_matches_host(&host, sender) {
             daemon_log(
                 &state.paths,
-                "INFO",
-                &format!("Received relevant event: {}", event_type),
+                "WARN",
+                &format!(
+                    "Rejected webhook from {} (team host is {})",
+                    sender.unwrap_or("github.com"),
+                    host.host
+                ),
             );
-            let team = state.team_name.clone();
-            let paths = Arc::clone(&state.paths);
-            let shutdown = Arc::clone(&state.shutdown);
-            tokio::task::spawn_blocking(move || {
-                handle_member_launch(&team, &paths, &shutdown);
-            });
-        } else {
+            return Validation::WrongHost;
+        }
+    }
+
+    // Validate signature if webhook secret is configured
+    let Some(ref secret) = state.webhook_secret else {
+        return Validation::Unsigned;
+    };
+    let sig_header = headers
+        .get(signature_header(forge_kind))
+        .and_then(|v| v.to_str().ok());
+    if !validate_forge_signature(forge_kind, secret, body_str, sig_header) {
+        daemon_log(&state.paths, "WARN", "Webhook signature validation failed");
+        return Validation::BadSignature;
+    }
+    Validation::Verified
+}
+
+/// Routes an accepted delivery by its typed event: events that should wake
+/// members launch them. Shared by the webhook handler and delivery replay.
+pub(super) fn route_event(state: &DaemonState, event: Option<&EventKind>) -> DeliveryAction {
+    let Some(event) = event else {
+        return DeliveryAction::NoEvent;
+    };
+
+    if !event.should_wake_for(&state.project) {
+        daemon_log(&state.paths, "DEBUG", &format!("Ignoring event: {}", event));
+        return DeliveryAction::Ignored;
+    }
+
+    daemon_log(
+        &state.paths,
+        "INFO",
+        &format!("Received relevant event: {}", event),
+    );
+    let team = state.team_name.clone();
+    let paths = Arc::clone(&state.paths);
+    let shutdown = Arc::clone(&state.shutdown);
+    tokio::task::spawn_blocking(move || {
+        handle_member_launch(&team, &paths, &shutdown);
+    });
+    DeliveryAction::LaunchedMembers
+}
+
+/// Resolves which board's item events wake the team's members. A failed
+/// lookup is logged and falls back to waking on every board.
+fn resolve_project_filter(team: &app_config::TeamEntry, paths: &DaemonPaths) -> ProjectFilter {
+    let Some(number) = team.project_number else {
+        return ProjectFilter::NoBoard;
+    };
+    let owner = team.github_repo.split('/').next().unwrap_or_default();
+    match git::project_node_id(&GitHubHost::for_team(team), owner, number) {
+        Ok(id) => ProjectFilter::Board(id),
+        Err(e) => {
             daemon_log(
-                &state.paths,
-                "DEBUG",
-                &format!("Ignoring irrelevant event: {}", event_type),
+                paths,
+                "WARN",
+                &format!(
+                    "Could not look up project board #{}: {:#}; item events from every \
+                     board will wake members",
+                    number, e
+                ),
             );
+            ProjectFilter::Any
         }
     }
-
-    StatusCode::OK
 }
 
 /// Axum handler for GET /health.
@@ -323,6 +479,7 @@ async fn run_poll_loop(
     team_name: &str,
     paths: &DaemonPaths,
     interval: u64,
+    project: &ProjectFilter,
     shutdown: &Arc<AtomicBool>,
 ) {
     daemon_log(
@@ -351,28 +508,34 @@ async fn run_poll_loop(
             break;
         }
 
-        // All poll operations (resolve_github_repo, poll_github_events,
-        // handle_member_launch) are blocking sync calls that spawn subprocesses
-        // or do file I/O. Run them on the blocking thread pool to avoid starving
-        // the async runtime's worker threads.
+        // All poll operations (poll_team_events, handle_member_launch) are
+        // blocking sync calls that spawn subprocesses or do file I/O. Run them
+        // on the blocking thread pool to avoid starving the async runtime's
+        // worker threads.
         let poll_team = team_name.to_string();
         let poll_state_clone = poll_state.clone();
         let poll_paths = paths.clone();
         let poll_shutdown = Arc::clone(shutdown);
+        let poll_project = project.clone();
 
         let result = tokio::task::spawn_blocking(move || {
-            let github_repo = resolve_github_repo(&poll_team)?;
-            let events = poll_github_events(&github_repo, &poll_state_clone)?;
-            let relevant_count = events
+            let events = poll_team_events(&poll_team, &poll_state_clone)?;
+            let relevant: Vec<String> = events
                 .iter()
-                .filter(|e| is_relevant_event(&e.event_type))
-                .count();
+                .map(|e| e.kind())
+                .filter(|k| k.should_wake_for(&poll_project))
+                .map(|k| k.to_string())
+                .collect();
 
-            if relevant_count > 0 {
+            if !relevant.is_empty() {
                 daemon_log(
                     &poll_paths,
                     "INFO",
-                    &format!("Found {} relevant event(s)", relevant_count),
+                    &format!(
+                        "Found {} relevant event(s): {}",
+                        relevant.len(),
+                        relevant.join("; ")
+                    ),
                 );
                 handle_member_launch(&poll_team, &poll_paths, &poll_shutdown);
             }
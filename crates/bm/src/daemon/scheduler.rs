@@ -0,0 +1,378 @@
+use std::collections::HashMap;
+use std::fs::OpenOptions;
+use std::io::Write as _;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+
+use super::config::DaemonPaths;
+use super::log::daemon_log;
+use crate::brain::inbox;
+use crate::config;
+use crate::formation;
+use crate::profile;
+use crate::schedule::{self, ResolvedSchedule, ResolvedTrigger};
+use crate::state;
+use crate::workspace;
+
+/// How often the scheduler wakes up. Triggers have minute resolution; ticks
+/// that land in an already-evaluated minute are no-ops.
+const TICK_SECS: u64 = 20;
+
+/// Longest gap the scheduler catches up on (e.g. after a suspend). Triggers
+/// older than this are dropped rather than fired in a burst.
+const MAX_CATCH_UP_MINUTES: i64 = 10;
+
+/// Event topic the brain's event watcher picks up for scheduled prompts.
+const TRIGGER_TOPIC: &str = "schedule.trigger";
+
+/// State carried between scheduler ticks.
+#[derive(Default)]
+struct SchedulerState {
+    /// Last minute whose triggers were evaluated.
+    last_minute: Option<DateTime<Utc>>,
+    /// Whether each member could be launched at the previous tick (see
+    /// `launchable`). Members are only started on a false→true transition,
+    /// so an operator's `bm stop` during working hours sticks.
+    launchable: HashMap<String, bool>,
+    /// Last load error, logged once until it changes.
+    last_error: Option<String>,
+}
+
+/// Runs the schedule loop as a background async task.
+pub(super) async fn run_scheduler_loop(
+    team_name: &str,
+    paths: &DaemonPaths,
+    shutdown: &Arc<AtomicBool>,
+) {
+    let mut ticker = tokio::time::interval(tokio::time::Duration::from_secs(TICK_SECS));
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+    let mut sched_state = SchedulerState::default();
+
+    loop {
+        ticker.tick().await;
+        if shutdown.load(Ordering::SeqCst) {
+            break;
+        }
+
+        // Loading state and starting/stopping members is blocking work.
+        let team = team_name.to_string();
+        let tick_paths = paths.clone();
+        let mut st = std::mem::take(&mut sched_state);
+        let result = tokio::task::spawn_blocking(move || {
+            tick(&team, &tick_paths, &mut st, Utc::now());
+            st
+        })
+        .await;
+
+        match result {
+            Ok(st) => sched_state = st,
+            Err(e) => daemon_log(paths, "ERROR", &format!("Scheduler task panicked: {}", e)),
+        }
+    }
+
+    daemon_log(paths, "INFO", "Scheduler stopped");
+}
+
+/// One scheduler pass: enforce working hours and fire due triggers.
+fn tick(team_name: &str, paths: &DaemonPaths, st: &mut SchedulerState, now: DateTime<Utc>) {
+    let now_minute = schedule::truncate_minute(now);
+    let minutes = match st.last_minute {
+        Some(last) if last >= now_minute => return,
+        Some(last) => {
+            let earliest = now_minute - chrono::Duration::minutes(MAX_CATCH_UP_MINUTES);
+            schedule::minutes_between(last.max(earliest), now)
+        }
+        None => vec![now_minute],
+    };
+    st.last_minute = Some(now_minute);
+
+    let cfg = match config::load() {
+        Ok(c) => c,
+        Err(e) => return log_load_error(paths, st, format!("{:#}", e)),
+    };
+    let team = match config::resolve_team(&cfg, Some(team_name)) {
+        Ok(t) => t,
+        Err(e) => return log_load_error(paths, st, format!("{:#}", e)),
+    };
+    let team_repo = team.path.join("team");
+    let schedules = match load_member_schedules(&team_repo) {
+        Ok(s) => s,
+        Err(e) => return log_load_error(paths, st, format!("{:#}", e)),
+    };
+    st.last_error = None;
+    if schedules.is_empty() {
+        return;
+    }
+
+    let runtime = state::load().unwrap_or_default();
+    let team_ws_base = cfg.workzone.join(&team.name);
+
+    for (member, sched) in &schedules {
+        let ws = workspace::find_workspace(&team_ws_base, member);
+        let brain_mode = ws.as_deref().is_some_and(formation::is_brain_member);
+        let running = runtime
+            .members
+            .get(&format!("{}/{}", team.name, member))
+            .is_some_and(|rt| state::is_alive(rt.pid));
+
+        // Working hours apply to ralph members; the brain stays reachable.
+        if !brain_mode && !sched.on_duty(now) && running {
+            daemon_log(
+                paths,
+                "INFO",
+                &format!("{}: outside working hours, stopping", member),
+            );
+            if let Err(e) = formation::stop_local_members(team, &cfg, Some(member.as_str()), false)
+            {
+                daemon_log(
+                    paths,
+                    "ERROR",
+                    &format!("{}: scheduled stop failed: {:#}", member, e),
+                );
+            }
+        }
+
+        // Launch when working hours open or quiet hours end.
+        let now_launchable = launchable(sched, brain_mode, now);
+        let was_launchable = st.launchable.insert(member.clone(), now_launchable);
+        // Members held by `bm pause` stay down and get no triggers.
+        let paused = runtime.is_paused(&team.name, member);
+        if now_launchable && was_launchable == Some(false) && !running && paused {
+            daemon_log(
+                paths,
+                "INFO",
+                &format!("{}: back on duty (schedule), but paused", member),
+            );
+        } else if now_launchable && was_launchable == Some(false) && !running {
+            daemon_log(
+                paths,
+                "INFO",
+                &format!("{}: back on duty (schedule), launching", member),
+            );
+            match formation::start_local_members(
+                team,
+                &cfg,
+                &team_repo,
+                Some(member.as_str()),
+                true,
+                None,
+            ) {
+                Ok(r) => {
+                    for f in &r.errors {
+                        daemon_log(paths, "ERROR", &format!("{}: {}", f.name, f.error));
+                    }
+                }
+                Err(e) => daemon_log(
+                    paths,
+                    "ERROR",
+                    &format!("{}: scheduled start failed: {:#}", member, e),
+                ),
+            }
+        }
+
+        for minute in &minutes {
+            for trigger in sched.due_triggers(*minute) {
+                if sched.is_quiet(*minute) || paused {
+                    let reason = if paused { "paused" } else { "quiet hours" };
+                    daemon_log(
+                        paths,
+                        "INFO",
+                        &format!(
+                            "{}: trigger '{}' skipped ({})",
+                            member, trigger.name, reason
+                        ),
+                    );
+                    continue;
+                }
+                let Some(ref ws) = ws else {
+                    daemon_log(
+                        paths,
+                        "WARN",
+                        &format!(
+                            "{}: trigger '{}' skipped (no workspace)",
+                            member, trigger.name
+                        ),
+                    );
+                    continue;
+                };
+                match deliver_trigger(ws, brain_mode, trigger) {
+                    Ok(()) => daemon_log(
+                        paths,
+                        "INFO",
+                        &format!("{}: trigger '{}' fired", member, trigger.name),
+                    ),
+                    Err(e) => daemon_log(
+                        paths,
+                        "ERROR",
+                        &format!("{}: trigger '{}' failed: {:#}", member, trigger.name, e),
+                    ),
+                }
+            }
+        }
+    }
+}
+
+fn log_load_error(paths: &DaemonPaths, st: &mut SchedulerState, err: String) {
+    if st.last_error.as_deref() != Some(err.as_str()) {
+        daemon_log(paths, "ERROR", &format!("Scheduler: {}", err));
+        st.last_error = Some(err);
+    }
+}
+
+/// Resolves the schedule of every hired member that has one.
+fn load_member_schedules(team_repo: &Path) -> Result<Vec<(String, ResolvedSchedule)>> {
+    let Some(cfg) = schedule::load(team_repo)? else {
+        return Ok(Vec::new());
+    };
+    let members_dir = team_repo.join("members");
+    if !members_dir.is_dir() {
+        return Ok(Vec::new());
+    }
+    let mut out = Vec::new();
+    for member in workspace::list_member_dirs(&members_dir)? {
+        let role = profile::read_member_role(&members_dir, &member);
+        if let Some(sched) = cfg
+            .resolve(&role, &member)
+            .with_context(|| format!("Schedule for {}", member))?
+        {
+            out.push((member, sched));
+        }
+    }
+    Ok(out)
+}
+
+/// Members the daemon should not launch right now: non-brain members outside
+/// working hours, and any member inside quiet hours.
+pub(super) fn off_duty_members(
+    team_repo: &Path,
+    team_ws_base: &Path,
+    now: DateTime<Utc>,
+) -> Result<Vec<String>> {
+    Ok(load_member_schedules(team_repo)?
+        .into_iter()
+        .filter(|(member, sched)| {
+            let brain_mode = workspace::find_workspace(team_ws_base, member)
+                .is_some_and(|ws| formation::is_brain_member(&ws));
+            !launchable(sched, brain_mode, now)
+        })
+        .map(|(member, _)| member)
+        .collect())
+}
+
+/// True when the schedule lets the daemon launch a member: outside quiet
+/// hours and, for non-brain members, inside working hours.
+fn launchable(sched: &ResolvedSchedule, brain_mode: bool, now: DateTime<Utc>) -> bool {
+    !sched.is_quiet(now) && (brain_mode || sched.on_duty(now))
+}
+
+/// Injects a trigger prompt into the member's workspace.
+///
+/// Ralph members receive it through the loop inbox (read by the coding
+/// agent's hooks). Brain members receive it as a `schedule.trigger` event,
+/// which the brain's event watcher queues like a loop event.
+fn deliver_trigger(ws: &Path, brain_mode: bool, trigger: &ResolvedTrigger) -> Result<()> {
+    if !brain_mode {
+        return inbox::write_message(&inbox::inbox_path(ws), "schedule", &trigger.prompt);
+    }
+    append_brain_event(
+        ws,
+        "events-schedule.jsonl",
+        TRIGGER_TOPIC,
+        &format!("{}: {}", trigger.name, trigger.prompt),
+    )
+}
+
+/// Appends an event line to `.ralph/<file>` in the member's workspace, where
+/// the brain's event watcher picks it up alongside loop events.
+pub(super) fn append_brain_event(ws: &Path, file: &str, topic: &str, payload: &str) -> Result<()> {
+    let path = ws.join(".ralph").join(file);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let line = serde_json::json!({
+        "topic": topic,
+        "payload": payload,
+        "ts": Utc::now().to_rfc3339(),
+    });
+    let mut f = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .with_context(|| format!("Failed to open {}", path.display()))?;
+    writeln!(f, "{}", line)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn team_repo_with_schedule(schedule_yml: &str) -> tempfile::TempDir {
+        let tmp = tempfile::tempdir().unwrap();
+        std::fs::write(tmp.path().join("schedule.yml"), schedule_yml).unwrap();
+        for m in ["engineer-bob", "reviewer-alice"] {
+            std::fs::create_dir_all(tmp.path().join("members").join(m)).unwrap();
+        }
+        tmp
+    }
+
+    #[test]
+    fn off_duty_respects_working_and_quiet_hours() {
+        let repo = team_repo_with_schedule(
+            r#"
+roles:
+  engineer:
+    working_hours: { start: "09:00", end: "17:00" }
+  reviewer:
+    quiet_hours:
+      - { start: "12:00", end: "13:00" }
+"#,
+        );
+        let ws_base = tempfile::tempdir().unwrap();
+
+        let morning = Utc.with_ymd_and_hms(2026, 10, 19, 8, 0, 0).unwrap();
+        let off = off_duty_members(repo.path(), ws_base.path(), morning).unwrap();
+        assert_eq!(off, vec!["engineer-bob"]);
+
+        let noon = Utc.with_ymd_and_hms(2026, 10, 19, 12, 30, 0).unwrap();
+        let off = off_duty_members(repo.path(), ws_base.path(), noon).unwrap();
+        assert_eq!(off, vec!["reviewer-alice"]);
+
+        let after_quiet = Utc.with_ymd_and_hms(2026, 10, 19, 13, 0, 0).unwrap();
+        let off = off_duty_members(repo.path(), ws_base.path(), after_quiet).unwrap();
+        assert!(off.is_empty());
+    }
+
+    #[test]
+    fn off_duty_is_empty_without_schedule() {
+        let tmp = tempfile::tempdir().unwrap();
+        let off = off_duty_members(tmp.path(), tmp.path(), Utc::now()).unwrap();
+        assert!(off.is_empty());
+    }
+
+    #[test]
+    fn deliver_trigger_writes_inbox_or_event() {
+        let ws = tempfile::tempdir().unwrap();
+        let trigger = ResolvedTrigger {
+            name: "standup".into(),
+            cron: schedule::CronExpr::parse("@daily").unwrap(),
+            prompt: "Post a standup summary".into(),
+        };
+
+        deliver_trigger(ws.path(), false, &trigger).unwrap();
+        let read = inbox::read_messages(&inbox::inbox_path(ws.path()), false).unwrap();
+        assert_eq!(read.messages.len(), 1);
+        assert_eq!(read.messages[0].from, "schedule");
+
+        deliver_trigger(ws.path(), true, &trigger).unwrap();
+        let events =
+            std::fs::read_to_string(ws.path().join(".ralph/events-schedule.jsonl")).unwrap();
+        assert!(events.contains("\"topic\":\"schedule.trigger\""));
+        assert!(events.contains("standup: Post a standup summary"));
+    }
+}
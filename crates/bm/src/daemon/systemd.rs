@@ -0,0 +1,352 @@
+//! systemd user-unit integration for the daemon and bridge.
+//!
+//! `bm daemon install` writes units to `~/.config/systemd/user/` so the team
+//! comes back after a reboot or logout (with lingering enabled). When a
+//! daemon unit is installed, `start_daemon`/`stop_daemon`/`query_status`
+//! delegate to `systemctl --user` instead of the PID file, and logs are read
+//! from journald.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use anyhow::{bail, Context, Result};
+
+/// Parameters for rendering the daemon unit.
+pub struct DaemonUnitParams<'a> {
+    pub team: &'a str,
+    pub exe: &'a Path,
+    pub mode: &'a str,
+    pub port: u16,
+    pub interval: u64,
+    pub bind: &'a str,
+    /// `PATH` for the service, so `ralph`, `gh`, and `just` resolve as they
+    /// do in the operator's shell.
+    pub path_env: Option<&'a str>,
+    /// Order the daemon after the team's bridge unit.
+    pub with_bridge: bool,
+}
+
+/// Result of `install`.
+pub struct InstallResult {
+    pub units: Vec<PathBuf>,
+    pub started: bool,
+    pub linger: bool,
+}
+
+/// Runtime state of a unit as reported by `systemctl --user show`.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct UnitState {
+    pub active_state: String,
+    pub sub_state: String,
+    pub main_pid: Option<u32>,
+    pub active_since: Option<String>,
+}
+
+impl UnitState {
+    pub fn is_active(&self) -> bool {
+        self.active_state == "active"
+    }
+}
+
+/// Daemon unit name: `botminter-daemon-<team>.service`.
+pub fn daemon_unit_name(team: &str) -> String {
+    format!("botminter-daemon-{}.service", team)
+}
+
+/// Bridge unit name: `botminter-bridge-<team>.service`.
+pub fn bridge_unit_name(team: &str) -> String {
+    format!("botminter-bridge-{}.service", team)
+}
+
+/// Directory for user units: `~/.config/systemd/user/`.
+pub fn unit_dir() -> Result<PathBuf> {
+    let config = dirs::config_dir().context("Could not determine config directory")?;
+    Ok(config.join("systemd").join("user"))
+}
+
+/// True when a daemon unit is installed for the team.
+pub fn is_installed(team: &str) -> bool {
+    unit_dir()
+        .map(|d| d.join(daemon_unit_name(team)).exists())
+        .unwrap_or(false)
+}
+
+/// Renders the daemon service unit.
+pub fn render_daemon_unit(p: &DaemonUnitParams<'_>) -> String {
+    let mut unit = format!("[Unit]\nDescription=botminter daemon for team {}\n", p.team);
+    if p.with_bridge {
+        let bridge = bridge_unit_name(p.team);
+        unit.push_str(&format!("Wants={}\nAfter={}\n", bridge, bridge));
+    }
+    // KillMode=process: on stop, systemd signals only the daemon, which stops
+    // members itself. Members it launched live in the unit's cgroup and
+    // would otherwise be killed outright, skipping their shutdown.
+    unit.push_str(&format!(
+        "\n[Service]\n\
+         Type=simple\n\
+         ExecStart={} daemon-run --team {} --mode {} --port {} --interval {} --bind {} --start-members\n\
+         Restart=on-failure\n\
+         RestartSec=10\n\
+         TimeoutStopSec=45\n\
+         KillMode=process\n",
+        quote(&p.exe.to_string_lossy()),
+        p.team,
+        p.mode,
+        p.port,
+        p.interval,
+        p.bind,
+    ));
+    if let Some(path) = p.path_env {
+        unit.push_str(&format!("Environment={}\n", quote(&format!("PATH={}", path))));
+    }
+    unit.push_str("\n[Install]\nWantedBy=default.target\n");
+    unit
+}
+
+/// Renders the bridge unit: a oneshot wrapping `bm bridge start|stop`.
+pub fn render_bridge_unit(team: &str, exe: &Path, path_env: Option<&str>) -> String {
+    let exe = quote(&exe.to_string_lossy());
+    let mut unit = format!(
+        "[Unit]\n\
+         Description=botminter bridge for team {team}\n\
+         \n\
+         [Service]\n\
+         Type=oneshot\n\
+         RemainAfterExit=yes\n\
+         ExecStart={exe} bridge start -t {team}\n\
+         ExecStop={exe} bridge stop -t {team}\n",
+    );
+    if let Some(path) = path_env {
+        unit.push_str(&format!("Environment={}\n", quote(&format!("PATH={}", path))));
+    }
+    unit.push_str("\n[Install]\nWantedBy=default.target\n");
+    unit
+}
+
+/// Quotes a value for a unit file if it contains whitespace or quotes.
+fn quote(s: &str) -> String {
+    if s.chars().any(|c| c.is_whitespace() || c == '"' || c == '\\') {
+        format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+    } else {
+        s.to_string()
+    }
+}
+
+/// Writes units, reloads systemd, and enables them (starting unless `start` is false).
+pub fn install(params: &DaemonUnitParams<'_>, start: bool) -> Result<InstallResult> {
+    check_systemctl()?;
+    let dir = unit_dir()?;
+    fs::create_dir_all(&dir).with_context(|| format!("Failed to create {}", dir.display()))?;
+
+    let mut units = Vec::new();
+    if params.with_bridge {
+        let path = dir.join(bridge_unit_name(params.team));
+        fs::write(&path, render_bridge_unit(params.team, params.exe, params.path_env))
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+        units.push(path);
+    }
+    let path = dir.join(daemon_unit_name(params.team));
+    fs::write(&path, render_daemon_unit(params))
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+    units.push(path);
+
+    systemctl(&["daemon-reload"])?;
+    let names: Vec<String> = units
+        .iter()
+        .filter_map(|p| p.file_name().map(|n| n.to_string_lossy().to_string()))
+        .collect();
+    let mut args = vec!["enable"];
+    if start {
+        args.push("--now");
+    }
+    args.extend(names.iter().map(String::as_str));
+    systemctl(&args)?;
+
+    Ok(InstallResult {
+        units,
+        started: start,
+        linger: linger_enabled(),
+    })
+}
+
+/// Disables, stops, and removes the team's units. Returns the removed unit names.
+pub fn uninstall(team: &str) -> Result<Vec<String>> {
+    check_systemctl()?;
+    let dir = unit_dir()?;
+    let mut removed = Vec::new();
+    for name in [daemon_unit_name(team), bridge_unit_name(team)] {
+        let path = dir.join(&name);
+        if !path.exists() {
+            continue;
+        }
+        // Best-effort: the unit may already be stopped or disabled.
+        let _ = systemctl(&["disable", "--now", &name]);
+        fs::remove_file(&path).with_context(|| format!("Failed to remove {}", path.display()))?;
+        removed.push(name);
+    }
+    if !removed.is_empty() {
+        systemctl(&["daemon-reload"])?;
+    }
+    Ok(removed)
+}
+
+/// Runs `systemctl --user <args>` and returns stdout.
+pub fn systemctl(args: &[&str]) -> Result<String> {
+    let output = Command::new("systemctl")
+        .arg("--user")
+        .args(args)
+        .output()
+        .with_context(|| format!("Failed to run systemctl --user {}", args.join(" ")))?;
+    if !output.status.success() {
+        bail!(
+            "systemctl --user {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+/// Queries a unit's runtime state.
+pub fn unit_state(unit: &str) -> Result<UnitState> {
+    let out = systemctl(&[
+        "show",
+        unit,
+        "--property=ActiveState,SubState,MainPID,ActiveEnterTimestamp",
+    ])?;
+    Ok(parse_show(&out))
+}
+
+/// Parses `systemctl show` key=value output.
+fn parse_show(output: &str) -> UnitState {
+    let mut state = UnitState::default();
+    for line in output.lines() {
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        match key {
+            "ActiveState" => state.active_state = value.to_string(),
+            "SubState" => state.sub_state = value.to_string(),
+            "MainPID" => state.main_pid = value.parse().ok().filter(|p| *p != 0),
+            "ActiveEnterTimestamp" if !value.is_empty() => {
+                state.active_since = Some(value.to_string())
+            }
+            _ => {}
+        }
+    }
+    state
+}
+
+/// Returns the last `lines` journald entries for a user unit.
+pub fn journal(unit: &str, lines: usize) -> Result<String> {
+    let output = Command::new("journalctl")
+        .args(["--user", "-u", unit, "-n", &lines.to_string(), "--no-pager", "-o", "short-iso"])
+        .output()
+        .context("Failed to run journalctl")?;
+    if !output.status.success() {
+        bail!(
+            "journalctl --user -u {} failed: {}",
+            unit,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+/// True when the user has lingering enabled (user units run without a login session).
+pub fn linger_enabled() -> bool {
+    match std::env::var("USER") {
+        Ok(user) => Path::new("/var/lib/systemd/linger").join(user).exists(),
+        Err(_) => false,
+    }
+}
+
+fn check_systemctl() -> Result<()> {
+    if which::which("systemctl").is_err() {
+        bail!("'systemctl' not found in PATH. systemd user units require a systemd-based Linux host.");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params<'a>(exe: &'a Path, with_bridge: bool) -> DaemonUnitParams<'a> {
+        DaemonUnitParams {
+            team: "my-team",
+            exe,
+            mode: "poll",
+            port: 8484,
+            interval: 60,
+            bind: "127.0.0.1",
+            path_env: Some("/usr/local/bin:/usr/bin"),
+            with_bridge,
+        }
+    }
+
+    #[test]
+    fn daemon_unit_runs_daemon_run_in_foreground() {
+        let unit = render_daemon_unit(&params(Path::new("/usr/bin/bm"), false));
+        assert!(unit.contains(
+            "ExecStart=/usr/bin/bm daemon-run --team my-team --mode poll --port 8484 \
+             --interval 60 --bind 127.0.0.1 --start-members"
+        ));
+        assert!(unit.contains("Type=simple"));
+        assert!(unit.contains("Restart=on-failure"));
+        assert!(unit.contains("KillMode=process"));
+        assert!(unit.contains("Environment=PATH=/usr/local/bin:/usr/bin"));
+        assert!(unit.contains("WantedBy=default.target"));
+        assert!(!unit.contains("botminter-bridge"));
+    }
+
+    #[test]
+    fn daemon_unit_orders_after_bridge() {
+        let unit = render_daemon_unit(&params(Path::new("/usr/bin/bm"), true));
+        assert!(unit.contains("Wants=botminter-bridge-my-team.service"));
+        assert!(unit.contains("After=botminter-bridge-my-team.service"));
+    }
+
+    #[test]
+    fn bridge_unit_wraps_bridge_start_stop() {
+        let unit = render_bridge_unit("my-team", Path::new("/usr/bin/bm"), None);
+        assert!(unit.contains("Type=oneshot"));
+        assert!(unit.contains("RemainAfterExit=yes"));
+        assert!(unit.contains("ExecStart=/usr/bin/bm bridge start -t my-team"));
+        assert!(unit.contains("ExecStop=/usr/bin/bm bridge stop -t my-team"));
+        assert!(!unit.contains("Environment="));
+    }
+
+    #[test]
+    fn exe_with_spaces_is_quoted() {
+        let unit = render_daemon_unit(&params(Path::new("/opt/my tools/bm"), false));
+        assert!(unit.contains("ExecStart=\"/opt/my tools/bm\" daemon-run"));
+    }
+
+    #[test]
+    fn unit_names() {
+        assert_eq!(daemon_unit_name("alpha"), "botminter-daemon-alpha.service");
+        assert_eq!(bridge_unit_name("alpha"), "botminter-bridge-alpha.service");
+    }
+
+    #[test]
+    fn parse_show_active_unit() {
+        let state = parse_show(
+            "ActiveState=active\nSubState=running\nMainPID=4242\n\
+             ActiveEnterTimestamp=Mon 2026-10-19 09:00:00 UTC\n",
+        );
+        assert!(state.is_active());
+        assert_eq!(state.sub_state, "running");
+        assert_eq!(state.main_pid, Some(4242));
+        assert_eq!(state.active_since.as_deref(), Some("Mon 2026-10-19 09:00:00 UTC"));
+    }
+
+    #[test]
+    fn parse_show_inactive_unit() {
+        let state = parse_show("ActiveState=inactive\nSubState=dead\nMainPID=0\nActiveEnterTimestamp=\n");
+        assert!(!state.is_active());
+        assert_eq!(state.main_pid, None);
+        assert_eq!(state.active_since, None);
+    }
+}
@@ -0,0 +1,359 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::config::DaemonPaths;
+use super::log::daemon_log;
+use crate::bridge;
+use crate::config;
+use crate::state;
+
+/// Alert when a token is this close to expiry and its refresh is failing.
+/// The refresh loop runs at the start of this window, so a healthy token
+/// passes through it briefly without a failure recorded.
+pub const ALERT_WINDOW_MINS: i64 = 10;
+
+/// Lifetime of a GitHub App installation token.
+pub const INSTALLATION_TOKEN_TTL_MINS: i64 = 60;
+
+/// How often the alert loop checks recorded token health.
+const CHECK_INTERVAL_SECS: u64 = 60;
+
+/// Serializes read-modify-write of the health file across refresh loops.
+static FILE_LOCK: Mutex<()> = Mutex::new(());
+
+/// Token health of one member, recorded by the daemon's refresh loop.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
+pub struct TokenHealth {
+    /// Last successful token exchange (RFC 3339).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_refresh: Option<String>,
+    /// Expiry of the token the member currently holds (RFC 3339).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<String>,
+    /// Failed refresh attempts since the last success.
+    #[serde(default)]
+    pub consecutive_failures: u32,
+    /// Error of the most recent failed attempt.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_error: Option<String>,
+    /// `expires_at` an alert was already sent for, so each expiry alerts once.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alerted_for: Option<String>,
+}
+
+/// Overall token state derived from [`TokenHealth`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenState {
+    /// Refreshing normally.
+    Healthy,
+    /// Refreshes are failing, but the token is not close to expiry yet.
+    Failing,
+    /// Refreshes are failing and the token is within the alert window of expiry.
+    Expiring,
+    /// Past expiry — GitHub calls from the member return 401.
+    Expired,
+}
+
+impl TokenState {
+    pub fn label(&self) -> &'static str {
+        match self {
+            TokenState::Healthy => "healthy",
+            TokenState::Failing => "failing",
+            TokenState::Expiring => "expiring",
+            TokenState::Expired => "expired",
+        }
+    }
+}
+
+impl TokenHealth {
+    /// Parsed `expires_at`.
+    pub fn expiry(&self) -> Option<DateTime<Utc>> {
+        self.expires_at
+            .as_deref()
+            .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
+            .map(|dt| dt.with_timezone(&Utc))
+    }
+
+    /// Classifies the token at `now`.
+    pub fn state(&self, now: DateTime<Utc>) -> TokenState {
+        match self.expiry() {
+            Some(exp) if exp <= now => TokenState::Expired,
+            Some(exp)
+                if self.consecutive_failures > 0
+                    && exp - now <= Duration::minutes(ALERT_WINDOW_MINS) =>
+            {
+                TokenState::Expiring
+            }
+            _ if self.consecutive_failures > 0 => TokenState::Failing,
+            _ => TokenState::Healthy,
+        }
+    }
+
+    /// Whether an operator alert is due: the token is expiring or expired
+    /// and no alert was sent for this expiry yet.
+    pub fn needs_alert(&self, now: DateTime<Utc>) -> bool {
+        matches!(self.state(now), TokenState::Expiring | TokenState::Expired)
+            && self.alerted_for != self.expires_at
+    }
+}
+
+/// Per-member token health, keyed by member name.
+pub type TokenHealthMap = BTreeMap<String, TokenHealth>;
+
+/// Token health file: `<config_dir>/daemon-<team>-tokens.json`.
+pub fn token_health_path(config_dir: &Path, team_name: &str) -> PathBuf {
+    config_dir.join(format!("daemon-{}-tokens.json", team_name))
+}
+
+/// Loads token health. Returns an empty map if the file is missing or corrupt.
+pub fn load_token_health(path: &Path) -> TokenHealthMap {
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+/// Saves token health atomically. Silently ignores write errors.
+fn save(path: &Path, health: &TokenHealthMap) {
+    if let Ok(contents) = serde_json::to_string_pretty(health) {
+        let tmp_path = path.with_extension("json.tmp");
+        if fs::write(&tmp_path, contents).is_ok() {
+            let _ = fs::rename(&tmp_path, path);
+        }
+    }
+}
+
+/// Applies `f` to a member's record under the file lock.
+fn update(path: &Path, member: &str, f: impl FnOnce(&mut TokenHealth)) {
+    let _guard = FILE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let mut health = load_token_health(path);
+    f(health.entry(member.to_string()).or_default());
+    save(path, &health);
+}
+
+/// Records a successful token exchange.
+pub fn record_success(path: &Path, member: &str, expires_at: DateTime<Utc>) {
+    update(path, member, |h| {
+        h.last_refresh = Some(Utc::now().to_rfc3339());
+        h.expires_at = Some(expires_at.to_rfc3339());
+        h.consecutive_failures = 0;
+        h.last_error = None;
+    });
+}
+
+/// Records a failed refresh attempt.
+pub fn record_failure(path: &Path, member: &str, error: &str) {
+    update(path, member, |h| {
+        h.consecutive_failures += 1;
+        h.last_error = Some(error.to_string());
+    });
+}
+
+/// Drops a member's record (the member was stopped).
+pub fn forget(path: &Path, member: &str) {
+    let _guard = FILE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let mut health = load_token_health(path);
+    if health.remove(member).is_some() {
+        save(path, &health);
+    }
+}
+
+/// Marks the current expiry of a member's token as alerted.
+fn mark_alerted(path: &Path, member: &str) {
+    update(path, member, |h| h.alerted_for = h.expires_at.clone());
+}
+
+/// Operator-facing alert text for a member's token.
+fn alert_message(
+    team_name: &str,
+    member: &str,
+    health: &TokenHealth,
+    now: DateTime<Utc>,
+) -> String {
+    let when = match health.expiry() {
+        Some(exp) if exp <= now => "has expired".to_string(),
+        Some(exp) => format!("expires in {} min", (exp - now).num_minutes().max(0)),
+        None => "is about to expire".to_string(),
+    };
+    let mut msg = format!(
+        "[botminter] GitHub token for {}/{} {} and has not been refreshed.",
+        team_name, member, when
+    );
+    if let Some(err) = &health.last_error {
+        msg.push_str(&format!(
+            " Last error ({} failed attempts): {}",
+            health.consecutive_failures, err
+        ));
+    }
+    msg.push_str(&format!(" Check `bm status -t {}`.", team_name));
+    msg
+}
+
+/// Posts an alert to the team's bridge room. Returns false if the team has
+/// no bridge, no room, or a bridge without a send recipe.
+pub(super) fn send_bridge_alert(team_name: &str, message: &str) -> anyhow::Result<bool> {
+    let cfg = config::load()?;
+    let team = config::resolve_team(&cfg, Some(team_name))?;
+    let team_repo = team.path.join("team");
+    let Some(bridge_dir) = bridge::discover(&team_repo, team_name)? else {
+        return Ok(false);
+    };
+    let state_path = bridge::state_path(&cfg.workzone, team_name);
+    let b = bridge::Bridge::new(bridge_dir, state_path, team_name.to_string())?;
+    let Some(room_id) = b.default_room_id() else {
+        return Ok(false);
+    };
+    b.send_message(room_id, message)
+}
+
+/// Background task: alerts the operator through the bridge when a running
+/// member's token is within [`ALERT_WINDOW_MINS`] of expiry without a
+/// successful refresh.
+pub(super) async fn run_token_alert_loop(
+    team_name: &str,
+    paths: &DaemonPaths,
+    shutdown: &Arc<AtomicBool>,
+) {
+    let mut ticker = tokio::time::interval(tokio::time::Duration::from_secs(CHECK_INTERVAL_SECS));
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
+    loop {
+        ticker.tick().await;
+        if shutdown.load(Ordering::SeqCst) {
+            break;
+        }
+
+        let team = team_name.to_string();
+        let tick_paths = paths.clone();
+        let result = tokio::task::spawn_blocking(move || check_and_alert(&team, &tick_paths)).await;
+        if let Err(e) = result {
+            daemon_log(
+                paths,
+                "ERROR",
+                &format!("Token alert check panicked: {}", e),
+            );
+        }
+    }
+}
+
+fn check_and_alert(team_name: &str, paths: &DaemonPaths) {
+    let health_path = paths.token_health();
+    let now = Utc::now();
+    let runtime_state = state::load().unwrap_or_default();
+
+    for (member, health) in load_token_health(&health_path) {
+        if !health.needs_alert(now) {
+            continue;
+        }
+        let alive = runtime_state
+            .members
+            .get(&format!("{}/{}", team_name, member))
+            .is_some_and(|rt| state::is_alive(rt.pid));
+        if !alive {
+            continue;
+        }
+
+        let message = alert_message(team_name, &member, &health, now);
+        daemon_log(paths, "WARN", &message);
+        match send_bridge_alert(team_name, &message) {
+            Ok(true) => {}
+            Ok(false) => daemon_log(
+                paths,
+                "WARN",
+                "No bridge room with a send recipe — token alert written to the log only",
+            ),
+            Err(e) => daemon_log(
+                paths,
+                "ERROR",
+                &format!("Failed to send token alert through the bridge: {:#}", e),
+            ),
+        }
+        mark_alerted(&health_path, &member);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn health_expiring_in(mins: i64, failures: u32) -> TokenHealth {
+        TokenHealth {
+            last_refresh: None,
+            expires_at: Some((Utc::now() + Duration::minutes(mins)).to_rfc3339()),
+            consecutive_failures: failures,
+            last_error: None,
+            alerted_for: None,
+        }
+    }
+
+    #[test]
+    fn state_follows_expiry_and_failures() {
+        let now = Utc::now();
+        assert_eq!(health_expiring_in(45, 0).state(now), TokenState::Healthy);
+        assert_eq!(health_expiring_in(45, 2).state(now), TokenState::Failing);
+        assert_eq!(health_expiring_in(9, 2).state(now), TokenState::Expiring);
+        // Refresh due but not attempted yet: no alert for a healthy token
+        assert_eq!(health_expiring_in(9, 0).state(now), TokenState::Healthy);
+        assert!(!health_expiring_in(9, 0).needs_alert(now));
+        assert_eq!(health_expiring_in(-1, 2).state(now), TokenState::Expired);
+        assert_eq!(TokenHealth::default().state(now), TokenState::Healthy);
+    }
+
+    #[test]
+    fn alert_fires_once_per_expiry() {
+        let now = Utc::now();
+        let mut health = health_expiring_in(5, 3);
+        assert!(health.needs_alert(now));
+
+        health.alerted_for = health.expires_at.clone();
+        assert!(!health.needs_alert(now));
+
+        // A new token (new expiry) re-arms the alert.
+        health.expires_at = Some((now + Duration::minutes(8)).to_rfc3339());
+        assert!(health.needs_alert(now));
+
+        assert!(!health_expiring_in(30, 3).needs_alert(now));
+    }
+
+    #[test]
+    fn success_resets_failures() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("tokens.json");
+
+        record_failure(&path, "dev-alice", "502 Bad Gateway");
+        record_failure(&path, "dev-alice", "502 Bad Gateway");
+        let health = load_token_health(&path);
+        assert_eq!(health["dev-alice"].consecutive_failures, 2);
+        assert_eq!(
+            health["dev-alice"].last_error.as_deref(),
+            Some("502 Bad Gateway")
+        );
+
+        let expires = Utc::now() + Duration::minutes(INSTALLATION_TOKEN_TTL_MINS);
+        record_success(&path, "dev-alice", expires);
+        let health = load_token_health(&path);
+        assert_eq!(health["dev-alice"].consecutive_failures, 0);
+        assert!(health["dev-alice"].last_error.is_none());
+        assert!(health["dev-alice"].last_refresh.is_some());
+
+        forget(&path, "dev-alice");
+        assert!(load_token_health(&path).is_empty());
+    }
+
+    #[test]
+    fn alert_message_names_member_and_error() {
+        let now = Utc::now();
+        let mut health = health_expiring_in(-2, 4);
+        health.last_error = Some("401 Bad credentials".to_string());
+        let msg = alert_message("my-team", "dev-alice", &health, now);
+        assert!(msg.contains("my-team/dev-alice has expired"));
+        assert!(msg.contains("4 failed attempts"));
+        assert!(msg.contains("401 Bad credentials"));
+    }
+}
@@ -0,0 +1,395 @@
+use std::path::Path;
+use std::process::Command;
+
+use anyhow::{bail, Context, Result};
+
+use super::{split_repo, Forge, Issue, StatusTracking, WEBHOOK_EVENTS};
+use crate::config::ForgeKind;
+use crate::git::{self, GitHubHost};
+use crate::profile::{LabelDef, StatusDef};
+use crate::workspace::{GhRemoteOps, RemoteRepoOps, RemoteRepoState};
+
+/// GitHub, driven through the `gh` CLI.
+pub struct GitHubForge {
+    repos: GhRemoteOps,
+    /// The team's Projects v2 board, used for status.
+    project_number: Option<u64>,
+}
+
+impl GitHubForge {
+    pub fn new(host: GitHubHost, token: &str, project_number: Option<u64>) -> Self {
+        Self {
+            repos: GhRemoteOps {
+                gh_token: token.to_string(),
+                host,
+            },
+            project_number,
+        }
+    }
+
+    /// A `gh` command pointed at the forge's host with its token.
+    fn command(&self, args: &[&str]) -> Command {
+        let mut cmd = Command::new("gh");
+        cmd.args(args);
+        self.repos.host.apply(&mut cmd, &self.repos.gh_token);
+        cmd
+    }
+
+    /// Runs `gh` with the forge's token and returns trimmed stdout.
+    fn gh(&self, args: &[&str]) -> Result<String> {
+        let output = self
+            .command(args)
+            .output()
+            .with_context(|| {
+                format!("Failed to run `gh {}`", args[..2.min(args.len())].join(" "))
+            })?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            bail!(
+                "gh {} failed: {}",
+                args[..2.min(args.len())].join(" "),
+                stderr.trim()
+            );
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    }
+
+    fn gh_json(&self, args: &[&str]) -> Result<serde_json::Value> {
+        let out = self.gh(args)?;
+        serde_json::from_str(&out)
+            .with_context(|| format!("Could not parse `gh {}` output", args[..2].join(" ")))
+    }
+
+    /// Finds the board item for an issue, adding the issue to the board if needed.
+    fn project_item_id(&self, owner: &str, number_str: &str, issue: &Issue) -> Result<String> {
+        let items = self.gh_json(&[
+            "project",
+            "item-list",
+            number_str,
+            "--owner",
+            owner,
+            "--limit",
+            "1000",
+            "--format",
+            "json",
+        ])?;
+        let existing = items["items"].as_array().and_then(|items| {
+            items.iter().find_map(|item| {
+                (item["content"]["url"].as_str() == Some(issue.url.as_str()))
+                    .then(|| item["id"].as_str().map(String::from))
+                    .flatten()
+            })
+        });
+        if let Some(id) = existing {
+            return Ok(id);
+        }
+        let added = self.gh_json(&[
+            "project", "item-add", number_str, "--owner", owner, "--url", &issue.url, "--format",
+            "json",
+        ])?;
+        added["id"]
+            .as_str()
+            .map(String::from)
+            .context("`gh project item-add` returned no item id")
+    }
+
+    fn view_issue(&self, repo: &str, number: u64) -> Result<Issue> {
+        let json = self.gh_json(&[
+            "issue",
+            "view",
+            &number.to_string(),
+            "--repo",
+            repo,
+            "--json",
+            "number,title,state,labels,url",
+        ])?;
+        Ok(parse_issue(&json))
+    }
+}
+
+fn parse_issue(json: &serde_json::Value) -> Issue {
+    Issue {
+        number: json["number"].as_u64().unwrap_or_default(),
+        title: json["title"].as_str().unwrap_or_default().to_string(),
+        state: json["state"].as_str().unwrap_or_default().to_lowercase(),
+        labels: json["labels"]
+            .as_array()
+            .map(|labels| {
+                labels
+                    .iter()
+                    .filter_map(|l| l["name"].as_str().map(String::from))
+                    .collect()
+            })
+            .unwrap_or_default(),
+        url: json["url"].as_str().unwrap_or_default().to_string(),
+    }
+}
+
+impl RemoteRepoOps for GitHubForge {
+    fn repo_state(&self, repo_name: &str) -> Result<RemoteRepoState> {
+        self.repos.repo_state(repo_name)
+    }
+
+    fn create_repo(&self, repo_name: &str) -> Result<()> {
+        self.repos.create_repo(repo_name)
+    }
+
+    fn delete_repo(&self, repo_name: &str) -> Result<()> {
+        self.repos.delete_repo(repo_name)
+    }
+
+    fn clone_repo(&self, repo_name: &str, target: &Path, recursive: bool) -> Result<()> {
+        self.repos.clone_repo(repo_name, target, recursive)
+    }
+
+    fn push_repo(&self, local_path: &Path) -> Result<()> {
+        self.repos.push_repo(local_path)
+    }
+
+    fn clone_url(&self, repo_name: &str) -> String {
+        self.repos.clone_url(repo_name)
+    }
+}
+
+impl Forge for GitHubForge {
+    fn kind(&self) -> ForgeKind {
+        ForgeKind::GitHub
+    }
+
+    fn current_user(&self) -> Result<String> {
+        self.gh(&["api", "user", "--jq", ".login"])
+    }
+
+    fn ensure_labels(&self, repo: &str, labels: &[LabelDef]) -> Result<()> {
+        for label in labels {
+            self.gh(&[
+                "label",
+                "create",
+                &label.name,
+                "--color",
+                &label.color,
+                "--description",
+                &label.description,
+                "--force",
+                "--repo",
+                repo,
+            ])
+            .with_context(|| format!("Failed to create label '{}'", label.name))?;
+        }
+        Ok(())
+    }
+
+    fn create_issue(&self, repo: &str, title: &str, body: &str, labels: &[&str]) -> Result<Issue> {
+        let mut args = vec![
+            "issue", "create", "--repo", repo, "--title", title, "--body", body,
+        ];
+        for label in labels {
+            args.extend(["--label", *label]);
+        }
+        let url = self.gh(&args)?;
+        let number = url
+            .rsplit('/')
+            .next()
+            .and_then(|n| n.parse().ok())
+            .with_context(|| format!("Unexpected `gh issue create` output: {}", url))?;
+        self.view_issue(repo, number)
+    }
+
+    fn list_issues(&self, repo: &str, label: Option<&str>) -> Result<Vec<Issue>> {
+        let mut args = vec![
+            "issue",
+            "list",
+            "--repo",
+            repo,
+            "--state",
+            "open",
+            "--limit",
+            "1000",
+            "--json",
+            "number,title,state,labels,url",
+        ];
+        if let Some(label) = label {
+            args.extend(["--label", label]);
+        }
+        let json = self.gh_json(&args)?;
+        Ok(json
+            .as_array()
+            .map(|issues| issues.iter().map(parse_issue).collect())
+            .unwrap_or_default())
+    }
+
+    fn comment_on_issue(&self, repo: &str, number: u64, body: &str) -> Result<()> {
+        self.gh(&[
+            "issue",
+            "comment",
+            &number.to_string(),
+            "--repo",
+            repo,
+            "--body",
+            body,
+        ])?;
+        Ok(())
+    }
+
+    fn setup_status_tracking(
+        &self,
+        repo: &str,
+        board_title: &str,
+        statuses: &[StatusDef],
+    ) -> Result<StatusTracking> {
+        let (owner, _) = split_repo(repo)?;
+        let host = &self.repos.host;
+        let projects = git::list_projects(host, owner)?;
+        let number = match projects.iter().find(|(_, t)| t == board_title) {
+            Some((number, _)) => {
+                git::sync_project_status_field(host, owner, *number, statuses)?;
+                *number
+            }
+            None => git::create_project(host, owner, board_title, statuses)?,
+        };
+        Ok(StatusTracking::ProjectBoard { number })
+    }
+
+    fn set_status(&self, repo: &str, number: u64, status: &str) -> Result<()> {
+        let (owner, _) = split_repo(repo)?;
+        let Some(project_number) = self.project_number else {
+            bail!("Team has no project board. Run `bm projects sync` first.");
+        };
+        let project_str = project_number.to_string();
+        let issue = self.view_issue(repo, number)?;
+        let item_id = self.project_item_id(owner, &project_str, &issue)?;
+
+        let project = self.gh_json(&[
+            "project",
+            "view",
+            &project_str,
+            "--owner",
+            owner,
+            "--format",
+            "json",
+        ])?;
+        let project_id = project["id"]
+            .as_str()
+            .context("Could not find project id")?
+            .to_string();
+
+        let fields = self.gh_json(&[
+            "project",
+            "field-list",
+            &project_str,
+            "--owner",
+            owner,
+            "--format",
+            "json",
+        ])?;
+        let status_field = fields["fields"]
+            .as_array()
+            .and_then(|f| f.iter().find(|f| f["name"].as_str() == Some("Status")))
+            .context("Could not find Status field in project")?;
+        let field_id = status_field["id"].as_str().unwrap_or_default().to_string();
+        let option_id = status_field["options"]
+            .as_array()
+            .and_then(|opts| {
+                opts.iter()
+                    .find(|o| o["name"].as_str() == Some(status))
+                    .and_then(|o| o["id"].as_str())
+            })
+            .with_context(|| format!("Status '{}' is not an option on the board", status))?
+            .to_string();
+
+        self.gh(&[
+            "project",
+            "item-edit",
+            "--id",
+            &item_id,
+            "--project-id",
+            &project_id,
+            "--field-id",
+            &field_id,
+            "--single-select-option-id",
+            &option_id,
+        ])?;
+        Ok(())
+    }
+
+    fn ensure_webhook(&self, repo: &str, url: &str, secret: Option<&str>) -> Result<()> {
+        let hooks = self.gh_json(&["api", &format!("repos/{}/hooks", repo)])?;
+        let existing = hooks.as_array().and_then(|hooks| {
+            hooks
+                .iter()
+                .find(|h| h["config"]["url"].as_str() == Some(url))
+                .and_then(|h| h["id"].as_u64())
+        });
+
+        let mut body = serde_json::json!({
+            "active": true,
+            "events": WEBHOOK_EVENTS,
+            "config": { "url": url, "content_type": "json" },
+        });
+        if let Some(secret) = secret {
+            body["config"]["secret"] = secret.into();
+        }
+        let (method, path) = match existing {
+            Some(id) => ("PATCH", format!("repos/{}/hooks/{}", repo, id)),
+            None => {
+                body["name"] = "web".into();
+                ("POST", format!("repos/{}/hooks", repo))
+            }
+        };
+
+        let mut child = self
+            .command(&["api", "-X", method, &path, "--input", "-"])
+            .stdin(std::process::Stdio::piped())
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::piped())
+            .spawn()
+            .context("Failed to run `gh api`")?;
+        if let Some(mut stdin) = child.stdin.take() {
+            use std::io::Write as _;
+            stdin.write_all(body.to_string().as_bytes())?;
+        }
+        let output = child.wait_with_output()?;
+        if !output.status.success() {
+            bail!(
+                "Failed to configure webhook on {}: {}",
+                repo,
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+        Ok(())
+    }
+
+    fn create_pull_request(
+        &self,
+        repo: &str,
+        head: &str,
+        base: &str,
+        title: &str,
+        body: &str,
+    ) -> Result<String> {
+        self.gh(&[
+            "pr", "create", "--repo", repo, "--head", head, "--base", base, "--title", title,
+            "--body", body,
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_issue_reads_gh_json() {
+        let json = serde_json::json!({
+            "number": 7,
+            "title": "Add login",
+            "state": "OPEN",
+            "labels": [{"name": "kind/story"}],
+            "url": "https://github.com/org/team/issues/7",
+        });
+        let issue = parse_issue(&json);
+        assert_eq!(issue.number, 7);
+        assert_eq!(issue.state, "open");
+        assert_eq!(issue.labels, vec!["kind/story"]);
+    }
+}
@@ -0,0 +1,274 @@
+//! Forge abstraction: the repo, issue, label, status and webhook operations
+//! botminter needs from wherever a team's repos are hosted.
+//!
+//! [`Forge`] extends [`RemoteRepoOps`] (workspace repo lifecycle) with the
+//! operations `bm init`, `bm daemon start` and knowledge proposals perform
+//! against a team repo. Callers get one from [`for_team`] (or
+//! [`for_workspace`] inside a member workspace). Two implementations exist:
+//!
+//! - [`GitHubForge`] — `gh` CLI; status lives on a Projects v2 board, member
+//!   identities are GitHub Apps.
+//! - [`GiteaForge`] — Gitea/Forgejo REST API; status lives in `status/<name>`
+//!   labels, member identities are bot users with personal access tokens.
+
+mod gitea;
+mod github;
+
+use std::fs;
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+use serde::Serialize;
+
+use crate::config::{ForgeConfig, ForgeKind, TeamEntry};
+use crate::git::GitHubHost;
+use crate::profile::{LabelDef, StatusDef};
+use crate::workspace::RemoteRepoOps;
+
+pub use gitea::{deliver_bot_token, workspace_bot_token, ActivityEntry, GiteaForge};
+pub use github::GitHubForge;
+
+/// Prefix of the labels that carry an issue's status on label-based forges.
+pub const STATUS_LABEL_PREFIX: &str = "status/";
+
+/// Repo webhook events botminter subscribes to on GitHub. `projects_v2_item`
+/// is an organization-level event and can't be added to a repo webhook.
+pub const WEBHOOK_EVENTS: &[&str] = &[
+    "issues",
+    "issue_comment",
+    "pull_request",
+    "pull_request_review",
+    "pull_request_review_comment",
+    "check_suite",
+    "check_run",
+    "workflow_run",
+];
+
+/// Webhook events botminter subscribes to on Gitea/Forgejo.
+pub const GITEA_WEBHOOK_EVENTS: &[&str] = &["issues", "issue_comment", "pull_request"];
+
+/// An issue (or pull request) on a forge.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Issue {
+    pub number: u64,
+    pub title: String,
+    /// `open` or `closed`.
+    pub state: String,
+    pub labels: Vec<String>,
+    pub url: String,
+}
+
+impl Issue {
+    /// The status recorded in a `status/<name>` label, if any.
+    pub fn label_status(&self) -> Option<&str> {
+        self.labels
+            .iter()
+            .find_map(|l| l.strip_prefix(STATUS_LABEL_PREFIX))
+    }
+}
+
+/// How a team's issue status is tracked.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StatusTracking {
+    /// GitHub Projects v2 board with a Status single-select field.
+    ProjectBoard { number: u64 },
+    /// One `status/<name>` label per status.
+    Labels,
+}
+
+/// Operations on a team's forge.
+pub trait Forge: RemoteRepoOps {
+    fn kind(&self) -> ForgeKind;
+    /// Login of the user (or bot) owning the token.
+    fn current_user(&self) -> Result<String>;
+    /// Creates missing labels and updates existing ones. Idempotent.
+    fn ensure_labels(&self, repo: &str, labels: &[LabelDef]) -> Result<()>;
+    /// Opens an issue with the given labels.
+    fn create_issue(&self, repo: &str, title: &str, body: &str, labels: &[&str]) -> Result<Issue>;
+    /// Lists open issues, optionally only those carrying `label`.
+    fn list_issues(&self, repo: &str, label: Option<&str>) -> Result<Vec<Issue>>;
+    fn comment_on_issue(&self, repo: &str, number: u64, body: &str) -> Result<()>;
+    /// Prepares status tracking for `statuses`. `board_title` names the
+    /// project board on forges that use one.
+    fn setup_status_tracking(
+        &self,
+        repo: &str,
+        board_title: &str,
+        statuses: &[StatusDef],
+    ) -> Result<StatusTracking>;
+    /// Moves an issue to `status`.
+    fn set_status(&self, repo: &str, number: u64, status: &str) -> Result<()>;
+    /// Points a repo webhook at `url`, creating or updating it.
+    fn ensure_webhook(&self, repo: &str, url: &str, secret: Option<&str>) -> Result<()>;
+    /// Opens a pull request from `head` into `base`. Returns its URL.
+    fn create_pull_request(
+        &self,
+        repo: &str,
+        head: &str,
+        base: &str,
+        title: &str,
+        body: &str,
+    ) -> Result<String>;
+}
+
+/// Builds the forge for a team with the operator's token.
+pub fn for_team(team: &TeamEntry, token: &str) -> Result<Box<dyn Forge>> {
+    for_config(
+        team.forge.as_ref(),
+        GitHubHost::for_team(team),
+        token,
+        team.project_number,
+    )
+}
+
+/// Builds a forge from its config; `host` is used when it is GitHub.
+pub fn for_config(
+    forge: Option<&ForgeConfig>,
+    host: GitHubHost,
+    token: &str,
+    project_number: Option<u64>,
+) -> Result<Box<dyn Forge>> {
+    Ok(match forge {
+        Some(cfg) if cfg.kind == ForgeKind::Gitea => Box::new(GiteaForge::new(&cfg.url, token)?),
+        _ => Box::new(GitHubForge::new(host, token, project_number)),
+    })
+}
+
+/// Builds the forge for an agent running in a member workspace, acting as
+/// the member: its bot token on Gitea/Forgejo, its `GH_TOKEN` on GitHub.
+pub fn for_workspace(team: &TeamEntry, workspace: &Path) -> Result<Box<dyn Forge>> {
+    let token = match &team.forge {
+        Some(cfg) if cfg.kind == ForgeKind::Gitea => workspace_bot_token(workspace)
+            .with_context(|| {
+                format!(
+                    "No bot token for {} in this workspace. Run `bm teams sync` to deliver it.",
+                    cfg.url
+                )
+            })?,
+        _ => require_token(None)?,
+    };
+    for_team(team, &token)
+}
+
+/// Finds the operator's token for a team's forge.
+///
+/// GitHub: `GH_TOKEN` or `gh auth token`; on Enterprise Server hosts
+/// `GH_ENTERPRISE_TOKEN` or the `gh` login for the host. Gitea/Forgejo:
+/// `GITEA_TOKEN`, `FORGEJO_TOKEN`, or the `tea` CLI login for the forge's URL.
+pub fn detect_token(forge: Option<&ForgeConfig>) -> Option<String> {
+    match forge {
+        Some(cfg) if cfg.kind == ForgeKind::Gitea => ["GITEA_TOKEN", "FORGEJO_TOKEN"]
+            .iter()
+            .find_map(|var| std::env::var(var).ok().filter(|t| !t.is_empty()))
+            .or_else(|| {
+                let tea_config = dirs::config_dir()?.join("tea").join("config.yml");
+                tea_login_token(&tea_config, &cfg.url)
+            }),
+        Some(cfg) => GitHubHost::new(Some(&cfg.url), cfg.api_base.as_deref()).detect_token(),
+        None => crate::git::detect_token(),
+    }
+}
+
+/// Like [`detect_token`], but fails with setup guidance.
+pub fn require_token(forge: Option<&ForgeConfig>) -> Result<String> {
+    if let Some(token) = detect_token(forge) {
+        return Ok(token);
+    }
+    match forge {
+        Some(cfg) if cfg.kind == ForgeKind::Gitea => bail!(
+            "No token found for {}.\n\
+             Set GITEA_TOKEN (or FORGEJO_TOKEN), or run `tea login add --url {}`.",
+            cfg.url,
+            cfg.url
+        ),
+        Some(cfg) => GitHubHost::new(Some(&cfg.url), cfg.api_base.as_deref()).require_token(),
+        None => bail!("No GitHub token found. Set GH_TOKEN or run `gh auth login`."),
+    }
+}
+
+/// Reads the token of the `tea` login whose URL matches `url`.
+fn tea_login_token(config_path: &Path, url: &str) -> Option<String> {
+    let contents = fs::read_to_string(config_path).ok()?;
+    let config: serde_yml::Value = serde_yml::from_str(&contents).ok()?;
+    let wanted = url.trim_end_matches('/');
+    config["logins"].as_sequence()?.iter().find_map(|login| {
+        let login_url = login["url"].as_str()?.trim_end_matches('/');
+        if login_url == wanted {
+            login["token"].as_str().map(String::from)
+        } else {
+            None
+        }
+    })
+}
+
+/// Validates a forge URL given to `bm init --forge-url`.
+pub fn normalize_forge_url(url: &str) -> Result<String> {
+    let url = url.trim().trim_end_matches('/');
+    if !(url.starts_with("https://") || url.starts_with("http://")) {
+        bail!("Forge URL must start with https:// (got '{}')", url);
+    }
+    reqwest::Url::parse(url).with_context(|| format!("Invalid forge URL '{}'", url))?;
+    Ok(url.to_string())
+}
+
+/// Splits `owner/name`.
+fn split_repo(repo: &str) -> Result<(&str, &str)> {
+    match repo.split_once('/') {
+        Some((owner, name)) if !owner.is_empty() && !name.is_empty() && !name.contains('/') => {
+            Ok((owner, name))
+        }
+        _ => bail!("Invalid repo '{}': expected owner/name", repo),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn label_status_reads_prefixed_label() {
+        let issue = Issue {
+            number: 1,
+            title: "t".into(),
+            state: "open".into(),
+            labels: vec!["kind/story".into(), "status/dev:in-progress".into()],
+            url: String::new(),
+        };
+        assert_eq!(issue.label_status(), Some("dev:in-progress"));
+    }
+
+    #[test]
+    fn tea_login_token_matches_url() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("config.yml");
+        fs::write(
+            &path,
+            "logins:\n\
+             - name: other\n  url: https://other.example.com\n  token: nope\n\
+             - name: ours\n  url: https://forgejo.example.com/\n  token: abc123\n",
+        )
+        .unwrap();
+        assert_eq!(
+            tea_login_token(&path, "https://forgejo.example.com").as_deref(),
+            Some("abc123")
+        );
+        assert!(tea_login_token(&path, "https://missing.example.com").is_none());
+    }
+
+    #[test]
+    fn normalize_forge_url_validates_scheme() {
+        assert_eq!(
+            normalize_forge_url("https://forgejo.example.com/").unwrap(),
+            "https://forgejo.example.com"
+        );
+        assert!(normalize_forge_url("forgejo.example.com").is_err());
+    }
+
+    #[test]
+    fn split_repo_requires_owner_and_name() {
+        assert_eq!(split_repo("org/team").unwrap(), ("org", "team"));
+        for bad in ["org", "/team", "org/", "a/b/c"] {
+            assert!(split_repo(bad).is_err(), "{}", bad);
+        }
+    }
+}
@@ -34,11 +34,14 @@ pub fn setup_new_team_repo(
             )
         })?;
 
-    if let Some(base) = profiles_base {
-        profile::extract_profile_from(base, selected_profile, team_repo, coding_agent)?;
-    } else {
-        profile::extract_profile_to(selected_profile, team_repo, coding_agent)?;
-    }
+    let base = match profiles_base {
+        Some(base) => base.to_path_buf(),
+        None => profile::profiles_dir()?,
+    };
+    // Resolve conditional tags for everything known at init; the formation
+    // is resolved later, when workspaces are synced.
+    let tags = profile::profile_tag_context(&base, selected_profile, coding_agent, bridge);
+    profile::extract_profile_with(&base, selected_profile, team_repo, coding_agent, &tags)?;
 
     if !projects.is_empty() {
         profile::augment_manifest_with_projects(team_repo, projects)?;
@@ -58,11 +61,7 @@ pub fn setup_new_team_repo(
         let member_dir = team_repo.join("members").join(&member_dir_name);
         fs::create_dir_all(&member_dir)
             .with_context(|| format!("Failed to create member dir {}", member_dir.display()))?;
-        if let Some(base) = profiles_base {
-            profile::extract_member_from(base, selected_profile, role, &member_dir, coding_agent)?;
-        } else {
-            profile::extract_member_to(selected_profile, role, &member_dir, coding_agent)?;
-        }
+        profile::extract_member_with(&base, selected_profile, role, &member_dir, coding_agent, &tags)?;
         profile::finalize_member_manifest(&member_dir, name)?;
     }
 
@@ -103,6 +102,8 @@ pub fn register_team(
         project_number: None,
         bridge_lifecycle: Default::default(),
         vm: None,
+        profile_source: profile::installed_source(profile_name).map(|s| s.team_record()),
+        forge: None,
     };
     cfg.teams.push(team_entry);
 
@@ -217,6 +218,8 @@ mod tests {
             project_number: None,
             bridge_lifecycle: Default::default(),
         vm: None,
+        profile_source: None,
+        forge: None,
         });
         cfg.default_team = Some("my-team".to_string());
 
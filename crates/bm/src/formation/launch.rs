@@ -3,6 +3,9 @@ use std::process::Command;
 
 use anyhow::{Context, Result};
 
+use super::limits::{self, MemberLimits};
+use crate::git::GitHubHost;
+
 /// Launches `ralph run -p PROMPT.md` in the given workspace directory.
 /// Returns the child PID.
 ///
@@ -10,17 +13,27 @@ use anyhow::{Context, Result};
 /// This is used for members with GitHub App credentials: the daemon writes
 /// `hosts.yml` with the installation token, and `gh` reads from it.
 /// `GH_TOKEN` would override `hosts.yml`, so we must not set both.
+///
+/// `github_host` sets `GH_HOST` on GitHub Enterprise Server teams, so `gh`
+/// talks to the team's host whichever way it authenticates.
+///
+/// If `member_limits` is set, the process runs inside a cgroup scope and/or
+/// filesystem sandbox (see `formation::limits`), and the returned PID is
+/// that of ralph itself rather than the wrapper.
 pub fn launch_ralph(
     workspace: &std::path::Path,
     member_token: Option<&str>,
     bridge_type: Option<&str>,
     service_url: Option<&str>,
     gh_config_dir: Option<&std::path::Path>,
+    github_host: &GitHubHost,
+    member_limits: Option<&MemberLimits>,
 ) -> Result<u32> {
     let mut cmd = Command::new("ralph");
     cmd.args(["run", "-p", "PROMPT.md"])
         .current_dir(workspace)
         .env_remove("CLAUDECODE");
+    github_host.apply_host(&mut cmd);
 
     // App-credential members use GH_CONFIG_DIR (daemon-managed hosts.yml).
     // Members without App creds rely on the host's `gh auth` session.
@@ -29,6 +42,7 @@ pub fn launch_ralph(
         // Remove token env vars so gh uses the App token from hosts.yml
         // instead of the operator's PAT.
         cmd.env_remove("GH_TOKEN");
+        cmd.env_remove("GH_ENTERPRISE_TOKEN");
         cmd.env_remove("GITHUB_TOKEN");
     }
 
@@ -52,6 +66,11 @@ pub fn launch_ralph(
         }
     }
 
+    let mut cmd = match member_limits {
+        Some(l) => limits::confine(cmd, l, workspace)?,
+        None => cmd,
+    };
+
     // Detach stdio from current process
     cmd.stdin(std::process::Stdio::null())
         .stdout(std::process::Stdio::null())
@@ -61,7 +80,10 @@ pub fn launch_ralph(
         format!("Failed to spawn ralph in {}", workspace.display())
     })?;
 
-    Ok(child.id())
+    Ok(match member_limits {
+        Some(l) => limits::member_pid(child.id(), l, "ralph".as_ref()),
+        None => child.id(),
+    })
 }
 
 /// Configuration for launching a brain process, bundling bridge-related params.
@@ -77,6 +99,10 @@ pub struct BrainLaunchConfig<'a> {
     pub team_repo: Option<&'a std::path::Path>,
     /// When set, uses GH_CONFIG_DIR instead of GH_TOKEN (App credential path).
     pub gh_config_dir: Option<&'a std::path::Path>,
+    /// The team's GitHub host; sets `GH_HOST` on Enterprise Server teams.
+    pub github_host: &'a GitHubHost,
+    /// Resource limits and sandboxing for the brain process.
+    pub member_limits: Option<&'a MemberLimits>,
 }
 
 /// Launches the brain multiplexer for a chat-first member.
@@ -97,6 +123,7 @@ pub fn launch_brain(config: &BrainLaunchConfig<'_>) -> Result<u32> {
     .arg(config.system_prompt_path)
     .current_dir(config.workspace)
     .env_remove("CLAUDECODE");
+    config.github_host.apply_host(&mut cmd);
 
     // App-credential members use GH_CONFIG_DIR (daemon-managed hosts.yml).
     // Members without App creds rely on the host's `gh auth` session.
@@ -105,6 +132,7 @@ pub fn launch_brain(config: &BrainLaunchConfig<'_>) -> Result<u32> {
         // Remove token env vars so gh uses the App token from hosts.yml
         // instead of the operator's PAT. gh respects all of these.
         cmd.env_remove("GH_TOKEN");
+        cmd.env_remove("GH_ENTERPRISE_TOKEN");
         cmd.env_remove("GITHUB_TOKEN");
     }
 
@@ -143,6 +171,11 @@ pub fn launch_brain(config: &BrainLaunchConfig<'_>) -> Result<u32> {
         cmd.env("BM_TEAM_REPO", repo);
     }
 
+    let mut cmd = match config.member_limits {
+        Some(l) => limits::confine(cmd, l, config.workspace)?,
+        None => cmd,
+    };
+
     // Detach from current process group — redirect stderr to log file for diagnostics.
     let log_path = config.workspace.join("brain-stderr.log");
     let log_file = std::fs::File::create(&log_path)
@@ -160,7 +193,10 @@ pub fn launch_brain(config: &BrainLaunchConfig<'_>) -> Result<u32> {
         )
     })?;
 
-    Ok(child.id())
+    Ok(match config.member_limits {
+        Some(l) => limits::member_pid(child.id(), l, bm_binary.as_os_str()),
+        None => child.id(),
+    })
 }
 
 /// Returns true if the workspace has a `brain-prompt.md` file,
@@ -218,8 +254,15 @@ mod tests {
         // via resolve_credential_from_store() in the member loop.
 
         // Verify launch_ralph compiles with bridge-type-aware parameters + gh_config_dir
-        let _: fn(&std::path::Path, Option<&str>, Option<&str>, Option<&str>, Option<&std::path::Path>) -> Result<u32> =
-            launch_ralph;
+        let _: fn(
+            &std::path::Path,
+            Option<&str>,
+            Option<&str>,
+            Option<&str>,
+            Option<&std::path::Path>,
+            &GitHubHost,
+            Option<&MemberLimits>,
+        ) -> Result<u32> = launch_ralph;
     }
 
     #[test]
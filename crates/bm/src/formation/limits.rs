@@ -0,0 +1,628 @@
+//! Per-member resource limits and filesystem sandboxing for local formation
+//! processes.
+//!
+//! Limits are declared in `formation.yml` under `limits:` (team default,
+//! per-role, per-member — most specific wins) and enforced at launch time:
+//!
+//! - CPU, memory, and task caps via a transient cgroup v2 scope
+//!   (`systemd-run --user --scope`), which execs the member in place so the
+//!   tracked PID stays the member's PID.
+//! - Filesystem sandbox via `bwrap`: the host is mounted read-only, with the
+//!   workspace, `/tmp`, and any `writable` paths bound read-write. `bwrap`
+//!   forks the member, so launch records the child's PID (`member_pid`).
+//! - Wall-clock per Ralph loop via `event_loop.max_runtime_seconds` in the
+//!   workspace's `ralph.yml`.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::os::unix::process::CommandExt;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+
+/// Prefix for transient scope unit names (`bm-{team}-{member}.scope`).
+const UNIT_PREFIX: &str = "bm-";
+
+/// How long to wait for `bwrap` to fork and exec the member.
+const SANDBOX_EXEC_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Resource limits for a member process. Unset fields are unlimited.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
+pub struct ResourceLimits {
+    /// CPU quota as a percentage of one core (e.g., "200%" = two cores).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cpu: Option<String>,
+    /// Memory cap in systemd syntax (e.g., "4G", "512M").
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory: Option<String>,
+    /// Maximum number of tasks (processes + threads).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tasks: Option<u32>,
+    /// Wall-clock limit per Ralph loop, in seconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub loop_timeout_secs: Option<u64>,
+    /// Restrict filesystem writes to the workspace and `limits.writable`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sandbox: Option<bool>,
+}
+
+impl ResourceLimits {
+    /// Returns `self` with every field set in `over` replaced.
+    pub fn merged(&self, over: &ResourceLimits) -> ResourceLimits {
+        ResourceLimits {
+            cpu: over.cpu.clone().or_else(|| self.cpu.clone()),
+            memory: over.memory.clone().or_else(|| self.memory.clone()),
+            tasks: over.tasks.or(self.tasks),
+            loop_timeout_secs: over.loop_timeout_secs.or(self.loop_timeout_secs),
+            sandbox: over.sandbox.or(self.sandbox),
+        }
+    }
+
+    /// True when any cgroup-enforced limit is set.
+    pub fn needs_cgroup(&self) -> bool {
+        self.cpu.is_some() || self.memory.is_some() || self.tasks.is_some()
+    }
+
+    /// True when the filesystem sandbox is enabled.
+    pub fn needs_sandbox(&self) -> bool {
+        self.sandbox.unwrap_or(false)
+    }
+
+    fn validate(&self, scope: &str) -> Result<()> {
+        if let Some(ref cpu) = self.cpu {
+            let pct = cpu.strip_suffix('%').and_then(|n| n.parse::<u32>().ok());
+            if !matches!(pct, Some(p) if p > 0) {
+                bail!(
+                    "Invalid cpu limit '{}' for {} — expected a percentage like \"200%\"",
+                    cpu,
+                    scope
+                );
+            }
+        }
+        if let Some(ref memory) = self.memory {
+            if parse_size(memory).is_none() {
+                bail!(
+                    "Invalid memory limit '{}' for {} — expected a size like \"4G\" or \"512M\"",
+                    memory,
+                    scope
+                );
+            }
+        }
+        if self.tasks == Some(0) {
+            bail!("Invalid tasks limit 0 for {} — must be at least 1", scope);
+        }
+        if self.loop_timeout_secs == Some(0) {
+            bail!("Invalid loop_timeout_secs 0 for {} — must be at least 1", scope);
+        }
+        Ok(())
+    }
+}
+
+/// The `limits:` section of `formation.yml`.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct LimitsConfig {
+    /// Limits applied to every member.
+    #[serde(default)]
+    pub default: ResourceLimits,
+    /// Per-role overrides, keyed by role name.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub roles: BTreeMap<String, ResourceLimits>,
+    /// Per-member overrides, keyed by member directory name.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub members: BTreeMap<String, ResourceLimits>,
+    /// Extra paths sandboxed members may write to (`~` expands to `$HOME`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub writable: Vec<String>,
+}
+
+impl LimitsConfig {
+    /// Validates all limit values.
+    pub fn validate(&self) -> Result<()> {
+        self.default.validate("limits.default")?;
+        for (role, l) in &self.roles {
+            l.validate(&format!("role '{}'", role))?;
+        }
+        for (member, l) in &self.members {
+            l.validate(&format!("member '{}'", member))?;
+        }
+        Ok(())
+    }
+
+    /// Resolves the effective limits for a member: default < role < member.
+    pub fn resolve(&self, role: &str, member: &str) -> ResourceLimits {
+        let mut limits = self.default.clone();
+        if let Some(l) = self.roles.get(role) {
+            limits = limits.merged(l);
+        }
+        if let Some(l) = self.members.get(member) {
+            limits = limits.merged(l);
+        }
+        limits
+    }
+
+    /// Returns the launch confinement for a member, or `None` when the member
+    /// has no process-level limits.
+    pub fn for_member(&self, team_name: &str, role: &str, member: &str) -> Option<MemberLimits> {
+        let limits = self.resolve(role, member);
+        if !limits.needs_cgroup() && !limits.needs_sandbox() && limits.loop_timeout_secs.is_none() {
+            return None;
+        }
+        Some(MemberLimits {
+            unit: unit_name(team_name, member),
+            limits,
+            writable: self.writable.iter().map(|p| expand_home(p)).collect(),
+        })
+    }
+}
+
+/// Effective limits for a single member launch.
+#[derive(Debug, Clone)]
+pub struct MemberLimits {
+    /// Transient scope unit name (without `.scope`).
+    pub unit: String,
+    pub limits: ResourceLimits,
+    /// Extra writable paths for the sandbox.
+    pub writable: Vec<PathBuf>,
+}
+
+/// Returns the scope unit name for a member.
+pub fn unit_name(team_name: &str, member: &str) -> String {
+    let raw = format!("{}{}-{}", UNIT_PREFIX, team_name, member);
+    raw.chars()
+        .map(|c| if c.is_ascii_alphanumeric() || "-_.:".contains(c) { c } else { '_' })
+        .collect()
+}
+
+/// Wraps a prepared command so it runs inside the member's cgroup scope
+/// and/or filesystem sandbox. Stdio is not carried over — configure it on the
+/// returned command.
+///
+/// The wrapped command starts a new session, so the member is detached from
+/// the terminal of `bm start` and keeps running after it exits.
+pub fn confine(cmd: Command, member: &MemberLimits, workspace: &Path) -> Result<Command> {
+    let limits = &member.limits;
+    if !limits.needs_cgroup() && !limits.needs_sandbox() {
+        return Ok(cmd);
+    }
+
+    let mut argv: Vec<std::ffi::OsString> = Vec::new();
+
+    if limits.needs_cgroup() {
+        check_cgroup_prerequisites()?;
+        argv.push("systemd-run".into());
+        for arg in systemd_run_args(&member.unit, limits) {
+            argv.push(arg.into());
+        }
+    }
+
+    if limits.needs_sandbox() {
+        if which::which("bwrap").is_err() {
+            bail!(
+                "'bwrap' not found in PATH. The filesystem sandbox needs bubblewrap — \
+                 install it or set `sandbox: false` in formation.yml limits."
+            );
+        }
+        argv.push("bwrap".into());
+        for arg in bwrap_args(workspace, &member.writable) {
+            argv.push(arg);
+        }
+    }
+
+    argv.push(cmd.get_program().to_os_string());
+    argv.extend(cmd.get_args().map(|a| a.to_os_string()));
+
+    let mut wrapped = Command::new(&argv[0]);
+    wrapped.args(&argv[1..]);
+    // SAFETY: setsid is async-signal-safe and touches no parent state.
+    unsafe {
+        wrapped.pre_exec(|| {
+            if libc::setsid() == -1 {
+                return Err(std::io::Error::last_os_error());
+            }
+            Ok(())
+        });
+    }
+    if let Some(dir) = cmd.get_current_dir() {
+        wrapped.current_dir(dir);
+    }
+    for (key, value) in cmd.get_envs() {
+        match value {
+            Some(v) => wrapped.env(key, v),
+            None => wrapped.env_remove(key),
+        };
+    }
+    Ok(wrapped)
+}
+
+/// Arguments for `systemd-run` creating a transient user scope.
+fn systemd_run_args(unit: &str, limits: &ResourceLimits) -> Vec<String> {
+    let mut args = vec![
+        "--user".to_string(),
+        "--scope".to_string(),
+        "--quiet".to_string(),
+        "--collect".to_string(),
+        format!("--unit={}", unit),
+    ];
+    if let Some(ref cpu) = limits.cpu {
+        args.push("-p".to_string());
+        args.push(format!("CPUQuota={}", cpu));
+    }
+    if let Some(ref memory) = limits.memory {
+        args.push("-p".to_string());
+        args.push(format!("MemoryMax={}", memory));
+        // Fail fast instead of swapping the host to a crawl.
+        args.push("-p".to_string());
+        args.push("MemorySwapMax=0".to_string());
+    }
+    if let Some(tasks) = limits.tasks {
+        args.push("-p".to_string());
+        args.push(format!("TasksMax={}", tasks));
+    }
+    args.push("--".to_string());
+    args
+}
+
+/// Arguments for `bwrap`: read-only host, writable workspace, /tmp, and extras.
+fn bwrap_args(workspace: &Path, writable: &[PathBuf]) -> Vec<std::ffi::OsString> {
+    let mut args: Vec<std::ffi::OsString> = vec![
+        "--ro-bind".into(),
+        "/".into(),
+        "/".into(),
+        "--dev".into(),
+        "/dev".into(),
+        "--proc".into(),
+        "/proc".into(),
+        "--bind".into(),
+        "/tmp".into(),
+        "/tmp".into(),
+        "--bind".into(),
+        workspace.into(),
+        workspace.into(),
+    ];
+    for path in writable {
+        if path.exists() {
+            args.push("--bind".into());
+            args.push(path.into());
+            args.push(path.into());
+        }
+    }
+    args.push("--".into());
+    args
+}
+
+/// Returns the PID to track for a launched member. Under the sandbox the
+/// spawned process is `bwrap`, which forks the member; stopping `bwrap` alone
+/// would leave the member running, so this waits for the forked `program`
+/// and returns its PID. Falls back to `spawned` if it doesn't show up.
+pub fn member_pid(spawned: u32, member: &MemberLimits, program: &std::ffi::OsStr) -> u32 {
+    if !member.limits.needs_sandbox() {
+        return spawned;
+    }
+    let Some(comm) = Path::new(program).file_name().and_then(|n| n.to_str()) else {
+        return spawned;
+    };
+    let deadline = Instant::now() + SANDBOX_EXEC_TIMEOUT;
+    loop {
+        let table = super::procfs::process_table();
+        if let Some(pid) = super::procfs::find_in_tree(&table, spawned, comm) {
+            if pid != spawned {
+                return pid;
+            }
+        }
+        if Instant::now() >= deadline {
+            return spawned;
+        }
+        thread::sleep(Duration::from_millis(50));
+    }
+}
+
+fn check_cgroup_prerequisites() -> Result<()> {
+    if which::which("systemd-run").is_err() {
+        bail!(
+            "'systemd-run' not found in PATH. Resource limits need systemd with cgroup v2 — \
+             remove `limits` from formation.yml to run members unconfined."
+        );
+    }
+    if !Path::new("/sys/fs/cgroup/cgroup.controllers").exists() {
+        bail!(
+            "cgroup v2 is not mounted at /sys/fs/cgroup. Resource limits need the unified \
+             cgroup hierarchy — remove `limits` from formation.yml to run members unconfined."
+        );
+    }
+    Ok(())
+}
+
+/// Caps `event_loop.max_runtime_seconds` in a workspace ralph.yml at `secs`.
+/// Leaves a lower existing value untouched.
+pub fn apply_loop_timeout(ralph_yml: &Path, secs: u64) -> Result<()> {
+    let contents = fs::read_to_string(ralph_yml)
+        .with_context(|| format!("Failed to read {}", ralph_yml.display()))?;
+    let mut doc: serde_yml::Value =
+        serde_yml::from_str(&contents).context("Failed to parse ralph.yml")?;
+
+    if !doc.get("event_loop").is_some_and(|v| v.is_mapping()) {
+        doc["event_loop"] = serde_yml::Value::Mapping(serde_yml::Mapping::new());
+    }
+    let current = doc["event_loop"]
+        .get("max_runtime_seconds")
+        .and_then(|v| v.as_u64());
+    if current.is_some_and(|c| c <= secs) {
+        return Ok(());
+    }
+    doc["event_loop"]["max_runtime_seconds"] = serde_yml::Value::Number(secs.into());
+
+    let out = serde_yml::to_string(&doc).context("Failed to serialize ralph.yml")?;
+    fs::write(ralph_yml, out)
+        .with_context(|| format!("Failed to write {}", ralph_yml.display()))?;
+    Ok(())
+}
+
+// ── Usage reporting ──────────────────────────────────────────────────
+
+/// Current cgroup usage of a confined member.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CgroupUsage {
+    pub unit: String,
+    pub memory_current: u64,
+    pub memory_max: Option<u64>,
+    pub cpu_usage_usec: u64,
+    /// CPU quota as a percentage of one core.
+    pub cpu_quota_pct: Option<u64>,
+    pub tasks_current: u64,
+    pub tasks_max: Option<u64>,
+}
+
+/// Reads cgroup usage for a PID running in a botminter scope.
+/// Returns `None` for unconfined processes or when cgroup v2 is unavailable.
+pub fn read_cgroup_usage(pid: u32) -> Option<CgroupUsage> {
+    let contents = fs::read_to_string(format!("/proc/{}/cgroup", pid)).ok()?;
+    let rel = parse_cgroup_path(&contents)?;
+    let unit = rel.rsplit('/').next()?.strip_suffix(".scope")?;
+    if !unit.starts_with(UNIT_PREFIX) {
+        return None;
+    }
+
+    let dir = Path::new("/sys/fs/cgroup").join(rel.trim_start_matches('/'));
+    let read = |name: &str| fs::read_to_string(dir.join(name)).ok();
+
+    Some(CgroupUsage {
+        unit: unit.to_string(),
+        memory_current: read("memory.current").and_then(|s| s.trim().parse().ok()).unwrap_or(0),
+        memory_max: read("memory.max").and_then(|s| parse_max(&s)),
+        cpu_usage_usec: read("cpu.stat").and_then(|s| parse_cpu_usage(&s)).unwrap_or(0),
+        cpu_quota_pct: read("cpu.max").and_then(|s| parse_cpu_max(&s)),
+        tasks_current: read("pids.current").and_then(|s| s.trim().parse().ok()).unwrap_or(0),
+        tasks_max: read("pids.max").and_then(|s| parse_max(&s)),
+    })
+}
+
+/// Extracts the cgroup v2 path (the `0::` line) from `/proc/<pid>/cgroup`.
+fn parse_cgroup_path(contents: &str) -> Option<&str> {
+    contents.lines().find_map(|l| l.strip_prefix("0::"))
+}
+
+/// Parses a cgroup limit file: a number, or "max" for unlimited.
+fn parse_max(s: &str) -> Option<u64> {
+    match s.trim() {
+        "max" => None,
+        n => n.parse().ok(),
+    }
+}
+
+/// Parses `cpu.max` ("<quota> <period>" or "max <period>") into a percentage.
+fn parse_cpu_max(s: &str) -> Option<u64> {
+    let mut parts = s.split_whitespace();
+    let quota: u64 = parts.next()?.parse().ok()?;
+    let period: u64 = parts.next()?.parse().ok()?;
+    if period == 0 {
+        return None;
+    }
+    Some(quota * 100 / period)
+}
+
+/// Extracts `usage_usec` from `cpu.stat`.
+fn parse_cpu_usage(s: &str) -> Option<u64> {
+    s.lines()
+        .find_map(|l| l.strip_prefix("usage_usec "))
+        .and_then(|v| v.trim().parse().ok())
+}
+
+/// Parses a systemd size ("4G", "512M", "1024") into bytes.
+fn parse_size(s: &str) -> Option<u64> {
+    let s = s.trim();
+    let (num, mult) = match s.chars().last()? {
+        'K' | 'k' => (&s[..s.len() - 1], 1u64 << 10),
+        'M' | 'm' => (&s[..s.len() - 1], 1u64 << 20),
+        'G' | 'g' => (&s[..s.len() - 1], 1u64 << 30),
+        'T' | 't' => (&s[..s.len() - 1], 1u64 << 40),
+        _ => (s, 1),
+    };
+    let n: u64 = num.parse().ok()?;
+    if n == 0 {
+        return None;
+    }
+    n.checked_mul(mult)
+}
+
+/// Formats a byte count for display ("1.5G", "512M").
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [(&str, u64); 3] = [("G", 1 << 30), ("M", 1 << 20), ("K", 1 << 10)];
+    for (suffix, size) in UNITS {
+        if bytes >= size {
+            return format!("{:.1}{}", bytes as f64 / size as f64, suffix);
+        }
+    }
+    format!("{}B", bytes)
+}
+
+fn expand_home(path: &str) -> PathBuf {
+    match path.strip_prefix("~/") {
+        Some(rest) => dirs::home_dir().map(|h| h.join(rest)).unwrap_or_else(|| PathBuf::from(path)),
+        None => PathBuf::from(path),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limits_config() -> LimitsConfig {
+        serde_yml::from_str(
+            r#"
+default:
+  cpu: "200%"
+  memory: 4G
+roles:
+  architect:
+    memory: 8G
+    loop_timeout_secs: 3600
+members:
+  architect-alice:
+    cpu: "400%"
+    sandbox: true
+writable:
+  - /var/cache/bm
+"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn resolve_member_overrides_role_overrides_default() {
+        let cfg = limits_config();
+        let l = cfg.resolve("architect", "architect-alice");
+        assert_eq!(l.cpu.as_deref(), Some("400%"));
+        assert_eq!(l.memory.as_deref(), Some("8G"));
+        assert_eq!(l.loop_timeout_secs, Some(3600));
+        assert_eq!(l.sandbox, Some(true));
+
+        let l = cfg.resolve("human-assistant", "human-assistant-bob");
+        assert_eq!(l.cpu.as_deref(), Some("200%"));
+        assert_eq!(l.memory.as_deref(), Some("4G"));
+        assert_eq!(l.loop_timeout_secs, None);
+    }
+
+    #[test]
+    fn for_member_none_when_unlimited() {
+        let cfg = LimitsConfig::default();
+        assert!(cfg.for_member("t", "architect", "architect-alice").is_none());
+    }
+
+    #[test]
+    fn for_member_builds_unit_name() {
+        let cfg = limits_config();
+        let m = cfg.for_member("my-team", "architect", "architect-alice").unwrap();
+        assert_eq!(m.unit, "bm-my-team-architect-alice");
+        assert_eq!(m.writable, vec![PathBuf::from("/var/cache/bm")]);
+    }
+
+    #[test]
+    fn validate_rejects_bad_values() {
+        let mut cfg = LimitsConfig::default();
+        cfg.default.cpu = Some("2 cores".into());
+        assert!(cfg.validate().unwrap_err().to_string().contains("cpu"));
+
+        let mut cfg = LimitsConfig::default();
+        cfg.roles.insert(
+            "architect".into(),
+            ResourceLimits { memory: Some("lots".into()), ..Default::default() },
+        );
+        let err = cfg.validate().unwrap_err().to_string();
+        assert!(err.contains("memory") && err.contains("architect"), "{}", err);
+
+        let mut cfg = LimitsConfig::default();
+        cfg.default.tasks = Some(0);
+        assert!(cfg.validate().is_err());
+
+        assert!(limits_config().validate().is_ok());
+    }
+
+    #[test]
+    fn systemd_run_args_include_properties() {
+        let limits = ResourceLimits {
+            cpu: Some("150%".into()),
+            memory: Some("2G".into()),
+            tasks: Some(256),
+            ..Default::default()
+        };
+        let args = systemd_run_args("bm-t-m", &limits);
+        assert!(args.contains(&"--scope".to_string()));
+        assert!(args.contains(&"--unit=bm-t-m".to_string()));
+        assert!(args.contains(&"CPUQuota=150%".to_string()));
+        assert!(args.contains(&"MemoryMax=2G".to_string()));
+        assert!(args.contains(&"TasksMax=256".to_string()));
+        assert_eq!(args.last().map(String::as_str), Some("--"));
+    }
+
+    #[test]
+    fn confine_without_limits_returns_command_unchanged() {
+        let mut cmd = Command::new("ralph");
+        cmd.args(["run", "-p", "PROMPT.md"]);
+        let member = MemberLimits {
+            unit: "bm-t-m".into(),
+            limits: ResourceLimits { loop_timeout_secs: Some(60), ..Default::default() },
+            writable: Vec::new(),
+        };
+        let out = confine(cmd, &member, Path::new("/ws")).unwrap();
+        assert_eq!(out.get_program(), "ralph");
+    }
+
+    #[test]
+    fn bwrap_args_bind_workspace_read_write() {
+        let args = bwrap_args(Path::new("/ws/alice"), &[PathBuf::from("/nonexistent-bm-path")]);
+        let args: Vec<String> = args.iter().map(|a| a.to_string_lossy().to_string()).collect();
+        let pos = args.iter().position(|a| a == "/ws/alice").unwrap();
+        assert_eq!(args[pos - 1], "--bind");
+        assert!(!args.contains(&"/nonexistent-bm-path".to_string()));
+        assert!(!args.contains(&"--die-with-parent".to_string()));
+        assert_eq!(args.last().map(String::as_str), Some("--"));
+    }
+
+    #[test]
+    fn apply_loop_timeout_caps_runtime() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("ralph.yml");
+        fs::write(&path, "event_loop:\n  max_runtime_seconds: 86400\n  persistent: true\n").unwrap();
+
+        apply_loop_timeout(&path, 3600).unwrap();
+        let doc: serde_yml::Value = serde_yml::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(doc["event_loop"]["max_runtime_seconds"].as_u64(), Some(3600));
+        assert_eq!(doc["event_loop"]["persistent"].as_bool(), Some(true));
+
+        // A lower existing value is kept
+        apply_loop_timeout(&path, 7200).unwrap();
+        let doc: serde_yml::Value = serde_yml::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(doc["event_loop"]["max_runtime_seconds"].as_u64(), Some(3600));
+    }
+
+    #[test]
+    fn parse_cgroup_files() {
+        assert_eq!(
+            parse_cgroup_path("0::/user.slice/user-1000.slice/user@1000.service/app.slice/bm-t-m.scope\n"),
+            Some("/user.slice/user-1000.slice/user@1000.service/app.slice/bm-t-m.scope")
+        );
+        assert_eq!(parse_max("max\n"), None);
+        assert_eq!(parse_max("4294967296\n"), Some(4294967296));
+        assert_eq!(parse_cpu_max("200000 100000\n"), Some(200));
+        assert_eq!(parse_cpu_max("max 100000\n"), None);
+        assert_eq!(
+            parse_cpu_usage("usage_usec 1234567\nuser_usec 1000000\nsystem_usec 234567\n"),
+            Some(1234567)
+        );
+    }
+
+    #[test]
+    fn parse_size_and_format_bytes() {
+        assert_eq!(parse_size("4G"), Some(4 << 30));
+        assert_eq!(parse_size("512M"), Some(512 << 20));
+        assert_eq!(parse_size("1024"), Some(1024));
+        assert_eq!(parse_size("0"), None);
+        assert_eq!(parse_size("G"), None);
+        assert_eq!(format_bytes(3 << 29), "1.5G");
+        assert_eq!(format_bytes(512), "512B");
+    }
+}
@@ -11,7 +11,8 @@ use anyhow::{bail, Context, Result};
 use crate::daemon::{self, DaemonClient};
 use crate::formation::{
     self, CredentialDomain, EnvironmentStatus, EnvironmentCheck, Formation,
-    KeyValueCredentialStore, MemberHandle, MemberStatus, SetupParams, StartParams, StopParams,
+    KeyValueCredentialStore, MemberHandle, MemberStatus, PauseParams, PauseResult, ResumeParams,
+    ResumeResult, SetupParams, StartParams, StopParams,
 };
 use crate::formation::start_members::{MemberLaunched, MemberSkipped, StartResult};
 use crate::formation::stop_members::StopResult;
@@ -121,6 +122,7 @@ impl Formation for LinuxLocalFormation {
         _member: &str,
         workspace: &Path,
         bot_user: &str,
+        github_host: &str,
     ) -> Result<()> {
         // Create GH_CONFIG_DIR at {workspace}/.config/gh/
         let gh_config_dir = workspace.join(".config").join("gh");
@@ -131,7 +133,7 @@ impl Formation for LinuxLocalFormation {
         // The actual token is written by refresh_token() immediately after.
         let hosts_yml = gh_config_dir.join("hosts.yml");
         let hosts_content = format!(
-            "github.com:\n    user: {bot_user}\n    oauth_token: placeholder\n    git_protocol: https\n"
+            "{github_host}:\n    user: {bot_user}\n    oauth_token: placeholder\n    git_protocol: https\n"
         );
         fs::write(&hosts_yml, &hosts_content)
             .with_context(|| format!("Failed to write {}", hosts_yml.display()))?;
@@ -144,8 +146,11 @@ impl Formation for LinuxLocalFormation {
                 .with_context(|| format!("Failed to read {}", git_config.display()))?;
 
             // Only add if not already configured
-            if !existing.contains("[credential \"https://github.com\"]") {
-                let credential_block = "\n[credential \"https://github.com\"]\n\thelper = \n\thelper = !/usr/bin/gh auth git-credential\n";
+            let section = format!("[credential \"https://{github_host}\"]");
+            if !existing.contains(&section) {
+                let credential_block = format!(
+                    "\n{section}\n\thelper = \n\thelper = !/usr/bin/gh auth git-credential\n"
+                );
                 let mut file = fs::OpenOptions::new()
                     .append(true)
                     .open(&git_config)
@@ -162,10 +167,15 @@ impl Formation for LinuxLocalFormation {
         let gh_config_dir = workspace.join(".config").join("gh");
         let hosts_yml = gh_config_dir.join("hosts.yml");
 
-        // Read existing hosts.yml to preserve bot_user
+        // Read existing hosts.yml to preserve the host and bot_user
         let existing = fs::read_to_string(&hosts_yml)
             .with_context(|| format!("Failed to read {}", hosts_yml.display()))?;
 
+        let github_host = existing
+            .lines()
+            .find_map(|line| line.strip_suffix(':').filter(|h| !h.starts_with(' ')))
+            .unwrap_or("github.com");
+
         // Extract bot_user from existing file
         let bot_user = existing
             .lines()
@@ -176,7 +186,7 @@ impl Formation for LinuxLocalFormation {
             .unwrap_or("bot");
 
         let hosts_content = format!(
-            "github.com:\n    user: {bot_user}\n    oauth_token: {token}\n    git_protocol: https\n"
+            "{github_host}:\n    user: {bot_user}\n    oauth_token: {token}\n    git_protocol: https\n"
         );
 
         // Atomic write: write to temp file, then rename
@@ -270,9 +280,26 @@ impl Formation for LinuxLocalFormation {
         )
     }
 
+    fn pause_members(&self, params: &PauseParams) -> Result<PauseResult> {
+        // Like stop, pausing is a local signal operation on processes
+        // recorded in state.json.
+        formation::pause_local_members(
+            params.team,
+            params.member_filter,
+            params.immediate,
+            params.wait,
+        )
+    }
+
+    fn resume_members(&self, params: &ResumeParams) -> Result<ResumeResult> {
+        formation::resume_local_members(params.team, params.member_filter)
+    }
+
     fn member_status(&self) -> Result<Vec<MemberStatus>> {
         let runtime_state = state::load()?;
         let team_prefix = format!("{}/", self.team_name);
+        let token_health =
+            daemon::load_token_health(&daemon::DaemonPaths::new(&self.team_name)?.token_health());
 
         let statuses = runtime_state
             .members
@@ -286,6 +313,7 @@ impl Formation for LinuxLocalFormation {
                     pid: Some(rt.pid),
                     workspace: Some(rt.workspace.clone()),
                     brain_mode: rt.brain_mode,
+                    token_health: token_health.get(member_name).cloned(),
                 }
             })
             .collect();
@@ -360,7 +388,7 @@ mod tests {
         std::fs::create_dir_all(tmp.path().join(".git")).unwrap();
         std::fs::write(tmp.path().join(".git/config"), "[core]\n\tbare = false\n").unwrap();
 
-        f.setup_token_delivery("superman", tmp.path(), "test-bot[bot]")
+        f.setup_token_delivery("superman", tmp.path(), "test-bot[bot]", "github.com")
             .unwrap();
 
         // Verify GH config dir created
@@ -387,8 +415,8 @@ mod tests {
         std::fs::write(tmp.path().join(".git/config"), "[core]\n\tbare = false\n").unwrap();
 
         // Call twice — credential helper should only be added once
-        f.setup_token_delivery("superman", tmp.path(), "bot").unwrap();
-        f.setup_token_delivery("superman", tmp.path(), "bot").unwrap();
+        f.setup_token_delivery("superman", tmp.path(), "bot", "github.com").unwrap();
+        f.setup_token_delivery("superman", tmp.path(), "bot", "github.com").unwrap();
 
         let git_config = std::fs::read_to_string(tmp.path().join(".git/config")).unwrap();
         let count = git_config.matches("[credential \"https://github.com\"]").count();
@@ -403,7 +431,7 @@ mod tests {
         // Setup first
         std::fs::create_dir_all(tmp.path().join(".git")).unwrap();
         std::fs::write(tmp.path().join(".git/config"), "").unwrap();
-        f.setup_token_delivery("superman", tmp.path(), "my-bot[bot]").unwrap();
+        f.setup_token_delivery("superman", tmp.path(), "my-bot[bot]", "github.com").unwrap();
 
         // Refresh with a real token
         f.refresh_token("superman", tmp.path(), "ghs_installation_token_abc")
@@ -425,7 +453,7 @@ mod tests {
 
         std::fs::create_dir_all(tmp.path().join(".git")).unwrap();
         std::fs::write(tmp.path().join(".git/config"), "").unwrap();
-        f.setup_token_delivery("superman", tmp.path(), "bot").unwrap();
+        f.setup_token_delivery("superman", tmp.path(), "bot", "github.com").unwrap();
 
         f.refresh_token("superman", tmp.path(), "ghs_first").unwrap();
         f.refresh_token("superman", tmp.path(), "ghs_second").unwrap();
@@ -435,6 +463,26 @@ mod tests {
         assert!(!hosts.contains("ghs_first"));
     }
 
+    #[test]
+    fn token_delivery_targets_enterprise_host() {
+        let f = LinuxLocalFormation::new("test-team");
+        let tmp = tempfile::tempdir().unwrap();
+
+        std::fs::create_dir_all(tmp.path().join(".git")).unwrap();
+        std::fs::write(tmp.path().join(".git/config"), "").unwrap();
+        f.setup_token_delivery("superman", tmp.path(), "bot", "github.acme.corp")
+            .unwrap();
+        f.refresh_token("superman", tmp.path(), "ghs_enterprise").unwrap();
+
+        let hosts = std::fs::read_to_string(tmp.path().join(".config/gh/hosts.yml")).unwrap();
+        assert!(hosts.starts_with("github.acme.corp:\n"), "{hosts}");
+        assert!(hosts.contains("oauth_token: ghs_enterprise"));
+        assert!(!hosts.contains("github.com"));
+
+        let git_config = std::fs::read_to_string(tmp.path().join(".git/config")).unwrap();
+        assert!(git_config.contains("[credential \"https://github.acme.corp\"]"));
+    }
+
     #[test]
     fn linux_formation_credential_store_returns_bridge_store() {
         let tmp = tempfile::tempdir().unwrap();
@@ -4,7 +4,8 @@ use anyhow::{bail, Result};
 
 use crate::formation::{
     CredentialDomain, EnvironmentStatus, Formation, KeyValueCredentialStore, MemberHandle,
-    MemberStatus, SetupParams, StartParams, StopParams,
+    MemberStatus, PauseParams, PauseResult, ResumeParams, ResumeResult, SetupParams, StartParams,
+    StopParams,
 };
 use crate::formation::start_members::StartResult;
 use crate::formation::stop_members::StopResult;
@@ -54,6 +55,7 @@ impl Formation for MacosLocalFormation {
         _member: &str,
         _workspace: &Path,
         _bot_user: &str,
+        _github_host: &str,
     ) -> Result<()> {
         bail!("macOS local formation is not yet supported")
     }
@@ -70,6 +72,14 @@ impl Formation for MacosLocalFormation {
         bail!("macOS local formation is not yet supported")
     }
 
+    fn pause_members(&self, _params: &PauseParams) -> Result<PauseResult> {
+        bail!("macOS local formation is not yet supported")
+    }
+
+    fn resume_members(&self, _params: &ResumeParams) -> Result<ResumeResult> {
+        bail!("macOS local formation is not yet supported")
+    }
+
     fn member_status(&self) -> Result<Vec<MemberStatus>> {
         bail!("macOS local formation is not yet supported")
     }
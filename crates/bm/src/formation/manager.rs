@@ -38,8 +38,12 @@ pub fn run_formation_manager(
     // Prepare env vars
     let mut env_vars = Vec::new();
     // GH_TOKEN is no longer stored in config. Detect from environment.
-    if let Some(token) = crate::git::detect_token() {
-        env_vars.push(("GH_TOKEN".to_string(), token));
+    let github_host = crate::git::GitHubHost::for_team(team);
+    if let Some(token) = github_host.detect_token() {
+        env_vars.push((github_host.token_env().to_string(), token));
+        if github_host.is_enterprise() {
+            env_vars.push(("GH_HOST".to_string(), github_host.host.clone()));
+        }
     }
     if let Some(token) = &team.credentials.telegram_bot_token {
         // Determine bridge type for correct env var dispatch
@@ -1,9 +1,12 @@
 mod init;
 mod launch;
+mod limits;
 pub mod lima;
 pub mod local;
 mod local_topology;
 mod manager;
+pub mod pause_members;
+mod procfs;
 pub mod start_members;
 pub mod stop_members;
 
@@ -14,8 +17,15 @@ pub use self::local::create_local_formation;
 pub(crate) use self::launch::{
     check_robot_enabled_mismatch, is_brain_member, launch_brain, BrainLaunchConfig, launch_ralph,
 };
+pub use self::limits::{
+    format_bytes, read_cgroup_usage, CgroupUsage, LimitsConfig, MemberLimits, ResourceLimits,
+};
 pub use self::local_topology::write_local_topology;
 pub use self::manager::{run_formation_manager, FormationManagerResult};
+pub use self::pause_members::{
+    pause_local_members, resume_local_members, MemberPaused, MemberResumed, PauseResult,
+    ResumeResult,
+};
 pub use self::start_members::{
     auto_start_bridge, start_local_members, AppCredentialsCached, BridgeAutoStartOutcome,
     MemberLaunched, MemberSkipped, StartResult,
@@ -70,9 +80,16 @@ pub trait Formation {
     fn credential_store(&self, domain: CredentialDomain) -> Result<Box<dyn KeyValueCredentialStore>>;
 
     /// One-time setup for token delivery to a member.
-    /// Creates GH_CONFIG_DIR, writes initial config, configures git
-    /// credential helper in workspace .git/config (not global .gitconfig).
-    fn setup_token_delivery(&self, member: &str, workspace: &Path, bot_user: &str) -> Result<()>;
+    /// Creates GH_CONFIG_DIR, writes initial config for `github_host`
+    /// (`github.com` or a GHES hostname), configures git credential helper
+    /// in workspace .git/config (not global .gitconfig).
+    fn setup_token_delivery(
+        &self,
+        member: &str,
+        workspace: &Path,
+        bot_user: &str,
+        github_host: &str,
+    ) -> Result<()>;
 
     /// Delivers a refreshed token to a member.
     /// Local: atomically writes hosts.yml. K8s: updates Secret.
@@ -88,6 +105,12 @@ pub trait Formation {
     /// Stops members. Daemon keeps running unless all members stopped.
     fn stop_members(&self, params: &StopParams) -> Result<StopResult>;
 
+    /// Pauses running members without tearing down their loops.
+    fn pause_members(&self, params: &PauseParams) -> Result<PauseResult>;
+
+    /// Resumes paused members where they left off.
+    fn resume_members(&self, params: &ResumeParams) -> Result<ResumeResult>;
+
     /// Returns status of all members including token health.
     fn member_status(&self) -> Result<Vec<MemberStatus>>;
 
@@ -192,6 +215,23 @@ pub struct StopParams<'a> {
     pub stop_all: bool,
 }
 
+/// Parameters for pausing members.
+pub struct PauseParams<'a> {
+    pub team: &'a TeamEntry,
+    pub member_filter: Option<&'a str>,
+    /// Freeze Ralph members mid-iteration instead of waiting for the
+    /// current iteration to finish. Corresponds to `bm pause --now`.
+    pub immediate: bool,
+    /// How long to wait for Ralph members to reach an iteration boundary.
+    pub wait: std::time::Duration,
+}
+
+/// Parameters for resuming members.
+pub struct ResumeParams<'a> {
+    pub team: &'a TeamEntry,
+    pub member_filter: Option<&'a str>,
+}
+
 /// Status of a single member in the formation.
 pub struct MemberStatus {
     pub name: String,
@@ -199,6 +239,8 @@ pub struct MemberStatus {
     pub pid: Option<u32>,
     pub workspace: Option<PathBuf>,
     pub brain_mode: bool,
+    /// App token health recorded by the daemon's refresh loop.
+    pub token_health: Option<crate::daemon::TokenHealth>,
 }
 
 /// Handle to a running member. Opaque to commands — passed back to the
@@ -274,6 +316,10 @@ pub struct FormationConfig {
     /// Formation manager configuration (only for non-local types).
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub manager: Option<ManagerConfig>,
+
+    /// Per-member resource limits and sandboxing (only for type=local).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limits: Option<LimitsConfig>,
 }
 
 /// K8s-specific formation settings.
@@ -344,6 +390,12 @@ pub fn load(team_repo: &Path, formation_name: &str) -> Result<FormationConfig> {
         )
     })?;
 
+    if let Some(ref limits) = config.limits {
+        limits.validate().with_context(|| {
+            format!("Invalid limits in {}", config_path.display())
+        })?;
+    }
+
     Ok(config)
 }
 
@@ -438,6 +490,45 @@ mod tests {
         assert!(config.is_local());
         assert!(config.k8s.is_none());
         assert!(config.manager.is_none());
+        assert!(config.limits.is_none());
+    }
+
+    #[test]
+    fn load_local_formation_with_limits() {
+        let tmp = tempfile::tempdir().unwrap();
+        let content = r#"
+name: local
+description: Run locally
+type: local
+limits:
+  default:
+    cpu: "200%"
+    memory: 4G
+    tasks: 512
+  roles:
+    architect:
+      loop_timeout_secs: 3600
+"#;
+        create_formation(tmp.path(), "local", content);
+
+        let config = load(tmp.path(), "local").unwrap();
+        let limits = config.limits.unwrap();
+        assert_eq!(limits.default.memory.as_deref(), Some("4G"));
+        assert_eq!(limits.roles["architect"].loop_timeout_secs, Some(3600));
+    }
+
+    #[test]
+    fn load_formation_with_invalid_limits_errors() {
+        let tmp = tempfile::tempdir().unwrap();
+        create_formation(
+            tmp.path(),
+            "local",
+            "name: local\ndescription: Run locally\ntype: local\nlimits:\n  default:\n    memory: lots\n",
+        );
+
+        let err = format!("{:#}", load(tmp.path(), "local").unwrap_err());
+        assert!(err.contains("Invalid limits"), "{}", err);
+        assert!(err.contains("lots"), "{}", err);
     }
 
     #[test]
@@ -0,0 +1,327 @@
+use std::thread;
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+
+use crate::config::TeamEntry;
+use crate::state;
+use crate::workspace;
+
+use super::procfs;
+use super::MemberFailed;
+
+// ---------------------------------------------------------------------------
+// Result types
+// ---------------------------------------------------------------------------
+
+/// Outcome of pausing local formation members.
+pub struct PauseResult {
+    pub paused: Vec<MemberPaused>,
+    pub errors: Vec<MemberFailed>,
+    pub no_members_running: bool,
+}
+
+pub struct MemberPaused {
+    pub name: String,
+    pub already_paused: bool,
+    /// Frozen mid-iteration (`--now`) instead of at an iteration boundary.
+    pub immediate: bool,
+    pub brain_mode: bool,
+}
+
+/// Outcome of resuming local formation members.
+pub struct ResumeResult {
+    pub resumed: Vec<MemberResumed>,
+    pub errors: Vec<MemberFailed>,
+    pub no_members_running: bool,
+}
+
+pub struct MemberResumed {
+    pub name: String,
+    /// False when the member was running but not paused.
+    pub was_paused: bool,
+}
+
+// ---------------------------------------------------------------------------
+// Pause / resume
+// ---------------------------------------------------------------------------
+
+/// How often to check whether a Ralph member reached an iteration boundary.
+const BOUNDARY_POLL_SECS: u64 = 1;
+
+/// Pauses running members of a team.
+///
+/// Brain members get SIGUSR1: the multiplexer finishes its current turn and
+/// queues everything else. Ralph members are held at the next iteration
+/// boundary — once the coding agent of the current iteration exits, the
+/// process tree is stopped (SIGSTOP) so Ralph can't start another one. With
+/// `immediate`, Ralph members are stopped right away, mid-iteration.
+///
+/// Members still inside their iteration after `wait` are reported as errors
+/// and left running.
+pub fn pause_local_members(
+    team: &TeamEntry,
+    member_filter: Option<&str>,
+    immediate: bool,
+    wait: Duration,
+) -> Result<PauseResult> {
+    let mut runtime_state = state::load()?;
+    let team_prefix = format!("{}/", team.name);
+
+    let mut result = PauseResult {
+        paused: Vec::new(),
+        errors: Vec::new(),
+        no_members_running: false,
+    };
+
+    // Hold the members so the daemon and scheduler don't relaunch them,
+    // even when they aren't running right now.
+    let paused_at = chrono::Utc::now().to_rfc3339();
+    let members_dir = team.path.join("team").join("members");
+    let hired = if members_dir.is_dir() {
+        workspace::list_member_dirs(&members_dir)?
+    } else {
+        Vec::new()
+    };
+    for name in hired.iter().filter(|m| member_filter.is_none_or(|f| f == m.as_str())) {
+        runtime_state
+            .paused
+            .entry(format!("{}{}", team_prefix, name))
+            .or_insert_with(|| paused_at.clone());
+    }
+
+    // Ralph members waiting for their iteration to finish: (key, pid)
+    let mut pending: Vec<(String, u32)> = Vec::new();
+
+    for (key, rt) in runtime_state.members.iter_mut() {
+        let Some(name) = member_name(key, &team_prefix, member_filter) else {
+            continue;
+        };
+        if !state::is_alive(rt.pid) {
+            continue;
+        }
+        if rt.paused_at.is_some() {
+            result.paused.push(MemberPaused {
+                name: name.to_string(),
+                already_paused: true,
+                immediate: false,
+                brain_mode: rt.brain_mode,
+            });
+            continue;
+        }
+
+        if rt.brain_mode {
+            signal(brain_pid(rt.pid), libc::SIGUSR1);
+            rt.paused_at = Some(chrono::Utc::now().to_rfc3339());
+            result.paused.push(MemberPaused {
+                name: name.to_string(),
+                already_paused: false,
+                immediate: false,
+                brain_mode: true,
+            });
+        } else if immediate {
+            freeze(rt.pid);
+            rt.paused_at = Some(chrono::Utc::now().to_rfc3339());
+            result.paused.push(MemberPaused {
+                name: name.to_string(),
+                already_paused: false,
+                immediate: true,
+                brain_mode: false,
+            });
+        } else {
+            pending.push((key.clone(), rt.pid));
+        }
+    }
+
+    state::save(&runtime_state)?;
+
+    // Wait for iteration boundaries. State is re-read when recording each
+    // pause since this can take minutes.
+    let deadline = Instant::now() + wait;
+    while !pending.is_empty() {
+        let mut frozen: Vec<String> = Vec::new();
+        pending.retain(|(key, pid)| {
+            if !at_iteration_boundary(*pid) {
+                return true;
+            }
+            freeze(*pid);
+            frozen.push(key.clone());
+            result.paused.push(MemberPaused {
+                name: key.strip_prefix(&team_prefix).unwrap_or(key).to_string(),
+                already_paused: false,
+                immediate: false,
+                brain_mode: false,
+            });
+            false
+        });
+        if !frozen.is_empty() {
+            let mut runtime_state = state::load()?;
+            for key in &frozen {
+                if let Some(rt) = runtime_state.members.get_mut(key) {
+                    rt.paused_at = Some(chrono::Utc::now().to_rfc3339());
+                }
+            }
+            state::save(&runtime_state)?;
+        }
+        if pending.is_empty() || Instant::now() >= deadline {
+            break;
+        }
+        thread::sleep(Duration::from_secs(BOUNDARY_POLL_SECS));
+    }
+
+    for (key, _) in &pending {
+        let name = key.strip_prefix(&team_prefix).unwrap_or(key);
+        result.errors.push(MemberFailed {
+            name: name.to_string(),
+            error: format!(
+                "current iteration still running after {}s\n  Hint: re-run `bm pause {}` to keep waiting, \
+                 or `bm pause {} --now` to freeze it mid-iteration",
+                wait.as_secs(),
+                name,
+                name
+            ),
+        });
+    }
+
+    result.no_members_running = result.paused.is_empty() && result.errors.is_empty();
+    Ok(result)
+}
+
+/// Resumes paused members of a team: SIGUSR2 for brain members, SIGCONT for
+/// the process tree of Ralph members.
+pub fn resume_local_members(team: &TeamEntry, member_filter: Option<&str>) -> Result<ResumeResult> {
+    let mut runtime_state = state::load()?;
+    let team_prefix = format!("{}/", team.name);
+
+    let mut result = ResumeResult {
+        resumed: Vec::new(),
+        errors: Vec::new(),
+        no_members_running: false,
+    };
+
+    // Lift the hold first; members that aren't running are reported as
+    // resumed when they were held.
+    let mut held: Vec<String> = runtime_state
+        .paused
+        .keys()
+        .filter(|key| member_name(key, &team_prefix, member_filter).is_some())
+        .cloned()
+        .collect();
+    for key in &held {
+        runtime_state.paused.remove(key);
+    }
+
+    for (key, rt) in runtime_state.members.iter_mut() {
+        let Some(name) = member_name(key, &team_prefix, member_filter) else {
+            continue;
+        };
+        if !state::is_alive(rt.pid) {
+            continue;
+        }
+        held.retain(|k| k != key);
+        if rt.paused_at.is_none() {
+            result.resumed.push(MemberResumed {
+                name: name.to_string(),
+                was_paused: false,
+            });
+            continue;
+        }
+
+        if rt.brain_mode {
+            signal(brain_pid(rt.pid), libc::SIGUSR2);
+        } else {
+            thaw(rt.pid);
+        }
+        rt.paused_at = None;
+        result.resumed.push(MemberResumed {
+            name: name.to_string(),
+            was_paused: true,
+        });
+    }
+
+    for key in &held {
+        result.resumed.push(MemberResumed {
+            name: key.strip_prefix(&team_prefix).unwrap_or(key).to_string(),
+            was_paused: true,
+        });
+    }
+
+    result.no_members_running = result.resumed.is_empty();
+    state::save(&runtime_state)?;
+    Ok(result)
+}
+
+/// Continues a stopped Ralph process tree. Stopping a paused member calls
+/// this first so the processes can act on the termination signal.
+pub(super) fn thaw(pid: u32) {
+    // Children first, so Ralph wakes up to running children.
+    for p in process_tree(pid).into_iter().rev() {
+        signal(p, libc::SIGCONT);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Private helpers
+// ---------------------------------------------------------------------------
+
+/// Returns the member name for a state key if it belongs to the team and
+/// matches the optional filter.
+fn member_name<'k>(
+    key: &'k str,
+    team_prefix: &str,
+    member_filter: Option<&str>,
+) -> Option<&'k str> {
+    let name = key.strip_prefix(team_prefix)?;
+    match member_filter {
+        Some(target) if target != name => None,
+        _ => Some(name),
+    }
+}
+
+/// Stops a process tree, parent first so it can't spawn new children.
+fn freeze(pid: u32) {
+    for p in process_tree(pid) {
+        signal(p, libc::SIGSTOP);
+    }
+}
+
+fn signal(pid: u32, sig: libc::c_int) {
+    unsafe {
+        libc::kill(pid as i32, sig);
+    }
+}
+
+/// The `bm brain-run` process behind `pid`, which may be a wrapper
+/// (systemd-run, bwrap) that would not forward the pause signals.
+fn brain_pid(pid: u32) -> u32 {
+    procfs::find_in_tree(&procfs::process_table(), pid, "bm").unwrap_or(pid)
+}
+
+/// True when Ralph has no child process, i.e. no coding agent is running an
+/// iteration. `pid` may be a wrapper (systemd-run, bwrap) around Ralph.
+fn at_iteration_boundary(pid: u32) -> bool {
+    let table = procfs::process_table();
+    let ralph = procfs::find_in_tree(&table, pid, "ralph").unwrap_or(pid);
+    !table.values().any(|ppid| *ppid == ralph)
+}
+
+/// `pid` and all of its descendants, parents before children.
+fn process_tree(pid: u32) -> Vec<u32> {
+    procfs::descendants(&procfs::process_table(), pid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn member_name_applies_team_and_filter() {
+        assert_eq!(member_name("team/alice", "team/", None), Some("alice"));
+        assert_eq!(
+            member_name("team/alice", "team/", Some("alice")),
+            Some("alice")
+        );
+        assert_eq!(member_name("team/alice", "team/", Some("bob")), None);
+        assert_eq!(member_name("other/alice", "team/", None), None);
+    }
+}
@@ -0,0 +1,100 @@
+//! Process-tree lookups via `/proc`, used to find the member process behind
+//! launch wrappers (`systemd-run`, `bwrap`).
+
+use std::collections::HashMap;
+use std::fs;
+
+/// Reads pid → ppid for every process in /proc.
+pub(super) fn process_table() -> HashMap<u32, u32> {
+    let mut table = HashMap::new();
+    let Ok(entries) = fs::read_dir("/proc") else {
+        return table;
+    };
+    for entry in entries.flatten() {
+        let Some(pid) = entry
+            .file_name()
+            .to_str()
+            .and_then(|s| s.parse::<u32>().ok())
+        else {
+            continue;
+        };
+        if let Some(ppid) = fs::read_to_string(entry.path().join("stat"))
+            .ok()
+            .and_then(|stat| parse_ppid(&stat))
+        {
+            table.insert(pid, ppid);
+        }
+    }
+    table
+}
+
+/// Walks a pid → ppid table breadth-first from `root` (included).
+pub(super) fn descendants(table: &HashMap<u32, u32>, root: u32) -> Vec<u32> {
+    let mut out = vec![root];
+    let mut i = 0;
+    while i < out.len() {
+        let parent = out[i];
+        let mut children: Vec<u32> = table
+            .iter()
+            .filter(|(_, ppid)| **ppid == parent)
+            .map(|(pid, _)| *pid)
+            .collect();
+        children.sort_unstable();
+        out.extend(children);
+        i += 1;
+    }
+    out
+}
+
+/// First process in the tree under `root` (included) whose command name is
+/// `comm`. Command names are truncated to 15 bytes by the kernel.
+pub(super) fn find_in_tree(table: &HashMap<u32, u32>, root: u32, comm: &str) -> Option<u32> {
+    let comm = comm.get(..15).unwrap_or(comm);
+    descendants(table, root)
+        .into_iter()
+        .find(|p| read_comm(*p).as_deref() == Some(comm))
+}
+
+/// Extracts the parent pid from `/proc/<pid>/stat`
+/// (`pid (comm) state ppid ...`; comm may contain spaces and parens).
+fn parse_ppid(stat: &str) -> Option<u32> {
+    let rest = &stat[stat.rfind(')')? + 1..];
+    rest.split_whitespace().nth(1)?.parse().ok()
+}
+
+fn read_comm(pid: u32) -> Option<String> {
+    fs::read_to_string(format!("/proc/{}/comm", pid))
+        .ok()
+        .map(|s| s.trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_ppid_handles_spaces_in_comm() {
+        assert_eq!(parse_ppid("123 (ralph) S 45 123 123 0 -1"), Some(45));
+        assert_eq!(parse_ppid("123 (my (odd) cmd) T 7 1 1"), Some(7));
+        assert_eq!(parse_ppid("garbage"), None);
+    }
+
+    #[test]
+    fn descendants_lists_parents_before_children() {
+        // 10 → 11 → 13, 10 → 12; 20 is unrelated
+        let table: HashMap<u32, u32> = [(10, 1), (11, 10), (12, 10), (13, 11), (20, 1)]
+            .into_iter()
+            .collect();
+        assert_eq!(descendants(&table, 10), vec![10, 11, 12, 13]);
+        assert_eq!(descendants(&table, 13), vec![13]);
+    }
+
+    #[test]
+    fn find_in_tree_matches_own_process() {
+        let me = std::process::id();
+        let table = process_table();
+        let comm = read_comm(me).unwrap();
+        assert_eq!(find_in_tree(&table, me, &comm), Some(me));
+        assert_eq!(find_in_tree(&table, me, "no-such-command"), None);
+    }
+}
@@ -5,10 +5,12 @@ use std::time::Duration;
 use anyhow::{bail, Context, Result};
 
 use crate::bridge::{self, BridgeStartResult};
-use crate::config::{BotminterConfig, TeamEntry};
+use crate::config::{BotminterConfig, ForgeKind, TeamEntry};
+use crate::forge;
 use crate::formation::{self, CredentialDomain};
-use crate::git::app_auth;
+use crate::git::{self, app_auth};
 use crate::git::manifest_flow::credential_keys;
+use crate::profile;
 use crate::state::{self, MemberRuntime};
 use crate::workspace;
 
@@ -94,6 +96,10 @@ pub fn start_local_members(
     // Discover members
     let member_dirs = discover_members(team_repo, member_filter)?;
 
+    // Resource limits from the formation config (if any)
+    let limits_cfg = load_limits(team_repo, resolved_formation)?;
+    let members_dir = team_repo.join("members");
+
     // Load state, clean up stale entries
     let mut state = state::load()?;
     let stale = state::cleanup_stale(&mut state);
@@ -153,11 +159,28 @@ pub fn start_local_members(
             false
         };
 
+        // Gitea/Forgejo teams: give the workspace its bot user's git credentials.
+        if let Some(forge_cfg) = team.forge.as_ref().filter(|f| f.kind == ForgeKind::Gitea) {
+            if let Err(e) = deliver_bot_credentials(
+                app_cred_store.as_ref(),
+                &forge_cfg.url,
+                member_dir_name,
+                &ws,
+            ) {
+                eprintln!(
+                    "Warning: bot token setup failed for {}, falling back to your forge credentials: {:#}",
+                    member_dir_name, e
+                );
+            }
+        }
+
         // Resolve GitHub App credentials for this member (on-demand — Req 8).
         // If App creds exist, do JWT→token exchange, setup delivery, and use GH_CONFIG_DIR.
+        let github_host = git::GitHubHost::for_team(team);
         let gh_config_dir: Option<PathBuf> = match resolve_app_credentials_and_deliver(
             app_cred_store.as_ref(),
             local_formation.as_ref(),
+            &github_host,
             member_dir_name,
             &ws,
         ) {
@@ -175,6 +198,23 @@ pub fn start_local_members(
         // Detect brain mode (chat-first member)
         let brain_mode = formation::is_brain_member(&ws);
 
+        // Resolve resource limits for this member
+        let member_limits = limits_cfg.as_ref().and_then(|l| {
+            let role = profile::read_member_role(&members_dir, member_dir_name);
+            l.for_member(&team.name, &role, member_dir_name)
+        });
+        if let Some(secs) = member_limits.as_ref().and_then(|l| l.limits.loop_timeout_secs) {
+            if !brain_mode {
+                if let Err(e) = super::limits::apply_loop_timeout(&ws.join("ralph.yml"), secs) {
+                    result.errors.push(MemberFailed {
+                        name: member_dir_name.clone(),
+                        error: format!("failed to apply loop timeout — {}", e),
+                    });
+                    continue;
+                }
+            }
+        }
+
         // Launch ralph or brain
         let launch_result = if brain_mode {
             let system_prompt_path = ws.join("brain-prompt.md");
@@ -189,6 +229,8 @@ pub fn start_local_members(
                 operator_user_id: bridge_creds.operator_user_id.as_deref(),
                 team_repo: Some(team_repo),
                 gh_config_dir: gh_config_dir.as_deref(),
+                github_host: &github_host,
+                member_limits: member_limits.as_ref(),
             };
             formation::launch_brain(&brain_config)
         } else {
@@ -198,6 +240,8 @@ pub fn start_local_members(
                 bridge_creds.bridge_type_name.as_deref(),
                 bridge_creds.service_url.as_deref(),
                 gh_config_dir.as_deref(),
+                &github_host,
+                member_limits.as_ref(),
             )
         };
 
@@ -211,6 +255,7 @@ pub fn start_local_members(
                         started_at,
                         workspace: ws,
                         brain_mode,
+                        paused_at: None,
                     },
                 );
                 state::save(&state)?;
@@ -385,6 +430,8 @@ pub struct AppCredentialsCached {
     pub private_key: String,
     pub installation_id: u64,
     pub workspace: PathBuf,
+    /// REST API base of the team's GitHub host.
+    pub api_base: String,
 }
 
 /// Resolves App credentials from keyring, exchanges JWT for installation token,
@@ -393,6 +440,7 @@ pub struct AppCredentialsCached {
 pub(crate) fn resolve_app_credentials_and_deliver(
     store: &dyn formation::KeyValueCredentialStore,
     formation: &dyn formation::Formation,
+    host: &git::GitHubHost,
     member_name: &str,
     workspace: &Path,
 ) -> Result<Option<PathBuf>> {
@@ -417,7 +465,7 @@ pub(crate) fn resolve_app_credentials_and_deliver(
     // Generate JWT and exchange for installation token
     let jwt = app_auth::generate_jwt(&client_id, &private_key)
         .context("Failed to generate JWT for App authentication")?;
-    let inst_token = app_auth::exchange_for_installation_token(&jwt, installation_id)
+    let inst_token = app_auth::exchange_for_installation_token(&host.api_base, &jwt, installation_id)
         .context("Failed to exchange JWT for installation token")?;
 
     // Derive bot user from numeric App ID (convention: {app-id}[bot]).
@@ -430,7 +478,7 @@ pub(crate) fn resolve_app_credentials_and_deliver(
     let bot_user = format!("{}[bot]", app_id);
 
     // Setup token delivery (creates GH_CONFIG_DIR + git credential helper)
-    formation.setup_token_delivery(member_name, workspace, &bot_user)?;
+    formation.setup_token_delivery(member_name, workspace, &bot_user, &host.host)?;
 
     // Write the initial token
     formation.refresh_token(member_name, workspace, &inst_token.token)?;
@@ -439,6 +487,47 @@ pub(crate) fn resolve_app_credentials_and_deliver(
     Ok(Some(gh_config_dir))
 }
 
+/// Writes a Gitea/Forgejo member's bot token into its workspace as a git
+/// credential. Returns false if the member has no bot token.
+fn deliver_bot_credentials(
+    store: &dyn formation::KeyValueCredentialStore,
+    forge_url: &str,
+    member_name: &str,
+    workspace: &Path,
+) -> Result<bool> {
+    let (Some(bot_user), Some(token)) = (
+        store.retrieve(&credential_keys::bot_user(member_name))?,
+        store.retrieve(&credential_keys::bot_token(member_name))?,
+    ) else {
+        return Ok(false);
+    };
+    forge::deliver_bot_token(workspace, forge_url, &bot_user, &token)?;
+    Ok(true)
+}
+
+/// Loads the `limits` section of the team's formation config.
+///
+/// The daemon calls `start_local_members` without a formation name, so this
+/// resolves the default formation itself. Teams without a formations dir
+/// (v1) or without `formation.yml` have no limits.
+fn load_limits(
+    team_repo: &Path,
+    resolved_formation: Option<&str>,
+) -> Result<Option<formation::LimitsConfig>> {
+    let name = match formation::resolve_formation(team_repo, resolved_formation)? {
+        Some(name) => name,
+        None => return Ok(None),
+    };
+    if !formation::formations_dir(team_repo)
+        .join(&name)
+        .join("formation.yml")
+        .exists()
+    {
+        return Ok(None);
+    }
+    Ok(formation::load(team_repo, &name)?.limits)
+}
+
 /// Discover and filter member directories in the team repo.
 fn discover_members(team_repo: &Path, member_filter: Option<&str>) -> Result<Vec<String>> {
     let members_dir = team_repo.join("members");
@@ -544,4 +633,19 @@ mod tests {
         let err = discover_members(tmp.path(), None).unwrap_err();
         assert!(err.to_string().contains("No members hired"));
     }
+
+    #[test]
+    fn deliver_bot_credentials_requires_stored_token() {
+        let ws = tempfile::tempdir().unwrap();
+        crate::git::run_git(ws.path(), &["init", "-b", "main"]).unwrap();
+        let store = formation::InMemoryKeyValueCredentialStore::new();
+        let url = "https://forgejo.example.com";
+
+        assert!(!deliver_bot_credentials(&store, url, "dev-01", ws.path()).unwrap());
+
+        store.store(&credential_keys::bot_user("dev-01"), "team-dev-01").unwrap();
+        store.store(&credential_keys::bot_token("dev-01"), "tok").unwrap();
+        assert!(deliver_bot_credentials(&store, url, "dev-01", ws.path()).unwrap());
+        assert!(ws.path().join(".config/forge/credentials").exists());
+    }
 }
@@ -54,11 +54,14 @@ pub fn stop_local_members(
 
     // Find running members for this team
     let team_prefix = format!("{}/", team_name);
-    let all_running: Vec<(String, u32, PathBuf, bool)> = runtime_state
+    let all_running: Vec<(String, u32, PathBuf, bool, bool)> = runtime_state
         .members
         .iter()
         .filter(|(key, _)| key.starts_with(&team_prefix))
-        .map(|(key, rt)| (key.clone(), rt.pid, rt.workspace.clone(), rt.brain_mode))
+        .map(|(key, rt)| {
+            let paused = rt.paused_at.is_some();
+            (key.clone(), rt.pid, rt.workspace.clone(), rt.brain_mode, paused)
+        })
         .collect();
 
     // Filter to a single member if requested
@@ -66,7 +69,7 @@ pub fn stop_local_members(
         let target_key = format!("{}/{}", team_name, target);
         all_running
             .into_iter()
-            .filter(|(k, _, _, _)| *k == target_key)
+            .filter(|(k, _, _, _, _)| *k == target_key)
             .collect()
     } else {
         all_running
@@ -84,9 +87,15 @@ pub fn stop_local_members(
         return Ok(result);
     }
 
-    for (key, pid, workspace, brain_mode) in &running {
+    for (key, pid, workspace, brain_mode, paused) in &running {
         let member_name = key.strip_prefix(&team_prefix).unwrap_or(key);
 
+        // A paused Ralph tree is stopped (SIGSTOP) and can't act on
+        // `ralph loops stop` or SIGTERM until it is continued.
+        if *paused && !*brain_mode {
+            super::pause_members::thaw(*pid);
+        }
+
         if !state::is_alive(*pid) {
             runtime_state.members.remove(key);
             state::save(&runtime_state)?;
@@ -51,17 +51,21 @@ pub fn generate_jwt(client_id: &str, private_key_pem: &str) -> Result<String> {
 
 /// Exchanges a JWT for an installation access token.
 ///
-/// Calls `POST /app/installations/{installation_id}/access_tokens` with the JWT
-/// in the Authorization header. Returns the token and its expiration time.
+/// Calls `POST {api_base}/app/installations/{installation_id}/access_tokens`
+/// with the JWT in the Authorization header. Returns the token and its
+/// expiration time. `api_base` is `https://api.github.com` or a GHES
+/// `https://{host}/api/v3`.
 ///
 /// Note: Installation tokens MUST NOT be validated via `/user` (returns 403).
 /// Trust the exchange response.
 pub fn exchange_for_installation_token(
+    api_base: &str,
     jwt: &str,
     installation_id: u64,
 ) -> Result<InstallationToken> {
     let url = format!(
-        "https://api.github.com/app/installations/{installation_id}/access_tokens"
+        "{}/app/installations/{installation_id}/access_tokens",
+        api_base.trim_end_matches('/')
     );
 
     let client = reqwest::blocking::Client::new();
@@ -87,14 +91,48 @@ pub fn exchange_for_installation_token(
         .context("Failed to parse installation token response")
 }
 
+/// Looks up the slug of the App the JWT was minted for.
+///
+/// Calls `GET {api_base}/app`. The slug names the App's settings page
+/// (`/organizations/{org}/settings/apps/{slug}`), where private keys are
+/// generated and deleted.
+pub fn get_app_slug(api_base: &str, jwt: &str) -> Result<String> {
+    let url = format!("{}/app", api_base.trim_end_matches('/'));
+
+    let client = reqwest::blocking::Client::new();
+    let response = client
+        .get(&url)
+        .header("Authorization", format!("Bearer {jwt}"))
+        .header("Accept", "application/vnd.github+json")
+        .header("User-Agent", "botminter")
+        .header("X-GitHub-Api-Version", "2022-11-28")
+        .send()
+        .context("Failed to call GitHub App endpoint")?;
+
+    let status = response.status();
+    if !status.is_success() {
+        let body = response.text().unwrap_or_default();
+        anyhow::bail!("GitHub API returned {status} when reading the App: {body}");
+    }
+
+    let app: serde_json::Value = response.json().context("Failed to parse App response")?;
+    app["slug"]
+        .as_str()
+        .map(String::from)
+        .context("GitHub App response has no slug")
+}
+
 /// Uninstalls a GitHub App installation.
 ///
-/// Calls `DELETE /app/installations/{installation_id}` with the JWT
+/// Calls `DELETE {api_base}/app/installations/{installation_id}` with the JWT
 /// in the Authorization header. Returns Ok(()) on success (204 No Content).
 /// This removes the App's access to the organization but does NOT delete
 /// the App itself — that must be done manually via GitHub UI.
-pub fn uninstall_app(jwt: &str, installation_id: u64) -> Result<()> {
-    let url = format!("https://api.github.com/app/installations/{installation_id}");
+pub fn uninstall_app(api_base: &str, jwt: &str, installation_id: u64) -> Result<()> {
+    let url = format!(
+        "{}/app/installations/{installation_id}",
+        api_base.trim_end_matches('/')
+    );
 
     let client = reqwest::blocking::Client::new();
     let response = client
@@ -243,4 +281,54 @@ mod tests {
         let result = serde_json::from_str::<InstallationToken>(json);
         assert!(result.is_err());
     }
+
+    /// Serves one HTTP request, replying with `reply`. Returns the base URL
+    /// and a receiver for the request line and headers.
+    fn one_shot_server(reply: &'static str) -> (String, std::sync::mpsc::Receiver<String>) {
+        use std::io::{BufRead, BufReader, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let base = format!("http://{}", listener.local_addr().unwrap());
+        let (tx, rx) = std::sync::mpsc::channel();
+        std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut head = String::new();
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line.trim().is_empty() {
+                    break;
+                }
+                head.push_str(&line);
+            }
+            tx.send(head).unwrap();
+            let _ = write!(
+                stream,
+                "HTTP/1.1 201 Created\r\nContent-Type: application/json\r\n\
+                 Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+                reply.len(),
+                reply
+            );
+        });
+        (base, rx)
+    }
+
+    #[test]
+    fn exchange_uses_given_api_base() {
+        let (base, rx) = one_shot_server(
+            r#"{"token": "ghs_enterprise", "expires_at": "2030-01-01T00:00:00Z"}"#,
+        );
+        let api_base = format!("{base}/api/v3/");
+
+        let token = exchange_for_installation_token(&api_base, "jwt-123", 42).unwrap();
+        assert_eq!(token.token, "ghs_enterprise");
+
+        let head = rx.recv().unwrap();
+        assert!(
+            head.starts_with("POST /api/v3/app/installations/42/access_tokens "),
+            "{head}"
+        );
+        assert!(head.contains("Bearer jwt-123"), "{head}");
+    }
 }
@@ -0,0 +1,482 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::process::Command;
+
+use anyhow::{bail, Context, Result};
+
+use super::app_auth;
+use super::manifest_flow::{self, credential_keys};
+use super::GitHubHost;
+use crate::config::TeamEntry;
+use crate::formation::{self, CredentialDomain};
+
+/// What the team needs from a member's GitHub App.
+#[derive(Debug, Clone, Default)]
+pub struct AppRequirements {
+    /// Permission name → minimum level (`read`, `write` or `admin`).
+    pub permissions: Vec<(String, String)>,
+    /// Webhook events the App must subscribe to.
+    pub events: Vec<String>,
+    /// `owner/repo` of the team repo and every project fork.
+    pub repos: Vec<String>,
+}
+
+impl AppRequirements {
+    /// The permissions and events the manifest flow creates Apps with, and
+    /// the team repo plus every configured project fork.
+    pub fn for_team(team: &TeamEntry) -> Self {
+        Self {
+            permissions: manifest_flow::REQUIRED_PERMISSIONS
+                .iter()
+                .map(|(name, level)| (name.to_string(), level.to_string()))
+                .collect(),
+            events: manifest_flow::REQUIRED_EVENTS
+                .iter()
+                .map(|e| e.to_string())
+                .collect(),
+            repos: manifest_flow::collect_team_repos(team),
+        }
+    }
+}
+
+/// Live configuration of a member's App and its installation.
+#[derive(Debug, Clone, Default)]
+pub struct AppSnapshot {
+    pub slug: String,
+    /// Permissions the App requests.
+    pub app_permissions: BTreeMap<String, String>,
+    /// Events the App subscribes to.
+    pub app_events: Vec<String>,
+    /// Permissions the organization granted the installation. Lags behind
+    /// `app_permissions` until an owner accepts the App's new request.
+    pub installation_permissions: BTreeMap<String, String>,
+    /// Login of the account the App is installed on.
+    pub account: String,
+    /// `all` or `selected`.
+    pub repository_selection: String,
+    /// `owner/repo` the installation can reach.
+    pub repos: Vec<String>,
+    pub suspended: bool,
+}
+
+/// One way a member's App differs from what the team needs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Drift {
+    /// The App does not request a permission, or requests a lower level.
+    AppPermission {
+        name: String,
+        required: String,
+        actual: Option<String>,
+    },
+    /// The App requests the permission but the installation has not been
+    /// granted it — an owner must accept the updated request.
+    UnacceptedPermission {
+        name: String,
+        required: String,
+        granted: Option<String>,
+    },
+    /// The App does not subscribe to a required event.
+    MissingEvent(String),
+    /// The installation cannot reach a team or project repo.
+    MissingRepo(String),
+    /// The installation is suspended.
+    Suspended,
+}
+
+impl fmt::Display for Drift {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Drift::AppPermission {
+                name,
+                required,
+                actual,
+            } => write!(
+                f,
+                "App permission '{}' is {}, needs {}",
+                name,
+                actual.as_deref().unwrap_or("not requested"),
+                required
+            ),
+            Drift::UnacceptedPermission {
+                name,
+                required,
+                granted,
+            } => write!(
+                f,
+                "installation has '{}' {}, needs {} (updated permissions not accepted)",
+                name,
+                granted.as_deref().unwrap_or("not granted"),
+                required
+            ),
+            Drift::MissingEvent(event) => write!(f, "App does not subscribe to '{}'", event),
+            Drift::MissingRepo(repo) => write!(f, "installation cannot access {}", repo),
+            Drift::Suspended => write!(f, "installation is suspended"),
+        }
+    }
+}
+
+/// Drift found for one member.
+#[derive(Debug, Clone)]
+pub struct MemberDrift {
+    pub member: String,
+    pub slug: String,
+    pub account: String,
+    pub installation_id: u64,
+    pub drift: Vec<Drift>,
+}
+
+impl MemberDrift {
+    /// Where in the GitHub UI an operator fixes a finding.
+    pub fn repair_url(&self, host: &GitHubHost, drift: &Drift) -> String {
+        match drift {
+            Drift::AppPermission { .. } | Drift::MissingEvent(_) => format!(
+                "{}/organizations/{}/settings/apps/{}/permissions",
+                host.web_base(),
+                self.account,
+                self.slug
+            ),
+            Drift::UnacceptedPermission { .. } | Drift::MissingRepo(_) | Drift::Suspended => {
+                format!(
+                    "{}/organizations/{}/settings/installations/{}",
+                    host.web_base(),
+                    self.account,
+                    self.installation_id
+                )
+            }
+        }
+    }
+}
+
+/// Compares a live App against the team's requirements.
+pub fn detect(required: &AppRequirements, snapshot: &AppSnapshot) -> Vec<Drift> {
+    let mut drift = Vec::new();
+    if snapshot.suspended {
+        drift.push(Drift::Suspended);
+    }
+
+    for (name, level) in &required.permissions {
+        let actual = snapshot.app_permissions.get(name);
+        if permission_rank(actual.map(String::as_str)) < permission_rank(Some(level.as_str())) {
+            drift.push(Drift::AppPermission {
+                name: name.clone(),
+                required: level.clone(),
+                actual: actual.cloned(),
+            });
+            continue;
+        }
+        let granted = snapshot.installation_permissions.get(name);
+        if permission_rank(granted.map(String::as_str)) < permission_rank(Some(level.as_str())) {
+            drift.push(Drift::UnacceptedPermission {
+                name: name.clone(),
+                required: level.clone(),
+                granted: granted.cloned(),
+            });
+        }
+    }
+
+    for event in &required.events {
+        if !snapshot.app_events.contains(event) {
+            drift.push(Drift::MissingEvent(event.clone()));
+        }
+    }
+
+    for repo in &required.repos {
+        let reachable = if snapshot.repository_selection == "all" {
+            repo.split('/')
+                .next()
+                .is_some_and(|owner| owner.eq_ignore_ascii_case(&snapshot.account))
+        } else {
+            snapshot.repos.iter().any(|r| r.eq_ignore_ascii_case(repo))
+        };
+        if !reachable {
+            drift.push(Drift::MissingRepo(repo.clone()));
+        }
+    }
+
+    drift
+}
+
+fn permission_rank(level: Option<&str>) -> u8 {
+    match level {
+        Some("read") => 1,
+        Some("write") => 2,
+        Some("admin") => 3,
+        _ => 0,
+    }
+}
+
+/// Checks a member's App against the team's requirements.
+///
+/// Returns `None` if the member has no App credentials (Gitea/Forgejo bot
+/// users, or members hired before Apps).
+pub fn check_member(team: &TeamEntry, member: &str) -> Result<Option<MemberDrift>> {
+    let formation = formation::create_local_formation(&team.name)?;
+    let store = formation.credential_store(CredentialDomain::GitHubApp {
+        team_name: team.name.clone(),
+        member_name: member.to_string(),
+    })?;
+    let client_id = store.retrieve(&credential_keys::client_id(member))?;
+    let private_key = store.retrieve(&credential_keys::private_key(member))?;
+    let installation_id = store.retrieve(&credential_keys::installation_id(member))?;
+    let (Some(client_id), Some(private_key), Some(installation_id)) =
+        (client_id, private_key, installation_id)
+    else {
+        return Ok(None);
+    };
+    let installation_id: u64 = installation_id
+        .parse()
+        .context("Stored installation ID is not a number")?;
+
+    let host = GitHubHost::for_team(team);
+    let snapshot = fetch_snapshot(&host, &client_id, &private_key, installation_id)
+        .with_context(|| format!("Failed to read GitHub App of '{}'", member))?;
+    Ok(Some(MemberDrift {
+        member: member.to_string(),
+        drift: detect(&AppRequirements::for_team(team), &snapshot),
+        slug: snapshot.slug,
+        account: snapshot.account,
+        installation_id,
+    }))
+}
+
+/// Reads the App, its installation, and the repos the installation can reach.
+pub fn fetch_snapshot(
+    host: &GitHubHost,
+    client_id: &str,
+    private_key: &str,
+    installation_id: u64,
+) -> Result<AppSnapshot> {
+    let jwt = app_auth::generate_jwt(client_id, private_key)?;
+    let app = github_get(&format!("{}/app", host.api_base), &jwt)?;
+    let installation = github_get(
+        &format!("{}/app/installations/{}", host.api_base, installation_id),
+        &jwt,
+    )?;
+
+    let token = app_auth::exchange_for_installation_token(&host.api_base, &jwt, installation_id)?;
+    let mut repos = Vec::new();
+    for page in 1.. {
+        let resp = github_get(
+            &format!(
+                "{}/installation/repositories?per_page=100&page={}",
+                host.api_base, page
+            ),
+            &token.token,
+        )?;
+        let batch = resp["repositories"].as_array().cloned().unwrap_or_default();
+        repos.extend(
+            batch
+                .iter()
+                .filter_map(|r| r["full_name"].as_str().map(String::from)),
+        );
+        let total = resp["total_count"].as_u64().unwrap_or_default() as usize;
+        if batch.len() < 100 || repos.len() >= total {
+            break;
+        }
+    }
+
+    Ok(snapshot_from_json(&app, &installation, repos))
+}
+
+fn snapshot_from_json(
+    app: &serde_json::Value,
+    installation: &serde_json::Value,
+    repos: Vec<String>,
+) -> AppSnapshot {
+    AppSnapshot {
+        slug: app["slug"].as_str().unwrap_or_default().to_string(),
+        app_permissions: string_map(&app["permissions"]),
+        app_events: string_list(&app["events"]),
+        installation_permissions: string_map(&installation["permissions"]),
+        account: installation["account"]["login"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string(),
+        repository_selection: installation["repository_selection"]
+            .as_str()
+            .unwrap_or("selected")
+            .to_string(),
+        repos,
+        suspended: !installation["suspended_at"].is_null(),
+    }
+}
+
+fn string_map(value: &serde_json::Value) -> BTreeMap<String, String> {
+    value
+        .as_object()
+        .map(|obj| {
+            obj.iter()
+                .filter_map(|(k, v)| v.as_str().map(|v| (k.clone(), v.to_string())))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn string_list(value: &serde_json::Value) -> Vec<String> {
+    value
+        .as_array()
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(|v| v.as_str().map(String::from))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn github_get(url: &str, bearer: &str) -> Result<serde_json::Value> {
+    let response = reqwest::blocking::Client::new()
+        .get(url)
+        .header("Authorization", format!("Bearer {bearer}"))
+        .header("Accept", "application/vnd.github+json")
+        .header("User-Agent", "botminter")
+        .header("X-GitHub-Api-Version", "2022-11-28")
+        .send()
+        .with_context(|| format!("Failed to call {url}"))?;
+
+    let status = response.status();
+    if !status.is_success() {
+        let body = response.text().unwrap_or_default();
+        bail!("GitHub API returned {status} for {url}: {body}");
+    }
+    response
+        .json()
+        .with_context(|| format!("Failed to parse response from {url}"))
+}
+
+/// Adds a repo to an installation with `repository_selection: selected`.
+///
+/// GitHub only accepts this from a user token of an organization owner, so
+/// it runs through `gh` with the operator's credentials.
+pub fn grant_repo(host: &GitHubHost, installation_id: u64, repo: &str) -> Result<()> {
+    let repo_id = gh_api(host, &["api", &format!("repos/{repo}"), "--jq", ".id"])?;
+    gh_api(
+        host,
+        &[
+            "api",
+            "-X",
+            "PUT",
+            &format!("user/installations/{installation_id}/repositories/{repo_id}"),
+            "--silent",
+        ],
+    )?;
+    Ok(())
+}
+
+fn gh_api(host: &GitHubHost, args: &[&str]) -> Result<String> {
+    let mut cmd = Command::new("gh");
+    cmd.args(args);
+    host.apply_detected_token(&mut cmd);
+    let output = cmd.output().context("Failed to run `gh api`")?;
+    if !output.status.success() {
+        bail!(
+            "gh {} failed: {}",
+            args[1..].join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn requirements() -> AppRequirements {
+        AppRequirements {
+            permissions: vec![
+                ("issues".into(), "write".into()),
+                ("organization_projects".into(), "admin".into()),
+            ],
+            events: vec![],
+            repos: vec!["acme/team".into(), "acme/app".into()],
+        }
+    }
+
+    fn healthy() -> AppSnapshot {
+        snapshot_from_json(
+            &serde_json::json!({
+                "slug": "team-superman",
+                "permissions": {"issues": "write", "organization_projects": "admin"},
+                "events": [],
+            }),
+            &serde_json::json!({
+                "account": {"login": "acme"},
+                "repository_selection": "selected",
+                "permissions": {"issues": "write", "organization_projects": "admin"},
+                "suspended_at": null,
+            }),
+            vec!["acme/team".into(), "acme/app".into()],
+        )
+    }
+
+    #[test]
+    fn matching_app_has_no_drift() {
+        assert!(detect(&requirements(), &healthy()).is_empty());
+    }
+
+    #[test]
+    fn new_project_fork_is_missing_repo() {
+        let mut req = requirements();
+        req.repos.push("acme/new-fork".into());
+        assert_eq!(
+            detect(&req, &healthy()),
+            vec![Drift::MissingRepo("acme/new-fork".into())]
+        );
+
+        let mut all = healthy();
+        all.repository_selection = "all".into();
+        all.repos.clear();
+        req.repos.push("other-org/fork".into());
+        assert_eq!(
+            detect(&req, &all),
+            vec![Drift::MissingRepo("other-org/fork".into())]
+        );
+    }
+
+    #[test]
+    fn downgraded_permissions_are_reported_by_where_they_lag() {
+        let mut snap = healthy();
+        snap.app_permissions.insert("issues".into(), "read".into());
+        snap.installation_permissions
+            .insert("organization_projects".into(), "write".into());
+        snap.suspended = true;
+
+        assert_eq!(
+            detect(&requirements(), &snap),
+            vec![
+                Drift::Suspended,
+                Drift::AppPermission {
+                    name: "issues".into(),
+                    required: "write".into(),
+                    actual: Some("read".into()),
+                },
+                Drift::UnacceptedPermission {
+                    name: "organization_projects".into(),
+                    required: "admin".into(),
+                    granted: Some("write".into()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn repair_url_points_at_app_or_installation() {
+        let member = MemberDrift {
+            member: "superman".into(),
+            slug: "team-superman".into(),
+            account: "acme".into(),
+            installation_id: 42,
+            drift: vec![],
+        };
+        let host = GitHubHost::default();
+        assert_eq!(
+            member.repair_url(&host, &Drift::MissingEvent("issues".into())),
+            "https://github.com/organizations/acme/settings/apps/team-superman/permissions"
+        );
+        assert_eq!(
+            member.repair_url(&host, &Drift::MissingRepo("acme/app".into())),
+            "https://github.com/organizations/acme/settings/installations/42"
+        );
+    }
+}
@@ -3,15 +3,9 @@ use std::process::Command;
 
 use anyhow::{bail, Context, Result};
 
+use super::GitHubHost;
 use crate::profile;
 
-/// Applies the detected GH_TOKEN to a Command, if available.
-fn apply_detected_token(cmd: &mut Command) {
-    if let Some(token) = detect_token() {
-        cmd.env("GH_TOKEN", token);
-    }
-}
-
 /// Derives the project name from a git URL (basename minus .git suffix).
 pub fn derive_project_name(url: &str) -> String {
     let url = url.trim_end_matches('/');
@@ -23,7 +17,7 @@ pub fn derive_project_name(url: &str) -> String {
 ///
 /// Rejects local paths — workspace repos need remote URLs so they can be cloned
 /// on any machine. Runs `gh repo view` to verify the repo exists and is accessible.
-pub fn verify_fork_url(url: &str) -> Result<()> {
+pub fn verify_fork_url(host: &GitHubHost, url: &str) -> Result<()> {
     if url.starts_with("file://") {
         // file:// URI — check the local path exists and is a git repo
         let path_str = url.strip_prefix("file://").unwrap();
@@ -47,7 +41,7 @@ pub fn verify_fork_url(url: &str) -> Result<()> {
 
     let mut cmd = Command::new("gh");
     cmd.args(["repo", "view", url, "--json", "name"]);
-    apply_detected_token(&mut cmd);
+    host.apply_detected_token(&mut cmd);
     let output = cmd.output().context("Failed to run `gh repo view`")?;
     if !output.status.success() {
         bail!(
@@ -62,6 +56,7 @@ pub fn verify_fork_url(url: &str) -> Result<()> {
 
 /// Creates a single label on a GitHub repo. Idempotent (uses --force).
 pub fn create_github_label(
+    host: &GitHubHost,
     repo: &str,
     name: &str,
     color: &str,
@@ -81,7 +76,7 @@ pub fn create_github_label(
         repo,
     ]);
 
-    apply_detected_token(&mut cmd);
+    host.apply_detected_token(&mut cmd);
 
     let output = cmd.output().with_context(|| {
         format!("Failed to create label '{}'", name)
@@ -101,6 +96,7 @@ pub fn create_github_label(
 
 /// Finds a GitHub Project by title for the given owner. Returns the project number.
 pub fn find_project_number(
+    host: &GitHubHost,
     owner: &str,
     team_name: &str,
 ) -> Result<u64> {
@@ -114,7 +110,7 @@ pub fn find_project_number(
         "--format",
         "json",
     ]);
-    apply_detected_token(&mut cmd);
+    host.apply_detected_token(&mut cmd);
     let output = cmd
         .output()
         .context("Failed to run `gh project list`")?;
@@ -141,6 +137,7 @@ pub fn find_project_number(
 /// Finds the built-in Status field ID and updates its options via GraphQL.
 /// This replaces the default (Todo/In Progress/Done) with profile-defined statuses.
 pub fn sync_project_status_field(
+    host: &GitHubHost,
     owner: &str,
     project_number: u64,
     statuses: &[profile::StatusDef],
@@ -158,7 +155,7 @@ pub fn sync_project_status_field(
         "--format",
         "json",
     ]);
-    apply_detected_token(&mut cmd);
+    host.apply_detected_token(&mut cmd);
     let output = cmd
         .output()
         .context("Failed to run `gh project field-list`")?;
@@ -205,7 +202,7 @@ pub fn sync_project_status_field(
 
     let mut cmd = Command::new("gh");
     cmd.args(["api", "graphql", "-f", &format!("query={}", mutation)]);
-    apply_detected_token(&mut cmd);
+    host.apply_detected_token(&mut cmd);
     let output = cmd
         .output()
         .context("Failed to run GraphQL updateProjectV2Field")?;
@@ -252,19 +249,19 @@ pub struct TokenInfo {
 }
 
 /// Checks if a GitHub repository exists and is accessible.
-pub fn repo_exists(repo_name: &str) -> Result<bool> {
+pub fn repo_exists(host: &GitHubHost, repo_name: &str) -> Result<bool> {
     let mut cmd = Command::new("gh");
     cmd.args(["repo", "view", repo_name, "--json", "name"]);
-    apply_detected_token(&mut cmd);
+    host.apply_detected_token(&mut cmd);
     let output = cmd.output().context("Failed to run `gh repo view`")?;
     Ok(output.status.success())
 }
 
 /// Deletes a GitHub repo.
-pub fn delete_repo(repo_name: &str) -> Result<()> {
+pub fn delete_repo(host: &GitHubHost, repo_name: &str) -> Result<()> {
     let mut cmd = Command::new("gh");
     cmd.args(["repo", "delete", repo_name, "--yes"]);
-    apply_detected_token(&mut cmd);
+    host.apply_detected_token(&mut cmd);
     let output = cmd.output().context("Failed to run `gh repo delete`")?;
 
     if !output.status.success() {
@@ -281,6 +278,7 @@ pub fn delete_repo(repo_name: &str) -> Result<()> {
 /// `gh repo create --source .` (creates repo + adds remote), then waits
 /// for the repo to be visible before pushing.
 pub fn create_repo_and_push(
+    host: &GitHubHost,
     local_repo: &Path,
     repo_name: &str,
 ) -> Result<()> {
@@ -289,7 +287,7 @@ pub fn create_repo_and_push(
     let mut cmd = Command::new("gh");
     cmd.args(["repo", "create", repo_name, "--private", "--source", "."])
         .current_dir(local_repo);
-    apply_detected_token(&mut cmd);
+    host.apply_detected_token(&mut cmd);
     let output = cmd.output().context("Failed to run `gh repo create`")?;
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
@@ -304,14 +302,14 @@ pub fn create_repo_and_push(
 
     // Ensure remote uses HTTPS — gh may set an SSH URL based on the
     // user's git_protocol config, which breaks token-based auth in VMs.
-    let https_url = format!("https://github.com/{}.git", repo_name);
+    let https_url = host.clone_url(repo_name);
     super::run_git(local_repo, &["remote", "set-url", "origin", &https_url])?;
 
     // Phase 2: Wait for the repo to be available before pushing.
     // GitHub API returns success before the repo is fully propagated to
     // git infrastructure — pushing immediately fails with "not found".
     for attempt in 1..=30 {
-        if repo_exists(repo_name)? {
+        if repo_exists(host, repo_name)? {
             break;
         }
         if attempt == 30 {
@@ -344,12 +342,12 @@ pub fn create_repo_and_push(
 }
 
 /// Clones an existing GitHub repo into `{parent_dir}/team/`.
-pub fn clone_repo(parent_dir: &Path, repo_name: &str) -> Result<()> {
+pub fn clone_repo(host: &GitHubHost, parent_dir: &Path, repo_name: &str) -> Result<()> {
     let target = parent_dir.join("team");
     let mut cmd = Command::new("gh");
     cmd.args(["repo", "clone", repo_name, &target.to_string_lossy()]);
 
-    apply_detected_token(&mut cmd);
+    host.apply_detected_token(&mut cmd);
 
     let output = cmd.output().context("Failed to run `gh repo clone`")?;
 
@@ -368,14 +366,14 @@ pub fn clone_repo(parent_dir: &Path, repo_name: &str) -> Result<()> {
 
     // Ensure remote uses HTTPS — gh may set an SSH URL based on the
     // user's git_protocol config, which breaks token-based auth in VMs.
-    let https_url = format!("https://github.com/{}.git", repo_name);
+    let https_url = host.clone_url(repo_name);
     super::run_git(&target, &["remote", "set-url", "origin", &https_url])?;
 
     Ok(())
 }
 
 /// Lists repository names for a given GitHub owner (org or user).
-pub fn list_repos(owner: &str) -> Result<Vec<String>> {
+pub fn list_repos(host: &GitHubHost, owner: &str) -> Result<Vec<String>> {
     let mut cmd = Command::new("gh");
     cmd.args([
             "repo", "list", owner,
@@ -383,7 +381,7 @@ pub fn list_repos(owner: &str) -> Result<Vec<String>> {
             "--json", "name",
             "--jq", ".[].name",
         ]);
-    apply_detected_token(&mut cmd);
+    host.apply_detected_token(&mut cmd);
     let output = cmd.output()
         .context("Failed to list repos")?;
 
@@ -397,12 +395,12 @@ pub fn list_repos(owner: &str) -> Result<Vec<String>> {
 }
 
 /// Lists GitHub Project boards for a given owner. Returns `(number, title)` pairs.
-pub fn list_projects(owner: &str) -> Result<Vec<(u64, String)>> {
+pub fn list_projects(host: &GitHubHost, owner: &str) -> Result<Vec<(u64, String)>> {
     let mut cmd = Command::new("gh");
     cmd.args([
             "project", "list", "--owner", owner, "--format", "json",
         ]);
-    apply_detected_token(&mut cmd);
+    host.apply_detected_token(&mut cmd);
     let output = cmd.output()
         .context("Failed to run `gh project list`")?;
 
@@ -431,8 +429,111 @@ pub fn list_projects(owner: &str) -> Result<Vec<(u64, String)>> {
         .collect())
 }
 
+/// Looks up the node ID of a GitHub Project board (`PVT_…`), which
+/// `projects_v2_item` webhook payloads carry instead of the board number.
+pub fn project_node_id(host: &GitHubHost, owner: &str, project_number: u64) -> Result<String> {
+    let number = project_number.to_string();
+    let mut cmd = Command::new("gh");
+    cmd.args([
+        "project", "view", &number, "--owner", owner, "--format", "json", "--jq", ".id",
+    ]);
+    host.apply_detected_token(&mut cmd);
+    let output = cmd
+        .output()
+        .context("Failed to run `gh project view`")?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        bail!("gh project view failed: {}", stderr.trim());
+    }
+
+    let id = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    if id.is_empty() {
+        bail!("Project {} of '{}' has no node ID", project_number, owner);
+    }
+    Ok(id)
+}
+
+/// An item on a GitHub Project board (issue, pull request or draft).
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ProjectItem {
+    pub title: String,
+    /// Value of the board's Status field, if set.
+    pub status: Option<String>,
+    /// `Issue`, `PullRequest` or `DraftIssue`.
+    pub item_type: String,
+    /// Issue/PR number (absent for drafts).
+    pub number: Option<u64>,
+    pub repository: Option<String>,
+    pub url: Option<String>,
+    pub assignees: Vec<String>,
+    pub labels: Vec<String>,
+}
+
+/// Lists items on a GitHub Project board via `gh project item-list`.
+pub fn list_project_items(host: &GitHubHost, owner: &str, project_number: u64, limit: usize) -> Result<Vec<ProjectItem>> {
+    let number = project_number.to_string();
+    let limit = limit.to_string();
+    let mut cmd = Command::new("gh");
+    cmd.args([
+        "project", "item-list", &number,
+        "--owner", owner,
+        "--limit", &limit,
+        "--format", "json",
+    ]);
+    host.apply_detected_token(&mut cmd);
+    let output = cmd
+        .output()
+        .context("Failed to run `gh project item-list`")?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        bail!("gh project item-list failed: {}", stderr.trim());
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    parse_project_items(host, stdout.trim())
+}
+
+fn parse_project_items(host: &GitHubHost, json_output: &str) -> Result<Vec<ProjectItem>> {
+    let json: serde_json::Value =
+        serde_json::from_str(json_output).context("Could not parse project item JSON")?;
+
+    let strings = |v: &serde_json::Value| -> Vec<String> {
+        v.as_array()
+            .map(|a| a.iter().filter_map(|s| s.as_str().map(String::from)).collect())
+            .unwrap_or_default()
+    };
+
+    Ok(json["items"]
+        .as_array()
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+        .iter()
+        .map(|item| {
+            let content = &item["content"];
+            ProjectItem {
+                title: item["title"]
+                    .as_str()
+                    .or_else(|| content["title"].as_str())
+                    .unwrap_or_default()
+                    .to_string(),
+                status: item["status"].as_str().map(String::from),
+                item_type: content["type"].as_str().unwrap_or("DraftIssue").to_string(),
+                number: content["number"].as_u64(),
+                repository: content["repository"]
+                    .as_str()
+                    .or_else(|| item["repository"].as_str())
+                    .map(|r| host.owner_repo(r).unwrap_or_else(|| r.to_string())),
+                url: content["url"].as_str().map(String::from),
+                assignees: strings(&item["assignees"]),
+                labels: strings(&item["labels"]),
+            }
+        })
+        .collect())
+}
+
 /// Creates a GitHub Project (v2), syncs the Status field options, and returns the project number.
 pub fn create_project(
+    host: &GitHubHost,
     owner: &str,
     title: &str,
     statuses: &[profile::StatusDef],
@@ -443,7 +544,7 @@ pub fn create_project(
         "--title", title,
         "--format", "json",
     ]);
-    apply_detected_token(&mut cmd);
+    host.apply_detected_token(&mut cmd);
     let output = cmd.output().context("Failed to run `gh project create`")?;
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
@@ -457,7 +558,7 @@ pub fn create_project(
         .as_u64()
         .context("Could not find 'number' field in gh project create output")?;
 
-    sync_project_status_field(owner, project_number, statuses)?;
+    sync_project_status_field(host, owner, project_number, statuses)?;
 
     Ok(project_number)
 }
@@ -465,37 +566,19 @@ pub fn create_project(
 /// Bootstraps labels on a GitHub repo from profile label definitions.
 /// Idempotent — uses `--force` per label.
 pub fn bootstrap_labels(
+    host: &GitHubHost,
     repo: &str,
     labels: &[profile::LabelDef],
 ) -> Result<()> {
     for label in labels {
-        create_github_label(repo, &label.name, &label.color, &label.description)?;
+        create_github_label(host, repo, &label.name, &label.color, &label.description)?;
     }
     Ok(())
 }
 
-/// Detects GH_TOKEN from environment or `gh auth token` (no interactive prompt).
-pub fn detect_token_non_interactive() -> Result<String> {
-    if let Ok(token) = std::env::var("GH_TOKEN") {
-        if !token.is_empty() {
-            return Ok(token);
-        }
-    }
-
-    let output = Command::new("gh")
-        .args(["auth", "token"])
-        .output()
-        .ok()
-        .filter(|o| o.status.success())
-        .map(|o| String::from_utf8_lossy(&o.stdout).trim().to_string())
-        .filter(|t| !t.is_empty());
-
-    match output {
-        Some(token) => Ok(token),
-        None => bail!(
-            "No GitHub token found. Set GH_TOKEN or run `gh auth login` before using --non-interactive."
-        ),
-    }
+/// Detects a token for `host` from environment or `gh auth token` (no interactive prompt).
+pub fn detect_token_non_interactive(host: &GitHubHost) -> Result<String> {
+    host.require_token()
 }
 
 /// Detects an existing GH_TOKEN from environment or `gh auth token`.
@@ -517,10 +600,11 @@ pub fn detect_token() -> Option<String> {
 
 /// Validates that a GitHub token works by calling `gh api user`.
 /// Returns the authenticated user's login on success.
-pub fn validate_token(token: &str) -> Result<TokenInfo> {
-    let output = Command::new("gh")
-        .args(["api", "user", "--jq", ".login"])
-        .env("GH_TOKEN", token)
+pub fn validate_token(host: &GitHubHost, token: &str) -> Result<TokenInfo> {
+    let mut cmd = Command::new("gh");
+    cmd.args(["api", "user", "--jq", ".login"]);
+    host.apply(&mut cmd, token);
+    let output = cmd
         .output()
         .context("Failed to run `gh api user` for token validation")?;
 
@@ -529,9 +613,10 @@ pub fn validate_token(token: &str) -> Result<TokenInfo> {
         bail!(
             "GitHub token validation failed: {}\n\n\
              Make sure your token is valid and not expired.\n\
-             To create a new token, visit: https://github.com/settings/tokens\n\
+             To create a new token, visit: {}/settings/tokens\n\
              Required permissions: Contents (Write), Issues (Write), Projects (Admin)",
             stderr.trim(),
+            host.web_base(),
         );
     }
 
@@ -548,10 +633,10 @@ pub fn mask_token(token: &str) -> String {
 }
 
 /// Returns the authenticated GitHub user's login.
-pub fn get_user_login() -> Result<String> {
+pub fn get_user_login(host: &GitHubHost) -> Result<String> {
     let mut cmd = Command::new("gh");
     cmd.args(["api", "user", "--jq", ".login"]);
-    apply_detected_token(&mut cmd);
+    host.apply_detected_token(&mut cmd);
     let output = cmd.output()
         .context("Failed to get GitHub user")?;
     Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
@@ -559,10 +644,10 @@ pub fn get_user_login() -> Result<String> {
 
 /// Lists the authenticated user's GitHub organizations.
 /// May return empty if the token lacks the Organization:Read scope.
-pub fn list_user_orgs() -> Result<Vec<String>> {
+pub fn list_user_orgs(host: &GitHubHost) -> Result<Vec<String>> {
     let mut cmd = Command::new("gh");
     cmd.args(["api", "user/orgs", "--jq", ".[].login"]);
-    apply_detected_token(&mut cmd);
+    host.apply_detected_token(&mut cmd);
     let output = cmd.output()
         .context("Failed to list GitHub orgs")?;
     let stdout = String::from_utf8_lossy(&output.stdout);
@@ -610,7 +695,7 @@ mod tests {
 
     #[test]
     fn verify_fork_url_rejects_bare_local_path() {
-        let result = verify_fork_url("/tmp/some-repo");
+        let result = verify_fork_url(&GitHubHost::default(), "/tmp/some-repo");
         assert!(result.is_err());
         let err = result.unwrap_err().to_string();
         assert!(err.contains("must use a URI scheme"), "{}", err);
@@ -618,7 +703,7 @@ mod tests {
 
     #[test]
     fn verify_fork_url_rejects_relative_path() {
-        let result = verify_fork_url("../my-project");
+        let result = verify_fork_url(&GitHubHost::default(), "../my-project");
         assert!(result.is_err());
         let err = result.unwrap_err().to_string();
         assert!(err.contains("must use a URI scheme"), "{}", err);
@@ -626,7 +711,7 @@ mod tests {
 
     #[test]
     fn verify_fork_url_rejects_dot_path() {
-        let result = verify_fork_url("./local-repo");
+        let result = verify_fork_url(&GitHubHost::default(), "./local-repo");
         assert!(result.is_err());
         let err = result.unwrap_err().to_string();
         assert!(err.contains("must use a URI scheme"), "{}", err);
@@ -639,12 +724,12 @@ mod tests {
         std::fs::create_dir_all(&repo).unwrap();
         Command::new("git").args(["init", "-b", "main"]).current_dir(&repo).output().unwrap();
         let url = format!("file://{}", repo.to_string_lossy());
-        assert!(verify_fork_url(&url).is_ok());
+        assert!(verify_fork_url(&GitHubHost::default(), &url).is_ok());
     }
 
     #[test]
     fn verify_fork_url_rejects_nonexistent_file_uri() {
-        let result = verify_fork_url("file:///tmp/does-not-exist-repo-xyz");
+        let result = verify_fork_url(&GitHubHost::default(), "file:///tmp/does-not-exist-repo-xyz");
         assert!(result.is_err());
         let err = result.unwrap_err().to_string();
         assert!(err.contains("not found") || err.contains("not a git repository"), "{}", err);
@@ -689,6 +774,29 @@ mod tests {
         assert!(parsed["number"].as_u64().is_none());
     }
 
+    // ── project item parsing ─────────────────────────────────────
+
+    #[test]
+    fn parse_project_items_from_gh_output() {
+        let json_output = r#"{"items":[
+            {"id":"PVTI_1","title":"Add login","status":"dev:implement",
+             "assignees":["bob"],"labels":["kind/story"],
+             "repository":"https://github.com/org/app",
+             "content":{"type":"Issue","number":7,"title":"Add login",
+                        "repository":"org/app","url":"https://github.com/org/app/issues/7"}},
+            {"id":"PVTI_2","title":"Idea","content":{"type":"DraftIssue","title":"Idea"}}
+        ],"totalCount":2}"#;
+        let items = parse_project_items(&GitHubHost::default(), json_output).unwrap();
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].status.as_deref(), Some("dev:implement"));
+        assert_eq!(items[0].number, Some(7));
+        assert_eq!(items[0].repository.as_deref(), Some("org/app"));
+        assert_eq!(items[0].assignees, vec!["bob"]);
+        assert_eq!(items[1].item_type, "DraftIssue");
+        assert!(items[1].status.is_none());
+        assert!(items[1].number.is_none());
+    }
+
     // ── list_repos parsing ───────────────────────────────────────
 
     #[test]
@@ -0,0 +1,275 @@
+use std::process::Command;
+
+use anyhow::{bail, Result};
+
+use crate::config::{ForgeConfig, ForgeKind, TeamEntry};
+
+/// The public GitHub host.
+pub const GITHUB_COM: &str = "github.com";
+
+/// A GitHub instance: github.com or a GitHub Enterprise Server host.
+///
+/// Every GitHub touchpoint (the `gh` CLI, clone URLs, REST calls, App
+/// manifest URLs) resolves its endpoints through this type so that a team
+/// on a GitHub Enterprise Server host never talks to github.com.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GitHubHost {
+    /// Hostname without scheme, e.g. `github.com` or `github.acme.corp`.
+    pub host: String,
+    /// REST API base URL without trailing slash.
+    pub api_base: String,
+}
+
+impl Default for GitHubHost {
+    fn default() -> Self {
+        Self {
+            host: GITHUB_COM.to_string(),
+            api_base: "https://api.github.com".to_string(),
+        }
+    }
+}
+
+impl GitHubHost {
+    /// Builds a host from a hostname and an optional API base override.
+    ///
+    /// GHES serves its REST API under `https://{host}/api/v3`; that is the
+    /// default when no override is given.
+    pub fn new(host: Option<&str>, api_base: Option<&str>) -> Self {
+        let host = host
+            .map(normalize_host)
+            .filter(|h| !h.is_empty())
+            .unwrap_or_else(|| GITHUB_COM.to_string());
+        let api_base = match api_base {
+            Some(base) => base.trim_end_matches('/').to_string(),
+            None if host == GITHUB_COM => "https://api.github.com".to_string(),
+            None => format!("https://{}/api/v3", host),
+        };
+        Self { host, api_base }
+    }
+
+    /// The host a team's GitHub repos live on: the GHES host recorded as the
+    /// team's forge, or github.com.
+    pub fn for_team(team: &TeamEntry) -> Self {
+        match &team.forge {
+            Some(forge) if forge.kind == ForgeKind::GitHub => {
+                Self::new(Some(&forge.url), forge.api_base.as_deref())
+            }
+            _ => Self::default(),
+        }
+    }
+
+    /// The team forge entry recording this host. `None` for github.com with
+    /// the default API base, which needs no entry.
+    pub fn forge_config(&self) -> Option<ForgeConfig> {
+        if *self == Self::default() {
+            return None;
+        }
+        let derived = Self::new(Some(&self.host), None).api_base;
+        Some(ForgeConfig {
+            kind: ForgeKind::GitHub,
+            url: self.web_base(),
+            api_base: (self.api_base != derived).then(|| self.api_base.clone()),
+        })
+    }
+
+    /// Whether this is a GitHub Enterprise Server instance.
+    pub fn is_enterprise(&self) -> bool {
+        self.host != GITHUB_COM
+    }
+
+    /// Web base URL, e.g. `https://github.acme.corp`.
+    pub fn web_base(&self) -> String {
+        format!("https://{}", self.host)
+    }
+
+    /// Browser URL of an `owner/repo`.
+    pub fn repo_url(&self, repo: &str) -> String {
+        format!("{}/{}", self.web_base(), repo)
+    }
+
+    /// HTTPS clone URL of an `owner/repo`.
+    pub fn clone_url(&self, repo: &str) -> String {
+        format!("{}/{}.git", self.web_base(), repo)
+    }
+
+    /// Browser URL of an organization's Projects v2 board.
+    pub fn project_url(&self, owner: &str, number: u64) -> String {
+        format!("{}/orgs/{}/projects/{}", self.web_base(), owner, number)
+    }
+
+    /// Converts a repo URL on this host to `owner/repo`.
+    /// Returns `None` for URLs on other hosts.
+    pub fn owner_repo(&self, url: &str) -> Option<String> {
+        let prefix = format!("{}/", self.web_base());
+        let repo = url
+            .strip_prefix(&prefix)?
+            .trim_end_matches('/')
+            .trim_end_matches(".git");
+        if repo.is_empty() || !repo.contains('/') {
+            return None;
+        }
+        Some(repo.to_string())
+    }
+
+    /// Environment variable `gh` reads the token for this host from.
+    pub fn token_env(&self) -> &'static str {
+        if self.is_enterprise() {
+            "GH_ENTERPRISE_TOKEN"
+        } else {
+            "GH_TOKEN"
+        }
+    }
+
+    /// Points a `gh` command at this host and hands it `token`.
+    pub fn apply(&self, cmd: &mut Command, token: &str) {
+        if self.is_enterprise() {
+            cmd.env("GH_HOST", &self.host);
+        }
+        cmd.env(self.token_env(), token);
+    }
+
+    /// Points a `gh` command at this host with the detected token, if any.
+    pub fn apply_detected_token(&self, cmd: &mut Command) {
+        match self.detect_token() {
+            Some(token) => self.apply(cmd, &token),
+            None => self.apply_host(cmd),
+        }
+    }
+
+    /// Points `gh` at this host without handing it a token, for processes
+    /// that authenticate through `GH_CONFIG_DIR` or the ambient `gh` login.
+    pub fn apply_host(&self, cmd: &mut Command) {
+        if self.is_enterprise() {
+            cmd.env("GH_HOST", &self.host);
+        }
+    }
+
+    /// Detects a token for this host from the environment or
+    /// `gh auth token --hostname` (no interactive prompt).
+    pub fn detect_token(&self) -> Option<String> {
+        std::env::var(self.token_env())
+            .ok()
+            .filter(|t| !t.is_empty())
+            .or_else(|| {
+                Command::new("gh")
+                    .args(["auth", "token", "--hostname", &self.host])
+                    .output()
+                    .ok()
+                    .filter(|o| o.status.success())
+                    .map(|o| String::from_utf8_lossy(&o.stdout).trim().to_string())
+                    .filter(|t| !t.is_empty())
+            })
+    }
+
+    /// Like [`detect_token`](Self::detect_token), but fails with setup instructions.
+    pub fn require_token(&self) -> Result<String> {
+        match self.detect_token() {
+            Some(token) => Ok(token),
+            None => bail!(
+                "No GitHub token found for {}. Set {} or run `gh auth login --hostname {}` \
+                 before using --non-interactive.",
+                self.host,
+                self.token_env(),
+                self.host
+            ),
+        }
+    }
+}
+
+/// Strips scheme, path and trailing slashes from a `--github-host` value.
+pub fn normalize_host(input: &str) -> String {
+    let input = input.trim();
+    let input = input
+        .strip_prefix("https://")
+        .or_else(|| input.strip_prefix("http://"))
+        .unwrap_or(input);
+    input.split('/').next().unwrap_or_default().to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_is_github_com() {
+        let host = GitHubHost::new(None, None);
+        assert_eq!(host, GitHubHost::default());
+        assert!(!host.is_enterprise());
+        assert_eq!(host.clone_url("org/team"), "https://github.com/org/team.git");
+        assert_eq!(host.token_env(), "GH_TOKEN");
+    }
+
+    #[test]
+    fn enterprise_host_derives_api_base() {
+        let host = GitHubHost::new(Some("https://GitHub.Acme.Corp/"), None);
+        assert_eq!(host.host, "github.acme.corp");
+        assert_eq!(host.api_base, "https://github.acme.corp/api/v3");
+        assert!(host.is_enterprise());
+        assert_eq!(host.token_env(), "GH_ENTERPRISE_TOKEN");
+        assert_eq!(
+            host.project_url("acme", 3),
+            "https://github.acme.corp/orgs/acme/projects/3"
+        );
+    }
+
+    #[test]
+    fn explicit_api_base_wins() {
+        let host = GitHubHost::new(Some("ghe.local"), Some("http://127.0.0.1:9000/"));
+        assert_eq!(host.api_base, "http://127.0.0.1:9000");
+    }
+
+    #[test]
+    fn team_host_round_trips_through_forge_config() {
+        let mut team: TeamEntry = serde_yml::from_str(
+            "name: t\npath: /tmp/t\nprofile: scrum\ngithub_repo: org/t\ncredentials: {}\n",
+        )
+        .unwrap();
+        assert_eq!(GitHubHost::for_team(&team), GitHubHost::default());
+        assert_eq!(GitHubHost::default().forge_config(), None);
+
+        for host in [
+            GitHubHost::new(Some("ghe.local"), None),
+            GitHubHost::new(Some("ghe.local"), Some("http://127.0.0.1:9000")),
+        ] {
+            team.forge = host.forge_config();
+            assert_eq!(team.forge.as_ref().unwrap().kind, ForgeKind::GitHub);
+            assert_eq!(GitHubHost::for_team(&team), host);
+        }
+        assert_eq!(team.forge.unwrap().api_base.as_deref(), Some("http://127.0.0.1:9000"));
+
+        // Gitea teams have no GitHub host
+        team.forge = Some(ForgeConfig {
+            kind: ForgeKind::Gitea,
+            url: "https://forgejo.example.com".to_string(),
+            api_base: None,
+        });
+        assert_eq!(GitHubHost::for_team(&team), GitHubHost::default());
+    }
+
+    #[test]
+    fn owner_repo_only_matches_own_host() {
+        let host = GitHubHost::new(Some("ghe.local"), None);
+        assert_eq!(
+            host.owner_repo("https://ghe.local/org/app.git"),
+            Some("org/app".to_string())
+        );
+        assert_eq!(host.owner_repo("https://github.com/org/app"), None);
+        assert_eq!(host.owner_repo("https://ghe.local/org"), None);
+    }
+
+    #[test]
+    fn apply_sets_gh_host_for_enterprise() {
+        let mut cmd = Command::new("gh");
+        GitHubHost::new(Some("ghe.local"), None).apply(&mut cmd, "tok");
+        let envs: Vec<_> = cmd
+            .get_envs()
+            .map(|(k, v)| (k.to_owned(), v.map(|v| v.to_owned())))
+            .collect();
+        assert!(envs.contains(&("GH_HOST".into(), Some("ghe.local".into()))));
+        assert!(envs.contains(&("GH_ENTERPRISE_TOKEN".into(), Some("tok".into()))));
+
+        let mut cmd = Command::new("gh");
+        GitHubHost::default().apply(&mut cmd, "tok");
+        assert!(cmd.get_envs().all(|(k, _)| k != "GH_HOST"));
+    }
+}
@@ -8,6 +8,7 @@ use axum::response::{Html, IntoResponse, Redirect};
 use axum::routing::get;
 use tokio::sync::{oneshot, Mutex};
 
+use super::GitHubHost;
 use crate::formation::KeyValueCredentialStore;
 
 // ── Public types ────────────────────────────────────────────────────
@@ -22,6 +23,20 @@ pub struct PreGeneratedCredentials {
 
 // ── Manifest JSON construction ──────────────────────────────────────
 
+/// Permissions every member App is created with. `bm members doctor`
+/// reports Apps whose live permissions fall below these.
+pub const REQUIRED_PERMISSIONS: &[(&str, &str)] = &[
+    ("issues", "write"),
+    ("contents", "write"),
+    ("pull_requests", "write"),
+    ("administration", "write"),
+    ("organization_projects", "admin"),
+];
+
+/// Webhook events member Apps subscribe to. The daemon receives events
+/// through the team repo's webhook, so Apps need none.
+pub const REQUIRED_EVENTS: &[&str] = &[];
+
 /// Builds the manifest JSON for GitHub App creation.
 ///
 /// The manifest includes:
@@ -41,14 +56,11 @@ pub fn build_manifest_json(
         "url": team_repo_url,
         "redirect_url": format!("http://127.0.0.1:{port}/callback"),
         "setup_url": format!("http://127.0.0.1:{port}/installed"),
-        "default_permissions": {
-            "issues": "write",
-            "contents": "write",
-            "pull_requests": "write",
-            "administration": "write",
-            "organization_projects": "admin"
-        },
-        "default_events": [],
+        "default_permissions": REQUIRED_PERMISSIONS
+            .iter()
+            .map(|(name, level)| (name.to_string(), serde_json::Value::from(*level)))
+            .collect::<serde_json::Map<_, _>>(),
+        "default_events": REQUIRED_EVENTS,
         "public": false
     })
 }
@@ -59,8 +71,8 @@ pub fn build_manifest_json(
 ///
 /// The slug is derived from the app name by lowercasing and replacing
 /// spaces with hyphens. GitHub App names share a namespace with org names.
-pub fn check_name_collision(slug: &str) -> Result<bool> {
-    let url = format!("https://github.com/apps/{slug}");
+pub fn check_name_collision(host: &GitHubHost, slug: &str) -> Result<bool> {
+    let url = format!("{}/apps/{slug}", host.web_base());
     let client = reqwest::blocking::Client::builder()
         .redirect(reqwest::redirect::Policy::none())
         .build()
@@ -96,6 +108,18 @@ pub mod credential_keys {
     pub fn installation_id(member: &str) -> String {
         format!("{member}/github-installation-id")
     }
+    /// RFC 3339 time the private key was last rotated by `bm credentials rotate`.
+    pub fn private_key_rotated_at(member: &str) -> String {
+        format!("{member}/github-app-private-key-rotated-at")
+    }
+    /// Bot user login on a Gitea/Forgejo forge.
+    pub fn bot_user(member: &str) -> String {
+        format!("{member}/forge-bot-user")
+    }
+    /// Bot user access token on a Gitea/Forgejo forge.
+    pub fn bot_token(member: &str) -> String {
+        format!("{member}/forge-bot-token")
+    }
 }
 
 /// Stores pre-generated credentials in the credential store.
@@ -121,8 +145,9 @@ pub fn store_pregenerated_credentials(
 
 /// Removes all GitHub App credentials for a member from the credential store.
 ///
-/// Removes the 4 known credential keys. Errors on individual keys are
-/// collected and reported as a single error at the end.
+/// Removes the 4 known credential keys, the key rotation timestamp, and the
+/// Gitea/Forgejo bot user and token. Errors on individual keys are collected
+/// and reported as a single error at the end.
 pub fn remove_member_credentials(
     store: &dyn KeyValueCredentialStore,
     member: &str,
@@ -132,6 +157,9 @@ pub fn remove_member_credentials(
         credential_keys::client_id(member),
         credential_keys::private_key(member),
         credential_keys::installation_id(member),
+        credential_keys::private_key_rotated_at(member),
+        credential_keys::bot_user(member),
+        credential_keys::bot_token(member),
     ];
 
     let mut errors = Vec::new();
@@ -180,13 +208,13 @@ pub fn save_credentials_to_file(
 
 /// Resolves the organization from a `github_repo` field (e.g., "org/repo").
 /// Returns an error if the owner is a personal account (not an org).
-pub fn resolve_org_from_repo(github_repo: &str) -> Result<String> {
+pub fn resolve_org_from_repo(host: &GitHubHost, github_repo: &str) -> Result<String> {
     let owner = github_repo
         .split('/')
         .next()
         .context("Invalid github_repo format — expected 'owner/repo'")?;
 
-    validate_is_org(owner)?;
+    validate_is_org(host, owner)?;
     Ok(owner.to_string())
 }
 
@@ -194,12 +222,10 @@ pub fn resolve_org_from_repo(github_repo: &str) -> Result<String> {
 ///
 /// Calls `GET /users/{owner}` and checks `.type == "Organization"`.
 /// Returns `Ok(())` if it is an org, or an error with a clear message if not.
-pub fn validate_is_org(owner: &str) -> Result<()> {
+pub fn validate_is_org(host: &GitHubHost, owner: &str) -> Result<()> {
     let mut cmd = std::process::Command::new("gh");
     cmd.args(["api", &format!("users/{owner}"), "--jq", ".type"]);
-    if let Some(token) = super::detect_token() {
-        cmd.env("GH_TOKEN", token);
-    }
+    host.apply_detected_token(&mut cmd);
 
     let output = cmd
         .output()
@@ -236,6 +262,7 @@ pub fn validate_is_org(owner: &str) -> Result<()> {
 ///
 /// This is idempotent: calling it multiple times is safe.
 pub fn ensure_app_on_repos(
+    host: &GitHubHost,
     installation_id: &str,
     client_id: &str,
     private_key: &str,
@@ -248,13 +275,14 @@ pub fn ensure_app_on_repos(
         if repo.is_empty() {
             continue;
         }
-        match check_repo_installation(repo, &jwt) {
+        match check_repo_installation(host, repo, &jwt) {
             RepoInstallationStatus::Installed => {}
             RepoInstallationStatus::NotInstalled => {
                 let org = repo.split('/').next().unwrap_or("UNKNOWN");
                 warnings.push(format!(
                     "App is not installed on {repo}. \
-                     Install it manually: https://github.com/organizations/{org}/settings/installations/{installation_id}"
+                     Install it manually: {}/organizations/{org}/settings/installations/{installation_id}",
+                    host.web_base()
                 ));
             }
             RepoInstallationStatus::CheckFailed(_) => {
@@ -281,7 +309,7 @@ enum RepoInstallationStatus {
 /// Uses the App's JWT for authentication via `-H Authorization: Bearer` header,
 /// which overrides any `GH_TOKEN` env var. The `GET /repos/{owner}/{repo}/installation`
 /// endpoint requires App-level auth and cannot be called with a user PAT.
-fn check_repo_installation(repo: &str, jwt: &str) -> RepoInstallationStatus {
+fn check_repo_installation(host: &GitHubHost, repo: &str, jwt: &str) -> RepoInstallationStatus {
     let auth_header = format!("Authorization: Bearer {jwt}");
     let mut cmd = std::process::Command::new("gh");
     cmd.args([
@@ -290,6 +318,9 @@ fn check_repo_installation(repo: &str, jwt: &str) -> RepoInstallationStatus {
         "-H", &auth_header,
         "--silent",
     ]);
+    if host.is_enterprise() {
+        cmd.env("GH_HOST", &host.host);
+    }
     cmd.stdout(std::process::Stdio::null());
     cmd.stderr(std::process::Stdio::piped());
 
@@ -313,6 +344,7 @@ fn check_repo_installation(repo: &str, jwt: &str) -> RepoInstallationStatus {
 /// Collects all repos that a member's App should have access to:
 /// the team repo + any configured project repos from `botminter.yml`.
 pub fn collect_team_repos(team: &crate::config::TeamEntry) -> Vec<String> {
+    let host = GitHubHost::for_team(team);
     let mut repos = Vec::new();
 
     // Team repo
@@ -324,7 +356,7 @@ pub fn collect_team_repos(team: &crate::config::TeamEntry) -> Vec<String> {
     let team_repo = team.path.join("team");
     for project in crate::profile::read_team_projects(&team_repo) {
         let url = project.fork_url.trim().to_string();
-        if let Some(repo) = fork_url_to_owner_repo(&url) {
+        if let Some(repo) = fork_url_to_owner_repo(&host, &url) {
             repos.push(repo);
         }
     }
@@ -332,18 +364,10 @@ pub fn collect_team_repos(team: &crate::config::TeamEntry) -> Vec<String> {
     repos
 }
 
-/// Converts a GitHub fork URL to `owner/repo` format.
-/// Returns `None` for non-GitHub or empty URLs.
-pub(crate) fn fork_url_to_owner_repo(url: &str) -> Option<String> {
-    let stripped = url
-        .strip_prefix("https://github.com/")?;
-    let repo = stripped
-        .trim_end_matches('/')
-        .trim_end_matches(".git");
-    if repo.is_empty() || !repo.contains('/') {
-        return None;
-    }
-    Some(repo.to_string())
+/// Converts a fork URL on the team's GitHub host to `owner/repo` format.
+/// Returns `None` for URLs on other hosts or empty URLs.
+pub(crate) fn fork_url_to_owner_repo(host: &GitHubHost, url: &str) -> Option<String> {
+    host.owner_repo(url)
 }
 
 // ── Interactive manifest flow (browser-based App creation) ─────────
@@ -1036,6 +1060,8 @@ mod tests {
             credential_keys::installation_id("superman"),
             "superman/github-installation-id"
         );
+        assert_eq!(credential_keys::bot_user("superman"), "superman/forge-bot-user");
+        assert_eq!(credential_keys::bot_token("superman"), "superman/forge-bot-token");
     }
 
     #[test]
@@ -1102,23 +1128,23 @@ mod tests {
     #[test]
     fn fork_url_to_owner_repo_extracts_correctly() {
         assert_eq!(
-            fork_url_to_owner_repo("https://github.com/org/repo"),
+            fork_url_to_owner_repo(&GitHubHost::default(), "https://github.com/org/repo"),
             Some("org/repo".to_string())
         );
         assert_eq!(
-            fork_url_to_owner_repo("https://github.com/org/repo.git"),
+            fork_url_to_owner_repo(&GitHubHost::default(), "https://github.com/org/repo.git"),
             Some("org/repo".to_string())
         );
         assert_eq!(
-            fork_url_to_owner_repo("https://github.com/org/repo/"),
+            fork_url_to_owner_repo(&GitHubHost::default(), "https://github.com/org/repo/"),
             Some("org/repo".to_string())
         );
         // Non-GitHub URLs return None
-        assert_eq!(fork_url_to_owner_repo("https://gitlab.com/org/repo"), None);
+        assert_eq!(fork_url_to_owner_repo(&GitHubHost::default(), "https://gitlab.com/org/repo"), None);
         // Empty or malformed
-        assert_eq!(fork_url_to_owner_repo(""), None);
-        assert_eq!(fork_url_to_owner_repo("https://github.com/"), None);
-        assert_eq!(fork_url_to_owner_repo("https://github.com/orgonly"), None);
+        assert_eq!(fork_url_to_owner_repo(&GitHubHost::default(), ""), None);
+        assert_eq!(fork_url_to_owner_repo(&GitHubHost::default(), "https://github.com/"), None);
+        assert_eq!(fork_url_to_owner_repo(&GitHubHost::default(), "https://github.com/orgonly"), None);
     }
 
     #[test]
@@ -1143,6 +1169,8 @@ mod tests {
             project_number: None,
             bridge_lifecycle: Default::default(),
             vm: None,
+            profile_source: None,
+            forge: None,
         };
 
         let repos = collect_team_repos(&team);
@@ -1169,6 +1197,8 @@ mod tests {
             project_number: None,
             bridge_lifecycle: Default::default(),
             vm: None,
+            profile_source: None,
+            forge: None,
         };
 
         let repos = collect_team_repos(&team);
@@ -1189,6 +1219,8 @@ mod tests {
             project_number: None,
             bridge_lifecycle: Default::default(),
             vm: None,
+            profile_source: None,
+            forge: None,
         };
 
         let repos = collect_team_repos(&team);
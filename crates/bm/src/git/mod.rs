@@ -1,14 +1,18 @@
 pub mod app_auth;
+pub mod app_drift;
 pub mod manifest_flow;
 mod github;
+mod host;
 mod project;
 
 pub use github::{
     bootstrap_labels, clone_repo, create_github_label, create_project, create_repo_and_push,
     delete_repo, derive_project_name, detect_token, detect_token_non_interactive,
-    find_project_number, get_user_login, list_projects, list_repos, list_user_orgs, mask_token,
-    repo_exists, sync_project_status_field, validate_token, verify_fork_url, TokenInfo,
+    find_project_number, get_user_login, list_project_items, list_projects, list_repos,
+    list_user_orgs, mask_token, project_node_id, repo_exists, sync_project_status_field,
+    validate_token, verify_fork_url, ProjectItem, TokenInfo,
 };
+pub use host::{normalize_host, GitHubHost, GITHUB_COM};
 pub use project::{add_project, sync_project_board, ProjectSyncResult, ViewDisplay};
 
 use std::path::Path;
@@ -31,6 +35,21 @@ pub fn run_git(dir: &Path, args: &[&str]) -> Result<()> {
     Ok(())
 }
 
+/// Runs a git command in the given directory and returns its trimmed stdout.
+pub fn git_stdout(dir: &Path, args: &[&str]) -> Result<String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .with_context(|| format!("Failed to run git {}", args.join(" ")))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        bail!("git {} failed: {}", args.join(" "), stderr.trim());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -5,7 +5,7 @@ use anyhow::{bail, Context, Result};
 
 use super::{
     create_github_label, derive_project_name, run_git,
-    sync_project_status_field, verify_fork_url,
+    sync_project_status_field, verify_fork_url, GitHubHost,
 };
 use crate::config::TeamEntry;
 use crate::profile;
@@ -14,6 +14,7 @@ use crate::profile;
 /// updates manifest, creates project directories, and commits.
 /// Returns the derived project name.
 pub fn add_project(
+    host: &GitHubHost,
     team_repo: &Path,
     url: &str,
     github_repo: &str,
@@ -27,11 +28,12 @@ pub fn add_project(
         bail!("Project '{}' already exists in this team.", name);
     }
 
-    verify_fork_url(url)?;
+    verify_fork_url(host, url)?;
 
     if !github_repo.is_empty() {
         let label_name = format!("project/{}", name);
         create_github_label(
+            host,
             github_repo,
             &label_name,
             "BFD4F2",
@@ -99,7 +101,8 @@ pub fn sync_project_board(
             team.name
         )
     })?;
-    sync_project_status_field(owner, project_number, &manifest.statuses)?;
+    let host = GitHubHost::for_team(team);
+    sync_project_status_field(&host, owner, project_number, &manifest.statuses)?;
 
     let views: Vec<ViewDisplay> = manifest
         .views
@@ -110,10 +113,7 @@ pub fn sync_project_board(
         })
         .collect();
 
-    let project_url = format!(
-        "https://github.com/orgs/{}/projects/{}",
-        owner, project_number
-    );
+    let project_url = host.project_url(owner, project_number);
 
     Ok(ProjectSyncResult {
         status_count: manifest.statuses.len(),
@@ -0,0 +1,469 @@
+//! Executable invariants: machine-checkable rules attached to invariant files.
+//!
+//! An invariant file may carry one fenced `invariant-check` block. Without
+//! one it stays advisory markdown; with one, `bm invariants check` and the
+//! daemon verify it against member workspaces.
+//!
+//! ````markdown
+//! ## Check
+//!
+//! ```invariant-check
+//! command: cargo test --quiet
+//! require_files: ["README.md"]
+//! forbid_files: ["*.orig", ".env"]
+//! forbid_pattern: "dbg!("
+//! files: ["*.rs"]
+//! on: project
+//! triggers: [task.close]
+//! ```
+//! ````
+//!
+//! - `command` runs through `sh -c` in the target directory and fails on a
+//!   non-zero exit (`timeout_secs`, default 600).
+//! - `require_files` globs must each match at least one file; `forbid_files`
+//!   globs must match none.
+//! - `forbid_pattern` is literal text that must not appear in files matching
+//!   `files` (default: every file).
+//! - `on` picks the target: `project` (default) runs in each project
+//!   submodule the invariant applies to, `workspace` runs at the member
+//!   workspace root. Project-scoped invariants always run in their project.
+//! - `triggers` lists loop events that make the daemon run the check;
+//!   currently `task.close`.
+//!
+//! Which invariants apply to a member follows knowledge resolution
+//! ([`crate::knowledge::resolve_knowledge`]): a narrower scope's file replaces
+//! a same-named broader one.
+
+mod rules;
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::knowledge::{self, ResolvedFile, Scope};
+use crate::profile;
+
+/// Fence info string that marks a check block.
+const CHECK_FENCE: &str = "invariant-check";
+
+/// Loop event topic that triggers checks from the daemon.
+pub const TASK_CLOSE: &str = "task.close";
+
+/// Where a check runs.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CheckOn {
+    #[default]
+    Project,
+    Workspace,
+}
+
+/// The machine-checkable part of an invariant.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CheckSpec {
+    #[serde(default)]
+    pub command: Option<String>,
+    #[serde(default)]
+    pub timeout_secs: Option<u64>,
+    #[serde(default)]
+    pub require_files: Vec<String>,
+    #[serde(default)]
+    pub forbid_files: Vec<String>,
+    #[serde(default)]
+    pub forbid_pattern: Option<String>,
+    /// Files `forbid_pattern` applies to.
+    #[serde(default)]
+    pub files: Vec<String>,
+    #[serde(default)]
+    pub on: CheckOn,
+    #[serde(default)]
+    pub triggers: Vec<String>,
+}
+
+impl CheckSpec {
+    fn validate(&self) -> Result<()> {
+        if self.command.is_none()
+            && self.require_files.is_empty()
+            && self.forbid_files.is_empty()
+            && self.forbid_pattern.is_none()
+        {
+            bail!("Check has no rule: set command, require_files, forbid_files or forbid_pattern");
+        }
+        if self.forbid_pattern.as_deref() == Some("") {
+            bail!("forbid_pattern must not be empty");
+        }
+        if !self.files.is_empty() && self.forbid_pattern.is_none() {
+            bail!("files only applies to forbid_pattern");
+        }
+        for trigger in &self.triggers {
+            if trigger != TASK_CLOSE {
+                bail!("Unknown trigger '{}'. Supported: {}", trigger, TASK_CLOSE);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Extracts and parses the `invariant-check` block of an invariant file.
+/// Returns `None` when the file has no block.
+pub fn parse_check(markdown: &str) -> Result<Option<CheckSpec>> {
+    let mut block: Option<Vec<&str>> = None;
+    let mut found = None;
+    for line in markdown.lines() {
+        let trimmed = line.trim();
+        match block.as_mut() {
+            None if trimmed.starts_with("```") && trimmed[3..].trim() == CHECK_FENCE => {
+                if found.is_some() {
+                    bail!("Only one {} block is allowed per invariant", CHECK_FENCE);
+                }
+                block = Some(Vec::new());
+            }
+            Some(lines) if trimmed.starts_with("```") => {
+                found = Some(lines.join("\n"));
+                block = None;
+            }
+            Some(lines) => lines.push(line),
+            None => {}
+        }
+    }
+    if block.is_some() {
+        bail!("Unterminated {} block", CHECK_FENCE);
+    }
+    let Some(yaml) = found else {
+        return Ok(None);
+    };
+    let spec: CheckSpec =
+        serde_yml::from_str(&yaml).with_context(|| format!("Invalid {} block", CHECK_FENCE))?;
+    spec.validate()?;
+    Ok(Some(spec))
+}
+
+/// A check bound to the directory it runs in.
+#[derive(Debug, Clone)]
+pub struct PlannedCheck {
+    /// Invariant path relative to the team repo.
+    pub invariant: String,
+    pub scope: Scope,
+    /// `workspace` or `projects/<name>`.
+    pub target: String,
+    pub dir: PathBuf,
+    /// `Err` holds the reason a check block could not be parsed.
+    pub spec: Result<CheckSpec, String>,
+}
+
+/// One failed rule.
+#[derive(Debug, Clone, Serialize)]
+pub struct Violation {
+    pub invariant: String,
+    pub target: String,
+    /// `command`, `require_files`, `forbid_files`, `forbid_pattern` or `invalid`.
+    pub rule: String,
+    pub message: String,
+}
+
+/// Result of checking one member.
+#[derive(Debug, Clone, Serialize)]
+pub struct CheckReport {
+    pub member: String,
+    pub checks_run: usize,
+    pub violations: Vec<Violation>,
+}
+
+/// Plans the checks that apply to `member`'s workspace.
+///
+/// With `trigger`, only checks that list it are included.
+pub fn plan_member_checks(
+    team_repo: &Path,
+    ws: &Path,
+    member: &str,
+    trigger: Option<&str>,
+) -> Result<Vec<PlannedCheck>> {
+    let mut planned = Vec::new();
+
+    let resolution = knowledge::resolve_knowledge(team_repo, member, None)?;
+    for file in resolution.invariants.iter().filter(|f| f.is_effective()) {
+        if let Some(spec) = load_spec(team_repo, file) {
+            let runs_here = spec.as_ref().map_or(true, |s| s.on == CheckOn::Workspace);
+            if runs_here {
+                planned.push(PlannedCheck {
+                    invariant: file.path.clone(),
+                    scope: file.scope.clone(),
+                    target: "workspace".to_string(),
+                    dir: ws.to_path_buf(),
+                    spec,
+                });
+            }
+        }
+    }
+
+    let team_projects = profile::list_subdirs(&team_repo.join("projects"));
+    for project in profile::list_subdirs(&ws.join("projects")) {
+        if !team_projects.contains(&project) {
+            continue;
+        }
+        let resolution = knowledge::resolve_knowledge(team_repo, member, Some(&project))?;
+        for file in resolution.invariants.iter().filter(|f| f.is_effective()) {
+            let Some(spec) = load_spec(team_repo, file) else {
+                continue;
+            };
+            let project_scoped = matches!(file.scope, Scope::Project | Scope::MemberProject);
+            // Invalid blocks are reported once, on the workspace target.
+            let runs_here = match &spec {
+                Ok(s) => project_scoped || s.on == CheckOn::Project,
+                Err(_) => project_scoped,
+            };
+            if runs_here {
+                planned.push(PlannedCheck {
+                    invariant: file.path.clone(),
+                    scope: file.scope.clone(),
+                    target: format!("projects/{}", project),
+                    dir: ws.join("projects").join(&project),
+                    spec,
+                });
+            }
+        }
+    }
+
+    if let Some(trigger) = trigger {
+        planned.retain(|c| {
+            c.spec
+                .as_ref()
+                .is_ok_and(|s| s.triggers.iter().any(|t| t == trigger))
+        });
+    }
+    Ok(planned)
+}
+
+/// Reads an invariant's check block. `None` when it has none.
+fn load_spec(team_repo: &Path, file: &ResolvedFile) -> Option<Result<CheckSpec, String>> {
+    let content = match fs::read_to_string(team_repo.join(&file.path)) {
+        Ok(c) => c,
+        Err(e) => return Some(Err(format!("Failed to read: {}", e))),
+    };
+    match parse_check(&content) {
+        Ok(Some(spec)) => Some(Ok(spec)),
+        Ok(None) => None,
+        Err(e) => Some(Err(format!("{:#}", e))),
+    }
+}
+
+/// Runs every check that applies to `member` and collects violations.
+pub fn check_member(
+    team_repo: &Path,
+    ws: &Path,
+    member: &str,
+    trigger: Option<&str>,
+) -> Result<CheckReport> {
+    let planned = plan_member_checks(team_repo, ws, member, trigger)?;
+    let mut violations = Vec::new();
+    for check in &planned {
+        violations.extend(run_check(check));
+    }
+    Ok(CheckReport {
+        member: member.to_string(),
+        checks_run: planned.len(),
+        violations,
+    })
+}
+
+/// Evaluates one planned check.
+pub fn run_check(check: &PlannedCheck) -> Vec<Violation> {
+    let violation = |rule: &str, message: String| Violation {
+        invariant: check.invariant.clone(),
+        target: check.target.clone(),
+        rule: rule.to_string(),
+        message,
+    };
+    let spec = match &check.spec {
+        Ok(s) => s,
+        Err(e) => return vec![violation("invalid", e.clone())],
+    };
+    if !check.dir.is_dir() {
+        return vec![violation(
+            "missing",
+            format!("{} does not exist", check.dir.display()),
+        )];
+    }
+
+    rules::evaluate(spec, &check.dir)
+        .into_iter()
+        .map(|(rule, message)| violation(rule, message))
+        .collect()
+}
+
+/// Formats violations as a prompt for the member's agent.
+pub fn violation_prompt(trigger: &str, violations: &[Violation]) -> String {
+    let mut out = format!(
+        "Invariant checks failed after {}. Fix these before moving on:\n",
+        trigger
+    );
+    for v in violations {
+        out.push_str(&format!(
+            "- {} [{}] {}: {}\n",
+            v.invariant, v.target, v.rule, v.message
+        ));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(root: &Path, rel: &str, content: &str) {
+        let path = root.join(rel);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    fn check_block(yaml: &str) -> String {
+        format!("# Rule\n\nText.\n\n```{}\n{}\n```\n", CHECK_FENCE, yaml)
+    }
+
+    #[test]
+    fn parse_check_reads_fenced_block() {
+        assert!(parse_check("# Plain invariant\n\n```bash\nls\n```\n")
+            .unwrap()
+            .is_none());
+
+        let spec = parse_check(&check_block(
+            "command: just test\non: workspace\ntriggers: [task.close]",
+        ))
+        .unwrap()
+        .unwrap();
+        assert_eq!(spec.command.as_deref(), Some("just test"));
+        assert_eq!(spec.on, CheckOn::Workspace);
+        assert_eq!(spec.triggers, vec!["task.close"]);
+    }
+
+    #[test]
+    fn parse_check_rejects_bad_blocks() {
+        for yaml in [
+            "on: project",
+            "command: x\nbogus: 1",
+            "command: x\ntriggers: [loop.start]",
+            "require_files: [a]\nfiles: ['*.rs']",
+        ] {
+            assert!(parse_check(&check_block(yaml)).is_err(), "{}", yaml);
+        }
+        assert!(parse_check("```invariant-check\ncommand: x\n").is_err());
+    }
+
+    /// Team repo with invariants at several scopes and a member workspace
+    /// with one project submodule.
+    fn fixture() -> (tempfile::TempDir, PathBuf, PathBuf) {
+        let tmp = tempfile::tempdir().unwrap();
+        let team = tmp.path().join("team");
+        let ws = tmp.path().join("dev-alice");
+        fs::create_dir_all(team.join("members/dev-alice")).unwrap();
+        fs::create_dir_all(team.join("projects/api")).unwrap();
+        fs::create_dir_all(team.join("projects/web")).unwrap();
+
+        write(&team, "invariants/advisory.md", "# Be kind\n");
+        write(
+            &team,
+            "invariants/readme.md",
+            &check_block("require_files: [README.md]"),
+        );
+        write(
+            &team,
+            "invariants/no-debug.md",
+            &check_block("forbid_pattern: 'dbg!('\nfiles: ['*.rs']\ntriggers: [task.close]"),
+        );
+        write(
+            &team,
+            "invariants/workspace.md",
+            &check_block("command: test -f PROMPT.md\non: workspace"),
+        );
+        write(
+            &team,
+            "projects/api/invariants/no-env.md",
+            &check_block("forbid_files: ['.env']\non: workspace"),
+        );
+
+        write(&ws, "PROMPT.md", "prompt");
+        write(&ws, "projects/api/src/lib.rs", "fn main() { dbg!(1); }\n");
+        write(&ws, "projects/api/.env", "SECRET=1");
+        (tmp, team, ws)
+    }
+
+    #[test]
+    fn plans_checks_per_target() {
+        let (_tmp, team, ws) = fixture();
+        let planned = plan_member_checks(&team, &ws, "dev-alice", None).unwrap();
+        let mut got: Vec<(String, String)> = planned
+            .iter()
+            .map(|c| (c.invariant.clone(), c.target.clone()))
+            .collect();
+        got.sort();
+        assert_eq!(
+            got,
+            vec![
+                ("invariants/no-debug.md".into(), "projects/api".into()),
+                ("invariants/readme.md".into(), "projects/api".into()),
+                ("invariants/workspace.md".into(), "workspace".into()),
+                (
+                    "projects/api/invariants/no-env.md".into(),
+                    "projects/api".into()
+                ),
+            ]
+        );
+
+        let triggered = plan_member_checks(&team, &ws, "dev-alice", Some(TASK_CLOSE)).unwrap();
+        assert_eq!(triggered.len(), 1);
+        assert_eq!(triggered[0].invariant, "invariants/no-debug.md");
+    }
+
+    #[test]
+    fn check_member_reports_violations() {
+        let (_tmp, team, ws) = fixture();
+        let report = check_member(&team, &ws, "dev-alice", None).unwrap();
+        assert_eq!(report.checks_run, 4);
+
+        let mut rules: Vec<(&str, &str)> = report
+            .violations
+            .iter()
+            .map(|v| (v.invariant.as_str(), v.rule.as_str()))
+            .collect();
+        rules.sort();
+        assert_eq!(
+            rules,
+            vec![
+                ("invariants/no-debug.md", "forbid_pattern"),
+                ("invariants/readme.md", "require_files"),
+                ("projects/api/invariants/no-env.md", "forbid_files"),
+            ]
+        );
+        let debug = report
+            .violations
+            .iter()
+            .find(|v| v.rule == "forbid_pattern")
+            .unwrap();
+        assert!(debug.message.contains("src/lib.rs:1"), "{}", debug.message);
+
+        let prompt = violation_prompt(TASK_CLOSE, &report.violations);
+        assert!(prompt.starts_with("Invariant checks failed after task.close."));
+        assert_eq!(prompt.lines().count(), 4);
+    }
+
+    #[test]
+    fn invalid_block_is_reported_as_violation() {
+        let (_tmp, team, ws) = fixture();
+        write(
+            &team,
+            "invariants/broken.md",
+            &check_block("command: [oops"),
+        );
+        let report = check_member(&team, &ws, "dev-alice", None).unwrap();
+        let invalid: Vec<_> = report
+            .violations
+            .iter()
+            .filter(|v| v.rule == "invalid")
+            .collect();
+        assert_eq!(invalid.len(), 1);
+        assert_eq!(invalid[0].target, "workspace");
+    }
+}
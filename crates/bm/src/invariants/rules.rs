@@ -0,0 +1,339 @@
+//! Evaluation of check rules against a target directory.
+
+use std::fs;
+use std::io::Read;
+use std::os::unix::process::CommandExt;
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use super::CheckSpec;
+
+const DEFAULT_TIMEOUT_SECS: u64 = 600;
+
+/// Lines of command output kept in a violation message.
+const OUTPUT_TAIL_LINES: usize = 20;
+
+/// Matches at most this many locations per `forbid_pattern` violation.
+const MAX_PATTERN_HITS: usize = 10;
+
+/// Evaluates every rule in `spec` inside `dir`. Returns `(rule, message)`
+/// for each failure.
+pub(super) fn evaluate(spec: &CheckSpec, dir: &Path) -> Vec<(&'static str, String)> {
+    let mut failures = Vec::new();
+    let needs_files = !spec.require_files.is_empty()
+        || !spec.forbid_files.is_empty()
+        || spec.forbid_pattern.is_some();
+    let files = if needs_files {
+        list_files(dir)
+    } else {
+        Vec::new()
+    };
+
+    for pattern in &spec.require_files {
+        if !files.iter().any(|f| glob_match(pattern, f)) {
+            failures.push(("require_files", format!("no file matches '{}'", pattern)));
+        }
+    }
+
+    for pattern in &spec.forbid_files {
+        let matched: Vec<&str> = files
+            .iter()
+            .filter(|f| glob_match(pattern, f))
+            .map(String::as_str)
+            .collect();
+        if !matched.is_empty() {
+            failures.push((
+                "forbid_files",
+                format!("'{}' matches {}", pattern, matched.join(", ")),
+            ));
+        }
+    }
+
+    if let Some(needle) = &spec.forbid_pattern {
+        let hits = find_pattern(dir, &files, &spec.files, needle);
+        if !hits.is_empty() {
+            let mut message = format!("'{}' found at {}", needle, hits.join(", "));
+            if hits.len() == MAX_PATTERN_HITS {
+                message.push_str(", ...");
+            }
+            failures.push(("forbid_pattern", message));
+        }
+    }
+
+    if let Some(command) = &spec.command {
+        let timeout = Duration::from_secs(spec.timeout_secs.unwrap_or(DEFAULT_TIMEOUT_SECS));
+        if let Err(message) = run_command(command, dir, timeout) {
+            failures.push(("command", message));
+        }
+    }
+
+    failures
+}
+
+fn find_pattern(dir: &Path, files: &[String], globs: &[String], needle: &str) -> Vec<String> {
+    let mut hits = Vec::new();
+    for file in files {
+        if !globs.is_empty() && !globs.iter().any(|g| glob_match(g, file)) {
+            continue;
+        }
+        // Binary and unreadable files are skipped.
+        let Ok(content) = fs::read_to_string(dir.join(file)) else {
+            continue;
+        };
+        for (i, line) in content.lines().enumerate() {
+            if line.contains(needle) {
+                hits.push(format!("{}:{}", file, i + 1));
+                if hits.len() == MAX_PATTERN_HITS {
+                    return hits;
+                }
+            }
+        }
+    }
+    hits
+}
+
+/// Runs `command` through `sh -c`, failing on a non-zero exit or timeout.
+/// The command gets its own process group so a timeout kills everything it
+/// spawned, not just the shell.
+fn run_command(command: &str, dir: &Path, timeout: Duration) -> Result<(), String> {
+    let mut child = Command::new("sh")
+        .args(["-c", command])
+        .current_dir(dir)
+        .process_group(0)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("failed to run '{}': {}", command, e))?;
+
+    // Drain both pipes so a chatty command cannot block on a full buffer.
+    let readers: Vec<_> = [
+        child
+            .stdout
+            .take()
+            .map(|p| Box::new(p) as Box<dyn Read + Send>),
+        child
+            .stderr
+            .take()
+            .map(|p| Box::new(p) as Box<dyn Read + Send>),
+    ]
+    .into_iter()
+    .flatten()
+    .map(|mut pipe| {
+        thread::spawn(move || {
+            let mut buf = Vec::new();
+            let _ = pipe.read_to_end(&mut buf);
+            buf
+        })
+    })
+    .collect();
+
+    let started = Instant::now();
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break Some(status),
+            Ok(None) if started.elapsed() >= timeout => {
+                // Grandchildren would otherwise keep the pipes open and block
+                // the readers below.
+                unsafe {
+                    libc::killpg(child.id() as i32, libc::SIGKILL);
+                }
+                let _ = child.wait();
+                break None;
+            }
+            Ok(None) => thread::sleep(Duration::from_millis(50)),
+            Err(e) => return Err(format!("failed to wait for '{}': {}", command, e)),
+        }
+    };
+
+    let mut output = Vec::new();
+    for reader in readers {
+        output.extend(reader.join().unwrap_or_default());
+    }
+    let output = String::from_utf8_lossy(&output);
+    let lines: Vec<&str> = output.lines().collect();
+    let tail = lines[lines.len().saturating_sub(OUTPUT_TAIL_LINES)..].join("\n");
+
+    let summary = match status {
+        None => format!("'{}' timed out after {}s", command, timeout.as_secs()),
+        Some(s) if s.success() => return Ok(()),
+        Some(s) => match s.code() {
+            Some(code) => format!("'{}' exited with {}", command, code),
+            None => format!("'{}' was killed by a signal", command),
+        },
+    };
+    if tail.trim().is_empty() {
+        Err(summary)
+    } else {
+        Err(format!("{}\n{}", summary, tail))
+    }
+}
+
+/// Files under `dir`, relative and `/`-separated. Uses git's view (tracked
+/// plus untracked, minus ignored) when `dir` is a work tree.
+fn list_files(dir: &Path) -> Vec<String> {
+    let git = Command::new("git")
+        .args(["ls-files", "-co", "--exclude-standard"])
+        .current_dir(dir)
+        .stderr(Stdio::null())
+        .output();
+    if let Ok(out) = git {
+        if out.status.success() {
+            let mut files: Vec<String> = String::from_utf8_lossy(&out.stdout)
+                .lines()
+                .filter(|l| !l.is_empty() && dir.join(l).is_file())
+                .map(String::from)
+                .collect();
+            files.sort();
+            return files;
+        }
+    }
+
+    let mut files = Vec::new();
+    walk(dir, "", &mut files);
+    files.sort();
+    files
+}
+
+fn walk(dir: &Path, prefix: &str, out: &mut Vec<String>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let name = entry.file_name().to_string_lossy().to_string();
+        if name == ".git" {
+            continue;
+        }
+        let rel = format!("{}{}", prefix, name);
+        match entry.file_type() {
+            Ok(t) if t.is_dir() => walk(&entry.path(), &format!("{}/", rel), out),
+            Ok(t) if t.is_file() => out.push(rel),
+            _ => {}
+        }
+    }
+}
+
+/// Matches a `/`-separated relative path against a glob.
+///
+/// `*` and `?` match within one path segment, `**` matches any number of
+/// segments. A pattern without `/` matches the file name at any depth.
+pub fn glob_match(pattern: &str, path: &str) -> bool {
+    let pattern = pattern.trim_start_matches("./");
+    if !pattern.contains('/') {
+        let name = path.rsplit('/').next().unwrap_or(path);
+        return segment_match(pattern.as_bytes(), name.as_bytes());
+    }
+    let pat: Vec<&str> = pattern.split('/').collect();
+    let segs: Vec<&str> = path.split('/').collect();
+    segments_match(&pat, &segs)
+}
+
+fn segments_match(pat: &[&str], segs: &[&str]) -> bool {
+    match pat.split_first() {
+        None => segs.is_empty(),
+        Some((&"**", rest)) => (0..=segs.len()).any(|i| segments_match(rest, &segs[i..])),
+        Some((p, rest)) => match segs.split_first() {
+            Some((s, more)) => {
+                segment_match(p.as_bytes(), s.as_bytes()) && segments_match(rest, more)
+            }
+            None => false,
+        },
+    }
+}
+
+fn segment_match(pat: &[u8], s: &[u8]) -> bool {
+    match pat.split_first() {
+        None => s.is_empty(),
+        Some((b'*', rest)) => (0..=s.len()).any(|i| segment_match(rest, &s[i..])),
+        Some((b'?', rest)) => !s.is_empty() && segment_match(rest, &s[1..]),
+        Some((c, rest)) => s.first() == Some(c) && segment_match(rest, &s[1..]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn glob_matching() {
+        assert!(glob_match("*.rs", "src/deep/lib.rs"));
+        assert!(glob_match(".env", "config/.env"));
+        assert!(!glob_match("*.rs", "src/lib.rsx"));
+        assert!(glob_match("src/*.rs", "src/lib.rs"));
+        assert!(!glob_match("src/*.rs", "src/a/lib.rs"));
+        assert!(glob_match("src/**/*.rs", "src/lib.rs"));
+        assert!(glob_match("src/**/*.rs", "src/a/b/lib.rs"));
+        assert!(glob_match("**/README.md", "README.md"));
+        assert!(glob_match("./docs/?.md", "docs/a.md"));
+        assert!(!glob_match("docs/?.md", "docs/ab.md"));
+    }
+
+    #[test]
+    fn evaluates_file_rules() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path();
+        fs::create_dir_all(dir.join("src")).unwrap();
+        fs::create_dir_all(dir.join(".git")).unwrap();
+        fs::write(dir.join("src/lib.rs"), "ok\n// TODO later\n").unwrap();
+        fs::write(dir.join("notes.md"), "TODO in docs is fine\n").unwrap();
+        fs::write(dir.join(".git/config"), "").unwrap();
+
+        let spec = CheckSpec {
+            require_files: vec!["src/lib.rs".into(), "LICENSE".into()],
+            forbid_files: vec!["config".into(), "*.md".into()],
+            forbid_pattern: Some("TODO".into()),
+            files: vec!["*.rs".into()],
+            ..Default::default()
+        };
+        let failures = evaluate(&spec, dir);
+        assert_eq!(
+            failures,
+            vec![
+                ("require_files", "no file matches 'LICENSE'".to_string()),
+                ("forbid_files", "'*.md' matches notes.md".to_string()),
+                ("forbid_pattern", "'TODO' found at src/lib.rs:2".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn command_rule_reports_exit_and_output() {
+        let tmp = tempfile::tempdir().unwrap();
+        let ok = CheckSpec {
+            command: Some("true".into()),
+            ..Default::default()
+        };
+        assert!(evaluate(&ok, tmp.path()).is_empty());
+
+        let failing = CheckSpec {
+            command: Some("echo broken >&2; exit 3".into()),
+            ..Default::default()
+        };
+        let failures = evaluate(&failing, tmp.path());
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].0, "command");
+        assert!(failures[0].1.contains("exited with 3"), "{}", failures[0].1);
+        assert!(failures[0].1.ends_with("broken"), "{}", failures[0].1);
+
+        let slow = CheckSpec {
+            command: Some("sleep 5".into()),
+            timeout_secs: Some(0),
+            ..Default::default()
+        };
+        let failures = evaluate(&slow, tmp.path());
+        assert!(failures[0].1.contains("timed out"), "{}", failures[0].1);
+
+        // A backgrounded grandchild holding the pipes is killed with the shell.
+        let forked = CheckSpec {
+            command: Some("sleep 30 & sleep 30; true".into()),
+            timeout_secs: Some(0),
+            ..Default::default()
+        };
+        let started = Instant::now();
+        let failures = evaluate(&forked, tmp.path());
+        assert!(failures[0].1.contains("timed out"), "{}", failures[0].1);
+        assert!(started.elapsed() < Duration::from_secs(10));
+    }
+}
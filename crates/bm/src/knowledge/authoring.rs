@@ -0,0 +1,575 @@
+//! Knowledge changes that land as pull requests against the team repo.
+//!
+//! Edits are applied in a throwaway git worktree based on the remote default
+//! branch, so the caller's checkout (an operator's team repo or a member's
+//! `team/` submodule) is never switched or dirtied. The worktree's commit is
+//! pushed to a `knowledge/*` branch and opened as a PR for review.
+
+use std::fs;
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+
+use super::resolve::{KnowledgeKind, Scope};
+use crate::forge::Forge;
+use crate::git::{git_stdout, run_git};
+use crate::profile;
+
+/// Where a knowledge file lives: a scope, its kind, and the project and
+/// member the scope needs.
+#[derive(Debug, Clone)]
+pub struct Placement<'a> {
+    pub scope: Scope,
+    pub kind: KnowledgeKind,
+    pub project: Option<&'a str>,
+    pub member: Option<&'a str>,
+}
+
+impl Placement<'_> {
+    /// Directory relative to the team repo (`members/dev-alice/knowledge`).
+    pub fn dir(&self) -> Result<String> {
+        let project = || {
+            self.project
+                .with_context(|| format!("--project is required for {} scope", self.scope.label()))
+        };
+        let member = || {
+            self.member
+                .with_context(|| format!("--member is required for {} scope", self.scope.label()))
+        };
+        let prefix = match self.scope {
+            Scope::Team => String::new(),
+            Scope::Project => format!("projects/{}/", project()?),
+            Scope::Member => format!("members/{}/", member()?),
+            Scope::MemberProject => format!("members/{}/projects/{}/", member()?, project()?),
+        };
+        Ok(format!("{}{}", prefix, self.kind.dir()))
+    }
+
+    /// Path of `name` in this placement. A missing `.md` extension is added.
+    pub fn file_path(&self, name: &str) -> Result<String> {
+        if name.is_empty() || name.contains('/') || name.starts_with('.') {
+            bail!(
+                "Invalid file name '{}': expected a plain name like style.md",
+                name
+            );
+        }
+        let name = if name.ends_with(".md") {
+            name.to_string()
+        } else {
+            format!("{}.md", name)
+        };
+        let path = format!("{}/{}", self.dir()?, name);
+        validate_authoring_path(&path)?;
+        Ok(path)
+    }
+
+    /// Checks that the project and member the placement names exist.
+    pub fn check_exists(&self, team_repo: &Path) -> Result<()> {
+        if let Some(member) = self.member.filter(|_| self.uses_member()) {
+            let members_dir = team_repo.join("members");
+            if !members_dir.join(member).is_dir() {
+                bail!(
+                    "Member '{}' not found. Available members: {}",
+                    member,
+                    profile::list_subdirs(&members_dir).join(", ")
+                );
+            }
+        }
+        if let Some(project) = self.project.filter(|_| self.uses_project()) {
+            let projects_dir = team_repo.join("projects");
+            if !projects_dir.join(project).is_dir() {
+                bail!(
+                    "Project '{}' not found. Available projects: {}",
+                    project,
+                    profile::list_subdirs(&projects_dir).join(", ")
+                );
+            }
+        }
+        Ok(())
+    }
+
+    fn uses_member(&self) -> bool {
+        matches!(self.scope, Scope::Member | Scope::MemberProject)
+    }
+
+    fn uses_project(&self) -> bool {
+        matches!(self.scope, Scope::Project | Scope::MemberProject)
+    }
+}
+
+/// Validates a path a knowledge change writes to, moves or removes.
+///
+/// Stricter than [`profile::validate_knowledge_path`]: the path must also be a
+/// relative `.md` file with no `..` or empty segments.
+pub fn validate_authoring_path(path: &str) -> Result<()> {
+    profile::validate_knowledge_path(path)?;
+    if path
+        .split('/')
+        .any(|s| s.is_empty() || s == "." || s == "..")
+    {
+        bail!("Invalid knowledge path '{}'", path);
+    }
+    if !path.ends_with(".md") {
+        bail!("Knowledge files must be Markdown (.md): {}", path);
+    }
+    Ok(())
+}
+
+/// Whether a validated path is knowledge or an invariant.
+pub fn kind_of_path(path: &str) -> Option<KnowledgeKind> {
+    let parts: Vec<&str> = path.split('/').collect();
+    let index = match parts.first() {
+        Some(&"projects") => 2,
+        Some(&"members") if parts.get(2) == Some(&"projects") => 4,
+        Some(&"members") => 2,
+        _ => 0,
+    };
+    match parts.get(index) {
+        Some(&"knowledge") => Some(KnowledgeKind::Knowledge),
+        Some(&"invariants") => Some(KnowledgeKind::Invariants),
+        _ => None,
+    }
+}
+
+/// One file operation in a knowledge change. Paths are relative to the team repo.
+#[derive(Debug, Clone)]
+pub enum KnowledgeEdit {
+    /// Writes a file. Fails if it exists unless `overwrite` is set.
+    Write {
+        path: String,
+        content: String,
+        overwrite: bool,
+    },
+    Move {
+        from: String,
+        to: String,
+    },
+    Remove {
+        path: String,
+    },
+}
+
+impl KnowledgeEdit {
+    fn paths(&self) -> Vec<&str> {
+        match self {
+            KnowledgeEdit::Write { path, .. } | KnowledgeEdit::Remove { path } => {
+                vec![path.as_str()]
+            }
+            KnowledgeEdit::Move { from, to } => vec![from.as_str(), to.as_str()],
+        }
+    }
+
+    /// One-line description for PR bodies.
+    pub fn summary(&self) -> String {
+        match self {
+            KnowledgeEdit::Write {
+                path,
+                overwrite: false,
+                ..
+            } => format!("Add `{}`", path),
+            KnowledgeEdit::Write { path, .. } => format!("Write `{}`", path),
+            KnowledgeEdit::Move { from, to } => format!("Move `{}` to `{}`", from, to),
+            KnowledgeEdit::Remove { path } => format!("Remove `{}`", path),
+        }
+    }
+
+    fn apply(&self, worktree: &Path) -> Result<()> {
+        match self {
+            KnowledgeEdit::Write {
+                path,
+                content,
+                overwrite,
+            } => {
+                let target = worktree.join(path);
+                if target.exists() && !overwrite {
+                    bail!(
+                        "{} already exists. Use `bm knowledge edit {}` to change it.",
+                        path,
+                        path
+                    );
+                }
+                if let Some(parent) = target.parent() {
+                    fs::create_dir_all(parent)
+                        .with_context(|| format!("Failed to create {}", parent.display()))?;
+                }
+                fs::write(&target, content).with_context(|| format!("Failed to write {}", path))
+            }
+            KnowledgeEdit::Move { from, to } => {
+                if !worktree.join(from).is_file() {
+                    bail!("File not found: {}", from);
+                }
+                if worktree.join(to).exists() {
+                    bail!("{} already exists", to);
+                }
+                if let Some(parent) = worktree.join(to).parent() {
+                    fs::create_dir_all(parent)
+                        .with_context(|| format!("Failed to create {}", parent.display()))?;
+                }
+                run_git(worktree, &["mv", from, to])
+            }
+            KnowledgeEdit::Remove { path } => {
+                if !worktree.join(path).is_file() {
+                    bail!("File not found: {}", path);
+                }
+                run_git(worktree, &["rm", "-q", path])
+            }
+        }
+    }
+}
+
+/// A reviewable knowledge change: the PR title, its body, and the edits.
+#[derive(Debug, Clone)]
+pub struct Proposal {
+    pub title: String,
+    pub body: String,
+    pub edits: Vec<KnowledgeEdit>,
+}
+
+impl Proposal {
+    /// Body followed by a list of the edits.
+    fn full_body(&self) -> String {
+        let mut body = self.body.trim_end().to_string();
+        if !body.is_empty() {
+            body.push_str("\n\n");
+        }
+        body.push_str("Changes:\n");
+        for edit in &self.edits {
+            body.push_str(&format!("- {}\n", edit.summary()));
+        }
+        body
+    }
+}
+
+/// A pushed proposal branch.
+#[derive(Debug)]
+pub struct ProposalBranch {
+    pub branch: String,
+    pub base: String,
+}
+
+/// Reads `path` from the remote default branch, which proposals are based
+/// on, so an edit starts from the content its pull request will change
+/// rather than from a possibly stale local checkout.
+pub fn read_base_file(team_repo: &Path, path: &str) -> Result<String> {
+    validate_authoring_path(path)?;
+    run_git(team_repo, &["fetch", "-q", "origin"]).context("Failed to fetch the team repo")?;
+    let base = default_branch(team_repo);
+    let output = std::process::Command::new("git")
+        .args(["show", &format!("origin/{}:{}", base, path)])
+        .current_dir(team_repo)
+        .output()
+        .context("Failed to run git show")?;
+    if !output.status.success() {
+        bail!("File not found on origin/{}: {}", base, path);
+    }
+    String::from_utf8(output.stdout).with_context(|| format!("{} is not valid UTF-8", path))
+}
+
+/// Pushes the proposal to a new branch and opens a PR on `repo`
+/// (`owner/name`) through the team's forge. Returns the branch and the PR
+/// URL.
+pub fn open_pull_request(
+    forge: &dyn Forge,
+    repo: &str,
+    team_repo: &Path,
+    proposal: &Proposal,
+) -> Result<(ProposalBranch, String)> {
+    let pushed = push_proposal_branch(team_repo, proposal)?;
+    let url = forge
+        .create_pull_request(
+            repo,
+            &pushed.branch,
+            &pushed.base,
+            &proposal.title,
+            &proposal.full_body(),
+        )
+        .with_context(|| {
+            format!(
+                "Branch '{}' was pushed but the pull request could not be opened",
+                pushed.branch
+            )
+        })?;
+    Ok((pushed, url))
+}
+
+/// Applies the proposal's edits on a fresh branch from the remote default
+/// branch, commits, and pushes it to `origin`.
+pub(crate) fn push_proposal_branch(
+    team_repo: &Path,
+    proposal: &Proposal,
+) -> Result<ProposalBranch> {
+    if proposal.edits.is_empty() {
+        bail!("Nothing to propose");
+    }
+    for edit in &proposal.edits {
+        for path in edit.paths() {
+            validate_authoring_path(path)?;
+        }
+    }
+
+    run_git(team_repo, &["fetch", "-q", "origin"]).context("Failed to fetch the team repo")?;
+    let base = default_branch(team_repo);
+    let branch = branch_name(
+        &proposal.title,
+        &chrono::Utc::now().format("%Y%m%d%H%M%S").to_string(),
+    );
+
+    let tmp = tempfile::tempdir().context("Failed to create a temporary directory")?;
+    let worktree = tmp.path().join("worktree");
+    let worktree_str = worktree.to_string_lossy().to_string();
+    run_git(
+        team_repo,
+        &[
+            "worktree",
+            "add",
+            "-q",
+            "-b",
+            &branch,
+            &worktree_str,
+            &format!("origin/{}", base),
+        ],
+    )?;
+
+    let result = commit_and_push(&worktree, proposal, &branch);
+
+    // The branch lives on the remote now (or failed to); don't leave a
+    // local `knowledge/*` branch behind either way.
+    let _ = run_git(team_repo, &["worktree", "remove", "--force", &worktree_str]);
+    let _ = run_git(team_repo, &["branch", "-q", "-D", &branch]);
+    result.map(|_| ProposalBranch { branch, base })
+}
+
+fn commit_and_push(worktree: &Path, proposal: &Proposal, branch: &str) -> Result<()> {
+    for edit in &proposal.edits {
+        edit.apply(worktree)?;
+    }
+    run_git(worktree, &["add", "-A"])?;
+    if git_stdout(worktree, &["status", "--porcelain"])?.is_empty() {
+        bail!("The change leaves the team repo unchanged");
+    }
+    run_git(worktree, &["commit", "-q", "-m", &proposal.title])?;
+    run_git(worktree, &["push", "-q", "-u", "origin", branch])
+        .context("Failed to push the knowledge branch")
+}
+
+/// The remote's default branch, from `origin/HEAD`. Falls back to `main`.
+fn default_branch(team_repo: &Path) -> String {
+    git_stdout(
+        team_repo,
+        &["symbolic-ref", "--short", "refs/remotes/origin/HEAD"],
+    )
+    .ok()
+    .and_then(|r| r.strip_prefix("origin/").map(String::from))
+    .unwrap_or_else(|| "main".to_string())
+}
+
+/// `knowledge/<slug>-<stamp>`, with the slug taken from the title.
+fn branch_name(title: &str, stamp: &str) -> String {
+    let mut slug = String::new();
+    for c in title.chars() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c.to_ascii_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+        if slug.len() >= 40 {
+            break;
+        }
+    }
+    let slug = slug.trim_end_matches('-');
+    if slug.is_empty() {
+        format!("knowledge/{}", stamp)
+    } else {
+        format!("knowledge/{}-{}", slug, stamp)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn placement(scope: Scope) -> Placement<'static> {
+        Placement {
+            scope,
+            kind: KnowledgeKind::Knowledge,
+            project: Some("api"),
+            member: Some("dev-alice"),
+        }
+    }
+
+    #[test]
+    fn placement_paths_per_scope() {
+        assert_eq!(
+            placement(Scope::Team).file_path("style").unwrap(),
+            "knowledge/style.md"
+        );
+        assert_eq!(
+            placement(Scope::Project).file_path("style.md").unwrap(),
+            "projects/api/knowledge/style.md"
+        );
+        assert_eq!(
+            placement(Scope::Member).file_path("style.md").unwrap(),
+            "members/dev-alice/knowledge/style.md"
+        );
+        let mut p = placement(Scope::MemberProject);
+        p.kind = KnowledgeKind::Invariants;
+        assert_eq!(
+            p.file_path("tests.md").unwrap(),
+            "members/dev-alice/projects/api/invariants/tests.md"
+        );
+    }
+
+    #[test]
+    fn placement_requires_scope_arguments() {
+        let p = Placement {
+            scope: Scope::Project,
+            kind: KnowledgeKind::Knowledge,
+            project: None,
+            member: None,
+        };
+        let err = p.file_path("style.md").unwrap_err().to_string();
+        assert!(err.contains("--project"), "{}", err);
+        assert!(placement(Scope::Team).file_path("../x.md").is_err());
+    }
+
+    #[test]
+    fn authoring_paths_reject_traversal_and_non_markdown() {
+        assert!(validate_authoring_path("knowledge/style.md").is_ok());
+        assert!(validate_authoring_path("knowledge/../botminter.yml").is_err());
+        assert!(validate_authoring_path("members/a/knowledge/../../x.md").is_err());
+        assert!(validate_authoring_path("knowledge/run.sh").is_err());
+        assert!(validate_authoring_path("skills/x.md").is_err());
+    }
+
+    #[test]
+    fn kind_of_path_reads_scope_position() {
+        assert_eq!(
+            kind_of_path("invariants/a.md"),
+            Some(KnowledgeKind::Invariants)
+        );
+        assert_eq!(
+            kind_of_path("members/invariants/knowledge/a.md"),
+            Some(KnowledgeKind::Knowledge)
+        );
+        assert_eq!(
+            kind_of_path("members/m/projects/p/invariants/a.md"),
+            Some(KnowledgeKind::Invariants)
+        );
+    }
+
+    #[test]
+    fn branch_name_slugs_title() {
+        assert_eq!(
+            branch_name("knowledge: Add style.md (team)", "20260101"),
+            "knowledge/knowledge-add-style-md-team-20260101"
+        );
+        assert_eq!(branch_name("!!!", "1"), "knowledge/1");
+    }
+
+    /// A bare `origin` and a clone of it with one knowledge file.
+    fn clone_with_origin(tmp: &Path) -> std::path::PathBuf {
+        let origin = tmp.join("origin.git");
+        fs::create_dir_all(&origin).unwrap();
+        run_git(&origin, &["init", "-q", "--bare", "-b", "main"]).unwrap();
+
+        let seed = tmp.join("seed");
+        fs::create_dir_all(seed.join("knowledge")).unwrap();
+        run_git(&seed, &["init", "-q", "-b", "main"]).unwrap();
+        run_git(&seed, &["config", "user.email", "test@example.com"]).unwrap();
+        run_git(&seed, &["config", "user.name", "Test"]).unwrap();
+        fs::write(seed.join("knowledge/style.md"), "tabs").unwrap();
+        run_git(&seed, &["add", "-A"]).unwrap();
+        run_git(&seed, &["commit", "-q", "-m", "init"]).unwrap();
+        run_git(&seed, &["push", "-q", origin.to_str().unwrap(), "main"]).unwrap();
+
+        run_git(tmp, &["clone", "-q", origin.to_str().unwrap(), "team"]).unwrap();
+        let team = tmp.join("team");
+        run_git(&team, &["config", "user.email", "test@example.com"]).unwrap();
+        run_git(&team, &["config", "user.name", "Test"]).unwrap();
+        team
+    }
+
+    #[test]
+    fn proposal_branch_is_pushed_without_touching_checkout() {
+        let tmp = tempfile::tempdir().unwrap();
+        let team = clone_with_origin(tmp.path());
+        fs::write(team.join("scratch.txt"), "local work").unwrap();
+
+        let proposal = Proposal {
+            title: "knowledge: move style".to_string(),
+            body: String::new(),
+            edits: vec![
+                KnowledgeEdit::Move {
+                    from: "knowledge/style.md".to_string(),
+                    to: "members/dev-alice/knowledge/style.md".to_string(),
+                },
+                KnowledgeEdit::Write {
+                    path: "invariants/tests.md".to_string(),
+                    content: "tests pass".to_string(),
+                    overwrite: false,
+                },
+            ],
+        };
+        let pushed = push_proposal_branch(&team, &proposal).unwrap();
+        assert_eq!(pushed.base, "main");
+        assert!(pushed.branch.starts_with("knowledge/knowledge-move-style-"));
+
+        // The caller's checkout is untouched and keeps no proposal branch.
+        assert!(team.join("knowledge/style.md").exists());
+        assert!(team.join("scratch.txt").exists());
+        assert_eq!(
+            git_stdout(&team, &["rev-parse", "--abbrev-ref", "HEAD"]).unwrap(),
+            "main"
+        );
+        assert_eq!(
+            git_stdout(&team, &["branch", "--list", "knowledge/*"]).unwrap(),
+            ""
+        );
+
+        let origin = tmp.path().join("origin.git");
+        let files = git_stdout(&origin, &["ls-tree", "-r", "--name-only", &pushed.branch]).unwrap();
+        assert_eq!(
+            files.lines().collect::<Vec<_>>(),
+            vec![
+                "invariants/tests.md",
+                "members/dev-alice/knowledge/style.md"
+            ]
+        );
+    }
+
+    #[test]
+    fn read_base_file_uses_remote_content() {
+        let tmp = tempfile::tempdir().unwrap();
+        let team = clone_with_origin(tmp.path());
+        // A stale local copy differs from origin.
+        fs::write(team.join("knowledge/style.md"), "local edit").unwrap();
+
+        assert_eq!(read_base_file(&team, "knowledge/style.md").unwrap(), "tabs");
+        let err = read_base_file(&team, "knowledge/missing.md").unwrap_err();
+        assert!(err.to_string().contains("not found on origin/main"), "{}", err);
+    }
+
+    #[test]
+    fn failed_proposal_leaves_no_branch() {
+        let tmp = tempfile::tempdir().unwrap();
+        let team = clone_with_origin(tmp.path());
+
+        let proposal = Proposal {
+            title: "knowledge: add style".to_string(),
+            body: String::new(),
+            edits: vec![KnowledgeEdit::Write {
+                path: "knowledge/style.md".to_string(),
+                content: "spaces".to_string(),
+                overwrite: false,
+            }],
+        };
+        let err = push_proposal_branch(&team, &proposal)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("already exists"), "{}", err);
+        assert_eq!(
+            git_stdout(&team, &["branch", "--list", "knowledge/*"]).unwrap(),
+            ""
+        );
+    }
+}
@@ -0,0 +1,15 @@
+//! Team knowledge and invariants across scopes.
+
+mod authoring;
+mod resolve;
+mod search;
+
+pub use authoring::{
+    kind_of_path, open_pull_request, read_base_file, validate_authoring_path, KnowledgeEdit,
+    Placement, Proposal, ProposalBranch,
+};
+pub use resolve::{resolve_knowledge, KnowledgeKind, KnowledgeResolution, ResolvedFile, Scope};
+pub use search::{
+    index_path, refresh_index, search_team, DocKind, IndexStats, SearchFilter, SearchHit,
+    SearchIndex,
+};
@@ -0,0 +1,299 @@
+//! Effective knowledge and invariants for one member.
+//!
+//! Each scope directory contributes its top-level `.md` files, layered from the
+//! least to the most specific scope: team → project → member → member-project.
+//! A file in a more specific scope replaces a same-named file from a broader
+//! one, the way `symlink_md_files` overwrites links when a workspace is
+//! assembled.
+
+use std::path::Path;
+use std::str::FromStr;
+
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::profile;
+
+/// One layer of the knowledge hierarchy.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Scope {
+    Team,
+    Project,
+    Member,
+    MemberProject,
+}
+
+impl Scope {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Scope::Team => "team",
+            Scope::Project => "project",
+            Scope::Member => "member",
+            Scope::MemberProject => "member-project",
+        }
+    }
+}
+
+impl FromStr for Scope {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "team" => Ok(Scope::Team),
+            "project" => Ok(Scope::Project),
+            "member" => Ok(Scope::Member),
+            "member-project" => Ok(Scope::MemberProject),
+            other => bail!(
+                "Unknown scope '{}'. Expected one of: team, project, member, member-project",
+                other
+            ),
+        }
+    }
+}
+
+/// Knowledge (guidance) or invariants (rules that must hold).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum KnowledgeKind {
+    Knowledge,
+    Invariants,
+}
+
+impl KnowledgeKind {
+    /// Directory name inside each scope.
+    pub fn dir(&self) -> &'static str {
+        match self {
+            KnowledgeKind::Knowledge => "knowledge",
+            KnowledgeKind::Invariants => "invariants",
+        }
+    }
+}
+
+/// A file found in one scope.
+#[derive(Debug, Clone, Serialize)]
+pub struct ResolvedFile {
+    /// File name — the key scopes override each other by.
+    pub name: String,
+    pub scope: Scope,
+    /// Path relative to the team repo (`projects/api/knowledge/style.md`).
+    pub path: String,
+    /// Paths of broader-scope files this one replaces.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub overrides: Vec<String>,
+    /// Path of the narrower-scope file that replaces this one, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shadowed_by: Option<String>,
+}
+
+impl ResolvedFile {
+    /// Whether the member's agent actually sees this file.
+    pub fn is_effective(&self) -> bool {
+        self.shadowed_by.is_none()
+    }
+}
+
+/// Everything a member sees, in resolution order.
+#[derive(Debug, Clone, Serialize)]
+pub struct KnowledgeResolution {
+    pub member: String,
+    pub project: Option<String>,
+    /// Every file found, broadest scope first; shadowed files included.
+    pub knowledge: Vec<ResolvedFile>,
+    pub invariants: Vec<ResolvedFile>,
+}
+
+impl KnowledgeResolution {
+    pub fn files(&self, kind: KnowledgeKind) -> &[ResolvedFile] {
+        match kind {
+            KnowledgeKind::Knowledge => &self.knowledge,
+            KnowledgeKind::Invariants => &self.invariants,
+        }
+    }
+}
+
+/// Resolves the knowledge and invariants `member` sees, optionally while
+/// working on `project`. Without a project only team and member scopes apply.
+pub fn resolve_knowledge(
+    team_repo: &Path,
+    member: &str,
+    project: Option<&str>,
+) -> Result<KnowledgeResolution> {
+    let members_dir = team_repo.join("members");
+    if !members_dir.join(member).is_dir() {
+        bail!(
+            "Member '{}' not found. Available members: {}",
+            member,
+            profile::list_subdirs(&members_dir).join(", ")
+        );
+    }
+    if let Some(p) = project {
+        let projects_dir = team_repo.join("projects");
+        if !projects_dir.join(p).is_dir() {
+            bail!(
+                "Project '{}' not found. Available projects: {}",
+                p,
+                profile::list_subdirs(&projects_dir).join(", ")
+            );
+        }
+    }
+
+    let mut layers: Vec<(Scope, String)> = vec![(Scope::Team, String::new())];
+    if let Some(p) = project {
+        layers.push((Scope::Project, format!("projects/{}/", p)));
+    }
+    layers.push((Scope::Member, format!("members/{}/", member)));
+    if let Some(p) = project {
+        layers.push((
+            Scope::MemberProject,
+            format!("members/{}/projects/{}/", member, p),
+        ));
+    }
+
+    Ok(KnowledgeResolution {
+        member: member.to_string(),
+        project: project.map(String::from),
+        knowledge: resolve_kind(team_repo, &layers, KnowledgeKind::Knowledge),
+        invariants: resolve_kind(team_repo, &layers, KnowledgeKind::Invariants),
+    })
+}
+
+fn resolve_kind(
+    team_repo: &Path,
+    layers: &[(Scope, String)],
+    kind: KnowledgeKind,
+) -> Vec<ResolvedFile> {
+    let mut files: Vec<ResolvedFile> = Vec::new();
+    for (scope, prefix) in layers {
+        let base = team_repo.join(prefix);
+        for name in profile::list_scope_files(&base, kind.dir()) {
+            let path = format!("{}{}/{}", prefix, kind.dir(), name);
+            let mut overrides = Vec::new();
+            for earlier in files
+                .iter_mut()
+                .filter(|f| f.name == name && f.shadowed_by.is_none())
+            {
+                earlier.shadowed_by = Some(path.clone());
+                overrides.push(earlier.path.clone());
+            }
+            files.push(ResolvedFile {
+                name,
+                scope: scope.clone(),
+                path,
+                overrides,
+                shadowed_by: None,
+            });
+        }
+    }
+    files
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn write(repo: &Path, rel: &str) {
+        let path = repo.join(rel);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, "x").unwrap();
+    }
+
+    fn team_repo() -> tempfile::TempDir {
+        let tmp = tempfile::tempdir().unwrap();
+        let repo = tmp.path();
+        write(repo, "knowledge/commits.md");
+        write(repo, "knowledge/style.md");
+        write(repo, "knowledge/notes.txt");
+        write(repo, "invariants/tests.md");
+        write(repo, "projects/api/knowledge/style.md");
+        write(repo, "projects/api/knowledge/endpoints.md");
+        write(repo, "projects/web/knowledge/css.md");
+        write(repo, "members/dev-alice/knowledge/style.md");
+        write(repo, "members/dev-alice/invariants/tests.md");
+        write(
+            repo,
+            "members/dev-alice/projects/api/knowledge/endpoints.md",
+        );
+        tmp
+    }
+
+    fn paths(files: &[ResolvedFile], effective: bool) -> Vec<&str> {
+        files
+            .iter()
+            .filter(|f| f.is_effective() == effective)
+            .map(|f| f.path.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn resolves_in_scope_order_with_shadowing() {
+        let tmp = team_repo();
+        let r = resolve_knowledge(tmp.path(), "dev-alice", Some("api")).unwrap();
+
+        assert_eq!(
+            paths(&r.knowledge, true),
+            vec![
+                "knowledge/commits.md",
+                "members/dev-alice/knowledge/style.md",
+                "members/dev-alice/projects/api/knowledge/endpoints.md",
+            ]
+        );
+        assert_eq!(
+            paths(&r.knowledge, false),
+            vec![
+                "knowledge/style.md",
+                "projects/api/knowledge/endpoints.md",
+                "projects/api/knowledge/style.md",
+            ]
+        );
+
+        let member_style = r
+            .knowledge
+            .iter()
+            .find(|f| f.path == "members/dev-alice/knowledge/style.md")
+            .unwrap();
+        assert_eq!(member_style.scope, Scope::Member);
+        assert_eq!(
+            member_style.overrides,
+            vec!["projects/api/knowledge/style.md"]
+        );
+        let team_style = &r.knowledge[1];
+        assert_eq!(
+            team_style.shadowed_by.as_deref(),
+            Some("projects/api/knowledge/style.md")
+        );
+
+        assert_eq!(
+            paths(&r.invariants, true),
+            vec!["members/dev-alice/invariants/tests.md"]
+        );
+    }
+
+    #[test]
+    fn without_project_skips_project_scopes() {
+        let tmp = team_repo();
+        let r = resolve_knowledge(tmp.path(), "dev-alice", None).unwrap();
+        assert!(r.knowledge.iter().all(|f| !f.path.contains("projects/")));
+        assert_eq!(
+            paths(&r.knowledge, true),
+            vec![
+                "knowledge/commits.md",
+                "members/dev-alice/knowledge/style.md"
+            ]
+        );
+    }
+
+    #[test]
+    fn unknown_member_or_project_errors() {
+        let tmp = team_repo();
+        let err = resolve_knowledge(tmp.path(), "nobody", None)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("dev-alice"), "{}", err);
+        let err = resolve_knowledge(tmp.path(), "dev-alice", Some("mobile"))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("api, web"), "{}", err);
+    }
+}
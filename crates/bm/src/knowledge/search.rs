@@ -0,0 +1,695 @@
+//! Full-text search over a team repo's knowledge, invariants, prompts and hats.
+//!
+//! The inverted index lives in the repo's git directory
+//! (`<git-dir>/bm-search-index.json`), so it is never committed and works the
+//! same for an operator's team repo and a member's `team/` submodule. Each
+//! update re-reads only files whose size or modification time changed.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::UNIX_EPOCH;
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+
+use super::resolve::Scope;
+use crate::git::git_stdout;
+
+const INDEX_VERSION: u32 = 1;
+const INDEX_FILE: &str = "bm-search-index.json";
+/// Files larger than this are not indexed.
+const MAX_FILE_BYTES: u64 = 1024 * 1024;
+const SNIPPET_CHARS: usize = 160;
+
+/// What an indexed file is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum DocKind {
+    Knowledge,
+    Invariant,
+    /// `PROMPT.md` files and `ralph-prompts/`.
+    Prompt,
+    /// `ralph.yml` hat definitions.
+    Hats,
+}
+
+impl DocKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            DocKind::Knowledge => "knowledge",
+            DocKind::Invariant => "invariant",
+            DocKind::Prompt => "prompt",
+            DocKind::Hats => "hats",
+        }
+    }
+}
+
+impl FromStr for DocKind {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "knowledge" => Ok(DocKind::Knowledge),
+            "invariant" | "invariants" => Ok(DocKind::Invariant),
+            "prompt" | "prompts" => Ok(DocKind::Prompt),
+            "hats" | "hat" => Ok(DocKind::Hats),
+            other => bail!(
+                "Unknown kind '{}'. Expected one of: knowledge, invariant, prompt, hats",
+                other
+            ),
+        }
+    }
+}
+
+/// Narrows a search. Unset fields match everything.
+#[derive(Debug, Clone, Default)]
+pub struct SearchFilter {
+    pub scope: Option<Scope>,
+    pub member: Option<String>,
+    pub project: Option<String>,
+    pub kind: Option<DocKind>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct IndexedDoc {
+    kind: DocKind,
+    scope: Scope,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    member: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    project: Option<String>,
+    size: u64,
+    modified_ns: u64,
+    /// Term frequencies.
+    terms: BTreeMap<String, u32>,
+}
+
+/// The on-disk inverted index for one team repo.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct SearchIndex {
+    version: u32,
+    docs: BTreeMap<String, IndexedDoc>,
+    /// Term → paths of the documents containing it.
+    postings: BTreeMap<String, BTreeSet<String>>,
+}
+
+/// What an index update did.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct IndexStats {
+    /// Files (re)read because they were new or changed.
+    pub indexed: usize,
+    /// Files dropped because they no longer exist.
+    pub removed: usize,
+    /// Files in the index after the update.
+    pub total: usize,
+}
+
+/// One search result.
+#[derive(Debug, Clone, Serialize)]
+pub struct SearchHit {
+    /// Path relative to the team repo.
+    pub path: String,
+    pub kind: DocKind,
+    pub scope: Scope,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub member: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+    pub score: f64,
+    /// 1-based line of the snippet.
+    pub line: usize,
+    pub snippet: String,
+    /// Byte ranges of matched terms within `snippet`.
+    pub highlights: Vec<(usize, usize)>,
+}
+
+impl SearchHit {
+    /// The snippet with every highlight wrapped by `wrap`.
+    pub fn highlighted(&self, wrap: impl Fn(&str) -> String) -> String {
+        let mut out = String::new();
+        let mut last = 0;
+        for &(start, end) in &self.highlights {
+            out.push_str(&self.snippet[last..start]);
+            out.push_str(&wrap(&self.snippet[start..end]));
+            last = end;
+        }
+        out.push_str(&self.snippet[last..]);
+        out
+    }
+}
+
+/// Where the index for `team_repo` is stored.
+pub fn index_path(team_repo: &Path) -> Result<PathBuf> {
+    let git_dir = git_stdout(team_repo, &["rev-parse", "--absolute-git-dir"])
+        .with_context(|| format!("{} is not a git repository", team_repo.display()))?;
+    Ok(PathBuf::from(git_dir).join(INDEX_FILE))
+}
+
+/// Brings the team repo's index up to date and saves it.
+pub fn refresh_index(team_repo: &Path) -> Result<IndexStats> {
+    load_updated(team_repo).map(|(_, stats)| stats)
+}
+
+/// Refreshes the index, then searches it.
+pub fn search_team(
+    team_repo: &Path,
+    query: &str,
+    filter: &SearchFilter,
+    limit: usize,
+) -> Result<Vec<SearchHit>> {
+    let (index, _) = load_updated(team_repo)?;
+    index.search(team_repo, query, filter, limit)
+}
+
+fn load_updated(team_repo: &Path) -> Result<(SearchIndex, IndexStats)> {
+    let path = index_path(team_repo)?;
+    let mut index = SearchIndex::load(&path);
+    let stats = index.update(team_repo)?;
+    if stats.indexed > 0 || stats.removed > 0 || !path.exists() {
+        index.save(&path)?;
+    }
+    Ok((index, stats))
+}
+
+impl SearchIndex {
+    /// Loads an index, starting empty when it is missing, unreadable or from
+    /// another index version.
+    pub fn load(path: &Path) -> SearchIndex {
+        fs::read_to_string(path)
+            .ok()
+            .and_then(|s| serde_json::from_str::<SearchIndex>(&s).ok())
+            .filter(|i| i.version == INDEX_VERSION)
+            .unwrap_or_else(|| SearchIndex {
+                version: INDEX_VERSION,
+                ..Default::default()
+            })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string(self).context("Failed to serialize search index")?;
+        fs::write(path, json)
+            .with_context(|| format!("Failed to write search index {}", path.display()))
+    }
+
+    /// Re-reads new and changed files under `team_repo` and drops deleted ones.
+    pub fn update(&mut self, team_repo: &Path) -> Result<IndexStats> {
+        let mut stats = IndexStats::default();
+        let mut seen = BTreeSet::new();
+
+        let mut files = Vec::new();
+        walk(team_repo, team_repo, &mut files)?;
+        for (rel, kind) in files {
+            let meta = match fs::metadata(team_repo.join(&rel)) {
+                Ok(m) => m,
+                Err(_) => continue,
+            };
+            if meta.len() > MAX_FILE_BYTES {
+                continue;
+            }
+            let modified_ns = meta
+                .modified()
+                .ok()
+                .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+                .map(|d| d.as_nanos() as u64)
+                .unwrap_or(0);
+            seen.insert(rel.clone());
+
+            if let Some(doc) = self.docs.get(&rel) {
+                if doc.size == meta.len() && doc.modified_ns == modified_ns {
+                    continue;
+                }
+            }
+            let content = match fs::read_to_string(team_repo.join(&rel)) {
+                Ok(c) => c,
+                Err(_) => continue,
+            };
+
+            let mut terms: BTreeMap<String, u32> = BTreeMap::new();
+            for (_, _, term) in tokenize(&content) {
+                *terms.entry(term).or_insert(0) += 1;
+            }
+            let (scope, member, project) = scope_of(&rel);
+            self.remove_doc(&rel);
+            for term in terms.keys() {
+                self.postings
+                    .entry(term.clone())
+                    .or_default()
+                    .insert(rel.clone());
+            }
+            self.docs.insert(
+                rel,
+                IndexedDoc {
+                    kind,
+                    scope,
+                    member,
+                    project,
+                    size: meta.len(),
+                    modified_ns,
+                    terms,
+                },
+            );
+            stats.indexed += 1;
+        }
+
+        let stale: Vec<String> = self
+            .docs
+            .keys()
+            .filter(|p| !seen.contains(*p))
+            .cloned()
+            .collect();
+        for path in stale {
+            self.remove_doc(&path);
+            self.docs.remove(&path);
+            stats.removed += 1;
+        }
+
+        stats.total = self.docs.len();
+        Ok(stats)
+    }
+
+    fn remove_doc(&mut self, path: &str) {
+        let Some(doc) = self.docs.get(path) else {
+            return;
+        };
+        for term in doc.terms.keys() {
+            if let Some(paths) = self.postings.get_mut(term) {
+                paths.remove(path);
+                if paths.is_empty() {
+                    self.postings.remove(term);
+                }
+            }
+        }
+    }
+
+    /// Documents containing every query term, best first. Scores are tf-idf.
+    pub fn search(
+        &self,
+        team_repo: &Path,
+        query: &str,
+        filter: &SearchFilter,
+        limit: usize,
+    ) -> Result<Vec<SearchHit>> {
+        let terms: BTreeSet<String> = tokenize(query).into_iter().map(|(_, _, t)| t).collect();
+        if terms.is_empty() {
+            bail!("Search query has no searchable terms");
+        }
+
+        let mut candidates: Option<BTreeSet<&String>> = None;
+        for term in &terms {
+            let paths: BTreeSet<&String> = self
+                .postings
+                .get(term)
+                .map(|p| p.iter().collect())
+                .unwrap_or_default();
+            candidates = Some(match candidates {
+                None => paths,
+                Some(c) => c.intersection(&paths).copied().collect(),
+            });
+        }
+
+        let total = self.docs.len() as f64;
+        let mut hits = Vec::new();
+        for path in candidates.unwrap_or_default() {
+            let doc = &self.docs[path];
+            if !matches(doc, filter) {
+                continue;
+            }
+            let score = terms
+                .iter()
+                .map(|t| {
+                    let tf = *doc.terms.get(t).unwrap_or(&0) as f64;
+                    let df = self.postings.get(t).map_or(0, |p| p.len()) as f64;
+                    (1.0 + tf.ln()) * (1.0 + total / df).ln()
+                })
+                .sum::<f64>();
+
+            let content = fs::read_to_string(team_repo.join(path)).unwrap_or_default();
+            let (line, snippet, highlights) = snippet(&content, &terms);
+            hits.push(SearchHit {
+                path: path.clone(),
+                kind: doc.kind,
+                scope: doc.scope.clone(),
+                member: doc.member.clone(),
+                project: doc.project.clone(),
+                score,
+                line,
+                snippet,
+                highlights,
+            });
+        }
+
+        hits.sort_by(|a, b| {
+            b.score
+                .partial_cmp(&a.score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.path.cmp(&b.path))
+        });
+        hits.truncate(limit);
+        Ok(hits)
+    }
+}
+
+fn matches(doc: &IndexedDoc, filter: &SearchFilter) -> bool {
+    accepts(filter.scope.as_ref(), Some(&doc.scope))
+        && accepts(filter.kind.as_ref(), Some(&doc.kind))
+        && accepts(filter.member.as_ref(), doc.member.as_ref())
+        && accepts(filter.project.as_ref(), doc.project.as_ref())
+}
+
+/// An unset filter field accepts anything; a set one requires an equal value.
+fn accepts<T: PartialEq>(want: Option<&T>, have: Option<&T>) -> bool {
+    match want {
+        None => true,
+        Some(w) => have == Some(w),
+    }
+}
+
+/// Collects indexable files as `(relative path, kind)`, skipping symlinks and
+/// dot-directories (`.git`, member `.skeleton` snapshots, agent state).
+fn walk(root: &Path, dir: &Path, out: &mut Vec<(String, DocKind)>) -> Result<()> {
+    let entries = fs::read_dir(dir).with_context(|| format!("Failed to read {}", dir.display()))?;
+    for entry in entries.flatten() {
+        let file_type = match entry.file_type() {
+            Ok(t) => t,
+            Err(_) => continue,
+        };
+        let path = entry.path();
+        if file_type.is_dir() {
+            if !entry.file_name().to_string_lossy().starts_with('.') {
+                walk(root, &path, out)?;
+            }
+        } else if file_type.is_file() {
+            let rel = path
+                .strip_prefix(root)
+                .unwrap_or(&path)
+                .to_string_lossy()
+                .replace('\\', "/");
+            if let Some(kind) = classify(&rel) {
+                out.push((rel, kind));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Decides whether a repo-relative path is indexed, and as what.
+fn classify(rel: &str) -> Option<DocKind> {
+    let parts: Vec<&str> = rel.split('/').collect();
+    let file = *parts.last()?;
+    let dirs = &parts[..parts.len() - 1];
+
+    if file == "PROMPT.md" {
+        return Some(DocKind::Prompt);
+    }
+    if file == "ralph.yml" {
+        return Some(DocKind::Hats);
+    }
+    if !file.ends_with(".md") {
+        return None;
+    }
+    if dirs.first() == Some(&"ralph-prompts") {
+        return Some(DocKind::Prompt);
+    }
+    if dirs.contains(&"invariants") {
+        return Some(DocKind::Invariant);
+    }
+    if dirs.contains(&"knowledge") {
+        return Some(DocKind::Knowledge);
+    }
+    None
+}
+
+/// Scope, member and project a path belongs to, from its prefix.
+fn scope_of(rel: &str) -> (Scope, Option<String>, Option<String>) {
+    let parts: Vec<&str> = rel.split('/').collect();
+    match parts.as_slice() {
+        ["members", member, "projects", project, _, ..] => (
+            Scope::MemberProject,
+            Some(member.to_string()),
+            Some(project.to_string()),
+        ),
+        ["members", member, _, ..] => (Scope::Member, Some(member.to_string()), None),
+        ["projects", project, _, ..] => (Scope::Project, None, Some(project.to_string())),
+        _ => (Scope::Team, None, None),
+    }
+}
+
+/// Lowercased alphanumeric terms (at least two characters) with their byte spans.
+fn tokenize(text: &str) -> Vec<(usize, usize, String)> {
+    let mut tokens = Vec::new();
+    let mut start = None;
+    for (i, c) in text
+        .char_indices()
+        .chain(std::iter::once((text.len(), ' ')))
+    {
+        if c.is_alphanumeric() || c == '_' {
+            start.get_or_insert(i);
+        } else if let Some(s) = start.take() {
+            let word = &text[s..i];
+            if word.chars().count() >= 2 {
+                tokens.push((s, i, word.to_lowercase()));
+            }
+        }
+    }
+    tokens
+}
+
+/// Picks the line matching the most distinct query terms and trims it to a
+/// window around the first match.
+fn snippet(content: &str, terms: &BTreeSet<String>) -> (usize, String, Vec<(usize, usize)>) {
+    let mut best: Option<(usize, &str, usize)> = None;
+    for (i, line) in content.lines().enumerate() {
+        let found: BTreeSet<String> = tokenize(line)
+            .into_iter()
+            .map(|(_, _, t)| t)
+            .filter(|t| terms.contains(t))
+            .collect();
+        let better = match best {
+            Some((_, _, n)) => found.len() > n,
+            None => !found.is_empty(),
+        };
+        if better {
+            best = Some((i + 1, line, found.len()));
+        }
+    }
+    let Some((line_no, line, _)) = best else {
+        return (0, String::new(), Vec::new());
+    };
+
+    let line = line.trim();
+    let first = tokenize(line)
+        .into_iter()
+        .find(|(_, _, t)| terms.contains(t))
+        .map_or(0, |(s, _, _)| s);
+    let mut start = if line.len() > SNIPPET_CHARS {
+        first.saturating_sub(SNIPPET_CHARS / 3)
+    } else {
+        0
+    };
+    while !line.is_char_boundary(start) {
+        start -= 1;
+    }
+    let mut end = (start + SNIPPET_CHARS).min(line.len());
+    while !line.is_char_boundary(end) {
+        end -= 1;
+    }
+
+    let mut text = String::new();
+    if start > 0 {
+        text.push('…');
+    }
+    text.push_str(&line[start..end]);
+    if end < line.len() {
+        text.push('…');
+    }
+    let highlights = tokenize(&text)
+        .into_iter()
+        .filter(|(_, _, t)| terms.contains(t))
+        .map(|(s, e, _)| (s, e))
+        .collect();
+    (line_no, text, highlights)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::git::run_git;
+
+    fn write(repo: &Path, rel: &str, content: &str) {
+        let path = repo.join(rel);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    fn team_repo() -> tempfile::TempDir {
+        let tmp = tempfile::tempdir().unwrap();
+        let repo = tmp.path();
+        run_git(repo, &["init", "-q", "-b", "main"]).unwrap();
+        write(
+            repo,
+            "knowledge/commits.md",
+            "# Commits\n\nUse conventional commits.\nReference the issue in every commit.\n\
+             Keep one commit per change.\n",
+        );
+        write(
+            repo,
+            "invariants/tests.md",
+            "Every commit keeps the tests green.\n",
+        );
+        write(
+            repo,
+            "projects/api/knowledge/errors.md",
+            "Return problem+json errors from the API.\n",
+        );
+        write(
+            repo,
+            "members/dev-alice/PROMPT.md",
+            "You are Alice. Commit small changes.\n",
+        );
+        write(
+            repo,
+            "members/dev-alice/ralph.yml",
+            "hats:\n  reviewer:\n    instructions: check each commit\n",
+        );
+        write(
+            repo,
+            "members/dev-alice/projects/api/knowledge/local.md",
+            "Run the api locally with just dev.\n",
+        );
+        write(repo, "skills/gh/SKILL.md", "commit helpers\n");
+        tmp
+    }
+
+    fn paths(hits: &[SearchHit]) -> Vec<&str> {
+        hits.iter().map(|h| h.path.as_str()).collect()
+    }
+
+    #[test]
+    fn classifies_and_scopes_paths() {
+        assert_eq!(classify("knowledge/a.md"), Some(DocKind::Knowledge));
+        assert_eq!(
+            classify("members/m/hats/h/knowledge/a.md"),
+            Some(DocKind::Knowledge)
+        );
+        assert_eq!(
+            classify("projects/p/invariants/a.md"),
+            Some(DocKind::Invariant)
+        );
+        assert_eq!(
+            classify("ralph-prompts/reference/x.md"),
+            Some(DocKind::Prompt)
+        );
+        assert_eq!(classify("members/m/ralph.yml"), Some(DocKind::Hats));
+        assert_eq!(classify("skills/gh/SKILL.md"), None);
+        assert_eq!(classify("knowledge/run.sh"), None);
+
+        assert_eq!(
+            scope_of("members/m/projects/p/knowledge/a.md"),
+            (
+                Scope::MemberProject,
+                Some("m".to_string()),
+                Some("p".to_string())
+            )
+        );
+        assert_eq!(scope_of("projects/p/knowledge/a.md").0, Scope::Project);
+        assert_eq!(scope_of("knowledge/a.md").0, Scope::Team);
+    }
+
+    #[test]
+    fn search_skips_dot_directories() {
+        let tmp = team_repo();
+        write(
+            tmp.path(),
+            "members/dev-alice/.skeleton/PROMPT.md",
+            "commit snapshot\n",
+        );
+        write(tmp.path(), ".github/knowledge/ci.md", "commit checks\n");
+        let hits = search_team(tmp.path(), "commit", &SearchFilter::default(), 10).unwrap();
+        assert_eq!(hits.len(), 4, "{:?}", paths(&hits));
+    }
+
+    #[test]
+    fn search_requires_all_terms_and_ranks_by_frequency() {
+        let tmp = team_repo();
+        let hits = search_team(tmp.path(), "commit", &SearchFilter::default(), 10).unwrap();
+        assert_eq!(hits[0].path, "knowledge/commits.md");
+        assert_eq!(hits.len(), 4, "{:?}", paths(&hits));
+        assert!(!paths(&hits).contains(&"skills/gh/SKILL.md"));
+
+        let hits = search_team(tmp.path(), "commit tests", &SearchFilter::default(), 10).unwrap();
+        assert_eq!(paths(&hits), vec!["invariants/tests.md"]);
+    }
+
+    #[test]
+    fn filters_narrow_results() {
+        let tmp = team_repo();
+        let member = SearchFilter {
+            member: Some("dev-alice".to_string()),
+            ..Default::default()
+        };
+        let hits = search_team(tmp.path(), "commit", &member, 10).unwrap();
+        assert_eq!(
+            paths(&hits),
+            vec!["members/dev-alice/PROMPT.md", "members/dev-alice/ralph.yml"]
+        );
+
+        let hats = SearchFilter {
+            kind: Some(DocKind::Hats),
+            ..Default::default()
+        };
+        let hits = search_team(tmp.path(), "commit", &hats, 10).unwrap();
+        assert_eq!(paths(&hits), vec!["members/dev-alice/ralph.yml"]);
+
+        let project = SearchFilter {
+            project: Some("api".to_string()),
+            scope: Some(Scope::MemberProject),
+            ..Default::default()
+        };
+        let hits = search_team(tmp.path(), "api", &project, 10).unwrap();
+        assert_eq!(
+            paths(&hits),
+            vec!["members/dev-alice/projects/api/knowledge/local.md"]
+        );
+    }
+
+    #[test]
+    fn snippet_highlights_matched_terms() {
+        let tmp = team_repo();
+        let hits = search_team(tmp.path(), "conventional", &SearchFilter::default(), 10).unwrap();
+        let hit = &hits[0];
+        assert_eq!(hit.line, 3);
+        assert_eq!(hit.snippet, "Use conventional commits.");
+        assert_eq!(
+            hit.highlighted(|t| format!("[{}]", t)),
+            "Use [conventional] commits."
+        );
+    }
+
+    #[test]
+    fn index_updates_incrementally() {
+        let tmp = team_repo();
+        let stats = refresh_index(tmp.path()).unwrap();
+        assert_eq!(stats.indexed, 6);
+        assert!(index_path(tmp.path()).unwrap().exists());
+
+        let stats = refresh_index(tmp.path()).unwrap();
+        assert_eq!(stats.indexed, 0);
+        assert_eq!(stats.total, 6);
+
+        write(
+            tmp.path(),
+            "knowledge/commits.md",
+            "Squash before merging.\n",
+        );
+        fs::remove_file(tmp.path().join("invariants/tests.md")).unwrap();
+        let stats = refresh_index(tmp.path()).unwrap();
+        assert_eq!((stats.indexed, stats.removed, stats.total), (1, 1, 5));
+
+        let hits = search_team(tmp.path(), "squash", &SearchFilter::default(), 10).unwrap();
+        assert_eq!(paths(&hits), vec!["knowledge/commits.md"]);
+        let hits = search_team(tmp.path(), "conventional", &SearchFilter::default(), 10).unwrap();
+        assert!(hits.is_empty());
+    }
+}
@@ -1,6 +1,7 @@
 pub mod acp;
 pub mod agent_cli;
 pub mod agent_tags;
+pub mod audit;
 pub mod brain;
 pub mod bridge;
 pub mod chat;
@@ -8,13 +9,19 @@ pub mod cli;
 pub mod commands;
 pub mod config;
 pub mod daemon;
+pub mod forge;
 pub mod formation;
 pub mod git;
+pub mod invariants;
+pub mod knowledge;
+pub mod mcp;
 pub mod member_lifecycle;
 pub mod profile;
+pub mod schedule;
 pub mod session;
 pub mod state;
 pub mod team;
+pub mod team_spec;
 pub mod topology;
 pub mod web;
 pub mod workspace;
@@ -2,9 +2,11 @@ use anyhow::Result;
 use clap::Parser;
 use clap_complete::CompleteEnv;
 
+use bm::audit::{self, Actor, Record};
 use bm::cli::{
-    BridgeCommand, BridgeIdentityCommand, BridgeRoomCommand, Cli, Command, CredentialsCommand,
-    DaemonCommand, DebugCommand, EnvCommand, KnowledgeCommand, MembersCommand, ProfilesCommand,
+    AuditCommand, BridgeCommand, BridgeIdentityCommand, BridgeRoomCommand, Cli, Command,
+    CredentialsCommand, DaemonCommand, DaemonEventsCommand, DebugCommand, EnvCommand,
+    InvariantsCommand, KnowledgeCommand, McpCommand, MembersCommand, ProfilesCommand,
     ProjectsCommand, RolesCommand, RuntimeCommand, TeamsCommand,
 };
 use bm::commands;
@@ -27,6 +29,10 @@ fn main() -> Result<()> {
             skip_github,
             workzone,
             credentials_file,
+            forge,
+            forge_url,
+            github_host,
+            github_api_base,
         } => {
             if non_interactive {
                 commands::init::run_non_interactive(
@@ -40,18 +46,44 @@ fn main() -> Result<()> {
                     skip_github,
                     workzone,
                     credentials_file,
+                    forge,
+                    forge_url,
+                    github_host,
+                    github_api_base,
                 )?;
             } else {
-                commands::init::run()?;
+                commands::init::run(github_host, github_api_base)?;
             }
         }
 
+        Command::Apply { file, dry_run, prune, yes, sync, skip_github, workzone } => {
+            commands::apply::run(commands::apply::ApplyOptions {
+                file: &file,
+                dry_run,
+                prune,
+                yes,
+                sync,
+                skip_github,
+                workzone,
+            })?;
+        }
+
         Command::Profiles { command } => match command {
             ProfilesCommand::List => commands::profiles::list()?,
             ProfilesCommand::Describe { profile, show_tags } => {
                 commands::profiles::describe(&profile, show_tags)?
             }
             ProfilesCommand::Init { force } => commands::profiles_init::run(force)?,
+            ProfilesCommand::Create {
+                name,
+                from_team,
+                agent_tags,
+                force,
+            } => commands::profiles::create(name.as_deref(), &from_team, agent_tags, force)?,
+            ProfilesCommand::Add { source, force } => commands::profiles::add(&source, force)?,
+            ProfilesCommand::Update { profile, check } => {
+                commands::profiles::update(profile.as_deref(), check)?
+            }
         },
 
         Command::Teams { command } => match command {
@@ -76,6 +108,7 @@ fn main() -> Result<()> {
             private_key_file,
             installation_id,
             save_credentials,
+            bot_token,
         } => {
             let app_flags = commands::hire::AppCredentialFlags {
                 reuse_app,
@@ -84,8 +117,28 @@ fn main() -> Result<()> {
                 private_key_file: private_key_file.as_deref(),
                 installation_id: installation_id.as_deref(),
                 save_credentials: save_credentials.as_deref(),
+                bot_token: bot_token.as_deref(),
+            };
+            let result = commands::hire::run(&role, name.as_deref(), team.as_deref(), app_flags);
+            // Member directories are `<role>-<name>`; without a result the
+            // generated name is unknown.
+            let member_dir = match &result {
+                Ok(dir) => Some(dir.clone()),
+                Err(_) => name.as_ref().map(|n| format!("{}-{}", role, n)),
             };
-            commands::hire::run(&role, name.as_deref(), team.as_deref(), app_flags)?;
+            let record = Record::new(Actor::cli(), "hire")
+                .target(member_dir.as_deref())
+                .param("role", role.as_str())
+                .param("reuse_app", reuse_app)
+                .param("app_id", app_id.clone())
+                .param("client_id", client_id.clone())
+                .param("private_key_file", private_key_file.clone())
+                .param("installation_id", installation_id.clone())
+                .param("save_credentials", save_credentials.clone())
+                .param("bot_token", bot_token.clone())
+                .outcome(&result);
+            audit::record_cli(team.as_deref(), record);
+            result?;
         }
 
         Command::Fire {
@@ -95,7 +148,15 @@ fn main() -> Result<()> {
             yes,
             delete_repo,
         } => {
-            commands::fire::run(&member, team.as_deref(), keep_app, yes, delete_repo)?;
+            let result = commands::fire::run(&member, team.as_deref(), keep_app, yes, delete_repo);
+            let record = Record::new(Actor::cli(), "fire")
+                .target(Some(member.as_str()))
+                .param("keep_app", keep_app)
+                .param("yes", yes)
+                .param("delete_repo", delete_repo)
+                .outcome(&result);
+            audit::record_cli(team.as_deref(), record);
+            result?;
         }
 
         Command::Members { command } => match command {
@@ -105,6 +166,48 @@ fn main() -> Result<()> {
             MembersCommand::Show { member, team } => {
                 commands::members::show(&member, team.as_deref())?;
             }
+            MembersCommand::Refresh {
+                member,
+                all,
+                dry_run,
+                team,
+            } => {
+                commands::members::refresh(member.as_deref(), all, dry_run, team.as_deref())?;
+            }
+            MembersCommand::Archive {
+                member,
+                reason,
+                team,
+            } => {
+                let result =
+                    commands::members::archive(&member, reason.as_deref(), team.as_deref());
+                let record = Record::new(Actor::cli(), "members.archive")
+                    .target(Some(member.as_str()))
+                    .param("reason", reason.clone())
+                    .outcome(&result);
+                audit::record_cli(team.as_deref(), record);
+                result?;
+            }
+            MembersCommand::Restore { member, team } => {
+                let result = commands::members::restore(&member, team.as_deref());
+                let record = Record::new(Actor::cli(), "members.restore")
+                    .target(Some(member.as_str()))
+                    .outcome(&result);
+                audit::record_cli(team.as_deref(), record);
+                result?;
+            }
+            MembersCommand::Doctor { member, fix, team } => {
+                let result = commands::members::doctor(member.as_deref(), fix, team.as_deref());
+                // Only --fix changes anything.
+                if fix {
+                    let record = Record::new(Actor::cli(), "members.doctor")
+                        .target(member.as_deref())
+                        .param("fix", fix)
+                        .outcome(&result);
+                    audit::record_cli(team.as_deref(), record);
+                }
+                result?;
+            }
         },
 
         Command::Roles { command } => match command {
@@ -130,7 +233,60 @@ fn main() -> Result<()> {
 
         Command::Credentials { command } => match command {
             CredentialsCommand::Export { output, team } => {
-                commands::credentials::export(&output, team.as_deref())?;
+                let result = commands::credentials::export(&output, team.as_deref());
+                let record = Record::new(Actor::cli(), "credentials.export")
+                    .param("output", output.as_str())
+                    .outcome(&result);
+                audit::record_cli(team.as_deref(), record);
+                result?;
+            }
+            CredentialsCommand::Rotate {
+                member,
+                key_file,
+                team,
+            } => {
+                let result =
+                    commands::credentials::rotate(&member, key_file.as_deref(), team.as_deref());
+                let record = Record::new(Actor::cli(), "credentials.rotate")
+                    .target(Some(member.as_str()))
+                    .param("key_file", key_file.clone())
+                    .outcome(&result);
+                audit::record_cli(team.as_deref(), record);
+                result?;
+            }
+        },
+
+        Command::Audit { command } => match command {
+            AuditCommand::List {
+                actor,
+                action,
+                since,
+                limit,
+                team,
+            } => {
+                commands::audit::list(
+                    actor.as_deref(),
+                    action.as_deref(),
+                    since.as_deref(),
+                    limit,
+                    team.as_deref(),
+                )?;
+            }
+            AuditCommand::Verify { team } => {
+                commands::audit::verify(team.as_deref())?;
+            }
+            AuditCommand::Export {
+                format,
+                output,
+                since,
+                team,
+            } => {
+                commands::audit::export(
+                    &format,
+                    output.as_deref(),
+                    since.as_deref(),
+                    team.as_deref(),
+                )?;
             }
         },
 
@@ -148,11 +304,101 @@ fn main() -> Result<()> {
                 let team_flag = t.as_deref().or(team.as_deref());
                 commands::knowledge::show(&path, team_flag)?;
             }
+            Some(KnowledgeCommand::Resolve {
+                member,
+                project,
+                team: t,
+            }) => {
+                let team_flag = t.as_deref().or(team.as_deref());
+                commands::knowledge::resolve(&member, project.as_deref(), team_flag)?;
+            }
+            Some(KnowledgeCommand::Add {
+                name,
+                scope: s,
+                project,
+                member,
+                invariant,
+                from,
+                message,
+                team: t,
+            }) => {
+                let team_flag = t.as_deref().or(team.as_deref());
+                commands::knowledge::add(
+                    &name,
+                    &s,
+                    project.as_deref(),
+                    member.as_deref(),
+                    invariant,
+                    from.as_deref(),
+                    message.as_deref(),
+                    team_flag,
+                )?;
+            }
+            Some(KnowledgeCommand::Edit {
+                path,
+                message,
+                team: t,
+            }) => {
+                let team_flag = t.as_deref().or(team.as_deref());
+                commands::knowledge::edit(&path, message.as_deref(), team_flag)?;
+            }
+            Some(KnowledgeCommand::Move {
+                path,
+                scope: s,
+                project,
+                member,
+                message,
+                team: t,
+            }) => {
+                let team_flag = t.as_deref().or(team.as_deref());
+                commands::knowledge::move_file(
+                    &path,
+                    &s,
+                    project.as_deref(),
+                    member.as_deref(),
+                    message.as_deref(),
+                    team_flag,
+                )?;
+            }
+            Some(KnowledgeCommand::Search {
+                query,
+                scope: s,
+                member,
+                project,
+                kind,
+                limit,
+                team: t,
+            }) => {
+                let team_flag = t.as_deref().or(team.as_deref());
+                commands::knowledge::search(
+                    &query.join(" "),
+                    s.as_deref(),
+                    member.as_deref(),
+                    project.as_deref(),
+                    kind.as_deref(),
+                    limit,
+                    team_flag,
+                )?;
+            }
+            Some(KnowledgeCommand::Rm {
+                path,
+                message,
+                team: t,
+            }) => {
+                let team_flag = t.as_deref().or(team.as_deref());
+                commands::knowledge::remove(&path, message.as_deref(), team_flag)?;
+            }
             None => {
                 commands::knowledge::interactive(team.as_deref(), scope.as_deref())?;
             }
         },
 
+        Command::Invariants { command } => match command {
+            InvariantsCommand::Check { member, team } => {
+                commands::invariants::check(member.as_deref(), team.as_deref())?;
+            }
+        },
+
         Command::Bridge { command } => match command {
             BridgeCommand::Start { team } => commands::bridge::start(team.as_deref())?,
             BridgeCommand::Stop { team } => commands::bridge::stop(team.as_deref())?,
@@ -194,8 +440,16 @@ fn main() -> Result<()> {
                 port,
                 interval,
                 bind,
+                webhook_url,
             } => {
-                commands::daemon::start(team.as_deref(), &mode, port, interval, &bind)?;
+                commands::daemon::start(
+                    team.as_deref(),
+                    &mode,
+                    port,
+                    interval,
+                    &bind,
+                    webhook_url.as_deref(),
+                )?;
             }
             DaemonCommand::Stop { team } => {
                 commands::daemon::stop(team.as_deref())?;
@@ -203,6 +457,44 @@ fn main() -> Result<()> {
             DaemonCommand::Status { team } => {
                 commands::daemon::status(team.as_deref())?;
             }
+            DaemonCommand::Install {
+                team,
+                mode,
+                port,
+                interval,
+                bind,
+                bridge,
+                no_start,
+            } => {
+                commands::daemon::install(
+                    team.as_deref(),
+                    &mode,
+                    port,
+                    interval,
+                    &bind,
+                    bridge,
+                    no_start,
+                )?;
+            }
+            DaemonCommand::Uninstall { team } => {
+                commands::daemon::uninstall(team.as_deref())?;
+            }
+            DaemonCommand::Events { command } => match command {
+                DaemonEventsCommand::List { team, limit } => {
+                    commands::daemon::events_list(team.as_deref(), limit)?;
+                }
+                DaemonEventsCommand::Show { id, team } => {
+                    commands::daemon::events_show(&id, team.as_deref())?;
+                }
+                DaemonEventsCommand::Replay { id, team } => {
+                    let result = commands::daemon::events_replay(&id, team.as_deref());
+                    let record = Record::new(Actor::cli(), "daemon.events.replay")
+                        .target(Some(id.as_str()))
+                        .outcome(&result);
+                    audit::record_cli(team.as_deref(), record);
+                    result?;
+                }
+            },
         },
 
         Command::DaemonRun {
@@ -211,8 +503,9 @@ fn main() -> Result<()> {
             port,
             interval,
             bind,
+            start_members,
         } => {
-            commands::daemon::run_daemon(&team, &mode, port, interval, &bind)?;
+            commands::daemon::run_daemon(&team, &mode, port, interval, &bind, start_members)?;
         }
 
         Command::Chat {
@@ -242,10 +535,60 @@ fn main() -> Result<()> {
             no_bridge,
             bridge_only,
         } => {
-            commands::start::run(team.as_deref(), formation.as_deref(), no_bridge, bridge_only, member.as_deref())?;
+            let result = commands::start::run(
+                team.as_deref(),
+                formation.as_deref(),
+                no_bridge,
+                bridge_only,
+                member.as_deref(),
+            );
+            // Member starts go through the daemon, which records them as
+            // `members.start`; only a bridge-only start never reaches it.
+            if bridge_only {
+                let record = Record::new(Actor::cli(), "start")
+                    .target(member.as_deref())
+                    .param("formation", formation.clone())
+                    .param("bridge_only", bridge_only)
+                    .outcome(&result);
+                audit::record_cli(team.as_deref(), record);
+            }
+            result?;
+        }
+        Command::Stop {
+            member,
+            team,
+            force,
+            bridge,
+            all,
+        } => {
+            let result =
+                commands::stop::run(team.as_deref(), force, member.as_deref(), bridge, all);
+            let record = Record::new(Actor::cli(), "stop")
+                .target(member.as_deref())
+                .param("force", force)
+                .param("bridge", bridge)
+                .param("all", all)
+                .outcome(&result);
+            audit::record_cli(team.as_deref(), record);
+            result?;
+        }
+        Command::Pause { member, team, now, timeout } => {
+            let result = commands::pause::run(team.as_deref(), member.as_deref(), now, timeout);
+            let record = Record::new(Actor::cli(), "pause")
+                .target(member.as_deref())
+                .param("now", now)
+                .param("timeout", timeout)
+                .outcome(&result);
+            audit::record_cli(team.as_deref(), record);
+            result?;
         }
-        Command::Stop { member, team, force, bridge, all } => {
-            commands::stop::run(team.as_deref(), force, member.as_deref(), bridge, all)?;
+        Command::Resume { member, team } => {
+            let result = commands::resume::run(team.as_deref(), member.as_deref());
+            let record = Record::new(Actor::cli(), "resume")
+                .target(member.as_deref())
+                .outcome(&result);
+            audit::record_cli(team.as_deref(), record);
+            result?;
         }
         Command::Status { team, verbose } => {
             commands::status::run(team.as_deref(), verbose)?;
@@ -290,6 +633,11 @@ fn main() -> Result<()> {
         Command::Attach { team } => {
             commands::attach::run(team.as_deref())?;
         }
+        Command::Mcp { command } => match command {
+            McpCommand::Serve { mode, team, actor } => {
+                commands::mcp::serve(&mode, team.as_deref(), actor.as_deref())?;
+            }
+        },
         Command::Debug { command } => match command {
             DebugCommand::BrainLogs {
                 member,
@@ -299,6 +647,9 @@ fn main() -> Result<()> {
             } => {
                 commands::debug::brain_logs(&member, team.as_deref(), lines, entries)?;
             }
+            DebugCommand::DaemonLogs { team, lines } => {
+                commands::debug::daemon_logs(team.as_deref(), lines)?;
+            }
         },
         Command::Completions { shell } => {
             commands::completions::run(shell)?;
@@ -0,0 +1,218 @@
+//! Model Context Protocol server (`bm mcp serve`).
+//!
+//! Exposes team operations as typed MCP tools over stdio so coding agents
+//! (Minty, brain members) can query and drive a team without parsing CLI
+//! tables. Read-only mode exposes queries; operator mode adds tools that start
+//! loops, write to member inboxes and sync workspaces.
+
+mod protocol;
+mod tools;
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::audit::Actor;
+
+pub use protocol::serve;
+pub use tools::ToolContext;
+
+/// Server name reported in `serverInfo` and used as the key in MCP config files.
+pub const SERVER_NAME: &str = "botminter";
+
+/// Which tools the server exposes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum McpMode {
+    /// Query tools only (status, members, loops, knowledge, board).
+    #[default]
+    ReadOnly,
+    /// Query tools plus loop start, inbox write and teams sync.
+    Operator,
+}
+
+impl McpMode {
+    pub fn parse(s: &str) -> Result<Self> {
+        match s {
+            "read-only" => Ok(McpMode::ReadOnly),
+            "operator" => Ok(McpMode::Operator),
+            other => bail!(
+                "Unknown MCP mode '{}'. Use 'read-only' or 'operator'.",
+                other
+            ),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            McpMode::ReadOnly => "read-only",
+            McpMode::Operator => "operator",
+        }
+    }
+}
+
+/// Path of the `bm` binary to register as the MCP server command.
+pub fn server_command() -> PathBuf {
+    std::env::current_exe().unwrap_or_else(|_| PathBuf::from("bm"))
+}
+
+/// Registers the botminter server in an MCP config file
+/// (`{"mcpServers": {...}}`), creating it if needed. Other servers in the
+/// file are preserved. `actor` is who the server's actions are audited as
+/// (the operator when unset). Returns `true` if the file changed.
+pub fn register_server(
+    config_file: &Path,
+    command: &Path,
+    mode: McpMode,
+    team: Option<&str>,
+    actor: Option<&Actor>,
+) -> Result<bool> {
+    let existing = if config_file.exists() {
+        Some(
+            fs::read_to_string(config_file)
+                .with_context(|| format!("Failed to read {}", config_file.display()))?,
+        )
+    } else {
+        None
+    };
+
+    let mut root: serde_json::Value = match &existing {
+        Some(contents) => serde_json::from_str(contents)
+            .with_context(|| format!("Failed to parse {}", config_file.display()))?,
+        None => serde_json::json!({}),
+    };
+    let Some(obj) = root.as_object_mut() else {
+        bail!("{} is not a JSON object", config_file.display());
+    };
+
+    let mut args = vec![
+        "mcp".to_string(),
+        "serve".to_string(),
+        "--mode".to_string(),
+        mode.as_str().to_string(),
+    ];
+    if let Some(team) = team {
+        args.push("--team".to_string());
+        args.push(team.to_string());
+    }
+    if let Some(actor) = actor {
+        args.push("--actor".to_string());
+        args.push(actor.to_string());
+    }
+
+    let servers = obj
+        .entry("mcpServers")
+        .or_insert_with(|| serde_json::json!({}));
+    let Some(servers) = servers.as_object_mut() else {
+        bail!(
+            "'mcpServers' in {} is not a JSON object",
+            config_file.display()
+        );
+    };
+    servers.insert(
+        SERVER_NAME.to_string(),
+        serde_json::json!({
+            "command": command.to_string_lossy(),
+            "args": args,
+        }),
+    );
+
+    let rendered = format!("{}\n", serde_json::to_string_pretty(&root)?);
+    if existing.as_deref() == Some(rendered.as_str()) {
+        return Ok(false);
+    }
+    fs::write(config_file, rendered)
+        .with_context(|| format!("Failed to write {}", config_file.display()))?;
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mode_parse_round_trips() {
+        for mode in [McpMode::ReadOnly, McpMode::Operator] {
+            assert_eq!(McpMode::parse(mode.as_str()).unwrap(), mode);
+        }
+        assert!(McpMode::parse("admin").is_err());
+    }
+
+    #[test]
+    fn mode_in_profile_defaults_to_read_only() {
+        assert_eq!(McpMode::default(), McpMode::ReadOnly);
+        for mode in [McpMode::ReadOnly, McpMode::Operator] {
+            let yaml = serde_yml::to_string(&mode).unwrap();
+            assert_eq!(yaml.trim(), mode.as_str());
+            assert_eq!(serde_yml::from_str::<McpMode>(&yaml).unwrap(), mode);
+        }
+    }
+
+    #[test]
+    fn register_server_creates_config() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join(".mcp.json");
+
+        let changed = register_server(
+            &path,
+            Path::new("/usr/bin/bm"),
+            McpMode::Operator,
+            Some("alpha"),
+            Some(&Actor::agent("chief-ann")),
+        )
+        .unwrap();
+        assert!(changed);
+
+        let json: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        let server = &json["mcpServers"]["botminter"];
+        assert_eq!(server["command"], "/usr/bin/bm");
+        assert_eq!(
+            server["args"],
+            serde_json::json!([
+                "mcp",
+                "serve",
+                "--mode",
+                "operator",
+                "--team",
+                "alpha",
+                "--actor",
+                "agent:chief-ann"
+            ])
+        );
+    }
+
+    #[test]
+    fn register_server_preserves_other_servers_and_is_idempotent() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join(".mcp.json");
+        fs::write(
+            &path,
+            r#"{"mcpServers":{"other":{"command":"other-server"}}}"#,
+        )
+        .unwrap();
+
+        assert!(register_server(&path, Path::new("bm"), McpMode::ReadOnly, None, None).unwrap());
+        assert!(!register_server(&path, Path::new("bm"), McpMode::ReadOnly, None, None).unwrap());
+
+        let json: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(json["mcpServers"]["other"]["command"], "other-server");
+        assert_eq!(
+            json["mcpServers"]["botminter"]["args"],
+            serde_json::json!(["mcp", "serve", "--mode", "read-only"])
+        );
+    }
+
+    #[test]
+    fn register_server_refuses_invalid_json() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join(".mcp.json");
+        fs::write(&path, "not json").unwrap();
+
+        assert!(register_server(&path, Path::new("bm"), McpMode::ReadOnly, None, None).is_err());
+        assert_eq!(fs::read_to_string(&path).unwrap(), "not json");
+    }
+}
@@ -0,0 +1,268 @@
+//! JSON-RPC 2.0 over newline-delimited stdio, as used by MCP's stdio transport.
+
+use std::io::{BufRead, Write};
+
+use anyhow::{Context, Result};
+use serde_json::{json, Value};
+
+use super::tools::{self, ToolContext};
+use super::SERVER_NAME;
+
+/// Protocol revision answered when the client asks for one we don't know.
+const LATEST_PROTOCOL_VERSION: &str = "2025-06-18";
+
+/// Protocol revisions this server can speak. Only the tools capability is
+/// offered, which is unchanged across these revisions.
+const SUPPORTED_PROTOCOL_VERSIONS: &[&str] = &["2025-06-18", "2025-03-26", "2024-11-05"];
+
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+
+/// A JSON-RPC error returned for protocol-level failures. Tool failures are
+/// reported in the tool result (`isError`) instead, so the model sees them.
+struct RpcError {
+    code: i64,
+    message: String,
+}
+
+impl RpcError {
+    fn new(code: i64, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+/// Serves MCP requests read line by line from `input` until it closes.
+pub fn serve(input: impl BufRead, mut output: impl Write, ctx: &ToolContext) -> Result<()> {
+    for line in input.lines() {
+        let line = line.context("Failed to read MCP request")?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        if let Some(response) = handle_line(&line, ctx) {
+            writeln!(output, "{}", response).context("Failed to write MCP response")?;
+            output.flush()?;
+        }
+    }
+    Ok(())
+}
+
+/// Handles one incoming message. Returns `None` for notifications and for
+/// responses to requests we never send.
+fn handle_line(line: &str, ctx: &ToolContext) -> Option<Value> {
+    let msg: Value = match serde_json::from_str(line) {
+        Ok(v) => v,
+        Err(e) => {
+            return Some(error_response(
+                Value::Null,
+                RpcError::new(PARSE_ERROR, format!("Parse error: {}", e)),
+            ))
+        }
+    };
+
+    let Some(method) = msg.get("method").and_then(Value::as_str) else {
+        if msg.get("result").is_some() || msg.get("error").is_some() {
+            return None;
+        }
+        let id = msg.get("id").cloned().unwrap_or(Value::Null);
+        return Some(error_response(
+            id,
+            RpcError::new(INVALID_REQUEST, "Invalid request"),
+        ));
+    };
+
+    // Notifications (no id) need no reply; none of them change our state.
+    let id = msg.get("id").cloned()?;
+    let params = msg.get("params").cloned().unwrap_or(Value::Null);
+
+    Some(match dispatch(method, &params, ctx) {
+        Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+        Err(e) => error_response(id, e),
+    })
+}
+
+fn dispatch(method: &str, params: &Value, ctx: &ToolContext) -> Result<Value, RpcError> {
+    match method {
+        "initialize" => Ok(initialize(params, ctx)),
+        "ping" => Ok(json!({})),
+        "tools/list" => Ok(json!({ "tools": tools::list(ctx.mode) })),
+        "tools/call" => call_tool(params, ctx),
+        other => Err(RpcError::new(
+            METHOD_NOT_FOUND,
+            format!("Method not found: {}", other),
+        )),
+    }
+}
+
+fn initialize(params: &Value, ctx: &ToolContext) -> Value {
+    let requested = params["protocolVersion"].as_str().unwrap_or_default();
+    let version = if SUPPORTED_PROTOCOL_VERSIONS.contains(&requested) {
+        requested
+    } else {
+        LATEST_PROTOCOL_VERSION
+    };
+
+    json!({
+        "protocolVersion": version,
+        "capabilities": { "tools": { "listChanged": false } },
+        "serverInfo": {
+            "name": SERVER_NAME,
+            "version": env!("CARGO_PKG_VERSION"),
+        },
+        "instructions": format!(
+            "botminter team operations ({} mode). Prefer these tools over parsing `bm` CLI output.",
+            ctx.mode.as_str()
+        ),
+    })
+}
+
+fn call_tool(params: &Value, ctx: &ToolContext) -> Result<Value, RpcError> {
+    let name = params["name"]
+        .as_str()
+        .ok_or_else(|| RpcError::new(INVALID_PARAMS, "Missing tool name"))?;
+    let tool = tools::find(name, ctx.mode)
+        .ok_or_else(|| RpcError::new(INVALID_PARAMS, format!("Unknown tool: {}", name)))?;
+
+    let args = match &params["arguments"] {
+        Value::Null => json!({}),
+        Value::Object(_) => params["arguments"].clone(),
+        _ => {
+            return Err(RpcError::new(
+                INVALID_PARAMS,
+                "Tool arguments must be an object",
+            ))
+        }
+    };
+
+    Ok(match (tool.run)(ctx, &args) {
+        Ok(value) => {
+            let text = serde_json::to_string_pretty(&value).unwrap_or_default();
+            json!({
+                "content": [{ "type": "text", "text": text }],
+                "structuredContent": value,
+                "isError": false,
+            })
+        }
+        Err(e) => json!({
+            "content": [{ "type": "text", "text": format!("{:#}", e) }],
+            "isError": true,
+        }),
+    })
+}
+
+fn error_response(id: Value, err: RpcError) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": { "code": err.code, "message": err.message },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audit::Actor;
+    use crate::mcp::McpMode;
+
+    fn ctx(mode: McpMode) -> ToolContext {
+        ToolContext {
+            mode,
+            team: None,
+            actor: Actor::cli(),
+        }
+    }
+
+    fn roundtrip(requests: &[Value], mode: McpMode) -> Vec<Value> {
+        let input: String = requests.iter().map(|r| format!("{}\n", r)).collect();
+        let mut output = Vec::new();
+        serve(input.as_bytes(), &mut output, &ctx(mode)).unwrap();
+        String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn initialize_negotiates_version() {
+        let responses = roundtrip(
+            &[
+                json!({"jsonrpc":"2.0","id":1,"method":"initialize","params":{"protocolVersion":"2025-03-26"}}),
+                json!({"jsonrpc":"2.0","id":2,"method":"initialize","params":{"protocolVersion":"1999-01-01"}}),
+            ],
+            McpMode::ReadOnly,
+        );
+        assert_eq!(responses[0]["result"]["protocolVersion"], "2025-03-26");
+        assert_eq!(responses[0]["result"]["serverInfo"]["name"], "botminter");
+        assert!(responses[0]["result"]["capabilities"]["tools"].is_object());
+        assert_eq!(
+            responses[1]["result"]["protocolVersion"],
+            LATEST_PROTOCOL_VERSION
+        );
+    }
+
+    #[test]
+    fn notifications_get_no_response() {
+        let responses = roundtrip(
+            &[
+                json!({"jsonrpc":"2.0","method":"notifications/initialized"}),
+                json!({"jsonrpc":"2.0","id":"a","method":"ping"}),
+            ],
+            McpMode::ReadOnly,
+        );
+        assert_eq!(responses.len(), 1);
+        assert_eq!(responses[0]["id"], "a");
+        assert_eq!(responses[0]["result"], json!({}));
+    }
+
+    #[test]
+    fn malformed_and_unknown_requests_return_errors() {
+        let mut output = Vec::new();
+        serve(
+            "not json\n{\"jsonrpc\":\"2.0\",\"id\":3,\"method\":\"resources/list\"}\n".as_bytes(),
+            &mut output,
+            &ctx(McpMode::ReadOnly),
+        )
+        .unwrap();
+        let responses: Vec<Value> = String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect();
+        assert_eq!(responses[0]["error"]["code"], PARSE_ERROR);
+        assert_eq!(responses[0]["id"], Value::Null);
+        assert_eq!(responses[1]["error"]["code"], METHOD_NOT_FOUND);
+        assert_eq!(responses[1]["id"], 3);
+    }
+
+    #[test]
+    fn operator_tools_hidden_in_read_only_mode() {
+        let responses = roundtrip(
+            &[
+                json!({"jsonrpc":"2.0","id":1,"method":"tools/call","params":{"name":"inbox_write","arguments":{}}}),
+            ],
+            McpMode::ReadOnly,
+        );
+        assert_eq!(responses[0]["error"]["code"], INVALID_PARAMS);
+    }
+
+    #[test]
+    fn tool_failures_are_reported_as_tool_errors() {
+        let responses = roundtrip(
+            &[
+                json!({"jsonrpc":"2.0","id":1,"method":"tools/call","params":{"name":"knowledge_show","arguments":{}}}),
+            ],
+            McpMode::ReadOnly,
+        );
+        let result = &responses[0]["result"];
+        assert_eq!(result["isError"], true);
+        assert!(result["content"][0]["text"]
+            .as_str()
+            .unwrap()
+            .contains("Missing required argument 'path'"));
+    }
+}
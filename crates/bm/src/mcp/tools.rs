@@ -0,0 +1,771 @@
+//! Tools exposed by `bm mcp serve`.
+//!
+//! Each tool loads config fresh on every call, so the server reflects hires,
+//! syncs and starts made while it is running.
+
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+use serde_json::{json, Value};
+
+use super::McpMode;
+use crate::audit::{self, Actor, Record};
+use crate::brain::inbox;
+use crate::config::{self, BotminterConfig, TeamEntry};
+use crate::daemon::{DaemonClient, StartLoopRequest};
+use crate::git;
+use crate::profile;
+use crate::session;
+use crate::state::{self, MemberStatus};
+use crate::workspace;
+
+/// Items fetched from the project board when the caller sets no limit.
+const DEFAULT_BOARD_LIMIT: u64 = 200;
+
+/// Knowledge scopes accepted by `knowledge_list`, as in `bm knowledge list --scope`.
+const KNOWLEDGE_SCOPES: &[&str] = &["team", "project", "member", "member-project"];
+
+/// Sender recorded for inbox messages when the caller does not name one.
+const DEFAULT_INBOX_SENDER: &str = "mcp";
+
+/// Settings shared by all tool calls of one server.
+pub struct ToolContext {
+    pub mode: McpMode,
+    /// Team the server is pinned to (`bm mcp serve -t`). When unset, tools
+    /// take a `team` argument and fall back to the default team.
+    pub team: Option<String>,
+    /// Who operator tool calls are audited as (`bm mcp serve --actor`).
+    pub actor: Actor,
+}
+
+pub(super) struct Tool {
+    pub name: &'static str,
+    pub description: &'static str,
+    /// Only exposed in operator mode.
+    pub operator: bool,
+    pub input_schema: fn() -> Value,
+    pub run: fn(&ToolContext, &Value) -> Result<Value>,
+}
+
+const TOOLS: &[Tool] = &[
+    Tool {
+        name: "team_status",
+        description: "Team dashboard: formation, daemon, bridge and every member's status.",
+        operator: false,
+        input_schema: team_only_schema,
+        run: team_status,
+    },
+    Tool {
+        name: "members_list",
+        description: "List hired members with their role and process status.",
+        operator: false,
+        input_schema: team_only_schema,
+        run: members_list,
+    },
+    Tool {
+        name: "member_show",
+        description: "Details of one member: status, workspace, branch, coding agent, knowledge and invariants.",
+        operator: false,
+        input_schema: member_show_schema,
+        run: member_show,
+    },
+    Tool {
+        name: "loops_list",
+        description: "List Ralph loops (`ralph loops list`) in member workspaces.",
+        operator: false,
+        input_schema: loops_list_schema,
+        run: loops_list,
+    },
+    Tool {
+        name: "knowledge_list",
+        description: "List knowledge and invariant files by scope. Paths can be passed to knowledge_show.",
+        operator: false,
+        input_schema: knowledge_list_schema,
+        run: knowledge_list,
+    },
+    Tool {
+        name: "knowledge_show",
+        description: "Read a knowledge or invariant file from the team repo.",
+        operator: false,
+        input_schema: knowledge_show_schema,
+        run: knowledge_show,
+    },
+    Tool {
+        name: "board_query",
+        description: "Query items on the team's GitHub Project board, filtered by status, view or assignee.",
+        operator: false,
+        input_schema: board_query_schema,
+        run: board_query,
+    },
+    Tool {
+        name: "loop_start",
+        description: "Start a Ralph loop through the team daemon.",
+        operator: true,
+        input_schema: loop_start_schema,
+        run: loop_start,
+    },
+    Tool {
+        name: "inbox_write",
+        description: "Write a message to a member's loop inbox.",
+        operator: true,
+        input_schema: inbox_write_schema,
+        run: inbox_write,
+    },
+    Tool {
+        name: "teams_sync",
+        description: "Sync member workspaces with the team repo (`bm teams sync` without --repos/--bridge).",
+        operator: true,
+        input_schema: team_only_schema,
+        run: teams_sync,
+    },
+];
+
+/// Tool descriptors for `tools/list`.
+pub(super) fn list(mode: McpMode) -> Vec<Value> {
+    available(mode)
+        .map(|t| {
+            json!({
+                "name": t.name,
+                "description": t.description,
+                "inputSchema": (t.input_schema)(),
+                "annotations": { "readOnlyHint": !t.operator },
+            })
+        })
+        .collect()
+}
+
+/// Looks up a tool by name, hiding operator tools in read-only mode.
+pub(super) fn find(name: &str, mode: McpMode) -> Option<&'static Tool> {
+    available(mode).find(|t| t.name == name)
+}
+
+fn available(mode: McpMode) -> impl Iterator<Item = &'static Tool> {
+    TOOLS
+        .iter()
+        .filter(move |t| mode == McpMode::Operator || !t.operator)
+}
+
+// ── Schemas ─────────────────────────────────────────────────────────
+
+/// Builds an object schema; every tool also accepts an optional `team`.
+fn object_schema(mut properties: Value, required: &[&str]) -> Value {
+    properties["team"] = json!({
+        "type": "string",
+        "description": "Team name. Defaults to the server's team, then the default team."
+    });
+    json!({
+        "type": "object",
+        "properties": properties,
+        "required": required,
+    })
+}
+
+fn team_only_schema() -> Value {
+    object_schema(json!({}), &[])
+}
+
+fn member_show_schema() -> Value {
+    object_schema(
+        json!({ "member": { "type": "string", "description": "Member directory name, e.g. engineer-bob" } }),
+        &["member"],
+    )
+}
+
+fn loops_list_schema() -> Value {
+    object_schema(
+        json!({ "member": { "type": "string", "description": "Only list this member's loops" } }),
+        &[],
+    )
+}
+
+fn knowledge_list_schema() -> Value {
+    object_schema(
+        json!({ "scope": { "type": "string", "enum": KNOWLEDGE_SCOPES } }),
+        &[],
+    )
+}
+
+fn knowledge_show_schema() -> Value {
+    object_schema(
+        json!({ "path": {
+            "type": "string",
+            "description": "Path relative to the team repo, e.g. knowledge/commit-convention.md"
+        } }),
+        &["path"],
+    )
+}
+
+fn board_query_schema() -> Value {
+    object_schema(
+        json!({
+            "status": { "type": "string", "description": "Exact status, e.g. dev:implement" },
+            "view": { "type": "string", "description": "Role view from botminter.yml, e.g. PO" },
+            "assignee": { "type": "string", "description": "GitHub login" },
+            "limit": { "type": "integer", "minimum": 1, "default": DEFAULT_BOARD_LIMIT }
+        }),
+        &[],
+    )
+}
+
+fn loop_start_schema() -> Value {
+    object_schema(
+        json!({
+            "prompt": { "type": "string", "description": "Prompt for `ralph run -p`" },
+            "member": { "type": "string", "description": "Member workspace to run in (defaults to the first member)" }
+        }),
+        &["prompt"],
+    )
+}
+
+fn inbox_write_schema() -> Value {
+    object_schema(
+        json!({
+            "member": { "type": "string" },
+            "message": { "type": "string" },
+            "from": { "type": "string", "default": DEFAULT_INBOX_SENDER }
+        }),
+        &["member", "message"],
+    )
+}
+
+// ── Argument helpers ────────────────────────────────────────────────
+
+fn str_arg<'a>(args: &'a Value, key: &str) -> Option<&'a str> {
+    args.get(key)
+        .and_then(Value::as_str)
+        .filter(|s| !s.is_empty())
+}
+
+fn required_str<'a>(args: &'a Value, key: &str) -> Result<&'a str> {
+    str_arg(args, key).with_context(|| format!("Missing required argument '{}'", key))
+}
+
+/// Resolves the team for a call: `team` argument > server team > default team.
+/// A server pinned to a team refuses requests for other teams.
+fn resolve_team<'a>(
+    ctx: &ToolContext,
+    cfg: &'a BotminterConfig,
+    args: &Value,
+) -> Result<&'a TeamEntry> {
+    let requested = str_arg(args, "team");
+    if let (Some(pinned), Some(requested)) = (ctx.team.as_deref(), requested) {
+        if pinned != requested {
+            bail!("This MCP server is scoped to team '{}'", pinned);
+        }
+    }
+    config::resolve_team(cfg, requested.or(ctx.team.as_deref()))
+}
+
+/// Checks that `member` names a hired member. Names are a single directory
+/// under `members/`, so separators and leading dots (`..`) are rejected before
+/// they reach a path.
+fn require_member_dir(team: &TeamEntry, member: &str) -> Result<()> {
+    if member.starts_with('.') || member.contains(['/', '\\']) {
+        bail!("Invalid member name '{}'", member);
+    }
+    if !team.path.join("team").join("members").join(member).is_dir() {
+        bail!("Member '{}' not found in team '{}'", member, team.name);
+    }
+    Ok(())
+}
+
+/// Adds `status`, `pid` and `started_at` fields to a member object.
+fn with_status(mut member: Value, status: &MemberStatus) -> Value {
+    member["status"] = json!(status.label());
+    if let MemberStatus::Running {
+        pid, started_at, ..
+    }
+    | MemberStatus::Crashed { pid, started_at } = status
+    {
+        member["pid"] = json!(pid);
+        member["started_at"] = json!(started_at);
+    }
+    member
+}
+
+// ── Read-only tools ─────────────────────────────────────────────────
+
+fn team_status(ctx: &ToolContext, args: &Value) -> Result<Value> {
+    let cfg = config::load()?;
+    let team = resolve_team(ctx, &cfg, args)?;
+    let info = state::gather_status(team, &cfg, false)?;
+    let now = chrono::Utc::now();
+
+    let members: Vec<Value> = info
+        .members
+        .iter()
+        .map(|m| {
+            with_status(
+                json!({
+                    "name": m.name,
+                    "role": m.role,
+                    "branch": m.branch,
+                    "token": m.token_health.as_ref().map(|h| h.state(now).label()),
+                }),
+                &m.status,
+            )
+        })
+        .collect();
+
+    Ok(json!({
+        "team": team.name,
+        "formation": info.formation,
+        "projects": info.project_names,
+        "daemon": info.daemon.map(|d| json!({
+            "pid": d.pid,
+            "mode": d.mode,
+            "port": d.port,
+            "interval_secs": d.interval_secs,
+            "unit": d.unit,
+        })),
+        "members": members,
+        "crashed_cleaned": info.crashed_cleaned,
+        "bridge": info.bridge.map(|b| json!({
+            "name": b.name,
+            "type": b.bridge_type,
+            "status": b.status,
+            "url": b.url,
+        })),
+    }))
+}
+
+fn members_list(ctx: &ToolContext, args: &Value) -> Result<Value> {
+    let cfg = config::load()?;
+    let team = resolve_team(ctx, &cfg, args)?;
+    let team_repo = team.path.join("team");
+    let members_dir = team_repo.join("members");
+    let runtime_state = state::load().unwrap_or_default();
+
+    let members: Vec<Value> = profile::discover_member_dirs(&team_repo)
+        .iter()
+        .map(|m| {
+            with_status(
+                json!({ "name": m, "role": profile::read_member_role(&members_dir, m) }),
+                &state::resolve_member_status(&runtime_state, &team.name, m),
+            )
+        })
+        .collect();
+
+    Ok(json!({ "team": team.name, "members": members }))
+}
+
+fn member_show(ctx: &ToolContext, args: &Value) -> Result<Value> {
+    let member = required_str(args, "member")?;
+    let cfg = config::load()?;
+    let team = resolve_team(ctx, &cfg, args)?;
+    require_member_dir(team, member)?;
+
+    let team_repo = team.path.join("team");
+    let member_dir = team_repo.join("members").join(member);
+    let runtime_state = state::load().unwrap_or_default();
+    let status = state::resolve_member_status(&runtime_state, &team.name, member);
+
+    let mut out = with_status(
+        json!({
+            "name": member,
+            "role": profile::read_member_role(&team_repo.join("members"), member),
+            "knowledge": profile::list_files_in_dir(&member_dir.join("knowledge")),
+            "invariants": profile::list_files_in_dir(&member_dir.join("invariants")),
+        }),
+        &status,
+    );
+
+    if let Some(ws) = workspace::find_workspace(&team.path, member) {
+        let submodules: Vec<Value> = workspace::workspace_submodule_status(&ws)
+            .iter()
+            .map(|s| json!({ "name": s.name, "status": s.status.label() }))
+            .collect();
+        out["workspace"] = json!(ws.display().to_string());
+        out["workspace_repo"] = json!(workspace::workspace_remote_url(&ws));
+        out["branch"] = json!(workspace::workspace_git_branch(&ws));
+        out["submodules"] = json!(submodules);
+    }
+
+    if let Ok(manifest) = profile::read_team_repo_manifest(&team_repo) {
+        if let Ok(agent) = profile::resolve_coding_agent(team, &manifest) {
+            out["coding_agent"] = json!(agent.display_name);
+        }
+    }
+
+    Ok(out)
+}
+
+fn loops_list(ctx: &ToolContext, args: &Value) -> Result<Value> {
+    let member_filter = str_arg(args, "member");
+    let cfg = config::load()?;
+    let team = resolve_team(ctx, &cfg, args)?;
+
+    let members = match member_filter {
+        Some(m) => {
+            require_member_dir(team, m)?;
+            vec![m.to_string()]
+        }
+        None => profile::discover_member_dirs(&team.path.join("team")),
+    };
+
+    let mut out = Vec::new();
+    for member in &members {
+        let Some(ws) = workspace::find_workspace(&team.path, member) else {
+            continue;
+        };
+        out.push(match session::run_ralph_cmd(&ws, &["loops", "list"]) {
+            Ok(output) => json!({ "member": member, "loops": output.trim() }),
+            Err(e) => json!({ "member": member, "error": format!("{:#}", e) }),
+        });
+    }
+
+    Ok(json!({ "team": team.name, "members": out }))
+}
+
+fn knowledge_list(ctx: &ToolContext, args: &Value) -> Result<Value> {
+    let scope = str_arg(args, "scope");
+    if let Some(s) = scope {
+        if !KNOWLEDGE_SCOPES.contains(&s) {
+            bail!(
+                "Unknown scope '{}'. Use one of: {}",
+                s,
+                KNOWLEDGE_SCOPES.join(", ")
+            );
+        }
+    }
+    let show = |s: &str| scope.is_none() || scope == Some(s);
+
+    let cfg = config::load()?;
+    let team = resolve_team(ctx, &cfg, args)?;
+    let team_repo = team.path.join("team");
+    let team_schema = profile::read_team_schema(&team_repo)?;
+    profile::require_current_schema(&team.name, &team_schema)?;
+
+    let both = ["knowledge", "invariants"];
+    let mut files = Vec::new();
+
+    if show("team") {
+        scope_files(&mut files, &team_repo, "team", None, "", &both);
+    }
+    if show("project") {
+        for project in &profile::list_subdirs(&team_repo.join("projects")) {
+            let rel = format!("projects/{}/", project);
+            scope_files(
+                &mut files,
+                &team_repo,
+                "project",
+                Some(project.as_str()),
+                &rel,
+                &both,
+            );
+        }
+    }
+    let members = profile::list_subdirs(&team_repo.join("members"));
+    if show("member") {
+        for member in &members {
+            let rel = format!("members/{}/", member);
+            scope_files(
+                &mut files,
+                &team_repo,
+                "member",
+                Some(member.as_str()),
+                &rel,
+                &both,
+            );
+        }
+    }
+    if show("member-project") {
+        for member in &members {
+            let projects_dir = team_repo.join("members").join(member).join("projects");
+            for project in &profile::list_subdirs(&projects_dir) {
+                let owner = format!("{}/{}", member, project);
+                let rel = format!("members/{}/projects/{}/", member, project);
+                scope_files(
+                    &mut files,
+                    &team_repo,
+                    "member-project",
+                    Some(&owner),
+                    &rel,
+                    &["knowledge"],
+                );
+            }
+        }
+    }
+
+    Ok(json!({ "team": team.name, "files": files }))
+}
+
+/// Appends the `.md` files under `<team_repo>/<rel><subdir>/` to `out`.
+fn scope_files(
+    out: &mut Vec<Value>,
+    team_repo: &Path,
+    scope: &str,
+    owner: Option<&str>,
+    rel: &str,
+    subdirs: &[&str],
+) {
+    let base = team_repo.join(rel);
+    for subdir in subdirs {
+        for file in profile::list_scope_files(&base, subdir) {
+            out.push(json!({
+                "scope": scope,
+                "owner": owner,
+                "kind": subdir,
+                "path": format!("{}{}/{}", rel, subdir, file),
+            }));
+        }
+    }
+}
+
+fn knowledge_show(ctx: &ToolContext, args: &Value) -> Result<Value> {
+    let path = required_str(args, "path")?;
+    let cfg = config::load()?;
+    let team = resolve_team(ctx, &cfg, args)?;
+    let team_repo = team.path.join("team");
+    let team_schema = profile::read_team_schema(&team_repo)?;
+    profile::require_current_schema(&team.name, &team_schema)?;
+
+    let content = profile::read_knowledge_file(&team_repo, path)?;
+    Ok(json!({ "path": path, "content": content }))
+}
+
+fn board_query(ctx: &ToolContext, args: &Value) -> Result<Value> {
+    let cfg = config::load()?;
+    let team = resolve_team(ctx, &cfg, args)?;
+
+    let owner = team
+        .github_repo
+        .split('/')
+        .next()
+        .filter(|o| !o.is_empty())
+        .with_context(|| format!("Team '{}' has no GitHub repo configured", team.name))?;
+    let project_number = team.project_number.with_context(|| {
+        format!(
+            "No project board number stored for team '{}'. \
+             Re-run `bm init` to select or create a project board.",
+            team.name
+        )
+    })?;
+
+    let view_statuses = match str_arg(args, "view") {
+        Some(view) => {
+            let manifest = profile::read_team_repo_manifest(&team.path.join("team"))?;
+            let def = manifest
+                .views
+                .iter()
+                .find(|v| v.name.eq_ignore_ascii_case(view))
+                .with_context(|| {
+                    let names: Vec<&str> = manifest.views.iter().map(|v| v.name.as_str()).collect();
+                    format!(
+                        "Unknown view '{}'. Available views: {}",
+                        view,
+                        names.join(", ")
+                    )
+                })?;
+            Some(def.resolve_statuses(&manifest.statuses))
+        }
+        None => None,
+    };
+    let status = str_arg(args, "status");
+    let assignee = str_arg(args, "assignee");
+    let limit = args["limit"].as_u64().unwrap_or(DEFAULT_BOARD_LIMIT).max(1) as usize;
+
+    let items: Vec<git::ProjectItem> = git::list_project_items(&git::GitHubHost::for_team(team), owner, project_number, limit)?
+        .into_iter()
+        .filter(|item| {
+            let item_status = item.status.as_deref().unwrap_or_default();
+            if status.is_some_and(|s| s != item_status) {
+                return false;
+            }
+            if let Some(ref statuses) = view_statuses {
+                if !statuses.iter().any(|s| s == item_status) {
+                    return false;
+                }
+            }
+            if let Some(login) = assignee {
+                if !item.assignees.iter().any(|a| a == login) {
+                    return false;
+                }
+            }
+            true
+        })
+        .collect();
+
+    Ok(json!({
+        "team": team.name,
+        "project_number": project_number,
+        "count": items.len(),
+        "items": items,
+    }))
+}
+
+// ── Operator tools ──────────────────────────────────────────────────
+
+fn loop_start(ctx: &ToolContext, args: &Value) -> Result<Value> {
+    let prompt = required_str(args, "prompt")?;
+    let cfg = config::load()?;
+    let team = resolve_team(ctx, &cfg, args)?;
+    let member = str_arg(args, "member");
+    if let Some(m) = member {
+        require_member_dir(team, m)?;
+    }
+
+    let client = DaemonClient::connect(&team.name)?.with_actor(ctx.actor.clone());
+    let resp = client.start_loop(&StartLoopRequest {
+        prompt: prompt.to_string(),
+        member: member.map(String::from),
+    })?;
+    if !resp.ok {
+        let err = resp.error.unwrap_or_else(|| "unknown error".to_string());
+        bail!("Failed to start loop: {}", err);
+    }
+
+    Ok(json!({ "loop_id": resp.loop_id, "pid": resp.pid }))
+}
+
+fn inbox_write(ctx: &ToolContext, args: &Value) -> Result<Value> {
+    let member = required_str(args, "member")?;
+    let message = required_str(args, "message")?;
+    let from = str_arg(args, "from").unwrap_or(DEFAULT_INBOX_SENDER);
+    let cfg = config::load()?;
+    let team = resolve_team(ctx, &cfg, args)?;
+    require_member_dir(team, member)?;
+
+    let ws = workspace::find_workspace(&team.path, member).with_context(|| {
+        format!(
+            "Member '{}' has no workspace. Run `bm teams sync` first.",
+            member
+        )
+    })?;
+    let result = inbox::write_message(&inbox::inbox_path(&ws), from, message);
+    let record = Record::new(ctx.actor.clone(), "inbox.write")
+        .target(Some(member))
+        .param("from", from)
+        .outcome(&result);
+    audit::record_for_team(&team.name, record);
+    result?;
+
+    Ok(json!({ "member": member, "from": from, "written": true }))
+}
+
+fn teams_sync(ctx: &ToolContext, args: &Value) -> Result<Value> {
+    let config_path = config::config_path()?;
+    let cfg = config::load_from(&config_path)?;
+    let team = resolve_team(ctx, &cfg, args)?;
+
+    let result = crate::web::sync::do_sync(&config_path, &team.name);
+    audit::record_for_team(
+        &team.name,
+        Record::new(ctx.actor.clone(), "teams.sync").outcome(&result),
+    );
+    Ok(serde_json::to_value(result?)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read_only_mode_hides_operator_tools() {
+        let names: Vec<String> = list(McpMode::ReadOnly)
+            .iter()
+            .map(|t| t["name"].as_str().unwrap().to_string())
+            .collect();
+        assert!(names.contains(&"team_status".to_string()));
+        assert!(names.contains(&"board_query".to_string()));
+        assert!(!names.contains(&"loop_start".to_string()));
+        assert!(!names.contains(&"inbox_write".to_string()));
+        assert!(!names.contains(&"teams_sync".to_string()));
+
+        assert_eq!(list(McpMode::Operator).len(), TOOLS.len());
+        assert!(find("teams_sync", McpMode::Operator).is_some());
+        assert!(find("teams_sync", McpMode::ReadOnly).is_none());
+    }
+
+    #[test]
+    fn every_schema_is_an_object_with_team() {
+        for tool in TOOLS {
+            let schema = (tool.input_schema)();
+            assert_eq!(schema["type"], "object", "{}", tool.name);
+            assert!(schema["properties"]["team"].is_object(), "{}", tool.name);
+            for req in schema["required"].as_array().unwrap() {
+                let key = req.as_str().unwrap();
+                assert!(
+                    schema["properties"][key].is_object(),
+                    "{} requires undeclared '{}'",
+                    tool.name,
+                    key
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn with_status_adds_pid_for_running_members() {
+        let running = MemberStatus::Running {
+            pid: 42,
+            started_at: "2026-01-01T00:00:00Z".into(),
+            brain_mode: true,
+            paused_at: None,
+        };
+        let v = with_status(json!({ "name": "chief-ann" }), &running);
+        assert_eq!(v["status"], "brain");
+        assert_eq!(v["pid"], 42);
+
+        let v = with_status(json!({ "name": "chief-ann" }), &MemberStatus::Stopped);
+        assert_eq!(v["status"], "stopped");
+        assert!(v.get("pid").is_none());
+    }
+
+    #[test]
+    fn pinned_server_rejects_other_teams() {
+        let cfg: BotminterConfig = serde_yml::from_str(
+            "workzone: /tmp\nteams:\n- name: alpha\n  path: /tmp/alpha\n  profile: scrum\n  github_repo: org/alpha\n  credentials: {}\n",
+        )
+        .unwrap();
+        let ctx = ToolContext {
+            mode: McpMode::ReadOnly,
+            team: Some("alpha".into()),
+            actor: Actor::cli(),
+        };
+
+        let team = resolve_team(&ctx, &cfg, &json!({})).unwrap();
+        assert_eq!(team.name, "alpha");
+        let err = resolve_team(&ctx, &cfg, &json!({ "team": "beta" })).unwrap_err();
+        assert!(err.to_string().contains("scoped to team 'alpha'"));
+    }
+
+    #[test]
+    fn require_member_dir_rejects_path_traversal() {
+        let tmp = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(tmp.path().join("team/members/dev-bob")).unwrap();
+        std::fs::create_dir_all(tmp.path().join("team/members/.git")).unwrap();
+        let team: TeamEntry = serde_yml::from_str(&format!(
+            "name: alpha\npath: {}\nprofile: scrum\ngithub_repo: org/alpha\ncredentials: {{}}\n",
+            tmp.path().display()
+        ))
+        .unwrap();
+
+        assert!(require_member_dir(&team, "dev-bob").is_ok());
+        for name in ["..", ".git", "../members/dev-bob", "dev-bob/knowledge"] {
+            let err = require_member_dir(&team, name).unwrap_err();
+            assert!(err.to_string().contains("Invalid member name"), "{}", name);
+        }
+    }
+
+    #[test]
+    fn scope_files_builds_team_relative_paths() {
+        let tmp = tempfile::tempdir().unwrap();
+        let repo = tmp.path();
+        std::fs::create_dir_all(repo.join("members/dev-bob/knowledge")).unwrap();
+        std::fs::write(repo.join("members/dev-bob/knowledge/style.md"), "x").unwrap();
+
+        let mut out = Vec::new();
+        scope_files(
+            &mut out,
+            repo,
+            "member",
+            Some("dev-bob"),
+            "members/dev-bob/",
+            &["knowledge", "invariants"],
+        );
+        assert_eq!(out.len(), 1);
+        assert_eq!(out[0]["path"], "members/dev-bob/knowledge/style.md");
+        assert!(profile::validate_knowledge_path(out[0]["path"].as_str().unwrap()).is_ok());
+    }
+}
@@ -1,4 +1,4 @@
-//! Member lifecycle domain operations: hire and fire.
+//! Member lifecycle domain operations: hire, fire, archive and restore.
 //!
 //! Single entry point for adding and removing members from a team.
 //! Composes profile extraction, credential management, bridge identity,
@@ -7,10 +7,12 @@
 use std::fs;
 use std::path::Path;
 
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
 
 use crate::bridge;
-use crate::config::{BotminterConfig, TeamEntry};
+use crate::config::{BotminterConfig, ForgeKind, TeamEntry};
+use crate::forge::{Forge, GiteaForge};
 use crate::formation::{self, CredentialDomain, Formation};
 use crate::git::{self, app_auth, manifest_flow};
 use crate::profile;
@@ -131,6 +133,7 @@ pub fn setup_app_credentials(
     let repo_refs: Vec<&str> = repos.iter().map(|s| s.as_str()).collect();
     if !repo_refs.is_empty() {
         manifest_flow::ensure_app_on_repos(
+            &git::GitHubHost::for_team(team),
             &creds.installation_id,
             &creds.client_id,
             &creds.private_key,
@@ -151,6 +154,34 @@ pub fn setup_app_credentials(
     })
 }
 
+/// Validates a Gitea/Forgejo bot user token against the team's forge and
+/// stores it with the bot's login. Returns the login.
+pub fn setup_bot_credentials(team: &TeamEntry, member_name: &str, token: &str) -> Result<String> {
+    let forge_cfg = match &team.forge {
+        Some(f) if f.kind == ForgeKind::Gitea => f,
+        _ => bail!(
+            "Team '{}' is on GitHub; bot tokens are only used on Gitea/Forgejo teams",
+            team.name
+        ),
+    };
+    let login = GiteaForge::new(&forge_cfg.url, token)?
+        .current_user()
+        .with_context(|| format!("Bot token was rejected by {}", forge_cfg.url))?;
+
+    let formation = formation::create_local_formation(&team.name)?;
+    let cred_store = formation.credential_store(CredentialDomain::GitHubApp {
+        team_name: team.name.clone(),
+        member_name: member_name.to_string(),
+    })?;
+    cred_store
+        .store(&manifest_flow::credential_keys::bot_user(member_name), &login)
+        .context("Failed to store bot user")?;
+    cred_store
+        .store(&manifest_flow::credential_keys::bot_token(member_name), token)
+        .context("Failed to store bot token")?;
+    Ok(login)
+}
+
 // ── Fire: member teardown ───────────────────────────────────────────
 
 /// Parameters for firing a member.
@@ -268,7 +299,7 @@ pub fn fire_member(params: &FireParams, formation: &dyn Formation) -> Result<Fir
     if params.delete_repo {
         if let Some(org) = params.team.github_repo.split('/').next().filter(|s| !s.is_empty()) {
             let ws_repo_name = format!("{}/{}-{}", org, params.team.name, params.member);
-            match git::delete_repo(&ws_repo_name) {
+            match git::delete_repo(&git::GitHubHost::for_team(params.team), &ws_repo_name) {
                 Ok(()) => result.repo_deleted = true,
                 Err(e) => result.errors.push(FireError {
                     step: "delete_repo",
@@ -281,6 +312,289 @@ pub fn fire_member(params: &FireParams, formation: &dyn Formation) -> Result<Fir
     Ok(result)
 }
 
+// ── Archive / restore ───────────────────────────────────────────────
+
+/// Directory under `members/` that holds archived members. It is hidden,
+/// so member discovery, sync and the daemon skip it.
+pub const ARCHIVE_DIR: &str = ".archived";
+
+/// Metadata file written into an archived member's directory.
+const ARCHIVE_META_FILE: &str = "archive.yml";
+
+/// When and why a member was archived.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ArchiveMeta {
+    pub archived_at: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    pub role: String,
+}
+
+/// Parameters for archiving a member.
+pub struct ArchiveParams<'a> {
+    pub team: &'a TeamEntry,
+    pub config: &'a BotminterConfig,
+    pub member: &'a str,
+    pub reason: Option<&'a str>,
+}
+
+/// Result of archiving a member. Stopping and bridge cleanup are
+/// best-effort; failures are collected in `errors`.
+pub struct ArchiveResult {
+    pub stopped: bool,
+    pub bridge_identity_removed: bool,
+    pub errors: Vec<FireError>,
+}
+
+/// Archives a member: stops it and moves its directory to
+/// `members/.archived/`. Unlike [`fire_member`], the GitHub App stays
+/// installed, its credentials stay in the keyring, and the workspace and
+/// workspace repo are kept, so [`restore_member`] can bring it back as it was.
+pub fn archive_member(params: &ArchiveParams, formation: &dyn Formation) -> Result<ArchiveResult> {
+    let team_repo = params.team.path.join("team");
+    let members_dir = team_repo.join("members");
+    if !members_dir.join(params.member).is_dir() {
+        bail!("Member '{}' not found in team '{}'.", params.member, params.team.name);
+    }
+    if members_dir.join(ARCHIVE_DIR).join(params.member).exists() {
+        bail!(
+            "An archived member '{}' already exists. Restore it with `bm members restore {}` first.",
+            params.member,
+            params.member
+        );
+    }
+
+    let mut result = ArchiveResult {
+        stopped: false,
+        bridge_identity_removed: false,
+        errors: Vec::new(),
+    };
+
+    match stop_member(params.team, params.config, params.member, formation) {
+        Ok(stopped) => result.stopped = stopped,
+        Err(e) => result.errors.push(FireError {
+            step: "stop",
+            error: format!("{e}"),
+        }),
+    }
+
+    // The bridge user and its token are kept; only the team's bridge state
+    // forgets the identity, so restore can re-onboard it.
+    match remove_bridge_identity(&team_repo, params.team, params.config, params.member) {
+        Ok(removed) => result.bridge_identity_removed = removed,
+        Err(e) => result.errors.push(FireError {
+            step: "remove_bridge_identity",
+            error: format!("{e}"),
+        }),
+    }
+
+    let meta = ArchiveMeta {
+        archived_at: chrono::Utc::now().to_rfc3339(),
+        reason: params.reason.map(String::from),
+        role: profile::read_member_role(&members_dir, params.member),
+    };
+    move_to_archive(&team_repo, params.member, &meta)?;
+
+    Ok(result)
+}
+
+/// Parameters for restoring an archived member.
+pub struct RestoreParams<'a> {
+    pub team: &'a TeamEntry,
+    pub config: &'a BotminterConfig,
+    pub member: &'a str,
+}
+
+/// Result of restoring a member.
+pub struct RestoreResult {
+    pub meta: ArchiveMeta,
+    /// The stored App credentials still mint tokens and cover the team's repos.
+    pub app_verified: bool,
+    /// A GitHub team member came back without App credentials in the keyring.
+    pub app_credentials_missing: bool,
+    pub bridge_identity_provisioned: bool,
+    pub errors: Vec<FireError>,
+}
+
+/// Restores an archived member: moves its directory back to `members/`,
+/// re-checks its App credentials against the team's current repos, and
+/// re-provisions its bridge identity.
+pub fn restore_member(params: &RestoreParams) -> Result<RestoreResult> {
+    let team_repo = params.team.path.join("team");
+    let meta = move_from_archive(&team_repo, params.member)?;
+
+    let mut result = RestoreResult {
+        meta,
+        app_verified: false,
+        app_credentials_missing: false,
+        bridge_identity_provisioned: false,
+        errors: Vec::new(),
+    };
+
+    if params.team.forge_kind() == ForgeKind::GitHub {
+        match verify_app_credentials(params.team, params.member) {
+            Ok(true) => result.app_verified = true,
+            Ok(false) => result.app_credentials_missing = true,
+            Err(e) => result.errors.push(FireError {
+                step: "verify_app",
+                error: format!("{e:#}"),
+            }),
+        }
+    }
+
+    match provision_bridge_identity(&team_repo, params.team, params.config, params.member) {
+        Ok(provisioned) => result.bridge_identity_provisioned = provisioned,
+        Err(e) => result.errors.push(FireError {
+            step: "provision_bridge_identity",
+            error: format!("{e:#}"),
+        }),
+    }
+
+    Ok(result)
+}
+
+/// Lists archived members with their metadata, sorted by name.
+pub fn list_archived(team_repo: &Path) -> Vec<(String, Option<ArchiveMeta>)> {
+    let archive_dir = team_repo.join("members").join(ARCHIVE_DIR);
+    profile::list_subdirs(&archive_dir)
+        .into_iter()
+        .map(|name| {
+            let meta = read_archive_meta(&archive_dir.join(&name)).ok();
+            (name, meta)
+        })
+        .collect()
+}
+
+fn read_archive_meta(dir: &Path) -> Result<ArchiveMeta> {
+    let path = dir.join(ARCHIVE_META_FILE);
+    let contents =
+        fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()))?;
+    serde_yml::from_str(&contents).with_context(|| format!("Failed to parse {}", path.display()))
+}
+
+/// Moves `members/<member>` to `members/.archived/<member>`, records the
+/// metadata, and commits the move to the team repo.
+fn move_to_archive(team_repo: &Path, member: &str, meta: &ArchiveMeta) -> Result<()> {
+    let members_dir = team_repo.join("members");
+    let archive_dir = members_dir.join(ARCHIVE_DIR);
+    let dest = archive_dir.join(member);
+
+    fs::create_dir_all(&archive_dir).context("Failed to create members/.archived/")?;
+    fs::rename(members_dir.join(member), &dest)
+        .with_context(|| format!("Failed to move '{}' to the archive", member))?;
+    fs::write(
+        dest.join(ARCHIVE_META_FILE),
+        serde_yml::to_string(meta).context("Failed to serialize archive metadata")?,
+    )
+    .context("Failed to write archive metadata")?;
+
+    commit_members(team_repo, &format!("feat: archive {}", member));
+    Ok(())
+}
+
+/// Moves an archived member back to `members/<member>` and commits it.
+/// Returns the metadata recorded at archive time.
+fn move_from_archive(team_repo: &Path, member: &str) -> Result<ArchiveMeta> {
+    let members_dir = team_repo.join("members");
+    let archive_dir = members_dir.join(ARCHIVE_DIR);
+    let src = archive_dir.join(member);
+    let dest = members_dir.join(member);
+
+    if !src.is_dir() {
+        bail!(
+            "No archived member '{}'. Run `bm members list` to see archived members.",
+            member
+        );
+    }
+    if dest.exists() {
+        bail!(
+            "Member '{}' already exists in the team. Fire or archive it before restoring.",
+            member
+        );
+    }
+
+    let meta = read_archive_meta(&src)?;
+    fs::remove_file(src.join(ARCHIVE_META_FILE)).context("Failed to remove archive metadata")?;
+    fs::rename(&src, &dest)
+        .with_context(|| format!("Failed to move '{}' out of the archive", member))?;
+    // Drop the archive dir once the last member leaves it.
+    let _ = fs::remove_dir(&archive_dir);
+
+    commit_members(team_repo, &format!("feat: restore {}", member));
+    Ok(meta)
+}
+
+/// Stages `members/` and commits, best-effort like the fire commit.
+fn commit_members(team_repo: &Path, message: &str) {
+    let _ = git::run_git(team_repo, &["add", "-A", "members/"]);
+    let _ = git::run_git(team_repo, &["commit", "-m", message]);
+}
+
+/// Checks that a member's stored App credentials still mint an installation
+/// token, and that the App covers repos added while it was archived.
+/// Returns false if the member has no App credentials.
+fn verify_app_credentials(team: &TeamEntry, member: &str) -> Result<bool> {
+    let formation = formation::create_local_formation(&team.name)?;
+    let cred_store = formation.credential_store(CredentialDomain::GitHubApp {
+        team_name: team.name.clone(),
+        member_name: member.to_string(),
+    })?;
+
+    let client_id = cred_store.retrieve(&manifest_flow::credential_keys::client_id(member))?;
+    let private_key = cred_store.retrieve(&manifest_flow::credential_keys::private_key(member))?;
+    let installation_id = cred_store.retrieve(&manifest_flow::credential_keys::installation_id(member))?;
+    let (Some(cid), Some(key), Some(iid)) = (client_id, private_key, installation_id) else {
+        return Ok(false);
+    };
+
+    let host = git::GitHubHost::for_team(team);
+    let inst_id: u64 = iid.parse().context("Invalid installation ID")?;
+    let jwt = app_auth::generate_jwt(&cid, &key)?;
+    app_auth::exchange_for_installation_token(&host.api_base, &jwt, inst_id)
+        .context("App installation no longer accepts the stored credentials")?;
+
+    let repos = manifest_flow::collect_team_repos(team);
+    let repo_refs: Vec<&str> = repos.iter().map(|s| s.as_str()).collect();
+    if !repo_refs.is_empty() {
+        manifest_flow::ensure_app_on_repos(&host, &iid, &cid, &key, &repo_refs)?;
+    }
+    Ok(true)
+}
+
+/// Re-onboards a member's bridge identity. Returns false if the team has
+/// no bridge or the bridge has no credentials for the member.
+fn provision_bridge_identity(
+    team_repo: &Path,
+    team: &TeamEntry,
+    config: &BotminterConfig,
+    member: &str,
+) -> Result<bool> {
+    let Some(bridge_dir) = bridge::discover(team_repo, &team.name)? else {
+        return Ok(false);
+    };
+
+    let state_path = bridge::state_path(&config.workzone, &team.name);
+    let mut b = bridge::Bridge::new(bridge_dir, state_path.clone(), team.name.clone())?;
+    let cred_store = bridge::LocalCredentialStore::new(&team.name, b.bridge_name(), state_path)
+        .with_collection(config.keyring_collection.clone());
+
+    let result = b.provision(
+        &[bridge::BridgeMember {
+            name: member.to_string(),
+            is_operator: false,
+        }],
+        &cred_store,
+    )?;
+    b.save()?;
+
+    Ok(result.members.iter().any(|(_, r)| {
+        !matches!(
+            r,
+            bridge::ProvisionMemberResult::NoCreds | bridge::ProvisionMemberResult::NoConfig
+        )
+    }))
+}
+
 // ── Private helpers ─────────────────────────────────────────────────
 
 fn stop_member(
@@ -322,7 +636,8 @@ fn uninstall_app(team: &TeamEntry, member: &str) -> Result<bool> {
         (Some(cid), Some(key), Some(iid)) => {
             let inst_id: u64 = iid.parse().context("Invalid installation ID")?;
             let jwt = app_auth::generate_jwt(&cid, &key)?;
-            app_auth::uninstall_app(&jwt, inst_id)?;
+            let host = git::GitHubHost::for_team(team);
+            app_auth::uninstall_app(&host.api_base, &jwt, inst_id)?;
             Ok(true)
         }
         _ => Ok(false), // No credentials found
@@ -399,4 +714,60 @@ mod tests {
         assert_eq!(creds.app_id, "123");
         assert_eq!(creds.installation_id, "456");
     }
+
+    fn team_repo_with_member(member: &str) -> tempfile::TempDir {
+        let tmp = tempfile::tempdir().unwrap();
+        let member_dir = tmp.path().join("members").join(member);
+        fs::create_dir_all(&member_dir).unwrap();
+        fs::write(member_dir.join("PROMPT.md"), "tuned prompt").unwrap();
+        git::run_git(tmp.path(), &["init"]).unwrap();
+        tmp
+    }
+
+    #[test]
+    fn archive_and_restore_round_trip() {
+        let tmp = team_repo_with_member("architect-alice");
+        let meta = ArchiveMeta {
+            archived_at: "2026-01-01T00:00:00+00:00".to_string(),
+            reason: Some("between projects".to_string()),
+            role: "architect".to_string(),
+        };
+
+        move_to_archive(tmp.path(), "architect-alice", &meta).unwrap();
+        assert!(!tmp.path().join("members/architect-alice").exists());
+        assert_eq!(
+            crate::workspace::list_member_dirs(&tmp.path().join("members")).unwrap(),
+            Vec::<String>::new(),
+            "archived members must not be discovered as hired"
+        );
+        assert_eq!(
+            list_archived(tmp.path()),
+            vec![("architect-alice".to_string(), Some(meta.clone()))]
+        );
+
+        let restored = move_from_archive(tmp.path(), "architect-alice").unwrap();
+        assert_eq!(restored, meta);
+        assert_eq!(
+            fs::read_to_string(tmp.path().join("members/architect-alice/PROMPT.md")).unwrap(),
+            "tuned prompt"
+        );
+        assert!(!tmp.path().join("members/architect-alice").join(ARCHIVE_META_FILE).exists());
+        assert!(!tmp.path().join("members").join(ARCHIVE_DIR).exists());
+    }
+
+    #[test]
+    fn restore_refuses_to_overwrite_a_hired_member() {
+        let tmp = team_repo_with_member("dev-bob");
+        let meta = ArchiveMeta {
+            archived_at: "2026-01-01T00:00:00+00:00".to_string(),
+            reason: None,
+            role: "dev".to_string(),
+        };
+        move_to_archive(tmp.path(), "dev-bob", &meta).unwrap();
+        fs::create_dir_all(tmp.path().join("members/dev-bob")).unwrap();
+
+        let err = move_from_archive(tmp.path(), "dev-bob").unwrap_err();
+        assert!(err.to_string().contains("already exists"), "got: {err}");
+        assert!(move_from_archive(tmp.path(), "nobody").is_err());
+    }
 }
@@ -1,12 +1,20 @@
+use std::collections::BTreeSet;
 use std::fs;
 use std::path::Path;
 
 use anyhow::{Context, Result, bail};
 
 use super::extraction::should_filter;
+use super::inherit::{extends_of, resolve_profile_from};
 use super::manifest::{CodingAgentDef, ProfileManifest};
 use super::{list_profiles_from, profiles_dir};
-use crate::agent_tags;
+use crate::agent_tags::{self, CommentSyntax, TagContext};
+
+/// Brain prompt template; its presence puts a team in brain mode.
+const BRAIN_TEMPLATE: &str = "brain/system-prompt.md";
+
+/// Collects the tag names of interest from one file's content.
+type TagCollector = fn(&str, CommentSyntax) -> BTreeSet<String>;
 
 /// Scans all files in a profile on disk for agent tags, returning a sorted list of
 /// (relative path, agents) pairs for files that contain at least one tag.
@@ -15,6 +23,20 @@ pub fn scan_agent_tags(profile_name: &str) -> Result<Vec<(String, Vec<String>)>>
 }
 
 fn scan_agent_tags_in(profile_name: &str, base: &Path) -> Result<Vec<(String, Vec<String>)>> {
+    scan_tags_in(profile_name, base, agent_tags::collect_agent_names)
+}
+
+/// Scans all files in a profile for conditional tags of every dimension, returning
+/// (relative path, conditions) pairs such as `("context.md", ["agent:claude-code", "brain"])`.
+pub fn scan_profile_tags(profile_name: &str) -> Result<Vec<(String, Vec<String>)>> {
+    scan_tags_in(profile_name, &profiles_dir()?, agent_tags::collect_tag_conditions)
+}
+
+fn scan_tags_in(
+    profile_name: &str,
+    base: &Path,
+    collect: TagCollector,
+) -> Result<Vec<(String, Vec<String>)>> {
     let profile_dir = base.join(profile_name);
     if !profile_dir.is_dir() {
         let available = list_profiles_from(base).unwrap_or_default().join(", ");
@@ -25,15 +47,23 @@ fn scan_agent_tags_in(profile_name: &str, base: &Path) -> Result<Vec<(String, Ve
     }
 
     let mut results = Vec::new();
-    scan_dir_for_tags_on_disk(&profile_dir, &profile_dir, &mut results)?;
+    if extends_of(profile_name, base).is_some() {
+        let resolved = resolve_profile_from(profile_name, base)?;
+        for (rel, (path, _)) in &resolved.files {
+            scan_file_for_tags(path, rel, collect, &mut results);
+        }
+    } else {
+        scan_dir_for_tags_on_disk(&profile_dir, &profile_dir, collect, &mut results)?;
+    }
     results.sort_by(|a, b| a.0.cmp(&b.0));
     Ok(results)
 }
 
-/// Recursively scans a disk directory for files containing agent tags.
+/// Recursively scans a disk directory for files containing tags.
 fn scan_dir_for_tags_on_disk(
     dir: &Path,
     root_path: &Path,
+    collect: TagCollector,
     results: &mut Vec<(String, Vec<String>)>,
 ) -> Result<()> {
     for entry in fs::read_dir(dir)
@@ -44,31 +74,41 @@ fn scan_dir_for_tags_on_disk(
         let rel = path.strip_prefix(root_path).unwrap_or(&path);
 
         if path.is_dir() {
-            scan_dir_for_tags_on_disk(&path, root_path, results)?;
+            scan_dir_for_tags_on_disk(&path, root_path, collect, results)?;
             continue;
         }
 
-        let filename = rel
-            .file_name()
-            .map(|f| f.to_string_lossy().to_string())
-            .unwrap_or_default();
+        scan_file_for_tags(&path, rel, collect, results);
+    }
+    Ok(())
+}
 
-        if !should_filter(&filename) {
-            continue;
-        }
+/// Records a file's tags, if it is a filterable text file with any.
+fn scan_file_for_tags(
+    path: &Path,
+    rel: &Path,
+    collect: TagCollector,
+    results: &mut Vec<(String, Vec<String>)>,
+) {
+    let filename = rel
+        .file_name()
+        .map(|f| f.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    if !should_filter(&filename) {
+        return;
+    }
 
-        if let Ok(content) = fs::read_to_string(&path) {
-            let syntax = agent_tags::detect_comment_syntax(&filename);
-            let agents = agent_tags::collect_agent_names(&content, syntax);
-            if !agents.is_empty() {
-                results.push((
-                    rel.to_string_lossy().to_string(),
-                    agents.into_iter().collect(),
-                ));
-            }
+    if let Ok(content) = fs::read_to_string(path) {
+        let syntax = agent_tags::detect_comment_syntax(&filename);
+        let agents = collect(&content, syntax);
+        if !agents.is_empty() {
+            results.push((
+                rel.to_string_lossy().to_string(),
+                agents.into_iter().collect(),
+            ));
         }
     }
-    Ok(())
 }
 
 /// Resolves the effective coding agent for a team.
@@ -142,6 +182,37 @@ pub fn resolve_agent_from_profiles() -> Result<CodingAgentDef> {
     )
 }
 
+/// Builds the tag context a team's files resolve against: its coding agent, its
+/// selected bridge and whether it runs in brain mode (the team repo has a
+/// `brain/system-prompt.md` template). The formation is left unknown here —
+/// `bm teams sync` adds it when it resolves workspace files.
+pub fn team_tag_context(team_repo: &Path, coding_agent: &CodingAgentDef) -> TagContext {
+    let bridge = super::read_team_repo_manifest(team_repo)
+        .ok()
+        .and_then(|m| m.bridge);
+    TagContext::for_agent(&coding_agent.name)
+        .with_opt("bridge", bridge.as_deref())
+        .with_flag("brain", team_repo.join(BRAIN_TEMPLATE).exists())
+}
+
+/// Like [`team_tag_context`], for a team that is about to be created from a profile.
+pub(crate) fn profile_tag_context(
+    base: &Path,
+    profile_name: &str,
+    coding_agent: &CodingAgentDef,
+    bridge: Option<&str>,
+) -> TagContext {
+    let has_brain = match extends_of(profile_name, base) {
+        Some(_) => resolve_profile_from(profile_name, base)
+            .map(|r| r.files.contains_key(Path::new(BRAIN_TEMPLATE)))
+            .unwrap_or(false),
+        None => base.join(profile_name).join(BRAIN_TEMPLATE).exists(),
+    };
+    TagContext::for_agent(&coding_agent.name)
+        .with_opt("bridge", bridge)
+        .with_flag("brain", has_brain)
+}
+
 /// Ensures Minty config is present on disk at `~/.config/botminter/minty/`.
 /// Auto-extracts embedded config if the directory is missing.
 pub fn ensure_minty_initialized() -> Result<std::path::PathBuf> {
@@ -212,6 +283,32 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn team_tag_context_reads_bridge_and_brain() {
+        let tmp = tempfile::tempdir().unwrap();
+        let repo = tmp.path();
+        fs::write(repo.join("botminter.yml"), "name: t\ndisplay_name: t\ndescription: t\nversion: '1'\nschema_version: '1.0'\nbridge: tuwunel\n").unwrap();
+
+        let ctx = team_tag_context(repo, &claude_code_agent());
+        assert_eq!(ctx.get("agent"), Some("claude-code"));
+        assert_eq!(ctx.get("bridge"), Some("tuwunel"));
+        assert_eq!(ctx.get("brain"), Some("false"));
+        assert_eq!(ctx.get("formation"), None);
+
+        fs::create_dir_all(repo.join("brain")).unwrap();
+        fs::write(repo.join(BRAIN_TEMPLATE), "You are the brain").unwrap();
+        let ctx = team_tag_context(repo, &claude_code_agent());
+        assert_eq!(ctx.get("brain"), Some("true"));
+    }
+
+    #[test]
+    fn scan_profile_tags_reports_conditions() {
+        let (_tmp, base) = setup_disk_profiles();
+        let results = scan_tags_in("scrum", &base, agent_tags::collect_tag_conditions).unwrap();
+        let (_, conditions) = results.iter().find(|(p, _)| p == "context.md").unwrap();
+        assert!(conditions.contains(&"agent:claude-code".to_string()));
+    }
+
     #[test]
     fn resolve_coding_agent_uses_profile_default() {
         let (_tmp, base) = setup_disk_profiles();
@@ -226,6 +323,8 @@ mod tests {
             project_number: None,
             bridge_lifecycle: Default::default(),
             vm: None,
+            profile_source: None,
+            forge: None,
         };
         let agent = resolve_coding_agent(&team, &manifest).unwrap();
         assert_eq!(agent.name, "claude-code");
@@ -246,6 +345,8 @@ mod tests {
             project_number: None,
             bridge_lifecycle: Default::default(),
             vm: None,
+            profile_source: None,
+            forge: None,
         };
         let agent = resolve_coding_agent(&team, &manifest).unwrap();
         assert_eq!(agent.name, "claude-code");
@@ -265,6 +366,8 @@ mod tests {
             project_number: None,
             bridge_lifecycle: Default::default(),
             vm: None,
+            profile_source: None,
+            forge: None,
         };
         let result = resolve_coding_agent(&team, &manifest);
         assert!(result.is_err());
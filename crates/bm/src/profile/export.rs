@@ -0,0 +1,692 @@
+//! Reverse extraction: turns a live team repo back into a profile.
+//!
+//! The inverse of `extract_profile_from` + `hire_member`: team-level content is
+//! copied to the profile root, one member per role becomes that role's skeleton
+//! under `roles/`, the coding agent's context file is renamed back to
+//! `context.md`, and member-specific values are turned back into
+//! `{{member_dir}}` / `{{member_name}}` placeholders.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+
+use super::extraction::should_filter;
+use super::member::is_text_file;
+use super::{
+    discover_member_dirs, read_member_role, team_tag_context, CodingAgentDef, SKELETON_DIR,
+};
+use crate::agent_tags::{self, TagContext};
+
+/// Team repo entries that are team state rather than profile content. The
+/// schedule is keyed by this team's member names and timezone.
+const TEAM_ONLY_ENTRIES: &[&str] = &[
+    ".git",
+    "members",
+    "projects",
+    "botminter.yml",
+    crate::schedule::SCHEDULE_FILE,
+];
+
+/// Manifest keys that describe one team's setup and are dropped on export.
+const TEAM_ONLY_MANIFEST_KEYS: &[&str] = &["bridge", "operator", "projects"];
+
+/// What to export and where from.
+pub struct ExportParams<'a> {
+    /// The team repo to export (`<team>/team`).
+    pub team_repo: &'a Path,
+    pub team_name: &'a str,
+    /// The team's coding agent, used to reverse the context file rename.
+    pub coding_agent: &'a CodingAgentDef,
+    /// The profile the team was created from, if still on disk. Used to
+    /// restore untouched files verbatim and to fill in roles nobody holds.
+    pub source_profile_dir: Option<&'a Path>,
+    /// Name written to the new profile's `botminter.yml`.
+    pub profile_name: &'a str,
+    /// Restore `+agent:` tags from the source profile, and tag lines that
+    /// reference the agent's directory in files the team changed.
+    pub agent_tags: bool,
+}
+
+/// Where a role's skeleton in the exported profile came from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RoleOrigin {
+    /// Reverse-extracted from this member's directory.
+    Member(String),
+    /// No member holds the role; copied from the source profile.
+    SourceProfile,
+}
+
+/// Summary of an export.
+#[derive(Debug)]
+pub struct ExportSummary {
+    pub roles: Vec<(String, RoleOrigin)>,
+    pub files: usize,
+    /// Files identical to the source profile once extracted, restored verbatim.
+    pub restored: usize,
+    /// Changed files that had `+agent:` tags inserted.
+    pub tagged: usize,
+}
+
+/// Reverse-extracts a team repo into a new profile directory at `target`,
+/// which must not exist.
+pub fn export_team_profile(params: &ExportParams, target: &Path) -> Result<ExportSummary> {
+    if target.exists() {
+        bail!("{} already exists", target.display());
+    }
+    let manifest_path = params.team_repo.join("botminter.yml");
+    if !manifest_path.exists() {
+        bail!(
+            "No botminter.yml in {} — is this a team repo?",
+            params.team_repo.display()
+        );
+    }
+
+    // Files were extracted against the team's tag context; render sources the same way
+    let tags = team_tag_context(params.team_repo, params.coding_agent);
+    let mut summary = ExportSummary {
+        roles: Vec::new(),
+        files: 0,
+        restored: 0,
+        tagged: 0,
+    };
+
+    // Team-level content → profile root
+    for rel in walk_files(params.team_repo, &|rel| {
+        rel.components().count() == 1 && TEAM_ONLY_ENTRIES.iter().any(|e| rel == Path::new(e))
+    })? {
+        export_file(
+            params,
+            &tags,
+            &params.team_repo.join(&rel),
+            &rel,
+            target,
+            params.source_profile_dir,
+            None,
+            &mut summary,
+        )?;
+    }
+
+    // One member per role → roles/<role>/
+    let members_dir = params.team_repo.join("members");
+    let mut roles: Vec<String> = Vec::new();
+    for member in discover_member_dirs(params.team_repo) {
+        let role = read_member_role(&members_dir, &member);
+        if roles.contains(&role) {
+            continue;
+        }
+        roles.push(role.clone());
+        export_member(
+            params,
+            &tags,
+            &members_dir.join(&member),
+            &member,
+            &role,
+            target,
+            &mut summary,
+        )?;
+        summary.roles.push((role, RoleOrigin::Member(member)));
+    }
+
+    // Roles nobody holds → copied from the source profile
+    if let Some(source) = params.source_profile_dir {
+        let source_roles = source.join("roles");
+        for role in super::list_subdirs(&source_roles) {
+            if roles.contains(&role) {
+                continue;
+            }
+            copy_dir(&source_roles.join(&role), &target.join("roles").join(&role))?;
+            roles.push(role.clone());
+            summary.roles.push((role, RoleOrigin::SourceProfile));
+        }
+        if source.join(".schema").is_dir() {
+            copy_dir(&source.join(".schema"), &target.join(".schema"))?;
+        }
+    }
+
+    write_manifest(params, &manifest_path, &roles, target)?;
+    Ok(summary)
+}
+
+/// Reverse-extracts one member directory into `roles/<role>/`.
+fn export_member(
+    params: &ExportParams,
+    tags: &TagContext,
+    member_dir: &Path,
+    member_dir_name: &str,
+    role: &str,
+    target: &Path,
+    summary: &mut ExportSummary,
+) -> Result<()> {
+    let role_target = target.join("roles").join(role);
+    let source_role = params
+        .source_profile_dir
+        .map(|s| s.join("roles").join(role))
+        .filter(|d| d.is_dir());
+
+    let manifest_path = member_dir.join("botminter.yml");
+    let member_name = member_name_of(&manifest_path, member_dir_name, role);
+
+    for rel in walk_files(member_dir, &|rel| {
+        first_component(rel).as_deref() == Some(SKELETON_DIR) || rel == Path::new("botminter.yml")
+    })? {
+        export_file(
+            params,
+            tags,
+            &member_dir.join(&rel),
+            &rel,
+            &role_target,
+            source_role.as_deref(),
+            Some((member_dir_name, role, &member_name)),
+            summary,
+        )?;
+    }
+
+    // botminter.yml → .botminter.yml template without the member's name
+    if manifest_path.exists() {
+        let contents = fs::read_to_string(&manifest_path)
+            .with_context(|| format!("Failed to read {}", manifest_path.display()))?;
+        let mut value: serde_yml::Value = serde_yml::from_str(&contents)
+            .with_context(|| format!("Failed to parse {}", manifest_path.display()))?;
+        if let serde_yml::Value::Mapping(ref mut map) = value {
+            map.remove("name");
+        }
+        // Keep the source template's formatting when only the name was added
+        let source_template = source_role
+            .map(|d| d.join(".botminter.yml"))
+            .and_then(|p| fs::read_to_string(p).ok())
+            .filter(|raw| {
+                serde_yml::from_str::<serde_yml::Value>(raw).ok().as_ref() == Some(&value)
+            });
+        let template = match source_template {
+            Some(raw) => raw,
+            None => serde_yml::to_string(&value)?,
+        };
+        fs::create_dir_all(&role_target)?;
+        fs::write(role_target.join(".botminter.yml"), template)?;
+        summary.files += 1;
+    }
+    Ok(())
+}
+
+/// Writes one file to the profile, reversing what extraction did to it.
+///
+/// `placeholders` is `(member_dir, role, member_name)` for member files.
+#[allow(clippy::too_many_arguments)]
+fn export_file(
+    params: &ExportParams,
+    tags: &TagContext,
+    path: &Path,
+    rel: &Path,
+    target_root: &Path,
+    source_root: Option<&Path>,
+    placeholders: Option<(&str, &str, &str)>,
+    summary: &mut ExportSummary,
+) -> Result<()> {
+    let agent = params.coding_agent;
+    let filename = rel
+        .file_name()
+        .map(|f| f.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    // CLAUDE.md (etc.) → context.md
+    let out_rel = if filename == agent.context_file {
+        rel.with_file_name("context.md")
+    } else {
+        rel.to_path_buf()
+    };
+    let out_path = target_root.join(&out_rel);
+    if let Some(parent) = out_path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create directory {}", parent.display()))?;
+    }
+    summary.files += 1;
+
+    let bytes = fs::read(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    let Ok(content) = String::from_utf8(bytes.clone()) else {
+        fs::write(&out_path, bytes)?;
+        return Ok(());
+    };
+    let filterable = should_filter(&filename);
+    let templated = placeholders.is_some() && is_text_file(path);
+
+    // Untouched since extraction: take the source profile's file as-is
+    let source_path = source_root
+        .map(|s| s.join(&out_rel))
+        .filter(|p| p.is_file());
+    if let Some(raw) = source_path.and_then(|p| fs::read_to_string(p).ok()) {
+        let rendered = render_like_extraction(
+            &raw,
+            &filename,
+            agent,
+            tags,
+            filterable,
+            if templated { placeholders } else { None },
+        );
+        if rendered == content {
+            let restored = if params.agent_tags || !filterable {
+                raw
+            } else {
+                agent_tags::filter_file(&raw, &filename, &agent.name)
+            };
+            fs::write(&out_path, restored)?;
+            summary.restored += 1;
+            return Ok(());
+        }
+    }
+
+    let mut out = content;
+    if filterable && agent.context_file != "context.md" {
+        out = out.replace(&agent.context_file, "context.md");
+    }
+    if templated {
+        if let Some((member_dir, _, member_name)) = placeholders {
+            out = out.replace(member_dir, "{{member_dir}}");
+            if member_name.chars().any(|c| !c.is_ascii_digit()) {
+                out = replace_word(&out, member_name, "{{member_name}}");
+            }
+        }
+    }
+    if params.agent_tags && filterable && !agent.agent_dir.is_empty() {
+        let tagged = tag_agent_lines(&out, &filename, agent);
+        if tagged != out {
+            summary.tagged += 1;
+            out = tagged;
+        }
+    }
+    fs::write(&out_path, out).with_context(|| format!("Failed to write {}", out_path.display()))?;
+    Ok(())
+}
+
+/// Renders a raw profile file the way extraction and hiring would.
+fn render_like_extraction(
+    raw: &str,
+    filename: &str,
+    agent: &CodingAgentDef,
+    tags: &TagContext,
+    filterable: bool,
+    placeholders: Option<(&str, &str, &str)>,
+) -> String {
+    let mut out = raw.to_string();
+    if filterable {
+        out = agent_tags::filter_file_with(&out, filename, tags);
+        if agent.context_file != "context.md" {
+            out = out.replace("context.md", &agent.context_file);
+        }
+    }
+    if let Some((member_dir, role, member_name)) = placeholders {
+        out = out
+            .replace("{{member_dir}}", member_dir)
+            .replace("{{role}}", role)
+            .replace("{{member_name}}", member_name);
+    }
+    out
+}
+
+/// Wraps runs of lines that mention the agent's directory (e.g. `.claude/`)
+/// in `+agent:<name>` / `-agent` tags.
+fn tag_agent_lines(content: &str, filename: &str, agent: &CodingAgentDef) -> String {
+    if agent_tags::collect_agent_names(content, agent_tags::detect_comment_syntax(filename))
+        .contains(&agent.name)
+    {
+        return content.to_string();
+    }
+    let (open, close) = match agent_tags::detect_comment_syntax(filename) {
+        agent_tags::CommentSyntax::Html => (
+            format!("<!-- +agent:{} -->", agent.name),
+            "<!-- -agent -->".to_string(),
+        ),
+        agent_tags::CommentSyntax::Hash => {
+            (format!("# +agent:{}", agent.name), "# -agent".to_string())
+        }
+    };
+
+    let mut out = Vec::new();
+    let mut in_block = false;
+    for line in content.lines() {
+        let agent_specific = line.contains(&agent.agent_dir);
+        if agent_specific && !in_block {
+            out.push(open.as_str());
+            in_block = true;
+        } else if !agent_specific && in_block {
+            out.push(close.as_str());
+            in_block = false;
+        }
+        out.push(line);
+    }
+    if in_block {
+        out.push(close.as_str());
+    }
+    let mut joined = out.join("\n");
+    if content.ends_with('\n') {
+        joined.push('\n');
+    }
+    joined
+}
+
+/// Replaces whole-word occurrences of `word`.
+fn replace_word(text: &str, word: &str, with: &str) -> String {
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+    let mut out = String::with_capacity(text.len());
+    let mut last = 0;
+    for (i, _) in text.match_indices(word) {
+        let before = text[..i].chars().next_back();
+        let after = text[i + word.len()..].chars().next();
+        if i < last || before.is_some_and(is_word) || after.is_some_and(is_word) {
+            continue;
+        }
+        out.push_str(&text[last..i]);
+        out.push_str(with);
+        last = i + word.len();
+    }
+    out.push_str(&text[last..]);
+    out
+}
+
+fn member_name_of(manifest_path: &Path, member_dir_name: &str, role: &str) -> String {
+    fs::read_to_string(manifest_path)
+        .ok()
+        .and_then(|c| serde_yml::from_str::<serde_yml::Value>(&c).ok())
+        .and_then(|v| v.get("name").and_then(|n| n.as_str()).map(String::from))
+        .unwrap_or_else(|| {
+            member_dir_name
+                .strip_prefix(&format!("{}-", role))
+                .unwrap_or(member_dir_name)
+                .to_string()
+        })
+}
+
+/// Writes the profile's `botminter.yml` from the team's manifest.
+fn write_manifest(
+    params: &ExportParams,
+    team_manifest: &Path,
+    roles: &[String],
+    target: &Path,
+) -> Result<()> {
+    let contents = fs::read_to_string(team_manifest)
+        .with_context(|| format!("Failed to read {}", team_manifest.display()))?;
+    let mut value: serde_yml::Value = serde_yml::from_str(&contents)
+        .with_context(|| format!("Failed to parse {}", team_manifest.display()))?;
+    let serde_yml::Value::Mapping(ref mut map) = value else {
+        bail!("{} is not a mapping", team_manifest.display());
+    };
+    let key = |k: &str| serde_yml::Value::String(k.to_string());
+
+    if !map
+        .get("schema_version")
+        .is_some_and(|v| v.as_str().is_some())
+    {
+        bail!("{} has no schema_version", team_manifest.display());
+    }
+    for k in TEAM_ONLY_MANIFEST_KEYS {
+        map.remove(*k);
+    }
+    map.insert(key("name"), key(params.profile_name));
+    map.insert(key("display_name"), key(params.profile_name));
+    map.insert(
+        key("description"),
+        key(&format!("Exported from team '{}'", params.team_name)),
+    );
+    map.insert(key("version"), key("1.0.0"));
+
+    // Keep existing role descriptions; add roles the profile didn't declare
+    let existing: Vec<serde_yml::Value> = map
+        .get("roles")
+        .and_then(|r| r.as_sequence())
+        .cloned()
+        .unwrap_or_default();
+    let mut role_defs = Vec::new();
+    for role in roles {
+        let def = existing
+            .iter()
+            .find(|r| r.get("name").and_then(|n| n.as_str()) == Some(role))
+            .cloned()
+            .unwrap_or_else(|| {
+                let mut m = serde_yml::Mapping::new();
+                m.insert(key("name"), key(role));
+                m.insert(key("description"), key(""));
+                serde_yml::Value::Mapping(m)
+            });
+        role_defs.push(def);
+    }
+    map.insert(key("roles"), serde_yml::Value::Sequence(role_defs));
+
+    fs::create_dir_all(target)?;
+    fs::write(target.join("botminter.yml"), serde_yml::to_string(&value)?)
+        .context("Failed to write botminter.yml")?;
+    Ok(())
+}
+
+fn first_component(rel: &Path) -> Option<String> {
+    rel.components()
+        .next()
+        .map(|c| c.as_os_str().to_string_lossy().to_string())
+}
+
+/// Lists files under `root` as relative paths, skipping entries for which
+/// `skip` returns true.
+pub(super) fn walk_files(root: &Path, skip: &dyn Fn(&Path) -> bool) -> Result<Vec<PathBuf>> {
+    fn walk(
+        root: &Path,
+        dir: &Path,
+        skip: &dyn Fn(&Path) -> bool,
+        out: &mut Vec<PathBuf>,
+    ) -> Result<()> {
+        let mut entries: Vec<_> = fs::read_dir(dir)
+            .with_context(|| format!("Failed to read directory {}", dir.display()))?
+            .collect::<Result<_, _>>()?;
+        entries.sort_by_key(|e| e.file_name());
+        for entry in entries {
+            let path = entry.path();
+            let rel = path.strip_prefix(root).unwrap_or(&path).to_path_buf();
+            if skip(&rel) {
+                continue;
+            }
+            if path.is_dir() {
+                walk(root, &path, skip, out)?;
+            } else {
+                out.push(rel);
+            }
+        }
+        Ok(())
+    }
+    let mut out = Vec::new();
+    walk(root, root, skip, &mut out)?;
+    Ok(out)
+}
+
+fn copy_dir(from: &Path, to: &Path) -> Result<()> {
+    for rel in walk_files(from, &|_| false)? {
+        let dest = to.join(&rel);
+        if let Some(parent) = dest.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::copy(from.join(&rel), &dest)
+            .with_context(|| format!("Failed to copy {}", from.join(&rel).display()))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::profile::member::render_member_placeholders;
+    use crate::profile::test_support::{claude_code_agent, setup_disk_profiles};
+    use crate::profile::{extract_member_from, extract_profile_from, finalize_member_manifest};
+
+    /// Builds a scrum team repo with `architect-alice` hired.
+    fn scrum_team(base: &Path) -> tempfile::TempDir {
+        let tmp = tempfile::tempdir().unwrap();
+        let repo = tmp.path();
+        let agent = claude_code_agent();
+        extract_profile_from(base, "scrum", repo, &agent).unwrap();
+        let member = repo.join("members/architect-alice");
+        fs::create_dir_all(&member).unwrap();
+        extract_member_from(base, "scrum", "architect", &member, &agent).unwrap();
+        finalize_member_manifest(&member, "alice").unwrap();
+        render_member_placeholders(&member, "architect-alice", "architect", "alice").unwrap();
+        tmp
+    }
+
+    fn export(
+        base: &Path,
+        team: &Path,
+        agent_tags: bool,
+    ) -> (tempfile::TempDir, PathBuf, ExportSummary) {
+        let out = tempfile::tempdir().unwrap();
+        let target = out.path().join("tuned");
+        let agent = claude_code_agent();
+        let params = ExportParams {
+            team_repo: team,
+            team_name: "alpha",
+            coding_agent: &agent,
+            source_profile_dir: Some(&base.join("scrum")),
+            profile_name: "tuned",
+            agent_tags,
+        };
+        let summary = export_team_profile(&params, &target).unwrap();
+        (out, target, summary)
+    }
+
+    #[test]
+    fn export_produces_valid_profile() {
+        let (_p, base) = setup_disk_profiles();
+        let team = scrum_team(&base);
+        let (out, target, summary) = export(&base, team.path(), false);
+
+        let manifest = crate::profile::read_manifest_from("tuned", out.path()).unwrap();
+        assert_eq!(manifest.name, "tuned");
+        assert_eq!(manifest.schema_version, "1.0");
+        assert!(manifest.roles.iter().any(|r| r.name == "architect"));
+        assert!(manifest.roles.iter().any(|r| r.name == "human-assistant"));
+
+        assert!(target.join("context.md").exists());
+        assert!(!target.join("CLAUDE.md").exists());
+        assert!(!target.join("members").exists());
+        assert!(target.join(".schema/v1.yml").exists());
+        assert!(target.join("roles/architect/context.md").exists());
+        assert!(!target.join("roles/architect/.skeleton").exists());
+
+        let role_manifest =
+            fs::read_to_string(target.join("roles/architect/.botminter.yml")).unwrap();
+        assert!(role_manifest.contains("role: architect"));
+        assert!(!role_manifest.contains("alice"));
+
+        assert!(summary.roles.contains(&(
+            "architect".to_string(),
+            RoleOrigin::Member("architect-alice".to_string())
+        )));
+        assert!(summary
+            .roles
+            .contains(&("human-assistant".to_string(), RoleOrigin::SourceProfile)));
+        assert!(summary.restored > 0);
+    }
+
+    #[test]
+    fn export_leaves_team_schedule_behind() {
+        let (_p, base) = setup_disk_profiles();
+        let team = scrum_team(&base);
+        fs::write(
+            team.path().join(crate::schedule::SCHEDULE_FILE),
+            "timezone: Europe/Berlin\nmembers:\n  architect-alice:\n    heartbeat_secs: 60\n",
+        )
+        .unwrap();
+
+        let (_out, target, _) = export(&base, team.path(), false);
+        assert!(!target.join(crate::schedule::SCHEDULE_FILE).exists());
+    }
+
+    #[test]
+    fn export_retemplates_customised_member_files() {
+        let (_p, base) = setup_disk_profiles();
+        let team = scrum_team(&base);
+        let prompt = team.path().join("members/architect-alice/PROMPT.md");
+        let tuned = format!(
+            "{}\nAlice works from members/architect-alice/ and reads CLAUDE.md first.\n",
+            fs::read_to_string(&prompt).unwrap()
+        );
+        fs::write(&prompt, tuned).unwrap();
+
+        let (_out, target, _) = export(&base, team.path(), false);
+        let exported = fs::read_to_string(target.join("roles/architect/PROMPT.md")).unwrap();
+        assert!(exported
+            .contains("Alice works from members/{{member_dir}}/ and reads context.md first."));
+        // Capitalised "Alice" is not the member name and is left alone
+        assert!(!exported.contains("architect-alice"));
+    }
+
+    #[test]
+    fn export_restores_agent_tags_only_when_asked() {
+        let (_p, base) = setup_disk_profiles();
+        let team = scrum_team(&base);
+        let raw = fs::read_to_string(base.join("scrum/context.md")).unwrap();
+        assert!(raw.contains("+agent:claude-code"));
+
+        let (_out, target, _) = export(&base, team.path(), true);
+        assert_eq!(fs::read_to_string(target.join("context.md")).unwrap(), raw);
+
+        let (_out, target, _) = export(&base, team.path(), false);
+        assert!(!fs::read_to_string(target.join("context.md"))
+            .unwrap()
+            .contains("+agent:"));
+    }
+
+    #[test]
+    fn exported_profile_hires_like_the_team() {
+        let (_p, base) = setup_disk_profiles();
+        let team = scrum_team(&base);
+        let (out, _target, _) = export(&base, team.path(), true);
+
+        let rehired = tempfile::tempdir().unwrap();
+        let agent = claude_code_agent();
+        extract_member_from(out.path(), "tuned", "architect", rehired.path(), &agent).unwrap();
+        finalize_member_manifest(rehired.path(), "alice").unwrap();
+        render_member_placeholders(rehired.path(), "architect-alice", "architect", "alice")
+            .unwrap();
+
+        for rel in ["PROMPT.md", "CLAUDE.md", "ralph.yml"] {
+            assert_eq!(
+                fs::read_to_string(rehired.path().join(rel)).unwrap(),
+                fs::read_to_string(team.path().join("members/architect-alice").join(rel)).unwrap(),
+                "{} differs after round trip",
+                rel
+            );
+        }
+    }
+
+    #[test]
+    fn tag_agent_lines_wraps_agent_dir_references() {
+        let agent = claude_code_agent();
+        let tagged = tag_agent_lines(
+            "intro\nsee .claude/agents\nmore .claude\noutro\n",
+            "a.md",
+            &agent,
+        );
+        assert_eq!(
+            tagged,
+            "intro\n<!-- +agent:claude-code -->\nsee .claude/agents\nmore .claude\n<!-- -agent -->\noutro\n"
+        );
+    }
+
+    #[test]
+    fn replace_word_respects_boundaries() {
+        assert_eq!(replace_word("bob bobby bob.", "bob", "X"), "X bobby X.");
+    }
+
+    #[test]
+    fn export_refuses_existing_target() {
+        let (_p, base) = setup_disk_profiles();
+        let team = scrum_team(&base);
+        let agent = claude_code_agent();
+        let params = ExportParams {
+            team_repo: team.path(),
+            team_name: "alpha",
+            coding_agent: &agent,
+            source_profile_dir: None,
+            profile_name: "tuned",
+            agent_tags: false,
+        };
+        let existing = tempfile::tempdir().unwrap();
+        assert!(export_team_profile(&params, existing.path()).is_err());
+    }
+}
@@ -1,17 +1,18 @@
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result, bail};
 
+use super::inherit::{extends_of, resolve_profile_from, resolve_profile_with};
 use super::manifest::CodingAgentDef;
 use super::{list_profiles_from, list_roles_from, profiles_dir};
-use crate::agent_tags;
+use crate::agent_tags::{self, TagContext};
 
 /// File extensions that should be filtered through the agent tag pipeline.
 const FILTERABLE_EXTENSIONS: &[&str] = &["md", "yml", "yaml", "sh"];
 
 /// Returns true if the filename has an extension that should be agent-tag filtered.
-pub(super) fn should_filter(filename: &str) -> bool {
+pub(crate) fn should_filter(filename: &str) -> bool {
     Path::new(filename)
         .extension()
         .and_then(|e| e.to_str())
@@ -40,6 +41,19 @@ pub fn extract_profile_from(
     profile_name: &str,
     target: &Path,
     coding_agent: &CodingAgentDef,
+) -> Result<()> {
+    let tags = TagContext::for_agent(&coding_agent.name);
+    extract_profile_with(base, profile_name, target, coding_agent, &tags)
+}
+
+/// Extracts a profile's team-repo content, resolving conditional tags against
+/// `tags`. Blocks on dimensions `tags` doesn't know are kept for a later pass.
+pub(crate) fn extract_profile_with(
+    base: &Path,
+    profile_name: &str,
+    target: &Path,
+    coding_agent: &CodingAgentDef,
+    tags: &TagContext,
 ) -> Result<()> {
     let profile_dir = base.join(profile_name);
     if !profile_dir.is_dir() {
@@ -50,13 +64,29 @@ pub fn extract_profile_from(
         );
     }
 
-    extract_dir_recursive_from_disk(&profile_dir, target, &profile_dir, coding_agent, &|rel_path| {
+    let is_member_only = |rel_path: &Path| {
         let first = rel_path
             .components()
             .next()
             .map(|c| c.as_os_str().to_string_lossy().to_string());
         matches!(first.as_deref(), Some("roles") | Some(".schema"))
-    })?;
+    };
+
+    if extends_of(profile_name, base).is_some() {
+        let resolved = resolve_profile_with(profile_name, base, tags)?;
+        for (rel, (path, _)) in &resolved.files {
+            if !is_member_only(rel.as_path()) {
+                extract_file(path, rel, target, coding_agent, tags)?;
+            }
+        }
+        // The merged manifest goes through the same text pipeline as the
+        // files above (tags and the context.md rename).
+        let manifest = serde_yml::to_string(&resolved.manifest_value)?;
+        write_text(&manifest, Path::new("botminter.yml"), target, coding_agent, tags)?;
+        return Ok(());
+    }
+
+    extract_dir_recursive_from_disk(&profile_dir, target, &profile_dir, coding_agent, tags, &is_member_only)?;
 
     Ok(())
 }
@@ -82,6 +112,39 @@ pub(crate) fn extract_member_from(
     target: &Path,
     coding_agent: &CodingAgentDef,
 ) -> Result<()> {
+    let tags = TagContext::for_agent(&coding_agent.name);
+    extract_member_with(base, profile_name, role, target, coding_agent, &tags)
+}
+
+/// Extracts a member skeleton, resolving conditional tags against `tags`.
+pub(crate) fn extract_member_with(
+    base: &Path,
+    profile_name: &str,
+    role: &str,
+    target: &Path,
+    coding_agent: &CodingAgentDef,
+    tags: &TagContext,
+) -> Result<()> {
+    if extends_of(profile_name, base).is_some() {
+        let resolved = resolve_profile_from(profile_name, base)?;
+        let role_dir = Path::new("roles").join(role);
+        let files: Vec<_> = resolved
+            .files
+            .iter()
+            .filter_map(|(rel, (path, _))| Some((rel.strip_prefix(&role_dir).ok()?, path)))
+            .collect();
+        if files.is_empty() {
+            bail!(
+                "Role '{}' not available in profile '{}'. Available roles: {}",
+                role, profile_name, resolved.roles().join(", ")
+            );
+        }
+        for (rel, path) in files {
+            extract_file(path, rel, target, coding_agent, tags)?;
+        }
+        return Ok(());
+    }
+
     let member_dir = base.join(profile_name).join("roles").join(role);
     if !member_dir.is_dir() {
         let roles = list_roles_from(profile_name, base).unwrap_or_default().join(", ");
@@ -91,7 +154,7 @@ pub(crate) fn extract_member_from(
         );
     }
 
-    extract_dir_recursive_from_disk(&member_dir, target, &member_dir, coding_agent, &|_| false)?;
+    extract_dir_recursive_from_disk(&member_dir, target, &member_dir, coding_agent, tags, &|_| false)?;
     Ok(())
 }
 
@@ -101,8 +164,8 @@ pub(crate) fn extract_member_from(
 /// to `root_path` and returns true to skip that entry.
 ///
 /// During extraction:
-/// - Text files (`.md`, `.yml`, `.yaml`, `.sh`) are filtered through `filter_file()`
-///   to strip non-matching agent tag sections.
+/// - Text files (`.md`, `.yml`, `.yaml`, `.sh`) are filtered through `filter_file_with()`
+///   to strip tag sections that don't match `tags`.
 /// - `context.md` is renamed to `coding_agent.context_file` (e.g., `CLAUDE.md`).
 /// - All other files (images, binary) are copied verbatim.
 fn extract_dir_recursive_from_disk(
@@ -110,6 +173,7 @@ fn extract_dir_recursive_from_disk(
     base_target: &Path,
     root_path: &Path,
     coding_agent: &CodingAgentDef,
+    tags: &TagContext,
     skip: &dyn Fn(&Path) -> bool,
 ) -> Result<()> {
     for entry in fs::read_dir(source_dir)
@@ -124,55 +188,94 @@ fn extract_dir_recursive_from_disk(
         }
 
         if path.is_dir() {
-            extract_dir_recursive_from_disk(&path, base_target, root_path, coding_agent, skip)?;
+            extract_dir_recursive_from_disk(&path, base_target, root_path, coding_agent, tags, skip)?;
             continue;
         }
 
-        let filename = rel
-            .file_name()
-            .map(|f| f.to_string_lossy().to_string())
-            .unwrap_or_default();
-
-        // Determine output path: rename context.md → coding_agent.context_file
-        let target_path = if filename == "context.md" {
-            let parent = rel.parent().unwrap_or(Path::new(""));
-            base_target.join(parent).join(&coding_agent.context_file)
-        } else {
-            base_target.join(rel)
-        };
+        extract_file(&path, rel, base_target, coding_agent, tags)?;
+    }
 
+    Ok(())
+}
+
+/// Extracts a single profile file to `base_target/rel`, applying agent-tag
+/// filtering and the `context.md` rename.
+fn extract_file(
+    path: &Path,
+    rel: &Path,
+    base_target: &Path,
+    coding_agent: &CodingAgentDef,
+    tags: &TagContext,
+) -> Result<()> {
+    let filename = rel
+        .file_name()
+        .map(|f| f.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    // Filter text files through agent tag pipeline; copy others verbatim
+    if should_filter(&filename) {
+        let content = fs::read_to_string(&path)
+            .with_context(|| format!("File {} is not valid UTF-8", rel.display()))?;
+        write_text(&content, rel, base_target, coding_agent, tags)?;
+    } else {
+        let target_path = target_path_for(rel, base_target, coding_agent);
         if let Some(parent) = target_path.parent() {
             fs::create_dir_all(parent).with_context(|| {
                 format!("Failed to create directory {}", parent.display())
             })?;
         }
-
-        // Filter text files through agent tag pipeline; copy others verbatim
-        if should_filter(&filename) {
-            let content = fs::read_to_string(&path)
-                .with_context(|| format!("File {} is not valid UTF-8", rel.display()))?;
-            let filtered = agent_tags::filter_file(&content, &filename, &coding_agent.name);
-            // Replace context.md references in content with the coding agent's
-            // context_file (e.g., CLAUDE.md). Profile templates use the agent-neutral
-            // name "context.md"; generated team repos need the resolved name.
-            let filtered = if coding_agent.context_file != "context.md" {
-                filtered.replace("context.md", &coding_agent.context_file)
-            } else {
-                filtered
-            };
-            fs::write(&target_path, filtered.as_bytes()).with_context(|| {
-                format!("Failed to write {}", target_path.display())
-            })?;
-        } else {
-            fs::copy(&path, &target_path).with_context(|| {
-                format!("Failed to copy {} to {}", path.display(), target_path.display())
-            })?;
-        }
+        fs::copy(&path, &target_path).with_context(|| {
+            format!("Failed to copy {} to {}", path.display(), target_path.display())
+        })?;
     }
 
     Ok(())
 }
 
+/// Writes text content to `base_target/rel` through the agent tag pipeline.
+fn write_text(
+    content: &str,
+    rel: &Path,
+    base_target: &Path,
+    coding_agent: &CodingAgentDef,
+    tags: &TagContext,
+) -> Result<()> {
+    let filename = rel
+        .file_name()
+        .map(|f| f.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let target_path = target_path_for(rel, base_target, coding_agent);
+    if let Some(parent) = target_path.parent() {
+        fs::create_dir_all(parent).with_context(|| {
+            format!("Failed to create directory {}", parent.display())
+        })?;
+    }
+
+    let filtered = agent_tags::filter_file_with(content, &filename, tags);
+    // Replace context.md references in content with the coding agent's
+    // context_file (e.g., CLAUDE.md). Profile templates use the agent-neutral
+    // name "context.md"; generated team repos need the resolved name.
+    let filtered = if coding_agent.context_file != "context.md" {
+        filtered.replace("context.md", &coding_agent.context_file)
+    } else {
+        filtered
+    };
+    fs::write(&target_path, filtered.as_bytes()).with_context(|| {
+        format!("Failed to write {}", target_path.display())
+    })
+}
+
+/// Output path for a profile file: `context.md` is renamed to the coding
+/// agent's context file.
+fn target_path_for(rel: &Path, base_target: &Path, coding_agent: &CodingAgentDef) -> PathBuf {
+    if rel.file_name().is_some_and(|f| f == "context.md") {
+        let parent = rel.parent().unwrap_or(Path::new(""));
+        base_target.join(parent).join(&coding_agent.context_file)
+    } else {
+        base_target.join(rel)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -418,6 +521,9 @@ mod tests {
             binary: "gemini".into(),
             system_prompt_flag: None,
             skip_permissions_flag: None,
+            mcp_config_file: None,
+            mcp_config_flag: None,
+            mcp_mode: None,
         };
         let (_profiles_tmp, base) = setup_disk_profiles();
         let output = tempfile::tempdir().unwrap();
@@ -441,6 +547,9 @@ mod tests {
             binary: "gemini".into(),
             system_prompt_flag: None,
             skip_permissions_flag: None,
+            mcp_config_file: None,
+            mcp_config_flag: None,
+            mcp_mode: None,
         };
         let (_profiles_tmp, base) = setup_disk_profiles();
         let output = tempfile::tempdir().unwrap();
@@ -487,6 +596,9 @@ mod tests {
             binary: "gemini".into(),
             system_prompt_flag: None,
             skip_permissions_flag: None,
+            mcp_config_file: None,
+            mcp_config_flag: None,
+            mcp_mode: None,
         };
         let (_profiles_tmp, base) = setup_disk_profiles();
         let output = tempfile::tempdir().unwrap();
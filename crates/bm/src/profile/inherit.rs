@@ -0,0 +1,570 @@
+//! Profile inheritance: `extends: <base-profile>` in `botminter.yml`.
+//!
+//! An overlay profile only carries what differs from its base. Resolution
+//! walks the `extends` chain base-first and layers each profile on top:
+//!
+//! - Files: the overlay's files are added to (or replace) the base tree.
+//!   `remove.files` deletes base files or directories first.
+//! - Manifest: named lists (`roles`, `statuses`, `labels`, `bridges`, `views`,
+//!   `projects`) merge by `name`, maps (e.g. `coding_agents`) merge by key, and
+//!   any other key replaces the base value. `remove.<list>` drops entries by
+//!   name; `remove.roles` also drops `roles/<name>/`.
+//!
+//! Profiles without `extends` are read exactly as before.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use serde_yml::{Mapping, Value};
+
+use super::export::walk_files;
+use super::{list_profiles_from, ProfileManifest};
+use crate::agent_tags::{self, TagContext};
+
+/// Longest `extends` chain accepted; also bounds cycles.
+const MAX_DEPTH: usize = 8;
+
+/// Manifest lists merged entry-by-entry on `name`.
+const NAMED_LISTS: &[&str] = &[
+    "roles", "statuses", "labels", "bridges", "views", "projects",
+];
+
+/// Manifest keys that describe a layer rather than the resolved profile.
+const LAYER_KEYS: &[&str] = &["extends", "remove"];
+
+/// A profile with its `extends` chain applied.
+#[derive(Debug)]
+pub struct ResolvedProfile {
+    /// Profile names, base first, ending with the requested profile.
+    pub chain: Vec<String>,
+    pub manifest: ProfileManifest,
+    /// The merged manifest, written as `botminter.yml` on extraction.
+    pub(super) manifest_value: Value,
+    /// Relative path → (source file, profile that provides it).
+    pub(super) files: BTreeMap<PathBuf, (PathBuf, String)>,
+    /// `(list, name)` → profile that last defined the entry.
+    origins: HashMap<(String, String), String>,
+    /// Files an overlay provides in place of a base file.
+    overridden: BTreeSet<PathBuf>,
+}
+
+impl ResolvedProfile {
+    /// Whether the profile extends another.
+    pub fn extends(&self) -> bool {
+        self.chain.len() > 1
+    }
+
+    /// Which profile in the chain defined a manifest entry, e.g.
+    /// `origin("roles", "architect")`.
+    pub fn origin(&self, list: &str, name: &str) -> Option<&str> {
+        self.origins
+            .get(&(list.to_string(), name.to_string()))
+            .map(String::as_str)
+    }
+
+    /// Number of resolved files contributed by each profile in the chain.
+    pub fn file_counts(&self) -> Vec<(String, usize)> {
+        self.chain
+            .iter()
+            .map(|layer| {
+                let n = self.files.values().filter(|(_, l)| l == layer).count();
+                (layer.clone(), n)
+            })
+            .collect()
+    }
+
+    /// Files an overlay replaced, with the profile that replaced them.
+    pub fn overridden_files(&self) -> Vec<(String, &str)> {
+        self.overridden
+            .iter()
+            .filter_map(|rel| {
+                let (_, layer) = self.files.get(rel)?;
+                Some((rel.to_string_lossy().to_string(), layer.as_str()))
+            })
+            .collect()
+    }
+
+    /// Role names with at least one file under `roles/<role>/`.
+    pub fn roles(&self) -> Vec<String> {
+        let roles: BTreeSet<String> = self
+            .files
+            .keys()
+            .filter_map(|rel| {
+                let mut parts = rel.components();
+                (parts.next()?.as_os_str() == "roles")
+                    .then(|| parts.next())
+                    .flatten()
+                    .filter(|_| parts.next().is_some())
+                    .map(|c| c.as_os_str().to_string_lossy().to_string())
+            })
+            .collect();
+        roles.into_iter().collect()
+    }
+}
+
+/// Returns the base profile named by `extends` in a profile's manifest, if any.
+pub(super) fn extends_of(name: &str, base: &Path) -> Option<String> {
+    let value = read_raw_manifest(name, base).ok()?;
+    layer_extends(&value)
+}
+
+fn layer_extends(value: &Value) -> Option<String> {
+    value.get("extends")?.as_str().map(String::from)
+}
+
+fn read_raw_manifest(name: &str, base: &Path) -> Result<Value> {
+    read_layer_manifest(name, base, &TagContext::default())
+}
+
+/// Reads a layer's `botminter.yml`, resolving conditional tags against `tags`
+/// before parsing so only the matching blocks are merged.
+fn read_layer_manifest(name: &str, base: &Path, tags: &TagContext) -> Result<Value> {
+    let path = base.join(name).join("botminter.yml");
+    let contents = fs::read_to_string(&path).with_context(|| {
+        let available = list_profiles_from(base).unwrap_or_default().join(", ");
+        format!(
+            "Profile '{}' not found at {}. Available profiles: {}",
+            name,
+            path.display(),
+            available
+        )
+    })?;
+    let contents = agent_tags::filter_file_with(&contents, "botminter.yml", tags);
+    serde_yml::from_str(&contents)
+        .with_context(|| format!("Failed to parse profile manifest {}", path.display()))
+}
+
+/// Resolves a profile's `extends` chain into one manifest and file tree.
+pub fn resolve_profile_from(name: &str, base: &Path) -> Result<ResolvedProfile> {
+    resolve_profile_with(name, base, &TagContext::default())
+}
+
+/// Like `resolve_profile_from`, with conditional tags in each layer's manifest
+/// resolved against `tags` (used on extraction).
+pub(super) fn resolve_profile_with(
+    name: &str,
+    base: &Path,
+    tags: &TagContext,
+) -> Result<ResolvedProfile> {
+    let mut chain = vec![name.to_string()];
+    let mut layers = vec![read_layer_manifest(name, base, tags)?];
+    while let Some(parent) = layer_extends(layers.last().expect("chain is never empty")) {
+        if chain.contains(&parent) {
+            chain.push(parent);
+            bail!("Profile inheritance cycle: {}", chain.join(" → "));
+        }
+        if chain.len() >= MAX_DEPTH {
+            bail!(
+                "Profile '{}' extends more than {} levels deep",
+                name,
+                MAX_DEPTH
+            );
+        }
+        layers.push(read_layer_manifest(&parent, base, tags).with_context(|| {
+            format!("Profile '{}' extends '{}'", chain.last().unwrap(), parent)
+        })?);
+        chain.push(parent);
+    }
+    chain.reverse();
+    layers.reverse();
+
+    let mut resolved = Layered {
+        manifest_value: Value::Mapping(Mapping::new()),
+        files: BTreeMap::new(),
+        origins: HashMap::new(),
+        overridden: BTreeSet::new(),
+    };
+
+    for (i, (layer, value)) in chain.iter().zip(layers).enumerate() {
+        let Value::Mapping(overlay) = value else {
+            bail!("Profile '{}' manifest is not a mapping", layer);
+        };
+        if i > 0 {
+            if let Some(removals) = overlay.get("remove") {
+                apply_removals(&mut resolved, removals, layer)?;
+            }
+        }
+        merge_manifest(&mut resolved, &overlay, layer)?;
+
+        let dir = base.join(layer);
+        for rel in walk_files(&dir, &|rel| rel == Path::new("botminter.yml"))? {
+            if resolved.files.contains_key(&rel) {
+                resolved.overridden.insert(rel.clone());
+            }
+            resolved
+                .files
+                .insert(rel.clone(), (dir.join(&rel), layer.clone()));
+        }
+    }
+
+    let merged = serde_yml::to_string(&resolved.manifest_value)?;
+    let manifest: ProfileManifest = serde_yml::from_str(&merged)
+        .with_context(|| format!("Resolved manifest of profile '{}' is invalid", name))?;
+    Ok(ResolvedProfile {
+        chain,
+        manifest,
+        manifest_value: resolved.manifest_value,
+        files: resolved.files,
+        origins: resolved.origins,
+        overridden: resolved.overridden,
+    })
+}
+
+/// State accumulated while applying the chain layer by layer.
+struct Layered {
+    manifest_value: Value,
+    files: BTreeMap<PathBuf, (PathBuf, String)>,
+    origins: HashMap<(String, String), String>,
+    overridden: BTreeSet<PathBuf>,
+}
+
+/// Layers one manifest onto the resolved manifest.
+fn merge_manifest(resolved: &mut Layered, overlay: &Mapping, layer: &str) -> Result<()> {
+    let Value::Mapping(merged) = &mut resolved.manifest_value else {
+        unreachable!("resolved manifest is always a mapping");
+    };
+
+    for (key, value) in overlay {
+        let Some(k) = key.as_str() else {
+            continue;
+        };
+        if LAYER_KEYS.contains(&k) {
+            continue;
+        }
+
+        if NAMED_LISTS.contains(&k) {
+            let Some(entries) = value.as_sequence() else {
+                bail!("'{}' in profile '{}' must be a list", k, layer);
+            };
+            if !merged.contains_key(k) {
+                merged.insert(key.clone(), Value::Sequence(Vec::new()));
+            }
+            let Some(list) = merged.get_mut(k).and_then(Value::as_sequence_mut) else {
+                bail!("'{}' in the base of profile '{}' is not a list", k, layer);
+            };
+            for entry in entries {
+                let Some(name) = entry.get("name").and_then(Value::as_str) else {
+                    bail!("Every '{}' entry in profile '{}' needs a name", k, layer);
+                };
+                match list
+                    .iter_mut()
+                    .find(|e| e.get("name").and_then(Value::as_str) == Some(name))
+                {
+                    Some(existing) => *existing = entry.clone(),
+                    None => list.push(entry.clone()),
+                }
+                resolved
+                    .origins
+                    .insert((k.to_string(), name.to_string()), layer.to_string());
+            }
+            continue;
+        }
+
+        if k == "schema_version" {
+            if let Some(base_schema) = merged.get(k).and_then(Value::as_str) {
+                if Some(base_schema) != value.as_str() {
+                    bail!(
+                        "Profile '{}' declares schema_version {} but its base uses {}",
+                        layer,
+                        value.as_str().unwrap_or("?"),
+                        base_schema
+                    );
+                }
+            }
+        }
+
+        match (merged.get_mut(k), value) {
+            (Some(Value::Mapping(base_map)), Value::Mapping(overlay_map)) => {
+                for (ek, ev) in overlay_map {
+                    base_map.insert(ek.clone(), ev.clone());
+                }
+            }
+            _ => {
+                merged.insert(key.clone(), value.clone());
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Applies an overlay's `remove:` section to what its base resolved to.
+fn apply_removals(resolved: &mut Layered, removals: &Value, layer: &str) -> Result<()> {
+    let Some(removals) = removals.as_mapping() else {
+        bail!("'remove' in profile '{}' must be a mapping", layer);
+    };
+    for (key, names) in removals {
+        let k = key.as_str().unwrap_or_default();
+        let Some(names) = names.as_sequence() else {
+            bail!("'remove.{}' in profile '{}' must be a list", k, layer);
+        };
+        let names: Vec<&str> = names.iter().filter_map(Value::as_str).collect();
+
+        if k == "files" {
+            for path in names {
+                remove_files(resolved, Path::new(path), layer)?;
+            }
+            continue;
+        }
+        if !NAMED_LISTS.contains(&k) {
+            bail!(
+                "Unknown 'remove.{}' in profile '{}'. Removable: files, {}",
+                k,
+                layer,
+                NAMED_LISTS.join(", ")
+            );
+        }
+
+        let Some(list) = resolved
+            .manifest_value
+            .get_mut(k)
+            .and_then(Value::as_sequence_mut)
+        else {
+            bail!("Profile '{}' removes {} but its base has none", layer, k);
+        };
+        for name in names {
+            let before = list.len();
+            list.retain(|e| e.get("name").and_then(Value::as_str) != Some(name));
+            if list.len() == before {
+                bail!(
+                    "Profile '{}' removes {} '{}', which its base doesn't define",
+                    layer,
+                    k,
+                    name
+                );
+            }
+            resolved.origins.remove(&(k.to_string(), name.to_string()));
+            if k == "roles" {
+                let dir = Path::new("roles").join(name);
+                resolved.files.retain(|rel, _| !rel.starts_with(&dir));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn remove_files(resolved: &mut Layered, path: &Path, layer: &str) -> Result<()> {
+    let before = resolved.files.len();
+    resolved.files.retain(|rel, _| !rel.starts_with(path));
+    if resolved.files.len() == before {
+        bail!(
+            "Profile '{}' removes '{}', which its base doesn't contain",
+            layer,
+            path.display()
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::profile::test_support::{claude_code_agent, setup_disk_profiles};
+    use crate::profile::{
+        extract_member_from, extract_profile_from, list_roles_from, read_manifest_from,
+    };
+
+    fn write(path: &Path, contents: &str) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, contents).unwrap();
+    }
+
+    /// `scrum-plus`: adds a reviewer role, drops human-assistant and one
+    /// invariant, recolours a label and overrides PROCESS.md.
+    fn overlay(base: &Path) {
+        let dir = base.join("scrum-plus");
+        write(
+            &dir.join("botminter.yml"),
+            r#"name: scrum-plus
+display_name: "Scrum Plus"
+description: "Scrum with a reviewer"
+version: "0.1.0"
+extends: scrum
+roles:
+  - name: reviewer
+    description: "Code review"
+labels:
+  - name: "kind/docs"
+    color: "000000"
+    description: "Docs"
+remove:
+  roles: [human-assistant]
+  files: [invariants/test-coverage.md]
+"#,
+        );
+        write(
+            &dir.join("roles/reviewer/.botminter.yml"),
+            "role: reviewer\n",
+        );
+        write(
+            &dir.join("roles/reviewer/PROMPT.md"),
+            "Review {{member_dir}}\n",
+        );
+        write(&dir.join("PROCESS.md"), "# Overlay process\n");
+    }
+
+    #[test]
+    fn resolves_manifest_with_merges_and_removals() {
+        let (_tmp, base) = setup_disk_profiles();
+        overlay(&base);
+
+        let resolved = resolve_profile_from("scrum-plus", &base).unwrap();
+        assert_eq!(resolved.chain, vec!["scrum", "scrum-plus"]);
+        let m = &resolved.manifest;
+        assert_eq!(m.name, "scrum-plus");
+        assert_eq!(m.version, "0.1.0");
+        assert_eq!(m.schema_version, "1.0");
+
+        let roles: Vec<&str> = m.roles.iter().map(|r| r.name.as_str()).collect();
+        assert!(roles.contains(&"architect"));
+        assert!(roles.contains(&"reviewer"));
+        assert!(!roles.contains(&"human-assistant"));
+
+        let docs = m.labels.iter().find(|l| l.name == "kind/docs").unwrap();
+        assert_eq!(docs.color, "000000");
+        assert_eq!(m.labels.iter().filter(|l| l.name == "kind/docs").count(), 1);
+        assert!(
+            !m.coding_agents.is_empty(),
+            "coding agents come from the base"
+        );
+
+        assert_eq!(resolved.origin("roles", "architect"), Some("scrum"));
+        assert_eq!(resolved.origin("roles", "reviewer"), Some("scrum-plus"));
+        assert_eq!(resolved.origin("labels", "kind/docs"), Some("scrum-plus"));
+        assert_eq!(resolved.origin("roles", "human-assistant"), None);
+    }
+
+    #[test]
+    fn resolves_file_tree() {
+        let (_tmp, base) = setup_disk_profiles();
+        overlay(&base);
+
+        let resolved = resolve_profile_from("scrum-plus", &base).unwrap();
+        let roles = resolved.roles();
+        assert!(roles.contains(&"reviewer".to_string()));
+        assert!(roles.contains(&"architect".to_string()));
+        assert!(!roles.contains(&"human-assistant".to_string()));
+
+        assert!(!resolved
+            .files
+            .contains_key(Path::new("invariants/test-coverage.md")));
+        let (_, layer) = &resolved.files[Path::new("PROCESS.md")];
+        assert_eq!(layer, "scrum-plus");
+        assert_eq!(
+            resolved.overridden_files(),
+            vec![("PROCESS.md".to_string(), "scrum-plus")]
+        );
+        assert!(!resolved.files.contains_key(Path::new("botminter.yml")));
+    }
+
+    #[test]
+    fn extracted_manifest_resolves_conditional_tags() {
+        let (_tmp, base) = setup_disk_profiles();
+        overlay(&base);
+        let path = base.join("scrum-plus/botminter.yml");
+        let tagged = fs::read_to_string(&path).unwrap().replace(
+            "    description: \"Docs\"\n",
+            "    description: \"Docs\"\n\
+             # +agent:gemini-cli\n  - name: \"kind/gemini\"\n    color: \"111111\"\n\
+             \x20   description: \"Gemini only\"\n# -agent\n",
+        );
+        fs::write(&path, tagged).unwrap();
+        assert!(read_manifest_from("scrum-plus", &base)
+            .unwrap()
+            .labels
+            .iter()
+            .any(|l| l.name == "kind/gemini"));
+
+        let team = tempfile::tempdir().unwrap();
+        extract_profile_from(&base, "scrum-plus", team.path(), &claude_code_agent()).unwrap();
+        let written = fs::read_to_string(team.path().join("botminter.yml")).unwrap();
+        assert!(written.contains("kind/docs"), "{}", written);
+        assert!(!written.contains("kind/gemini"), "{}", written);
+    }
+
+    #[test]
+    fn profile_api_uses_resolved_overlay() {
+        let (_tmp, base) = setup_disk_profiles();
+        overlay(&base);
+        let agent = claude_code_agent();
+
+        let manifest = read_manifest_from("scrum-plus", &base).unwrap();
+        assert!(manifest.roles.iter().any(|r| r.name == "reviewer"));
+        let roles = list_roles_from("scrum-plus", &base).unwrap();
+        assert!(roles.contains(&"reviewer".to_string()));
+        assert!(!roles.contains(&"human-assistant".to_string()));
+
+        let team = tempfile::tempdir().unwrap();
+        extract_profile_from(&base, "scrum-plus", team.path(), &agent).unwrap();
+        assert_eq!(
+            fs::read_to_string(team.path().join("PROCESS.md")).unwrap(),
+            "# Overlay process\n"
+        );
+        assert!(team.path().join("CLAUDE.md").exists());
+        assert!(team
+            .path()
+            .join("invariants/code-review-required.md")
+            .exists());
+        assert!(!team.path().join("invariants/test-coverage.md").exists());
+        assert!(!team.path().join("roles").exists());
+        let written = fs::read_to_string(team.path().join("botminter.yml")).unwrap();
+        assert!(!written.contains("extends"));
+        assert!(!written.contains("human-assistant"));
+
+        let member = tempfile::tempdir().unwrap();
+        extract_member_from(&base, "scrum-plus", "reviewer", member.path(), &agent).unwrap();
+        assert!(member.path().join("PROMPT.md").exists());
+        let member = tempfile::tempdir().unwrap();
+        extract_member_from(&base, "scrum-plus", "architect", member.path(), &agent).unwrap();
+        assert!(member.path().join(".botminter.yml").exists());
+        let err = extract_member_from(
+            &base,
+            "scrum-plus",
+            "human-assistant",
+            member.path(),
+            &agent,
+        )
+        .unwrap_err()
+        .to_string();
+        assert!(err.contains("not available"), "{}", err);
+    }
+
+    #[test]
+    fn rejects_cycles() {
+        let (_tmp, base) = setup_disk_profiles();
+        for (name, parent) in [("a", "b"), ("b", "a")] {
+            write(
+                &base.join(name).join("botminter.yml"),
+                &format!(
+                    "name: {}\ndisplay_name: x\ndescription: x\nversion: '1'\nschema_version: '1.0'\nextends: {}\n",
+                    name, parent
+                ),
+            );
+        }
+        let err = resolve_profile_from("a", &base).unwrap_err().to_string();
+        assert!(err.contains("cycle"), "{}", err);
+    }
+
+    #[test]
+    fn rejects_schema_mismatch_and_unknown_removals() {
+        let (_tmp, base) = setup_disk_profiles();
+        write(
+            &base.join("bad-schema/botminter.yml"),
+            "name: bad-schema\ndisplay_name: x\ndescription: x\nversion: '1'\nschema_version: '2.0'\nextends: scrum\n",
+        );
+        let err = resolve_profile_from("bad-schema", &base)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("schema_version"), "{}", err);
+
+        write(
+            &base.join("bad-remove/botminter.yml"),
+            "name: bad-remove\ndisplay_name: x\ndescription: x\nversion: '1'\nextends: scrum\nremove:\n  roles: [nobody]\n",
+        );
+        let err = resolve_profile_from("bad-remove", &base)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("nobody"), "{}", err);
+    }
+}
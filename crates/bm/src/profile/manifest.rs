@@ -2,6 +2,8 @@ use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
 
+use crate::mcp::McpMode;
+
 /// Profile manifest parsed from botminter.yml
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ProfileManifest {
@@ -95,6 +97,17 @@ pub struct CodingAgentDef {
     /// CLI flag to skip permission prompts (e.g. "--dangerously-skip-permissions")
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub skip_permissions_flag: Option<String>,
+    /// Project MCP config file the agent reads from its working directory
+    /// (e.g. ".mcp.json"). `bm` registers its MCP server there.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mcp_config_file: Option<String>,
+    /// CLI flag to load an MCP config file explicitly (e.g. "--mcp-config")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mcp_config_flag: Option<String>,
+    /// Mode `bm teams sync` registers the MCP server in for brain members.
+    /// Read-only unless the profile opts into `operator`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mcp_mode: Option<McpMode>,
 }
 
 /// Defines a role-based view for the GitHub Project board.
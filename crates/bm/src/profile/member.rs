@@ -59,11 +59,31 @@ pub fn hire_member(
         });
     }
 
+    let archived_dir = team_repo
+        .join("members")
+        .join(crate::member_lifecycle::ARCHIVE_DIR)
+        .join(&member_dir_name);
+    if archived_dir.exists() {
+        bail!(
+            "Member '{}' is archived. Bring it back with `bm members restore {}`.",
+            member_dir_name,
+            member_dir_name
+        );
+    }
+
     // Extract member skeleton from embedded profile
     fs::create_dir_all(&member_dir)
         .with_context(|| format!("Failed to create member dir {}", member_dir.display()))?;
 
-    super::extract_member_to(team_profile, role, &member_dir, coding_agent)?;
+    let tags = super::team_tag_context(team_repo, coding_agent);
+    super::extract_member_with(
+        &super::profiles_dir()?,
+        team_profile,
+        role,
+        &member_dir,
+        coding_agent,
+        &tags,
+    )?;
 
     // Finalize member manifest: .botminter.yml → botminter.yml with name added
     finalize_member_manifest(&member_dir, &member_name)?;
@@ -71,6 +91,9 @@ pub fn hire_member(
     // Render {{member_dir}} placeholders in all text files
     render_member_placeholders(&member_dir, &member_dir_name, role, &member_name)?;
 
+    // Keep the pristine skeleton as the merge base for `bm members refresh`
+    super::refresh::snapshot_member_dir(&member_dir)?;
+
     // Git add + commit (no auto-push)
     run_git(
         team_repo,
@@ -88,15 +111,22 @@ pub fn hire_member(
 
 /// Computes the next auto-suffix for a role by scanning existing member dirs.
 /// Returns a 2-digit, zero-padded string (e.g., "01", "02").
-/// Fills gaps: if 01 and 03 exist, returns "02".
+/// Fills gaps: if 01 and 03 exist, returns "02". Archived members under
+/// `members/.archived/` keep their suffix so they can be restored.
 pub fn auto_suffix(team_repo: &Path, role: &str) -> Result<String> {
     let team_members_dir = team_repo.join("members");
     let prefix = format!("{}-", role);
 
     let mut used: Vec<u32> = Vec::new();
 
-    if team_members_dir.is_dir() {
-        for entry in fs::read_dir(&team_members_dir)? {
+    for dir in [
+        team_members_dir.clone(),
+        team_members_dir.join(crate::member_lifecycle::ARCHIVE_DIR),
+    ] {
+        if !dir.is_dir() {
+            continue;
+        }
+        for entry in fs::read_dir(&dir)? {
             let entry = entry?;
             if !entry.file_type()?.is_dir() {
                 continue;
@@ -159,7 +189,7 @@ pub fn finalize_member_manifest(member_dir: &Path, name: &str) -> Result<()> {
 /// Renders `{{member_dir}}`, `{{role}}`, and `{{member_name}}` placeholders
 /// in all text files within the member directory. Called during `bm hire`
 /// after the member skeleton is extracted and the manifest is finalized.
-fn render_member_placeholders(
+pub(super) fn render_member_placeholders(
     member_dir: &Path,
     member_dir_name: &str,
     role: &str,
@@ -197,7 +227,7 @@ fn render_placeholders_recursive(
     Ok(())
 }
 
-fn is_text_file(path: &Path) -> bool {
+pub(super) fn is_text_file(path: &Path) -> bool {
     matches!(
         path.extension().and_then(|e| e.to_str()),
         Some(
@@ -243,6 +273,17 @@ mod tests {
         assert_eq!(result, "02");
     }
 
+    #[test]
+    fn auto_suffix_counts_archived_members() {
+        let tmp = tempfile::tempdir().unwrap();
+        let team_repo = tmp.path();
+        fs::create_dir_all(team_repo.join("members/architect-01")).unwrap();
+        fs::create_dir_all(team_repo.join("members/.archived/architect-02")).unwrap();
+
+        let result = auto_suffix(team_repo, "architect").unwrap();
+        assert_eq!(result, "03");
+    }
+
     #[test]
     fn auto_suffix_skips_non_numeric() {
         let tmp = tempfile::tempdir().unwrap();
@@ -1,22 +1,40 @@
 mod agent;
 pub(crate) mod embedded;
+mod export;
 mod extraction;
+mod inherit;
 mod manifest;
 mod member;
+mod refresh;
+mod source;
 mod team_repo;
 
 // Re-export public API
 pub use agent::{
     ensure_minty_initialized, resolve_agent_from_profiles, resolve_coding_agent, scan_agent_tags,
+    scan_profile_tags, team_tag_context,
 };
+pub(crate) use agent::profile_tag_context;
 pub use embedded::{
     extract_embedded_to_disk, extract_single_profile_to_disk, list_embedded_profiles,
     list_embedded_roles, minty_dir,
 };
 pub use embedded::minty::extract_minty_to_disk;
+pub use export::{export_team_profile, ExportParams, ExportSummary, RoleOrigin};
 pub use extraction::{extract_member_to, extract_profile_from, extract_profile_to};
-pub(crate) use extraction::extract_member_from;
+pub(crate) use extraction::{
+    extract_member_from, extract_member_with, extract_profile_with, should_filter,
+};
+pub use inherit::{resolve_profile_from, ResolvedProfile};
 pub use member::{auto_suffix, finalize_member_manifest, hire_member, HireResult};
+pub use source::{
+    add_source, installed_source, load_registry, parse_source_spec, update_sources,
+    InstalledSource, SourcePaths, SourceRegistry, SourceUpdate, SourceUpdates,
+};
+pub use refresh::{
+    commit_refresh, refresh_member, BaseSource, FileRefresh, RefreshAction, RefreshResult,
+    SKELETON_DIR,
+};
 pub use manifest::{
     BridgeDef, CodingAgentDef, LabelDef, OperatorDef, ProfileManifest, ProjectDef, RoleDef,
     StatusDef, ViewDef,
@@ -24,7 +42,7 @@ pub use manifest::{
 pub use team_repo::{
     augment_manifest_with_projects, credentials_env, discover_member_dirs, gather_team_summary,
     infer_role_from_dir, list_files_in_dir, list_scope_files, list_subdirs, read_member_role,
-    read_team_projects, read_team_repo_manifest, read_team_schema, record_bridge_in_manifest,
+    read_knowledge_file, read_team_projects, read_team_repo_manifest, read_team_schema, record_bridge_in_manifest,
     validate_bridge_selection, validate_knowledge_path, TeamSummary,
 };
 
@@ -61,7 +79,7 @@ pub struct ProfileVersionMismatch {
 /// Compare two semver-like version strings (e.g. "1.0.0" vs "2.0.0").
 /// Returns Ordering::Less if a < b, Equal if a == b, Greater if a > b.
 /// Falls back to string comparison if either version isn't valid semver.
-fn compare_versions(a: &str, b: &str) -> std::cmp::Ordering {
+pub(super) fn compare_versions(a: &str, b: &str) -> std::cmp::Ordering {
     let parse = |v: &str| -> Option<(u64, u64, u64)> {
         let parts: Vec<&str> = v.split('.').collect();
         if parts.len() != 3 {
@@ -137,12 +155,21 @@ pub fn read_manifest_from(name: &str, base: &Path) -> Result<ProfileManifest> {
         )
     })?;
 
+    if inherit::extends_of(name, base).is_some() {
+        return Ok(resolve_profile_from(name, base)?.manifest);
+    }
+
     let manifest: ProfileManifest =
         serde_yml::from_str(&contents).context("Failed to parse profile manifest")?;
 
     Ok(manifest)
 }
 
+/// Resolves a profile's `extends` chain from the profiles directory on disk.
+pub fn resolve_profile(name: &str) -> Result<ResolvedProfile> {
+    resolve_profile_from(name, &profiles_dir()?)
+}
+
 /// Lists the role names available in a profile by reading its roles/ subdirectory on disk.
 pub fn list_roles(name: &str) -> Result<Vec<String>> {
     list_roles_from(name, &profiles_dir()?)
@@ -150,6 +177,9 @@ pub fn list_roles(name: &str) -> Result<Vec<String>> {
 
 /// Lists role names from a profile at a given base directory.
 pub fn list_roles_from(name: &str, base: &Path) -> Result<Vec<String>> {
+    if inherit::extends_of(name, base).is_some() {
+        return Ok(resolve_profile_from(name, base)?.roles());
+    }
     let roles_dir = base.join(name).join("roles");
     if !roles_dir.is_dir() {
         bail!("Profile '{}' has no roles/ directory", name);
@@ -393,6 +423,9 @@ pub(crate) mod test_support {
             binary: "claude".into(),
             system_prompt_flag: Some("--append-system-prompt-file".into()),
             skip_permissions_flag: Some("--dangerously-skip-permissions".into()),
+            mcp_config_file: Some(".mcp.json".into()),
+            mcp_config_flag: Some("--mcp-config".into()),
+            mcp_mode: None,
         }
     }
 
@@ -0,0 +1,753 @@
+//! Member refresh: three-way merge of a member's role skeleton.
+//!
+//! The skeleton a member was hired from (or last refreshed to) is kept as a
+//! snapshot in `members/<member>/.skeleton/`. A refresh re-extracts the role
+//! from the current profile and merges the profile's changes into the member's
+//! files with `git merge-file`, using the snapshot as the common ancestor.
+//! Members hired before snapshots existed fall back to the files of their
+//! hire commit.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use anyhow::{bail, Context, Result};
+
+use super::member::render_member_placeholders;
+use super::{
+    extract_member_with, finalize_member_manifest, profiles_dir, team_tag_context, CodingAgentDef,
+};
+use crate::agent_tags::TagContext;
+use crate::git::run_git;
+
+/// Directory inside a member dir holding the pristine skeleton snapshot.
+pub const SKELETON_DIR: &str = ".skeleton";
+
+/// Files keyed by `/`-separated path relative to the member dir.
+type FileSet = BTreeMap<String, Vec<u8>>;
+
+/// Where the merge base came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BaseSource {
+    /// `members/<member>/.skeleton/`
+    Snapshot,
+    /// Files as committed when the member was hired (no snapshot yet).
+    HireCommit,
+}
+
+/// What a refresh did (or would do) to one file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RefreshAction {
+    /// Profile changed the file, member had not: taken from the profile.
+    Updated,
+    /// Both changed the file; merged without conflicts.
+    Merged,
+    /// New file in the profile.
+    Added,
+    /// Removed from the profile and unmodified locally: deleted.
+    Removed,
+    /// Profile changed or removed the file, but the member deleted or
+    /// customised it in a way that can't be merged: local version kept.
+    KeptLocal,
+    /// Both changed the same lines; conflict markers written.
+    Conflict,
+    /// Both changed a binary file; local version kept.
+    BinaryConflict,
+}
+
+impl RefreshAction {
+    pub fn label(&self) -> &'static str {
+        match self {
+            RefreshAction::Updated => "updated",
+            RefreshAction::Merged => "merged",
+            RefreshAction::Added => "added",
+            RefreshAction::Removed => "removed",
+            RefreshAction::KeptLocal => "kept local",
+            RefreshAction::Conflict => "CONFLICT",
+            RefreshAction::BinaryConflict => "CONFLICT (binary)",
+        }
+    }
+
+    pub fn is_conflict(&self) -> bool {
+        matches!(
+            self,
+            RefreshAction::Conflict | RefreshAction::BinaryConflict
+        )
+    }
+}
+
+/// Outcome for a single file.
+#[derive(Debug)]
+pub struct FileRefresh {
+    pub path: String,
+    pub action: RefreshAction,
+    /// Conflicting regions, including markers, for display.
+    pub conflict_hunks: Vec<String>,
+}
+
+/// Result of refreshing one member.
+#[derive(Debug)]
+pub struct RefreshResult {
+    pub member_dir_name: String,
+    pub base_source: BaseSource,
+    /// Files touched by the refresh. Files the profile didn't change are omitted.
+    pub files: Vec<FileRefresh>,
+}
+
+impl RefreshResult {
+    pub fn conflicts(&self) -> usize {
+        self.files.iter().filter(|f| f.action.is_conflict()).count()
+    }
+}
+
+/// Refreshes a member from the profile on disk. See [`refresh_member_from`].
+pub fn refresh_member(
+    team_repo: &Path,
+    team_profile: &str,
+    member_dir_name: &str,
+    coding_agent: &CodingAgentDef,
+    dry_run: bool,
+) -> Result<RefreshResult> {
+    refresh_member_from(
+        &profiles_dir()?,
+        team_repo,
+        team_profile,
+        member_dir_name,
+        coding_agent,
+        dry_run,
+    )
+}
+
+/// Merges profile changes to a member's role skeleton into its files.
+///
+/// Writes merged files (with conflict markers where needed) and updates the
+/// skeleton snapshot unless `dry_run`. Does not commit — see [`commit_refresh`].
+pub(crate) fn refresh_member_from(
+    profiles_base: &Path,
+    team_repo: &Path,
+    team_profile: &str,
+    member_dir_name: &str,
+    coding_agent: &CodingAgentDef,
+    dry_run: bool,
+) -> Result<RefreshResult> {
+    let members_dir = team_repo.join("members");
+    let member_dir = members_dir.join(member_dir_name);
+    if !member_dir.is_dir() {
+        bail!(
+            "Member '{}' not found in {}",
+            member_dir_name,
+            members_dir.display()
+        );
+    }
+
+    if !dry_run && has_uncommitted_changes(team_repo, member_dir_name)? {
+        bail!(
+            "Member '{}' has uncommitted changes in the team repo. \
+             Commit or discard them before refreshing.",
+            member_dir_name
+        );
+    }
+
+    let role = super::read_member_role(&members_dir, member_dir_name);
+    let member_name = read_member_name(&member_dir).unwrap_or_else(|| {
+        member_dir_name
+            .strip_prefix(&format!("{}-", role))
+            .unwrap_or(member_dir_name)
+            .to_string()
+    });
+
+    let (base, base_source) = load_base(team_repo, &member_dir, member_dir_name)?;
+
+    let skeleton = tempfile::tempdir().context("Failed to create temp dir")?;
+    render_skeleton(
+        profiles_base,
+        team_profile,
+        &role,
+        member_dir_name,
+        &member_name,
+        coding_agent,
+        &team_tag_context(team_repo, coding_agent),
+        skeleton.path(),
+    )?;
+    let new = read_tree(skeleton.path())?;
+
+    let paths: BTreeSet<&String> = base.keys().chain(new.keys()).collect();
+    let mut files = Vec::new();
+
+    for path in paths {
+        let b = base.get(path).map(Vec::as_slice);
+        let n = new.get(path).map(Vec::as_slice);
+        if b == n {
+            continue;
+        }
+        let target = member_dir.join(path);
+        let local = if target.is_file() {
+            Some(
+                fs::read(&target)
+                    .with_context(|| format!("Failed to read {}", target.display()))?,
+            )
+        } else {
+            None
+        };
+        let l = local.as_deref();
+        if l == n {
+            continue;
+        }
+
+        let (action, write, hunks) = match (b, n, l) {
+            // Removed upstream
+            (_, None, None) => continue,
+            (Some(b), None, Some(l)) if l == b => (RefreshAction::Removed, None, Vec::new()),
+            (_, None, Some(_)) => (RefreshAction::KeptLocal, None, Vec::new()),
+            // Added or changed upstream, absent locally
+            (None, Some(n), None) => (RefreshAction::Added, Some(n.to_vec()), Vec::new()),
+            (Some(_), Some(_), None) => (RefreshAction::KeptLocal, None, Vec::new()),
+            // Changed upstream, untouched locally
+            (Some(b), Some(n), Some(l)) if l == b => {
+                (RefreshAction::Updated, Some(n.to_vec()), Vec::new())
+            }
+            // Both sides changed
+            (b, Some(n), Some(l)) => match merge3(l, b.unwrap_or_default(), n)? {
+                Merge::Clean(merged) => (RefreshAction::Merged, Some(merged), Vec::new()),
+                Merge::Conflict(merged) => {
+                    let hunks = conflict_hunks(&String::from_utf8_lossy(&merged));
+                    (RefreshAction::Conflict, Some(merged), hunks)
+                }
+                Merge::Binary => (RefreshAction::BinaryConflict, None, Vec::new()),
+            },
+        };
+
+        if !dry_run {
+            match (&action, write) {
+                (RefreshAction::Removed, _) => fs::remove_file(&target)
+                    .with_context(|| format!("Failed to remove {}", target.display()))?,
+                (_, Some(contents)) => {
+                    if let Some(parent) = target.parent() {
+                        fs::create_dir_all(parent)?;
+                    }
+                    fs::write(&target, contents)
+                        .with_context(|| format!("Failed to write {}", target.display()))?;
+                }
+                _ => {}
+            }
+        }
+
+        files.push(FileRefresh {
+            path: path.clone(),
+            action,
+            conflict_hunks: hunks,
+        });
+    }
+
+    if !dry_run {
+        write_snapshot(&member_dir, &new)?;
+    }
+
+    Ok(RefreshResult {
+        member_dir_name: member_dir_name.to_string(),
+        base_source,
+        files,
+    })
+}
+
+/// Stages and commits refreshed members. Returns `false` if nothing changed.
+pub fn commit_refresh(
+    team_repo: &Path,
+    member_dir_names: &[String],
+    profile: &str,
+) -> Result<bool> {
+    if member_dir_names.is_empty() {
+        return Ok(false);
+    }
+    for member in member_dir_names {
+        run_git(team_repo, &["add", "-A", &format!("members/{}/", member)])?;
+    }
+    if run_git(team_repo, &["diff", "--cached", "--quiet"]).is_ok() {
+        return Ok(false);
+    }
+    let msg = match member_dir_names {
+        [one] => format!("chore: refresh {} from profile {}", one, profile),
+        many => format!(
+            "chore: refresh {} members from profile {}",
+            many.len(),
+            profile
+        ),
+    };
+    run_git(team_repo, &["commit", "-m", &msg])?;
+    Ok(true)
+}
+
+/// Records the member's current files as its skeleton snapshot. Called by
+/// `hire_member` right after extraction, when the files are pristine.
+pub(super) fn snapshot_member_dir(member_dir: &Path) -> Result<()> {
+    let files = read_tree(member_dir)?;
+    write_snapshot(member_dir, &files)
+}
+
+/// Extracts and renders a role skeleton exactly as `bm hire` does.
+#[allow(clippy::too_many_arguments)]
+fn render_skeleton(
+    profiles_base: &Path,
+    team_profile: &str,
+    role: &str,
+    member_dir_name: &str,
+    member_name: &str,
+    coding_agent: &CodingAgentDef,
+    tags: &TagContext,
+    target: &Path,
+) -> Result<()> {
+    extract_member_with(profiles_base, team_profile, role, target, coding_agent, tags)?;
+    finalize_member_manifest(target, member_name)?;
+    render_member_placeholders(target, member_dir_name, role, member_name)
+}
+
+fn read_member_name(member_dir: &Path) -> Option<String> {
+    let contents = fs::read_to_string(member_dir.join("botminter.yml")).ok()?;
+    let value: serde_yml::Value = serde_yml::from_str(&contents).ok()?;
+    value.get("name")?.as_str().map(String::from)
+}
+
+fn has_uncommitted_changes(team_repo: &Path, member_dir_name: &str) -> Result<bool> {
+    let output = Command::new("git")
+        .args(["status", "--porcelain", "--"])
+        .arg(format!("members/{}/", member_dir_name))
+        .current_dir(team_repo)
+        .output()
+        .context("Failed to run git status")?;
+    if !output.status.success() {
+        bail!(
+            "git status failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(!output.stdout.is_empty())
+}
+
+/// Loads the merge base: the snapshot if present, else the hire commit.
+fn load_base(
+    team_repo: &Path,
+    member_dir: &Path,
+    member_dir_name: &str,
+) -> Result<(FileSet, BaseSource)> {
+    let snapshot = member_dir.join(SKELETON_DIR);
+    if snapshot.is_dir() {
+        return Ok((read_tree(&snapshot)?, BaseSource::Snapshot));
+    }
+
+    let prefix = format!("members/{}/", member_dir_name);
+    let log = git_stdout(
+        team_repo,
+        &[
+            "log",
+            "--diff-filter=A",
+            "--format=%H",
+            "--",
+            &format!("{}botminter.yml", prefix),
+        ],
+    )?;
+    let commit = String::from_utf8_lossy(&log)
+        .lines()
+        .next()
+        .map(String::from)
+        .with_context(|| {
+            format!(
+                "No skeleton snapshot or hire commit found for '{}'; cannot determine the merge base",
+                member_dir_name
+            )
+        })?;
+
+    let listing = git_stdout(
+        team_repo,
+        &["ls-tree", "-r", "--name-only", &commit, "--", &prefix],
+    )?;
+    let mut files = FileSet::new();
+    for name in String::from_utf8_lossy(&listing).lines() {
+        let Some(rel) = name.strip_prefix(&prefix) else {
+            continue;
+        };
+        if rel.starts_with(&format!("{}/", SKELETON_DIR)) {
+            continue;
+        }
+        let contents = git_stdout(team_repo, &["show", &format!("{}:{}", commit, name)])?;
+        files.insert(rel.to_string(), contents);
+    }
+    Ok((files, BaseSource::HireCommit))
+}
+
+fn git_stdout(dir: &Path, args: &[&str]) -> Result<Vec<u8>> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .with_context(|| format!("Failed to run git {}", args.join(" ")))?;
+    if !output.status.success() {
+        bail!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(output.stdout)
+}
+
+/// Reads all regular files under `dir`, skipping the snapshot directory.
+fn read_tree(dir: &Path) -> Result<FileSet> {
+    let mut files = FileSet::new();
+    read_tree_into(dir, dir, &mut files)?;
+    Ok(files)
+}
+
+fn read_tree_into(root: &Path, dir: &Path, files: &mut FileSet) -> Result<()> {
+    for entry in fs::read_dir(dir).with_context(|| format!("Failed to read {}", dir.display()))? {
+        let entry = entry?;
+        let path = entry.path();
+        let file_type = entry.file_type()?;
+        if dir == root && entry.file_name() == SKELETON_DIR {
+            continue;
+        }
+        if file_type.is_dir() {
+            read_tree_into(root, &path, files)?;
+        } else if file_type.is_file() {
+            let rel = path
+                .strip_prefix(root)
+                .unwrap_or(&path)
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            files.insert(rel, fs::read(&path)?);
+        }
+    }
+    Ok(())
+}
+
+fn write_snapshot(member_dir: &Path, files: &FileSet) -> Result<()> {
+    let snapshot = member_dir.join(SKELETON_DIR);
+    if snapshot.exists() {
+        fs::remove_dir_all(&snapshot)
+            .with_context(|| format!("Failed to clear {}", snapshot.display()))?;
+    }
+    for (rel, contents) in files {
+        let path = snapshot.join(rel);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, contents)
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+    }
+    Ok(())
+}
+
+enum Merge {
+    Clean(Vec<u8>),
+    Conflict(Vec<u8>),
+    Binary,
+}
+
+fn is_binary(contents: &[u8]) -> bool {
+    contents.contains(&0) || std::str::from_utf8(contents).is_err()
+}
+
+/// Three-way merges `local` and `profile` against `base` with `git merge-file`.
+fn merge3(local: &[u8], base: &[u8], profile: &[u8]) -> Result<Merge> {
+    if is_binary(local) || is_binary(base) || is_binary(profile) {
+        return Ok(Merge::Binary);
+    }
+
+    let tmp = tempfile::tempdir().context("Failed to create temp dir")?;
+    let (l, b, p) = (
+        tmp.path().join("local"),
+        tmp.path().join("base"),
+        tmp.path().join("profile"),
+    );
+    fs::write(&l, local)?;
+    fs::write(&b, base)?;
+    fs::write(&p, profile)?;
+
+    let output = Command::new("git")
+        .args([
+            "merge-file",
+            "-p",
+            "-L",
+            "local",
+            "-L",
+            "base",
+            "-L",
+            "profile",
+        ])
+        .arg(&l)
+        .arg(&b)
+        .arg(&p)
+        .output()
+        .context("Failed to run git merge-file")?;
+
+    // Exit status is the number of conflicts (capped at 127); negative on error.
+    match output.status.code() {
+        Some(0) => Ok(Merge::Clean(output.stdout)),
+        Some(n) if (1..=127).contains(&n) => Ok(Merge::Conflict(output.stdout)),
+        _ => bail!(
+            "git merge-file failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ),
+    }
+}
+
+/// Extracts the `<<<<<<<` … `>>>>>>>` regions from merged text.
+fn conflict_hunks(merged: &str) -> Vec<String> {
+    let mut hunks = Vec::new();
+    let mut current: Option<Vec<&str>> = None;
+    for line in merged.lines() {
+        if line.starts_with("<<<<<<< ") {
+            current = Some(vec![line]);
+        } else if let Some(ref mut hunk) = current {
+            hunk.push(line);
+            if line.starts_with(">>>>>>> ") {
+                hunks.push(hunk.join("\n"));
+                current = None;
+            }
+        }
+    }
+    hunks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::profile::test_support::{claude_code_agent, setup_disk_profiles};
+
+    const PROFILE: &str = "scrum";
+    const ROLE: &str = "architect";
+    const MEMBER: &str = "architect-01";
+
+    /// Team repo with one hired member, committed, with a snapshot.
+    fn hired_team(base: &Path) -> tempfile::TempDir {
+        let tmp = tempfile::tempdir().unwrap();
+        let repo = tmp.path();
+        run_git(repo, &["init", "-b", "main"]).unwrap();
+        run_git(repo, &["config", "user.email", "test@example.com"]).unwrap();
+        run_git(repo, &["config", "user.name", "Test"]).unwrap();
+
+        let member_dir = repo.join("members").join(MEMBER);
+        fs::create_dir_all(&member_dir).unwrap();
+        render_skeleton(
+            base,
+            PROFILE,
+            ROLE,
+            MEMBER,
+            "01",
+            &claude_code_agent(),
+            &TagContext::for_agent("claude-code"),
+            &member_dir,
+        )
+        .unwrap();
+        snapshot_member_dir(&member_dir).unwrap();
+        run_git(repo, &["add", "-A"]).unwrap();
+        run_git(repo, &["commit", "-m", "feat: hire architect as 01"]).unwrap();
+        tmp
+    }
+
+    fn profile_file(base: &Path, rel: &str) -> std::path::PathBuf {
+        base.join(PROFILE).join("roles").join(ROLE).join(rel)
+    }
+
+    fn refresh(base: &Path, repo: &Path, dry_run: bool) -> RefreshResult {
+        refresh_member_from(base, repo, PROFILE, MEMBER, &claude_code_agent(), dry_run).unwrap()
+    }
+
+    fn action_for<'a>(result: &'a RefreshResult, path: &str) -> Option<&'a FileRefresh> {
+        result.files.iter().find(|f| f.path == path)
+    }
+
+    #[test]
+    fn no_profile_changes_is_a_noop() {
+        let (_p, base) = setup_disk_profiles();
+        let team = hired_team(&base);
+
+        let result = refresh(&base, team.path(), false);
+        assert!(result.files.is_empty(), "{:?}", result.files);
+        assert_eq!(result.base_source, BaseSource::Snapshot);
+        assert!(!commit_refresh(team.path(), &[MEMBER.to_string()], PROFILE).unwrap());
+    }
+
+    #[test]
+    fn upstream_change_applies_to_untouched_file() {
+        let (_p, base) = setup_disk_profiles();
+        let team = hired_team(&base);
+        let prompt = profile_file(&base, "PROMPT.md");
+        let updated = format!(
+            "{}\nNew upstream guidance.\n",
+            fs::read_to_string(&prompt).unwrap()
+        );
+        fs::write(&prompt, &updated).unwrap();
+
+        let result = refresh(&base, team.path(), false);
+        assert_eq!(
+            action_for(&result, "PROMPT.md").unwrap().action,
+            RefreshAction::Updated
+        );
+
+        let member_dir = team.path().join("members").join(MEMBER);
+        let local = fs::read_to_string(member_dir.join("PROMPT.md")).unwrap();
+        assert!(local.contains("New upstream guidance."));
+        let snap = fs::read_to_string(member_dir.join(SKELETON_DIR).join("PROMPT.md")).unwrap();
+        assert!(snap.contains("New upstream guidance."));
+
+        assert!(commit_refresh(team.path(), &[MEMBER.to_string()], PROFILE).unwrap());
+        assert!(!has_uncommitted_changes(team.path(), MEMBER).unwrap());
+    }
+
+    #[test]
+    fn local_and_upstream_changes_merge() {
+        let (_p, base) = setup_disk_profiles();
+        let team = hired_team(&base);
+        let member_dir = team.path().join("members").join(MEMBER);
+
+        let original = fs::read_to_string(member_dir.join("PROMPT.md")).unwrap();
+        fs::write(
+            member_dir.join("PROMPT.md"),
+            format!("Local preamble.\n\n{}", original),
+        )
+        .unwrap();
+        run_git(team.path(), &["commit", "-am", "customise"]).unwrap();
+
+        let prompt = profile_file(&base, "PROMPT.md");
+        let upstream = fs::read_to_string(&prompt).unwrap();
+        fs::write(&prompt, format!("{}\nUpstream footer.\n", upstream)).unwrap();
+
+        let result = refresh(&base, team.path(), false);
+        assert_eq!(
+            action_for(&result, "PROMPT.md").unwrap().action,
+            RefreshAction::Merged
+        );
+        let merged = fs::read_to_string(member_dir.join("PROMPT.md")).unwrap();
+        assert!(merged.starts_with("Local preamble."));
+        assert!(merged.contains("Upstream footer."));
+    }
+
+    #[test]
+    fn conflicting_changes_report_hunks_and_dry_run_writes_nothing() {
+        let (_p, base) = setup_disk_profiles();
+        let team = hired_team(&base);
+        let member_dir = team.path().join("members").join(MEMBER);
+
+        fs::write(member_dir.join("PROMPT.md"), "local rewrite\n").unwrap();
+        run_git(team.path(), &["commit", "-am", "customise"]).unwrap();
+        fs::write(profile_file(&base, "PROMPT.md"), "upstream rewrite\n").unwrap();
+
+        let result = refresh(&base, team.path(), true);
+        let file = action_for(&result, "PROMPT.md").unwrap();
+        assert_eq!(file.action, RefreshAction::Conflict);
+        assert_eq!(result.conflicts(), 1);
+        assert!(file.conflict_hunks[0].contains("local rewrite"));
+        assert!(file.conflict_hunks[0].contains("upstream rewrite"));
+        assert_eq!(
+            fs::read_to_string(member_dir.join("PROMPT.md")).unwrap(),
+            "local rewrite\n"
+        );
+
+        refresh(&base, team.path(), false);
+        let written = fs::read_to_string(member_dir.join("PROMPT.md")).unwrap();
+        assert!(written.contains("<<<<<<< local"));
+        assert!(written.contains(">>>>>>> profile"));
+    }
+
+    #[test]
+    fn added_and_removed_profile_files() {
+        let (_p, base) = setup_disk_profiles();
+        let team = hired_team(&base);
+        let member_dir = team.path().join("members").join(MEMBER);
+
+        fs::write(profile_file(&base, "knowledge/new-guide.md"), "# Guide\n").unwrap();
+        fs::remove_file(profile_file(&base, "ralph.yml")).unwrap();
+
+        let result = refresh(&base, team.path(), false);
+        assert_eq!(
+            action_for(&result, "knowledge/new-guide.md")
+                .unwrap()
+                .action,
+            RefreshAction::Added
+        );
+        assert_eq!(
+            action_for(&result, "ralph.yml").unwrap().action,
+            RefreshAction::Removed
+        );
+        assert!(member_dir.join("knowledge/new-guide.md").exists());
+        assert!(!member_dir.join("ralph.yml").exists());
+    }
+
+    #[test]
+    fn refuses_uncommitted_changes_unless_dry_run() {
+        let (_p, base) = setup_disk_profiles();
+        let team = hired_team(&base);
+        let member_dir = team.path().join("members").join(MEMBER);
+        fs::write(member_dir.join("PROMPT.md"), "work in progress\n").unwrap();
+
+        let err = refresh_member_from(
+            &base,
+            team.path(),
+            PROFILE,
+            MEMBER,
+            &claude_code_agent(),
+            false,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("uncommitted changes"));
+        assert!(refresh_member_from(
+            &base,
+            team.path(),
+            PROFILE,
+            MEMBER,
+            &claude_code_agent(),
+            true
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn falls_back_to_hire_commit_without_snapshot() {
+        let (_p, base) = setup_disk_profiles();
+        let team = hired_team(&base);
+        let member_dir = team.path().join("members").join(MEMBER);
+        fs::remove_dir_all(member_dir.join(SKELETON_DIR)).unwrap();
+        run_git(team.path(), &["commit", "-am", "drop snapshot"]).unwrap();
+
+        let prompt = profile_file(&base, "PROMPT.md");
+        let upstream = fs::read_to_string(&prompt).unwrap();
+        fs::write(&prompt, format!("{}\nUpstream footer.\n", upstream)).unwrap();
+
+        let result = refresh(&base, team.path(), false);
+        assert_eq!(result.base_source, BaseSource::HireCommit);
+        assert_eq!(
+            action_for(&result, "PROMPT.md").unwrap().action,
+            RefreshAction::Updated
+        );
+        assert!(member_dir.join(SKELETON_DIR).join("PROMPT.md").exists());
+    }
+
+    #[test]
+    fn legacy_member_can_refresh_twice() {
+        let (_p, base) = setup_disk_profiles();
+        let team = hired_team(&base);
+        let member_dir = team.path().join("members").join(MEMBER);
+        fs::remove_dir_all(member_dir.join(SKELETON_DIR)).unwrap();
+        run_git(team.path(), &["commit", "-am", "drop snapshot"]).unwrap();
+
+        let first = refresh(&base, team.path(), false);
+        assert_eq!(first.base_source, BaseSource::HireCommit);
+        assert!(first.files.is_empty(), "{:?}", first.files);
+        assert!(commit_refresh(team.path(), &[MEMBER.to_string()], PROFILE).unwrap());
+        assert!(!has_uncommitted_changes(team.path(), MEMBER).unwrap());
+
+        let second = refresh(&base, team.path(), false);
+        assert_eq!(second.base_source, BaseSource::Snapshot);
+        assert!(!commit_refresh(team.path(), &[MEMBER.to_string()], PROFILE).unwrap());
+    }
+
+    #[test]
+    fn conflict_hunks_extracts_marked_regions() {
+        let text = "a\n<<<<<<< local\nx\n=======\ny\n>>>>>>> profile\nb\n";
+        let hunks = conflict_hunks(text);
+        assert_eq!(hunks, vec!["<<<<<<< local\nx\n=======\ny\n>>>>>>> profile"]);
+    }
+}
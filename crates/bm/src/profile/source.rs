@@ -0,0 +1,698 @@
+//! Profile sources: profiles installed from git repositories.
+//!
+//! `bm profiles add <url>[@ref]` clones a repo into a cache next to the
+//! profiles directory, checks out the ref, and installs every profile it
+//! contains (a `botminter.yml` at the repo root, or one per subdirectory) into
+//! `profiles_dir()`. The registry (`profile-sources.yml`) records the URL,
+//! ref, commit and manifest version of each installed profile so
+//! `bm profiles update` can fetch and install newer versions later.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use super::export::walk_files;
+use super::{compare_versions, embedded, profiles_dir, ProfileManifest};
+use crate::config::TeamProfileSource;
+use crate::git::{git_stdout, run_git};
+
+/// Registry file, stored next to the profiles directory.
+pub const SOURCES_FILE: &str = "profile-sources.yml";
+
+/// Locations used by profile sources.
+#[derive(Debug, Clone)]
+pub struct SourcePaths {
+    /// Installed profiles (`~/.config/botminter/profiles`).
+    pub profiles: PathBuf,
+    /// Git clones of profile sources.
+    pub cache: PathBuf,
+    /// The registry file.
+    pub registry: PathBuf,
+}
+
+impl SourcePaths {
+    /// Paths under `~/.config/botminter/`.
+    pub fn resolve() -> Result<Self> {
+        let profiles = profiles_dir()?;
+        let root = profiles
+            .parent()
+            .context("Profiles directory has no parent")?
+            .to_path_buf();
+        Ok(Self::under(&root))
+    }
+
+    /// Paths under an arbitrary root (`<root>/profiles`, ...).
+    pub fn under(root: &Path) -> Self {
+        Self {
+            profiles: root.join("profiles"),
+            cache: root.join("profile-sources"),
+            registry: root.join(SOURCES_FILE),
+        }
+    }
+}
+
+/// All profiles installed from git sources.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct SourceRegistry {
+    #[serde(default)]
+    pub profiles: Vec<InstalledSource>,
+}
+
+/// A profile installed from a git source.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InstalledSource {
+    /// Installed profile name (from its `botminter.yml`).
+    pub profile: String,
+    /// Git URL or absolute local path.
+    pub url: String,
+    /// Branch, tag or commit requested (`None` = default branch).
+    #[serde(rename = "ref", default, skip_serializing_if = "Option::is_none")]
+    pub git_ref: Option<String>,
+    /// Subdirectory of the repo holding the profile (`None` = repo root).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    /// `version` from the installed profile's `botminter.yml`.
+    pub version: String,
+    /// Commit the installed profile was taken from.
+    pub commit: String,
+}
+
+impl InstalledSource {
+    /// The record stored on a team built from this profile.
+    pub fn team_record(&self) -> TeamProfileSource {
+        TeamProfileSource {
+            url: self.url.clone(),
+            git_ref: self.git_ref.clone(),
+            version: self.version.clone(),
+            commit: self.commit.clone(),
+        }
+    }
+
+    /// `url` or `url@ref`.
+    pub fn spec(&self) -> String {
+        match &self.git_ref {
+            Some(r) => format!("{}@{}", self.url, r),
+            None => self.url.clone(),
+        }
+    }
+}
+
+/// Outcome of checking one installed profile for updates.
+#[derive(Debug)]
+pub struct SourceUpdate {
+    pub source: InstalledSource,
+    pub to_version: String,
+    pub to_commit: String,
+    /// The ref tracks a branch (or the default branch), so it can move.
+    pub tracks_branch: bool,
+    /// Whether the new version was installed (false for `--check`).
+    pub applied: bool,
+    /// `(old, new)` when the profile's schema version changed.
+    pub schema_change: Option<(String, String)>,
+    /// Version tags newer than the installed version (for pinned refs).
+    pub newer_tags: Vec<String>,
+}
+
+impl SourceUpdate {
+    pub fn changed(&self) -> bool {
+        self.source.commit != self.to_commit
+    }
+}
+
+/// Outcome of `update_sources`: one update per profile that could be checked,
+/// and the profiles that failed (fetch, checkout or install errors).
+#[derive(Debug, Default)]
+pub struct SourceUpdates {
+    pub updates: Vec<SourceUpdate>,
+    pub failed: Vec<(String, String)>,
+}
+
+/// Loads the registry, returning an empty one if the file doesn't exist.
+pub fn load_registry(path: &Path) -> Result<SourceRegistry> {
+    if !path.exists() {
+        return Ok(SourceRegistry::default());
+    }
+    let contents =
+        fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    serde_yml::from_str(&contents).with_context(|| format!("Failed to parse {}", path.display()))
+}
+
+fn save_registry(path: &Path, registry: &SourceRegistry) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, serde_yml::to_string(registry)?)
+        .with_context(|| format!("Failed to write {}", path.display()))
+}
+
+/// Returns the source a profile was installed from, if any.
+pub fn installed_source(profile: &str) -> Option<InstalledSource> {
+    let paths = SourcePaths::resolve().ok()?;
+    load_registry(&paths.registry)
+        .ok()?
+        .profiles
+        .into_iter()
+        .find(|s| s.profile == profile)
+}
+
+/// Splits `<url>[@ref]`. An `@` only starts a ref when it follows the
+/// repository path, so `git@host:org/repo` and `https://user@host/repo`
+/// are left intact.
+pub fn parse_source_spec(spec: &str) -> (String, Option<String>) {
+    if let Some(at) = spec.rfind('@') {
+        let (url, git_ref) = (&spec[..at], &spec[at + 1..]);
+        let path_part = url.split_once("://").map(|(_, rest)| rest).unwrap_or(url);
+        let has_path = path_part.contains('/') || (!url.contains("://") && url.contains(':'));
+        if has_path && !git_ref.is_empty() && !git_ref.contains(':') {
+            return (url.to_string(), Some(git_ref.to_string()));
+        }
+    }
+    (spec.to_string(), None)
+}
+
+/// Registers a profile source and installs the profiles it contains.
+///
+/// Refuses to shadow an embedded profile, and to replace a profile installed
+/// by hand or from another source unless `force`.
+pub fn add_source(paths: &SourcePaths, spec: &str, force: bool) -> Result<Vec<InstalledSource>> {
+    let (url, git_ref) = parse_source_spec(spec);
+    let url = normalize_url(&url);
+
+    let cache = fetch(paths, &url)?;
+    let (commit, _) = resolve_ref(&cache, git_ref.as_deref())?;
+    checkout(&cache, &commit)?;
+
+    let found = discover_profiles(&cache)?;
+    if found.is_empty() {
+        bail!(
+            "No profile found in {}: expected botminter.yml at the repo root or in its subdirectories",
+            url
+        );
+    }
+
+    let mut registry = load_registry(&paths.registry)?;
+    let embedded = embedded::list_embedded_profiles();
+    for (_, manifest) in &found {
+        if embedded.contains(&manifest.name) {
+            bail!(
+                "Profile '{}' from {} has the same name as a built-in profile. Rename it in its botminter.yml.",
+                manifest.name,
+                url
+            );
+        }
+        let existing = registry
+            .profiles
+            .iter()
+            .find(|s| s.profile == manifest.name);
+        let installed = paths.profiles.join(&manifest.name).exists();
+        match existing {
+            Some(s) if s.url != url && !force => bail!(
+                "Profile '{}' is already installed from {}. Use --force to replace it.",
+                manifest.name,
+                s.url
+            ),
+            None if installed && !force => bail!(
+                "Profile '{}' already exists in {}. Use --force to replace it.",
+                manifest.name,
+                paths.profiles.display()
+            ),
+            _ => {}
+        }
+    }
+
+    let mut added = Vec::new();
+    for (subdir, manifest) in found {
+        let src = match &subdir {
+            Some(d) => cache.join(d),
+            None => cache.clone(),
+        };
+        install(&src, &paths.profiles, &manifest.name)?;
+        let entry = InstalledSource {
+            profile: manifest.name.clone(),
+            url: url.clone(),
+            git_ref: git_ref.clone(),
+            path: subdir,
+            version: manifest.version.clone(),
+            commit: commit.clone(),
+        };
+        registry.profiles.retain(|s| s.profile != entry.profile);
+        registry.profiles.push(entry.clone());
+        added.push(entry);
+    }
+    registry.profiles.sort_by(|a, b| a.profile.cmp(&b.profile));
+    save_registry(&paths.registry, &registry)?;
+    Ok(added)
+}
+
+/// Fetches each installed source and, unless `check_only`, installs newer
+/// versions. `only` limits the update to one profile.
+///
+/// A failing profile doesn't stop the others: errors are collected in
+/// `SourceUpdates::failed` and the registry still records every update that
+/// was installed.
+pub fn update_sources(
+    paths: &SourcePaths,
+    only: Option<&str>,
+    check_only: bool,
+) -> Result<SourceUpdates> {
+    let mut registry = load_registry(&paths.registry)?;
+    if let Some(name) = only {
+        if !registry.profiles.iter().any(|s| s.profile == name) {
+            bail!(
+                "Profile '{}' was not installed from a git source. Run `bm profiles add <url>` first.",
+                name
+            );
+        }
+    }
+
+    // Each URL is fetched once; profiles sharing it share the outcome.
+    let mut fetched: HashMap<String, std::result::Result<PathBuf, String>> = HashMap::new();
+    let mut result = SourceUpdates::default();
+    for entry in registry.profiles.iter_mut() {
+        if only.is_some_and(|n| n != entry.profile) {
+            continue;
+        }
+        let cache = fetched
+            .entry(entry.url.clone())
+            .or_insert_with(|| fetch(paths, &entry.url).map_err(|e| format!("{:#}", e)))
+            .clone();
+        let outcome = match cache {
+            Ok(cache) => update_source(paths, entry, &cache, check_only),
+            Err(e) => Err(anyhow::anyhow!(e)),
+        };
+        match outcome {
+            Ok(update) => result.updates.push(update),
+            Err(e) => result.failed.push((entry.profile.clone(), format!("{:#}", e))),
+        }
+    }
+
+    if result.updates.iter().any(|u| u.applied) {
+        save_registry(&paths.registry, &registry)?;
+    }
+    Ok(result)
+}
+
+/// Checks one installed profile against its fetched cache and, unless
+/// `check_only`, installs the new version and updates `entry`.
+fn update_source(
+    paths: &SourcePaths,
+    entry: &mut InstalledSource,
+    cache: &Path,
+    check_only: bool,
+) -> Result<SourceUpdate> {
+    let (to_commit, tracks_branch) = resolve_ref(cache, entry.git_ref.as_deref())?;
+    let newer_tags = newer_version_tags(cache, &entry.version)?;
+    let mut update = SourceUpdate {
+        source: entry.clone(),
+        to_version: entry.version.clone(),
+        to_commit: to_commit.clone(),
+        tracks_branch,
+        applied: false,
+        schema_change: None,
+        newer_tags,
+    };
+    if !update.changed() {
+        return Ok(update);
+    }
+
+    checkout(cache, &to_commit)?;
+    let src = match &entry.path {
+        Some(d) => cache.join(d),
+        None => cache.to_path_buf(),
+    };
+    let manifest = read_manifest_at(&src)?;
+    if manifest.name != entry.profile {
+        bail!(
+            "Profile in {} is now named '{}' (was '{}'). Re-add it with `bm profiles add`.",
+            entry.spec(),
+            manifest.name,
+            entry.profile
+        );
+    }
+    let old_schema = read_manifest_at(&paths.profiles.join(&entry.profile))
+        .map(|m| m.schema_version)
+        .unwrap_or_default();
+    if old_schema != manifest.schema_version {
+        update.schema_change = Some((old_schema, manifest.schema_version.clone()));
+    }
+    update.to_version = manifest.version.clone();
+
+    if !check_only {
+        install(&src, &paths.profiles, &entry.profile)?;
+        entry.commit = to_commit;
+        entry.version = manifest.version;
+        update.applied = true;
+    }
+    Ok(update)
+}
+
+/// Local paths are stored absolute so the source works from any directory.
+fn normalize_url(url: &str) -> String {
+    let path = Path::new(url);
+    if !url.contains("://") && path.exists() {
+        if let Ok(abs) = path.canonicalize() {
+            return abs.to_string_lossy().to_string();
+        }
+    }
+    url.to_string()
+}
+
+fn cache_dir(paths: &SourcePaths, url: &str) -> PathBuf {
+    let repo = url
+        .trim_end_matches('/')
+        .trim_end_matches(".git")
+        .rsplit(['/', ':'])
+        .next()
+        .unwrap_or("source")
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect::<String>();
+    let digest = hex::encode(Sha256::digest(url.as_bytes()));
+    paths.cache.join(format!("{}-{}", repo, &digest[..12]))
+}
+
+/// Clones the source into the cache, or fetches if already cloned.
+fn fetch(paths: &SourcePaths, url: &str) -> Result<PathBuf> {
+    let dir = cache_dir(paths, url);
+    if dir.join(".git").is_dir() {
+        run_git(
+            &dir,
+            &["fetch", "--quiet", "--tags", "--force", "--prune", "origin"],
+        )
+        .with_context(|| format!("Failed to fetch profile source {}", url))?;
+        // Follow default branch renames; failure leaves the old origin/HEAD.
+        let _ = run_git(&dir, &["remote", "set-head", "origin", "--auto"]);
+    } else {
+        fs::create_dir_all(&paths.cache)
+            .with_context(|| format!("Failed to create {}", paths.cache.display()))?;
+        let target = dir.to_string_lossy().to_string();
+        run_git(&paths.cache, &["clone", "--quiet", url, &target])
+            .with_context(|| format!("Failed to clone profile source {}", url))?;
+    }
+    Ok(dir)
+}
+
+/// Resolves a ref to a commit. Returns whether the ref tracks a branch.
+///
+/// Tries, in order: the default branch (no ref), a remote branch, a tag,
+/// a `v`-prefixed tag (so `@1.2.0` finds `v1.2.0`), and a commit.
+fn resolve_ref(repo: &Path, git_ref: Option<&str>) -> Result<(String, bool)> {
+    let rev = |spec: &str| git_stdout(repo, &["rev-parse", "--verify", "--quiet", spec]).ok();
+    let Some(r) = git_ref else {
+        return rev("refs/remotes/origin/HEAD^{commit}")
+            .map(|c| (c, true))
+            .context("Profile source has no default branch");
+    };
+    if let Some(c) = rev(&format!("refs/remotes/origin/{}^{{commit}}", r)) {
+        return Ok((c, true));
+    }
+    for candidate in [
+        format!("refs/tags/{}", r),
+        format!("refs/tags/v{}", r),
+        r.to_string(),
+    ] {
+        if let Some(c) = rev(&format!("{}^{{commit}}", candidate)) {
+            return Ok((c, false));
+        }
+    }
+    bail!("Ref '{}' not found in profile source", r)
+}
+
+fn checkout(repo: &Path, commit: &str) -> Result<()> {
+    run_git(
+        repo,
+        &["checkout", "--quiet", "--force", "--detach", commit],
+    )
+}
+
+/// Tags that look like versions newer than `current`, oldest first.
+fn newer_version_tags(repo: &Path, current: &str) -> Result<Vec<String>> {
+    let tags = git_stdout(repo, &["tag", "--list"])?;
+    let mut newer: Vec<String> = tags
+        .lines()
+        .filter(|t| {
+            let v = t.strip_prefix('v').unwrap_or(t);
+            v.split('.').count() == 3
+                && v.split('.').all(|p| p.parse::<u64>().is_ok())
+                && compare_versions(v, current) == std::cmp::Ordering::Greater
+        })
+        .map(String::from)
+        .collect();
+    newer.sort_by(|a, b| compare_versions(a.trim_start_matches('v'), b.trim_start_matches('v')));
+    Ok(newer)
+}
+
+/// Finds profiles in a checkout: the root, or each subdirectory with a
+/// `botminter.yml`.
+fn discover_profiles(repo: &Path) -> Result<Vec<(Option<String>, ProfileManifest)>> {
+    if repo.join("botminter.yml").exists() {
+        return Ok(vec![(None, read_manifest_at(repo)?)]);
+    }
+    let mut found = Vec::new();
+    for dir in super::list_subdirs(repo) {
+        if repo.join(&dir).join("botminter.yml").exists() {
+            let manifest = read_manifest_at(&repo.join(&dir))?;
+            found.push((Some(dir), manifest));
+        }
+    }
+    Ok(found)
+}
+
+fn read_manifest_at(dir: &Path) -> Result<ProfileManifest> {
+    let path = dir.join("botminter.yml");
+    let contents =
+        fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()))?;
+    let manifest: ProfileManifest = serde_yml::from_str(&contents)
+        .with_context(|| format!("Failed to parse {}", path.display()))?;
+    if manifest.schema_version.is_empty() {
+        bail!("{} has no schema_version", path.display());
+    }
+    Ok(manifest)
+}
+
+/// Copies a profile (minus `.git`) into `profiles/<name>`, replacing any
+/// previous install.
+fn install(src: &Path, profiles: &Path, name: &str) -> Result<()> {
+    let staging = profiles.join(format!(".{}.installing", name));
+    if staging.exists() {
+        fs::remove_dir_all(&staging)?;
+    }
+    for rel in walk_files(src, &|rel| rel == Path::new(".git"))? {
+        let dest = staging.join(&rel);
+        if let Some(parent) = dest.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::copy(src.join(&rel), &dest)
+            .with_context(|| format!("Failed to copy {}", src.join(&rel).display()))?;
+    }
+
+    let target = profiles.join(name);
+    if target.exists() {
+        fs::remove_dir_all(&target)
+            .with_context(|| format!("Failed to remove old {}", target.display()))?;
+    }
+    fs::rename(&staging, &target)
+        .with_context(|| format!("Failed to install profile to {}", target.display()))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::profile::test_support::setup_disk_profiles;
+
+    /// A git repo whose root is a copy of the scrum profile renamed `platform`.
+    fn source_repo(version: &str) -> tempfile::TempDir {
+        let (_p, base) = setup_disk_profiles();
+        let tmp = tempfile::tempdir().unwrap();
+        let repo = tmp.path();
+        for rel in walk_files(&base.join("scrum"), &|_| false).unwrap() {
+            let dest = repo.join(&rel);
+            fs::create_dir_all(dest.parent().unwrap()).unwrap();
+            fs::copy(base.join("scrum").join(&rel), dest).unwrap();
+        }
+        run_git(repo, &["init", "-q", "-b", "main"]).unwrap();
+        run_git(repo, &["config", "user.email", "test@example.com"]).unwrap();
+        run_git(repo, &["config", "user.name", "Test"]).unwrap();
+        commit_version(repo, version);
+        tmp
+    }
+
+    fn commit_version(repo: &Path, version: &str) {
+        let manifest = repo.join("botminter.yml");
+        let contents = fs::read_to_string(&manifest).unwrap();
+        let mut value: serde_yml::Value = serde_yml::from_str(&contents).unwrap();
+        let map = value.as_mapping_mut().unwrap();
+        map.insert("name".into(), "platform".into());
+        map.insert("version".into(), version.into());
+        fs::write(&manifest, serde_yml::to_string(&value).unwrap()).unwrap();
+        run_git(repo, &["add", "-A"]).unwrap();
+        run_git(
+            repo,
+            &["commit", "-q", "-m", &format!("release {}", version)],
+        )
+        .unwrap();
+        run_git(repo, &["tag", &format!("v{}", version)]).unwrap();
+    }
+
+    fn installed_version(paths: &SourcePaths) -> String {
+        read_manifest_at(&paths.profiles.join("platform"))
+            .unwrap()
+            .version
+    }
+
+    #[test]
+    fn parse_source_spec_splits_refs() {
+        assert_eq!(
+            parse_source_spec("https://github.com/org/profiles@v1.2.0"),
+            (
+                "https://github.com/org/profiles".to_string(),
+                Some("v1.2.0".to_string())
+            )
+        );
+        assert_eq!(
+            parse_source_spec("git@github.com:org/profiles.git"),
+            ("git@github.com:org/profiles.git".to_string(), None)
+        );
+        assert_eq!(
+            parse_source_spec("git@github.com:org/profiles.git@main"),
+            (
+                "git@github.com:org/profiles.git".to_string(),
+                Some("main".to_string())
+            )
+        );
+        assert_eq!(
+            parse_source_spec("https://user@host.example/repo"),
+            ("https://user@host.example/repo".to_string(), None)
+        );
+        assert_eq!(
+            parse_source_spec("/srv/profiles@release/2"),
+            ("/srv/profiles".to_string(), Some("release/2".to_string()))
+        );
+    }
+
+    #[test]
+    fn add_installs_from_local_path_and_records_source() {
+        let repo = source_repo("1.0.0");
+        let root = tempfile::tempdir().unwrap();
+        let paths = SourcePaths::under(root.path());
+
+        let added = add_source(&paths, &repo.path().to_string_lossy(), false).unwrap();
+        assert_eq!(added.len(), 1);
+        assert_eq!(added[0].profile, "platform");
+        assert_eq!(added[0].version, "1.0.0");
+        assert!(paths.profiles.join("platform/roles/architect").is_dir());
+        assert!(!paths.profiles.join("platform/.git").exists());
+
+        let registry = load_registry(&paths.registry).unwrap();
+        assert_eq!(registry.profiles, added);
+        assert_eq!(added[0].team_record().version, "1.0.0");
+    }
+
+    #[test]
+    fn add_pins_to_version_tag() {
+        let repo = source_repo("1.0.0");
+        commit_version(repo.path(), "1.1.0");
+        let root = tempfile::tempdir().unwrap();
+        let paths = SourcePaths::under(root.path());
+
+        let spec = format!("{}@1.0.0", repo.path().display());
+        add_source(&paths, &spec, false).unwrap();
+        assert_eq!(installed_version(&paths), "1.0.0");
+
+        // Pinned: update reports the newer tag but stays put
+        let updates = update_sources(&paths, None, false).unwrap().updates;
+        assert!(!updates[0].changed());
+        assert!(!updates[0].tracks_branch);
+        assert_eq!(updates[0].newer_tags, vec!["v1.1.0"]);
+        assert_eq!(installed_version(&paths), "1.0.0");
+    }
+
+    #[test]
+    fn update_follows_branch_and_check_only_installs_nothing() {
+        let repo = source_repo("1.0.0");
+        let root = tempfile::tempdir().unwrap();
+        let paths = SourcePaths::under(root.path());
+        add_source(&paths, &repo.path().to_string_lossy(), false).unwrap();
+
+        commit_version(repo.path(), "1.1.0");
+
+        let checked = update_sources(&paths, Some("platform"), true).unwrap().updates;
+        assert!(checked[0].changed());
+        assert!(!checked[0].applied);
+        assert_eq!(checked[0].to_version, "1.1.0");
+        assert_eq!(installed_version(&paths), "1.0.0");
+
+        let applied = update_sources(&paths, None, false).unwrap().updates;
+        assert!(applied[0].applied);
+        assert_eq!(installed_version(&paths), "1.1.0");
+        let registry = load_registry(&paths.registry).unwrap();
+        assert_eq!(registry.profiles[0].version, "1.1.0");
+
+        let again = update_sources(&paths, None, false).unwrap().updates;
+        assert!(!again[0].changed());
+    }
+
+    #[test]
+    fn update_continues_past_failing_source() {
+        let repo = source_repo("1.0.0");
+        let root = tempfile::tempdir().unwrap();
+        let paths = SourcePaths::under(root.path());
+        add_source(&paths, &repo.path().to_string_lossy(), false).unwrap();
+
+        let mut registry = load_registry(&paths.registry).unwrap();
+        registry.profiles.insert(
+            0,
+            InstalledSource {
+                profile: "ghost".into(),
+                url: root.path().join("missing-repo").to_string_lossy().to_string(),
+                git_ref: None,
+                path: None,
+                version: "1.0.0".into(),
+                commit: "0000000".into(),
+            },
+        );
+        save_registry(&paths.registry, &registry).unwrap();
+        commit_version(repo.path(), "1.1.0");
+
+        let result = update_sources(&paths, None, false).unwrap();
+        assert_eq!(result.failed.len(), 1);
+        assert_eq!(result.failed[0].0, "ghost");
+        assert_eq!(result.updates.len(), 1);
+        assert!(result.updates[0].applied);
+
+        let registry = load_registry(&paths.registry).unwrap();
+        let platform = registry.profiles.iter().find(|s| s.profile == "platform").unwrap();
+        assert_eq!(platform.version, "1.1.0");
+    }
+
+    #[test]
+    fn add_refuses_to_replace_unmanaged_profile() {
+        let repo = source_repo("1.0.0");
+        let root = tempfile::tempdir().unwrap();
+        let paths = SourcePaths::under(root.path());
+        fs::create_dir_all(paths.profiles.join("platform")).unwrap();
+
+        let err = add_source(&paths, &repo.path().to_string_lossy(), false).unwrap_err();
+        assert!(err.to_string().contains("--force"));
+        add_source(&paths, &repo.path().to_string_lossy(), true).unwrap();
+        assert!(paths.profiles.join("platform/botminter.yml").exists());
+    }
+
+    #[test]
+    fn update_unknown_profile_errors() {
+        let root = tempfile::tempdir().unwrap();
+        let paths = SourcePaths::under(root.path());
+        assert!(update_sources(&paths, Some("scrum"), false).is_err());
+    }
+}
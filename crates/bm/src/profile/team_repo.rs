@@ -186,6 +186,34 @@ pub fn validate_knowledge_path(path: &str) -> Result<()> {
     Ok(())
 }
 
+/// Reads a knowledge or invariant file from the team repo.
+///
+/// Validates the path with [`validate_knowledge_path`] and refuses paths that
+/// resolve (e.g. via symlinks) outside the team repo.
+pub fn read_knowledge_file(team_repo: &Path, path: &str) -> Result<String> {
+    validate_knowledge_path(path)?;
+
+    let file_path = team_repo.join(path);
+
+    let canonical_repo = team_repo
+        .canonicalize()
+        .context("Failed to resolve team repo path")?;
+    if file_path.exists() {
+        let canonical_file = file_path
+            .canonicalize()
+            .context("Failed to resolve file path")?;
+        if !canonical_file.starts_with(&canonical_repo) {
+            bail!("Path resolves outside the team repo");
+        }
+    }
+
+    if !file_path.exists() {
+        bail!("File not found: {}", path);
+    }
+
+    fs::read_to_string(&file_path).with_context(|| format!("Failed to read {}", path))
+}
+
 /// Builds env vars from team credentials for interactive sessions.
 pub fn credentials_env(_team: &crate::config::TeamEntry) -> Vec<(String, String)> {
     // GH_TOKEN is no longer stored in config — members use GH_CONFIG_DIR
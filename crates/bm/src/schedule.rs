@@ -0,0 +1,746 @@
+//! Member schedules: working hours, quiet hours, and cron-style triggers.
+//!
+//! Schedules live in `schedule.yml` at the root of the team repo. The daemon
+//! evaluates them once a minute: it stops non-brain members outside their
+//! working hours and starts them again when the window opens, holds back
+//! event-driven launches during quiet hours, and injects trigger prompts
+//! into member workspaces. Brain members read their own schedule to pace
+//! (and silence) the heartbeat.
+//!
+//! ```yaml
+//! timezone: Europe/Berlin
+//! default:
+//!   working_hours: { days: mon-fri, start: "09:00", end: "18:00" }
+//!   quiet_hours:
+//!     - { start: "22:00", end: "07:00" }
+//! roles:
+//!   chief-of-staff:
+//!     heartbeat_secs: 300
+//! members:
+//!   chief-of-staff-mgr:
+//!     triggers:
+//!       - name: standup
+//!         cron: "0 9 * * mon-fri"
+//!         prompt: Post a standup summary of yesterday's board activity.
+//! ```
+//!
+//! Layers merge `default` → `roles.<role>` → `members.<member>`: scalar
+//! fields and `quiet_hours` are replaced by the more specific layer, while
+//! `triggers` accumulate (a trigger with the same name replaces the earlier
+//! one).
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use anyhow::{anyhow, bail, Context, Result};
+use chrono::{DateTime, Datelike, Duration, NaiveDateTime, Timelike, Utc};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+
+/// File name of the schedule config in the team repo.
+pub const SCHEDULE_FILE: &str = "schedule.yml";
+
+/// Top-level `schedule.yml`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ScheduleConfig {
+    /// IANA timezone for all windows and cron expressions (default: UTC).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    #[serde(default)]
+    pub default: MemberSchedule,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub roles: HashMap<String, MemberSchedule>,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub members: HashMap<String, MemberSchedule>,
+}
+
+/// One schedule layer (default, role, or member).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MemberSchedule {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    /// Outside this window non-brain members are stopped.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub working_hours: Option<TimeWindow>,
+    /// No triggers, heartbeats, or event-driven launches inside these windows.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quiet_hours: Option<Vec<TimeWindow>>,
+    /// Brain heartbeat interval in seconds (0 disables it).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub heartbeat_secs: Option<u64>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub triggers: Vec<Trigger>,
+}
+
+/// A daily time window, optionally restricted to certain days.
+///
+/// `end` before `start` spans midnight; the window belongs to the day it
+/// starts on. `start == end` covers the whole day.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TimeWindow {
+    /// Day spec such as `mon-fri` or `sat,sun`. Omitted means every day.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub days: Option<String>,
+    /// `HH:MM`, 24-hour clock.
+    pub start: String,
+    /// `HH:MM`, 24-hour clock.
+    pub end: String,
+}
+
+/// A cron-style trigger that injects a prompt into the member.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Trigger {
+    pub name: String,
+    /// Five-field cron expression (`min hour dom month dow`) or `@hourly`,
+    /// `@daily`, `@weekly`.
+    pub cron: String,
+    pub prompt: String,
+}
+
+/// Loads `schedule.yml` from the team repo. Returns `None` when absent.
+pub fn load(team_repo: &Path) -> Result<Option<ScheduleConfig>> {
+    let path = team_repo.join(SCHEDULE_FILE);
+    if !path.exists() {
+        return Ok(None);
+    }
+    let contents =
+        fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()))?;
+    let cfg: ScheduleConfig = serde_yml::from_str(&contents)
+        .with_context(|| format!("Failed to parse {}", path.display()))?;
+    cfg.validate()
+        .with_context(|| format!("Invalid {}", path.display()))?;
+    Ok(Some(cfg))
+}
+
+impl ScheduleConfig {
+    /// Checks every layer: timezones, windows, and cron expressions.
+    pub fn validate(&self) -> Result<()> {
+        if let Some(ref tz) = self.timezone {
+            parse_tz(tz)?;
+        }
+        self.default.validate().context("default")?;
+        for (role, layer) in &self.roles {
+            layer
+                .validate()
+                .with_context(|| format!("roles.{}", role))?;
+        }
+        for (member, layer) in &self.members {
+            layer
+                .validate()
+                .with_context(|| format!("members.{}", member))?;
+        }
+        Ok(())
+    }
+
+    /// Resolves the effective schedule for a member. Returns `None` when no
+    /// layer sets anything for them.
+    pub fn resolve(&self, role: &str, member: &str) -> Result<Option<ResolvedSchedule>> {
+        let mut merged = self.default.clone();
+        if let Some(layer) = self.roles.get(role) {
+            merged = merged.merged(layer);
+        }
+        if let Some(layer) = self.members.get(member) {
+            merged = merged.merged(layer);
+        }
+        if merged.is_empty() {
+            return Ok(None);
+        }
+
+        let tz_name = merged.timezone.as_ref().or(self.timezone.as_ref());
+        let tz = match tz_name {
+            Some(name) => parse_tz(name)?,
+            None => Tz::UTC,
+        };
+        let working_hours = merged
+            .working_hours
+            .as_ref()
+            .map(Window::parse)
+            .transpose()?;
+        let quiet_hours = merged
+            .quiet_hours
+            .iter()
+            .flatten()
+            .map(Window::parse)
+            .collect::<Result<Vec<_>>>()?;
+        let triggers = merged
+            .triggers
+            .iter()
+            .map(|t| {
+                Ok(ResolvedTrigger {
+                    name: t.name.clone(),
+                    cron: CronExpr::parse(&t.cron)?,
+                    prompt: t.prompt.clone(),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Some(ResolvedSchedule {
+            tz,
+            working_hours,
+            quiet_hours,
+            heartbeat_secs: merged.heartbeat_secs,
+            triggers,
+        }))
+    }
+}
+
+impl MemberSchedule {
+    fn validate(&self) -> Result<()> {
+        if let Some(ref tz) = self.timezone {
+            parse_tz(tz)?;
+        }
+        if let Some(ref w) = self.working_hours {
+            Window::parse(w).context("working_hours")?;
+        }
+        for w in self.quiet_hours.iter().flatten() {
+            Window::parse(w).context("quiet_hours")?;
+        }
+        for t in &self.triggers {
+            if t.name.trim().is_empty() {
+                bail!("trigger name must not be empty");
+            }
+            if t.prompt.trim().is_empty() {
+                bail!("trigger '{}' has an empty prompt", t.name);
+            }
+            CronExpr::parse(&t.cron).with_context(|| format!("trigger '{}'", t.name))?;
+        }
+        Ok(())
+    }
+
+    /// Overlays `other` on top of `self`.
+    fn merged(&self, other: &MemberSchedule) -> MemberSchedule {
+        let mut triggers = self.triggers.clone();
+        for t in &other.triggers {
+            triggers.retain(|existing| existing.name != t.name);
+            triggers.push(t.clone());
+        }
+        MemberSchedule {
+            timezone: other.timezone.clone().or_else(|| self.timezone.clone()),
+            working_hours: other
+                .working_hours
+                .clone()
+                .or_else(|| self.working_hours.clone()),
+            quiet_hours: other
+                .quiet_hours
+                .clone()
+                .or_else(|| self.quiet_hours.clone()),
+            heartbeat_secs: other.heartbeat_secs.or(self.heartbeat_secs),
+            triggers,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.working_hours.is_none()
+            && self.quiet_hours.iter().flatten().next().is_none()
+            && self.heartbeat_secs.is_none()
+            && self.triggers.is_empty()
+    }
+}
+
+fn parse_tz(name: &str) -> Result<Tz> {
+    name.parse::<Tz>()
+        .map_err(|e| anyhow!("unknown timezone '{}': {}", name, e))
+}
+
+// ---------------------------------------------------------------------------
+// Resolved schedule
+// ---------------------------------------------------------------------------
+
+/// A member's effective schedule, ready to evaluate.
+#[derive(Debug, Clone)]
+pub struct ResolvedSchedule {
+    pub tz: Tz,
+    pub working_hours: Option<Window>,
+    pub quiet_hours: Vec<Window>,
+    pub heartbeat_secs: Option<u64>,
+    pub triggers: Vec<ResolvedTrigger>,
+}
+
+/// A trigger with its cron expression parsed.
+#[derive(Debug, Clone)]
+pub struct ResolvedTrigger {
+    pub name: String,
+    pub cron: CronExpr,
+    pub prompt: String,
+}
+
+impl ResolvedSchedule {
+    fn local(&self, now: DateTime<Utc>) -> NaiveDateTime {
+        now.with_timezone(&self.tz).naive_local()
+    }
+
+    /// True inside working hours, or always when no working hours are set.
+    pub fn on_duty(&self, now: DateTime<Utc>) -> bool {
+        match self.working_hours {
+            Some(ref w) => w.contains(self.local(now)),
+            None => true,
+        }
+    }
+
+    /// True inside any quiet-hours window.
+    pub fn is_quiet(&self, now: DateTime<Utc>) -> bool {
+        let local = self.local(now);
+        self.quiet_hours.iter().any(|w| w.contains(local))
+    }
+
+    /// Triggers whose cron expression matches the minute containing `now`.
+    pub fn due_triggers(&self, now: DateTime<Utc>) -> Vec<&ResolvedTrigger> {
+        let local = self.local(now);
+        self.triggers
+            .iter()
+            .filter(|t| t.cron.matches(local))
+            .collect()
+    }
+}
+
+/// Minutes strictly after `from` up to and including `to`, truncated to the
+/// minute. Used to catch up on triggers when a scheduler tick runs late.
+pub fn minutes_between(from: DateTime<Utc>, to: DateTime<Utc>) -> Vec<DateTime<Utc>> {
+    let from = truncate_minute(from);
+    let to = truncate_minute(to);
+    let mut out = Vec::new();
+    let mut t = from + Duration::minutes(1);
+    while t <= to {
+        out.push(t);
+        t += Duration::minutes(1);
+    }
+    out
+}
+
+/// Truncates a timestamp to the start of its minute.
+pub fn truncate_minute(t: DateTime<Utc>) -> DateTime<Utc> {
+    t.with_second(0)
+        .and_then(|t| t.with_nanosecond(0))
+        .unwrap_or(t)
+}
+
+// ---------------------------------------------------------------------------
+// Time windows
+// ---------------------------------------------------------------------------
+
+/// A parsed `TimeWindow`. Minutes are counted from local midnight.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Window {
+    /// Indexed by days from Monday.
+    days: [bool; 7],
+    start: u32,
+    end: u32,
+}
+
+impl Window {
+    fn parse(w: &TimeWindow) -> Result<Window> {
+        let days = match w.days {
+            Some(ref spec) => parse_days(spec)?,
+            None => [true; 7],
+        };
+        Ok(Window {
+            days,
+            start: parse_hhmm(&w.start)?,
+            end: parse_hhmm(&w.end)?,
+        })
+    }
+
+    fn contains(&self, local: NaiveDateTime) -> bool {
+        let minute = local.hour() * 60 + local.minute();
+        let today = local.weekday().num_days_from_monday() as usize;
+        let yesterday = (today + 6) % 7;
+        if self.start == self.end {
+            self.days[today]
+        } else if self.start < self.end {
+            self.days[today] && minute >= self.start && minute < self.end
+        } else {
+            (self.days[today] && minute >= self.start)
+                || (self.days[yesterday] && minute < self.end)
+        }
+    }
+}
+
+const DAY_NAMES: [&str; 7] = ["mon", "tue", "wed", "thu", "fri", "sat", "sun"];
+
+/// Parses `mon-fri`, `sat,sun`, `daily`, or `weekdays`/`weekends`.
+fn parse_days(spec: &str) -> Result<[bool; 7]> {
+    let spec = spec.trim().to_ascii_lowercase();
+    let mut days = [false; 7];
+    match spec.as_str() {
+        "daily" | "*" => return Ok([true; 7]),
+        "weekdays" => return Ok([true, true, true, true, true, false, false]),
+        "weekends" => return Ok([false, false, false, false, false, true, true]),
+        _ => {}
+    }
+    for part in spec.split(',') {
+        let part = part.trim();
+        let day = |name: &str| {
+            DAY_NAMES
+                .iter()
+                .position(|d| name.starts_with(d))
+                .ok_or_else(|| anyhow!("unknown day '{}' (use mon, tue, ... sun)", name))
+        };
+        match part.split_once('-') {
+            Some((a, b)) => {
+                let (a, b) = (day(a.trim())?, day(b.trim())?);
+                let mut i = a;
+                loop {
+                    days[i] = true;
+                    if i == b {
+                        break;
+                    }
+                    i = (i + 1) % 7;
+                }
+            }
+            None => days[day(part)?] = true,
+        }
+    }
+    Ok(days)
+}
+
+fn parse_hhmm(s: &str) -> Result<u32> {
+    let (h, m) = s
+        .trim()
+        .split_once(':')
+        .ok_or_else(|| anyhow!("invalid time '{}' (expected HH:MM)", s))?;
+    let h: u32 = h.parse().map_err(|_| anyhow!("invalid hour in '{}'", s))?;
+    let m: u32 = m
+        .parse()
+        .map_err(|_| anyhow!("invalid minute in '{}'", s))?;
+    // 24:00 is allowed as an end-of-day marker.
+    if h > 24 || m > 59 || (h == 24 && m != 0) {
+        bail!("time '{}' out of range", s);
+    }
+    Ok((h * 60 + m) % (24 * 60))
+}
+
+// ---------------------------------------------------------------------------
+// Cron expressions
+// ---------------------------------------------------------------------------
+
+/// A parsed five-field cron expression, evaluated in the member's timezone.
+///
+/// Follows Vixie cron semantics: when both day-of-month and day-of-week are
+/// restricted, a day matches if either does.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CronExpr {
+    minutes: u64,
+    hours: u32,
+    days_of_month: u32,
+    months: u16,
+    days_of_week: u8,
+    dom_any: bool,
+    dow_any: bool,
+}
+
+const MONTH_NAMES: [&str; 12] = [
+    "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+];
+const CRON_DAY_NAMES: [&str; 7] = ["sun", "mon", "tue", "wed", "thu", "fri", "sat"];
+
+impl CronExpr {
+    pub fn parse(expr: &str) -> Result<CronExpr> {
+        let expr = match expr.trim() {
+            "@hourly" => "0 * * * *",
+            "@daily" | "@midnight" => "0 0 * * *",
+            "@weekly" => "0 0 * * 0",
+            "@monthly" => "0 0 1 * *",
+            other => other,
+        };
+        let fields: Vec<&str> = expr.split_whitespace().collect();
+        if fields.len() != 5 {
+            bail!(
+                "invalid cron expression '{}': expected 5 fields (min hour dom month dow)",
+                expr
+            );
+        }
+        let minutes = parse_field(fields[0], 0, 59, &[]).context("minute field")?;
+        let hours = parse_field(fields[1], 0, 23, &[]).context("hour field")? as u32;
+        let days_of_month =
+            parse_field(fields[2], 1, 31, &[]).context("day-of-month field")? as u32;
+        let months = parse_field(fields[3], 1, 12, &MONTH_NAMES).context("month field")? as u16;
+        let mut dow = parse_field(fields[4], 0, 7, &CRON_DAY_NAMES).context("day-of-week field")?;
+        // 7 is an alias for Sunday.
+        if dow & (1 << 7) != 0 {
+            dow |= 1;
+        }
+        Ok(CronExpr {
+            minutes,
+            hours,
+            days_of_month,
+            months,
+            days_of_week: (dow & 0x7f) as u8,
+            dom_any: fields[2] == "*",
+            dow_any: fields[4] == "*",
+        })
+    }
+
+    /// True when the expression matches the given local minute.
+    pub fn matches(&self, local: NaiveDateTime) -> bool {
+        let bit = |mask: u64, v: u32| mask & (1u64 << v) != 0;
+        if !bit(self.minutes, local.minute())
+            || !bit(self.hours as u64, local.hour())
+            || !bit(self.months as u64, local.month())
+        {
+            return false;
+        }
+        let dom = bit(self.days_of_month as u64, local.day());
+        let dow = bit(
+            self.days_of_week as u64,
+            local.weekday().num_days_from_sunday(),
+        );
+        match (self.dom_any, self.dow_any) {
+            (true, true) => true,
+            (true, false) => dow,
+            (false, true) => dom,
+            (false, false) => dom || dow,
+        }
+    }
+}
+
+/// Parses one cron field into a bitmask. Supports `*`, lists, ranges, steps,
+/// and (for month/day-of-week) three-letter names.
+fn parse_field(field: &str, min: u32, max: u32, names: &[&str]) -> Result<u64> {
+    let value = |s: &str| -> Result<u32> {
+        let lower = s.to_ascii_lowercase();
+        if let Some(i) = names.iter().position(|n| *n == lower) {
+            // Month names start at 1, day names at 0.
+            return Ok(i as u32 + min);
+        }
+        let v: u32 = s.parse().map_err(|_| anyhow!("invalid value '{}'", s))?;
+        if v < min || v > max {
+            bail!("value {} out of range {}-{}", v, min, max);
+        }
+        Ok(v)
+    };
+
+    let mut mask = 0u64;
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((r, s)) => {
+                let step: u32 = s.parse().map_err(|_| anyhow!("invalid step '{}'", s))?;
+                if step == 0 {
+                    bail!("step must be greater than 0");
+                }
+                (r, Some(step))
+            }
+            None => (part, None),
+        };
+        let (lo, hi) = if range == "*" {
+            (min, max)
+        } else if let Some((a, b)) = range.split_once('-') {
+            (value(a)?, value(b)?)
+        } else {
+            let v = value(range)?;
+            // `5/15` means "from 5 to the end, every 15".
+            (v, if step.is_some() { max } else { v })
+        };
+        if lo > hi {
+            bail!("invalid range '{}'", range);
+        }
+        let mut v = lo;
+        while v <= hi {
+            mask |= 1u64 << v;
+            v += step.unwrap_or(1);
+        }
+    }
+    Ok(mask)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn window(days: Option<&str>, start: &str, end: &str) -> Window {
+        Window::parse(&TimeWindow {
+            days: days.map(String::from),
+            start: start.to_string(),
+            end: end.to_string(),
+        })
+        .unwrap()
+    }
+
+    fn local(y: i32, mo: u32, d: u32, h: u32, mi: u32) -> NaiveDateTime {
+        chrono::NaiveDate::from_ymd_opt(y, mo, d)
+            .unwrap()
+            .and_hms_opt(h, mi, 0)
+            .unwrap()
+    }
+
+    // 2026-10-19 is a Monday.
+
+    #[test]
+    fn weekday_window() {
+        let w = window(Some("mon-fri"), "09:00", "18:00");
+        assert!(w.contains(local(2026, 10, 19, 9, 0)));
+        assert!(w.contains(local(2026, 10, 19, 17, 59)));
+        assert!(!w.contains(local(2026, 10, 19, 18, 0)));
+        assert!(!w.contains(local(2026, 10, 19, 8, 59)));
+        assert!(!w.contains(local(2026, 10, 24, 10, 0)), "Saturday");
+    }
+
+    #[test]
+    fn overnight_window_belongs_to_start_day() {
+        let w = window(Some("fri"), "22:00", "07:00");
+        assert!(w.contains(local(2026, 10, 23, 23, 0)), "Friday night");
+        assert!(w.contains(local(2026, 10, 24, 6, 59)), "Saturday early");
+        assert!(!w.contains(local(2026, 10, 24, 7, 0)));
+        assert!(!w.contains(local(2026, 10, 22, 23, 0)), "Thursday night");
+    }
+
+    #[test]
+    fn wrapping_day_range() {
+        assert_eq!(
+            parse_days("fri-mon").unwrap(),
+            [true, false, false, false, true, true, true]
+        );
+        assert_eq!(
+            parse_days("sat,sun").unwrap(),
+            [false, false, false, false, false, true, true]
+        );
+        assert!(parse_days("funday").is_err());
+    }
+
+    #[test]
+    fn invalid_times_rejected() {
+        assert!(parse_hhmm("9").is_err());
+        assert!(parse_hhmm("25:00").is_err());
+        assert!(parse_hhmm("12:60").is_err());
+        assert_eq!(parse_hhmm("24:00").unwrap(), 0);
+    }
+
+    #[test]
+    fn cron_weekday_morning() {
+        let c = CronExpr::parse("0 9 * * mon-fri").unwrap();
+        assert!(c.matches(local(2026, 10, 19, 9, 0)));
+        assert!(!c.matches(local(2026, 10, 19, 9, 1)));
+        assert!(!c.matches(local(2026, 10, 25, 9, 0)), "Sunday");
+    }
+
+    #[test]
+    fn cron_steps_lists_and_aliases() {
+        let c = CronExpr::parse("*/15 8-10 * * *").unwrap();
+        assert!(c.matches(local(2026, 10, 19, 8, 45)));
+        assert!(!c.matches(local(2026, 10, 19, 11, 0)));
+
+        let c = CronExpr::parse("30 12 1,15 * *").unwrap();
+        assert!(c.matches(local(2026, 10, 15, 12, 30)));
+        assert!(!c.matches(local(2026, 10, 16, 12, 30)));
+
+        let c = CronExpr::parse("@weekly").unwrap();
+        assert!(c.matches(local(2026, 10, 25, 0, 0)));
+
+        let c = CronExpr::parse("0 0 * * 7").unwrap();
+        assert!(c.matches(local(2026, 10, 25, 0, 0)), "7 is Sunday");
+    }
+
+    #[test]
+    fn cron_dom_and_dow_are_ored() {
+        let c = CronExpr::parse("0 9 1 * mon").unwrap();
+        assert!(c.matches(local(2026, 10, 19, 9, 0)), "a Monday");
+        assert!(c.matches(local(2026, 10, 1, 9, 0)), "the 1st");
+        assert!(!c.matches(local(2026, 10, 20, 9, 0)));
+    }
+
+    #[test]
+    fn cron_rejects_bad_expressions() {
+        assert!(CronExpr::parse("0 9 * *").is_err());
+        assert!(CronExpr::parse("60 * * * *").is_err());
+        assert!(CronExpr::parse("*/0 * * * *").is_err());
+        assert!(CronExpr::parse("0 9 * * someday").is_err());
+    }
+
+    #[test]
+    fn resolve_merges_layers() {
+        let cfg: ScheduleConfig = serde_yml::from_str(
+            r#"
+timezone: Europe/Berlin
+default:
+  working_hours: { days: mon-fri, start: "09:00", end: "18:00" }
+  triggers:
+    - { name: standup, cron: "0 9 * * mon-fri", prompt: "Standup" }
+roles:
+  engineer:
+    quiet_hours:
+      - { start: "22:00", end: "07:00" }
+members:
+  engineer-bob:
+    timezone: America/New_York
+    triggers:
+      - { name: standup, cron: "30 9 * * mon-fri", prompt: "Late standup" }
+      - { name: retro, cron: "0 16 * * fri", prompt: "Retro" }
+"#,
+        )
+        .unwrap();
+        cfg.validate().unwrap();
+
+        let s = cfg.resolve("engineer", "engineer-bob").unwrap().unwrap();
+        assert_eq!(s.tz, chrono_tz::America::New_York);
+        assert_eq!(s.quiet_hours.len(), 1);
+        let names: Vec<_> = s.triggers.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["standup", "retro"]);
+        assert_eq!(s.triggers[0].prompt, "Late standup");
+
+        let s = cfg.resolve("reviewer", "reviewer-alice").unwrap().unwrap();
+        assert_eq!(s.tz, chrono_tz::Europe::Berlin);
+        assert!(s.quiet_hours.is_empty());
+    }
+
+    #[test]
+    fn resolve_empty_is_none() {
+        let cfg = ScheduleConfig::default();
+        assert!(cfg.resolve("engineer", "engineer-bob").unwrap().is_none());
+    }
+
+    #[test]
+    fn evaluation_uses_member_timezone() {
+        let cfg: ScheduleConfig = serde_yml::from_str(
+            r#"
+timezone: Asia/Tokyo
+default:
+  working_hours: { start: "09:00", end: "18:00" }
+  quiet_hours:
+    - { start: "22:00", end: "07:00" }
+  triggers:
+    - { name: standup, cron: "0 9 * * *", prompt: "Standup" }
+"#,
+        )
+        .unwrap();
+        let s = cfg.resolve("any", "any-one").unwrap().unwrap();
+
+        // 00:00 UTC is 09:00 in Tokyo
+        let morning = Utc.with_ymd_and_hms(2026, 10, 19, 0, 0, 0).unwrap();
+        assert!(s.on_duty(morning));
+        assert!(!s.is_quiet(morning));
+        assert_eq!(s.due_triggers(morning).len(), 1);
+
+        // 14:00 UTC is 23:00 in Tokyo
+        let night = Utc.with_ymd_and_hms(2026, 10, 19, 14, 0, 0).unwrap();
+        assert!(!s.on_duty(night));
+        assert!(s.is_quiet(night));
+        assert!(s.due_triggers(night).is_empty());
+    }
+
+    #[test]
+    fn validate_rejects_unknown_timezone_and_empty_prompt() {
+        let cfg: ScheduleConfig = serde_yml::from_str("timezone: Mars/Olympus_Mons\n").unwrap();
+        assert!(cfg.validate().is_err());
+
+        let cfg: ScheduleConfig = serde_yml::from_str(
+            "members:\n  bob:\n    triggers:\n      - { name: x, cron: \"@daily\", prompt: \"  \" }\n",
+        )
+        .unwrap();
+        let err = format!("{:#}", cfg.validate().unwrap_err());
+        assert!(err.contains("members.bob"), "got: {err}");
+    }
+
+    #[test]
+    fn minutes_between_is_exclusive_of_start() {
+        let from = Utc.with_ymd_and_hms(2026, 10, 19, 9, 0, 40).unwrap();
+        let to = Utc.with_ymd_and_hms(2026, 10, 19, 9, 3, 5).unwrap();
+        let mins = minutes_between(from, to);
+        assert_eq!(mins.len(), 3);
+        assert_eq!(
+            mins[0],
+            Utc.with_ymd_and_hms(2026, 10, 19, 9, 1, 0).unwrap()
+        );
+        assert!(minutes_between(to, to).is_empty());
+    }
+}
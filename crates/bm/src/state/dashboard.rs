@@ -5,6 +5,7 @@ use anyhow::Result;
 use crate::bridge;
 use crate::config::{BotminterConfig, TeamEntry};
 use crate::daemon;
+use crate::formation;
 use crate::profile;
 use crate::topology;
 use crate::workspace;
@@ -33,6 +34,8 @@ pub struct DaemonDisplay {
     pub mode: String,
     pub port: u16,
     pub interval_secs: u64,
+    /// systemd unit supervising the daemon, if any.
+    pub unit: Option<String>,
 }
 
 /// A single member's status row.
@@ -41,6 +44,8 @@ pub struct MemberRow {
     pub role: String,
     pub status: MemberStatus,
     pub branch: String,
+    /// App token health of a running member, if the daemon refreshes its token.
+    pub token_health: Option<daemon::TokenHealth>,
 }
 
 /// Bridge status info for display.
@@ -82,6 +87,8 @@ pub struct RalphMemberInfo {
     pub member: String,
     pub pid: u32,
     pub sections: Vec<(String, String)>,
+    /// Resource usage when the member runs in a limits scope.
+    pub usage: Option<formation::CgroupUsage>,
 }
 
 // ---------------------------------------------------------------------------
@@ -116,6 +123,8 @@ pub fn gather_status(
     let member_dirs = profile::discover_member_dirs(&team_repo);
     let has_members = !member_dirs.is_empty();
     let mut runtime_state = super::load()?;
+    let token_health =
+        daemon::load_token_health(&daemon::DaemonPaths::new(team_name)?.token_health());
 
     let mut members = Vec::new();
     let mut crashed_keys: Vec<String> = Vec::new();
@@ -132,11 +141,16 @@ pub fn gather_status(
         if matches!(&status, MemberStatus::Crashed { .. }) {
             crashed_keys.push(format!("{}/{}", team_name, name));
         }
+        let token_health = match &status {
+            MemberStatus::Running { .. } => token_health.get(name).cloned(),
+            _ => None,
+        };
         members.push(MemberRow {
             name: name.clone(),
             role,
             status,
             branch,
+            token_health,
         });
     }
 
@@ -181,8 +195,9 @@ pub fn gather_status(
 
 fn gather_daemon_info(team_name: &str) -> Option<DaemonDisplay> {
     match daemon::query_status(team_name) {
-        Ok(daemon::DaemonStatusInfo::Running { pid, config }) => Some(DaemonDisplay {
+        Ok(daemon::DaemonStatusInfo::Running { pid, config, unit }) => Some(DaemonDisplay {
             pid,
+            unit,
             mode: config
                 .as_ref()
                 .map(|c| c.mode.clone())
@@ -282,6 +297,7 @@ fn gather_verbose(
             member: member_name,
             pid: rt.pid,
             sections,
+            usage: formation::read_cgroup_usage(rt.pid),
         });
     }
 
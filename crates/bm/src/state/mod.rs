@@ -22,6 +22,19 @@ const STATE_FILE: &str = "state.json";
 pub struct RuntimeState {
     #[serde(default)]
     pub members: HashMap<String, MemberRuntime>,
+    /// Members held by `bm pause`, keyed like `members` (`team/member`), with
+    /// when they were paused. Unlike `MemberRuntime::paused_at` this outlives
+    /// the process, so the daemon and scheduler don't relaunch a paused
+    /// member. Cleared by `bm resume`.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub paused: HashMap<String, String>,
+}
+
+impl RuntimeState {
+    /// True when `bm pause` holds the member.
+    pub fn is_paused(&self, team_name: &str, member: &str) -> bool {
+        self.paused.contains_key(&format!("{}/{}", team_name, member))
+    }
 }
 
 /// Runtime info for a single running member.
@@ -34,11 +47,21 @@ pub struct MemberRuntime {
     /// instead of a raw Ralph Orchestrator process.
     #[serde(default)]
     pub brain_mode: bool,
+    /// When the member was paused with `bm pause`. The process stays alive
+    /// but takes no new work until `bm resume`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub paused_at: Option<String>, // ISO 8601
 }
 
 /// Returns the path to state.json.
 fn state_path() -> Result<PathBuf> {
-    Ok(config::config_dir()?.join(STATE_FILE))
+    Ok(state_path_in(&config::config_dir()?))
+}
+
+/// Returns the path to state.json inside a given config directory
+/// (the directory holding `config.yml`).
+pub fn state_path_in(config_dir: &Path) -> PathBuf {
+    config_dir.join(STATE_FILE)
 }
 
 /// Loads runtime state from disk. Returns empty state if file is missing.
@@ -114,6 +137,8 @@ pub enum MemberStatus {
         pid: u32,
         started_at: String,
         brain_mode: bool,
+        /// Set while the member is paused.
+        paused_at: Option<String>,
     },
     Crashed {
         pid: u32,
@@ -125,6 +150,7 @@ pub enum MemberStatus {
 impl MemberStatus {
     pub fn label(&self) -> &'static str {
         match self {
+            MemberStatus::Running { paused_at: Some(_), .. } => "paused",
             MemberStatus::Running { brain_mode: true, .. } => "brain",
             MemberStatus::Running { .. } => "running",
             MemberStatus::Crashed { .. } => "crashed",
@@ -147,6 +173,7 @@ pub fn resolve_member_status(
                     pid: rt.pid,
                     started_at: rt.started_at.clone(),
                     brain_mode: rt.brain_mode,
+                    paused_at: rt.paused_at.clone(),
                 }
             } else {
                 MemberStatus::Crashed {
@@ -192,6 +219,7 @@ mod tests {
                 started_at: "2026-02-20T10:00:00Z".to_string(),
                 workspace: PathBuf::from("/tmp/ws/arch-01"),
                 brain_mode: false,
+                paused_at: None,
             },
         );
 
@@ -250,6 +278,7 @@ mod tests {
                 started_at: "2026-01-01T00:00:00Z".to_string(),
                 workspace: PathBuf::from("/tmp/dead"),
                 brain_mode: false,
+                paused_at: None,
             },
         );
         state.members.insert(
@@ -259,6 +288,7 @@ mod tests {
                 started_at: "2026-01-01T00:00:00Z".to_string(),
                 workspace: PathBuf::from("/tmp/alive"),
                 brain_mode: false,
+                paused_at: None,
             },
         );
 
@@ -283,6 +313,7 @@ mod tests {
                 started_at: "2026-02-21T10:00:00Z".to_string(),
                 workspace: PathBuf::from("/tmp/ws"),
                 brain_mode: false,
+                paused_at: None,
             },
         );
 
@@ -305,6 +336,7 @@ mod tests {
                 started_at: "2026-02-21T10:00:00Z".to_string(),
                 workspace: PathBuf::from("/tmp/ws"),
                 brain_mode: false,
+                paused_at: None,
             },
         );
 
@@ -332,6 +364,7 @@ mod tests {
                 pid: 1,
                 started_at: String::new(),
                 brain_mode: false,
+                paused_at: None,
             }
             .label(),
             "running"
@@ -341,10 +374,21 @@ mod tests {
                 pid: 1,
                 started_at: String::new(),
                 brain_mode: true,
+                paused_at: None,
             }
             .label(),
             "brain"
         );
+        assert_eq!(
+            MemberStatus::Running {
+                pid: 1,
+                started_at: String::new(),
+                brain_mode: true,
+                paused_at: Some("2026-01-01T00:00:00Z".to_string()),
+            }
+            .label(),
+            "paused"
+        );
         assert_eq!(
             MemberStatus::Crashed {
                 pid: 1,
@@ -371,5 +415,23 @@ mod tests {
         let state: RuntimeState = serde_json::from_str(json).unwrap();
         let rt = state.members.get("team/member").unwrap();
         assert!(!rt.brain_mode, "brain_mode should default to false");
+        assert!(rt.paused_at.is_none(), "paused_at should default to None");
+        assert!(state.paused.is_empty(), "paused should default to empty");
+    }
+
+    #[test]
+    fn pause_flag_round_trips() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("state.json");
+        let mut state = RuntimeState::default();
+        state
+            .paused
+            .insert("team/alice".to_string(), "2026-01-01T00:00:00Z".to_string());
+        save_to(&path, &state).unwrap();
+
+        let loaded = load_from(&path).unwrap();
+        assert!(loaded.is_paused("team", "alice"));
+        assert!(!loaded.is_paused("team", "bob"));
+        assert!(!loaded.is_paused("other", "alice"));
     }
 }
@@ -1,9 +1,10 @@
 use anyhow::Result;
 
 use crate::config::{BotminterConfig, TeamEntry};
+use crate::formation::pause_members::{PauseResult, ResumeResult};
 use crate::formation::start_members::StartResult;
 use crate::formation::stop_members::StopResult;
-use crate::formation::{Formation, StartParams, StopParams};
+use crate::formation::{Formation, PauseParams, ResumeParams, StartParams, StopParams};
 
 /// Operator-facing API boundary wrapping a team entry and its formation.
 ///
@@ -67,6 +68,29 @@ impl<'a> Team<'a> {
             stop_all,
         })
     }
+
+    /// Pause members via the formation.
+    pub fn pause(
+        &self,
+        member_filter: Option<&str>,
+        immediate: bool,
+        wait: std::time::Duration,
+    ) -> Result<PauseResult> {
+        self.formation.pause_members(&PauseParams {
+            team: self.entry,
+            member_filter,
+            immediate,
+            wait,
+        })
+    }
+
+    /// Resume paused members via the formation.
+    pub fn resume(&self, member_filter: Option<&str>) -> Result<ResumeResult> {
+        self.formation.resume_members(&ResumeParams {
+            team: self.entry,
+            member_filter,
+        })
+    }
 }
 
 #[cfg(test)]
@@ -79,6 +103,7 @@ mod tests {
         CredentialDomain, EnvironmentCheck, EnvironmentStatus, KeyValueCredentialStore,
         MemberHandle, MemberStatus, SetupParams,
     };
+    use crate::formation::pause_members::{MemberPaused, MemberResumed};
     use crate::formation::start_members::MemberLaunched;
     use crate::formation::stop_members::MemberStopped;
 
@@ -159,6 +184,7 @@ mod tests {
             _member: &str,
             _workspace: &Path,
             _bot_user: &str,
+            _github_host: &str,
         ) -> Result<()> {
             Ok(())
         }
@@ -183,6 +209,30 @@ mod tests {
                 .ok_or_else(|| anyhow::anyhow!("mock stop error"))
         }
 
+        fn pause_members(&self, params: &PauseParams) -> Result<PauseResult> {
+            Ok(PauseResult {
+                paused: vec![MemberPaused {
+                    name: params.member_filter.unwrap_or("superman").to_string(),
+                    already_paused: false,
+                    immediate: params.immediate,
+                    brain_mode: false,
+                }],
+                errors: vec![],
+                no_members_running: false,
+            })
+        }
+
+        fn resume_members(&self, params: &ResumeParams) -> Result<ResumeResult> {
+            Ok(ResumeResult {
+                resumed: vec![MemberResumed {
+                    name: params.member_filter.unwrap_or("superman").to_string(),
+                    was_paused: true,
+                }],
+                errors: vec![],
+                no_members_running: false,
+            })
+        }
+
         fn member_status(&self) -> Result<Vec<MemberStatus>> {
             Ok(vec![])
         }
@@ -216,6 +266,8 @@ mod tests {
             project_number: None,
             bridge_lifecycle: BridgeLifecycle::default(),
             vm: None,
+            profile_source: None,
+            forge: None,
         }
     }
 
@@ -291,6 +343,22 @@ mod tests {
         assert_eq!(result.stopped.len(), 1);
     }
 
+    #[test]
+    fn team_pause_and_resume_delegate_to_formation() {
+        let entry = test_team_entry();
+        let team = Team::new(&entry, Box::new(MockFormation::new()));
+
+        let paused = team
+            .pause(Some("superman"), true, std::time::Duration::from_secs(1))
+            .unwrap();
+        assert_eq!(paused.paused.len(), 1);
+        assert_eq!(paused.paused[0].name, "superman");
+        assert!(paused.paused[0].immediate, "--now is passed through");
+
+        let resumed = team.resume(Some("superman")).unwrap();
+        assert!(resumed.resumed[0].was_paused);
+    }
+
     #[test]
     fn team_start_propagates_formation_error() {
         let entry = test_team_entry();
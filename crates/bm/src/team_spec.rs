@@ -0,0 +1,511 @@
+//! Declarative team specs for `bm apply -f team.yml`.
+//!
+//! A spec declares the desired shape of a team: profile, GitHub repo,
+//! project forks, members by role, bridge, formation, and coding agent.
+//! This module parses and validates specs and diffs them against the
+//! current team state to produce an ordered plan of actions. Executing
+//! the plan is a command-layer concern (`commands::apply`), which calls
+//! the existing lifecycle operations (`member_lifecycle::hire_member`,
+//! `git::add_project`, etc.).
+
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::config::TeamEntry;
+use crate::git;
+use crate::profile::{self, ProfileManifest};
+
+/// Declarative team spec parsed from a `team.yml` file.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TeamSpec {
+    pub name: String,
+    pub profile: String,
+    /// GitHub org/repo hosting the team repo. Required to create a team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub github: Option<GitHubSpec>,
+    /// Override the profile's default coding agent (e.g., "gemini-cli").
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub coding_agent: Option<String>,
+    /// Bridge name from the profile's `bridges` list.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bridge: Option<String>,
+    /// Formation members are expected to run under (e.g., "local").
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub formation: Option<String>,
+    #[serde(default)]
+    pub projects: Vec<ProjectSpec>,
+    #[serde(default)]
+    pub members: Vec<MemberSpec>,
+}
+
+/// GitHub location of the team repo and its project board.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct GitHubSpec {
+    pub org: String,
+    pub repo: String,
+    /// GitHub Project board title. Defaults to "{team} Board".
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_board: Option<String>,
+    /// GitHub Enterprise Server hostname. Defaults to github.com.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub host: Option<String>,
+    /// REST API base for `host`. Defaults to `https://{host}/api/v3`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_base: Option<String>,
+}
+
+impl GitHubSpec {
+    /// Returns the `owner/repo` form stored in `TeamEntry.github_repo`.
+    pub fn full_name(&self) -> String {
+        format!("{}/{}", self.org, self.repo)
+    }
+}
+
+/// A project fork declared in the spec.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ProjectSpec {
+    pub url: String,
+}
+
+/// A member declared in the spec. The member directory is `{role}-{name}`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MemberSpec {
+    pub role: String,
+    pub name: String,
+}
+
+impl MemberSpec {
+    pub fn dir_name(&self) -> String {
+        format!("{}-{}", self.role, self.name)
+    }
+}
+
+/// Loads a team spec from a YAML file.
+pub fn load_spec(path: &Path) -> Result<TeamSpec> {
+    let contents = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read team spec {}", path.display()))?;
+    let spec: TeamSpec = serde_yml::from_str(&contents)
+        .with_context(|| format!("Failed to parse team spec {}", path.display()))?;
+    Ok(spec)
+}
+
+/// Validates a spec against the profile it references.
+///
+/// `formations` lists the formation names available to the team (from the
+/// team repo when it exists, otherwise from the profile).
+pub fn validate_spec(spec: &TeamSpec, manifest: &ProfileManifest, formations: &[String]) -> Result<()> {
+    validate_name("Team name", &spec.name)?;
+
+    let roles: Vec<&str> = manifest.roles.iter().map(|r| r.name.as_str()).collect();
+    let mut seen = Vec::new();
+    for member in &spec.members {
+        validate_name("Member name", &member.name)?;
+        if !roles.contains(&member.role.as_str()) {
+            bail!(
+                "Role '{}' not available in profile '{}'. Available roles: {}",
+                member.role,
+                spec.profile,
+                roles.join(", ")
+            );
+        }
+        let dir = member.dir_name();
+        if seen.contains(&dir) {
+            bail!("Member '{}' is declared more than once.", dir);
+        }
+        seen.push(dir);
+    }
+
+    let mut project_names = Vec::new();
+    for project in &spec.projects {
+        let name = git::derive_project_name(&project.url);
+        if project_names.contains(&name) {
+            bail!("Project '{}' is declared more than once.", name);
+        }
+        project_names.push(name);
+    }
+
+    if let Some(ref agent) = spec.coding_agent {
+        if !manifest.coding_agents.contains_key(agent) {
+            let mut available: Vec<&str> =
+                manifest.coding_agents.keys().map(|k| k.as_str()).collect();
+            available.sort();
+            bail!(
+                "Coding agent '{}' not found in profile '{}'. Available agents: {}",
+                agent,
+                spec.profile,
+                available.join(", ")
+            );
+        }
+    }
+
+    if let Some(ref bridge) = spec.bridge {
+        profile::validate_bridge_selection(bridge, &manifest.bridges)?;
+    }
+
+    if let Some(ref formation) = spec.formation {
+        if !formations.contains(formation) {
+            bail!(
+                "Formation '{}' not found. Available formations: {}",
+                formation,
+                formations.join(", ")
+            );
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_name(label: &str, name: &str) -> Result<()> {
+    if name.is_empty() {
+        bail!("{} cannot be empty", label);
+    }
+    if name.contains('/') || name.contains(' ') {
+        bail!("{} '{}' cannot contain '/' or spaces", label, name);
+    }
+    Ok(())
+}
+
+/// Current state of an existing team, as far as a spec is concerned.
+#[derive(Debug, Clone, Default)]
+pub struct TeamState {
+    pub profile: String,
+    pub github_repo: String,
+    pub coding_agent: Option<String>,
+    pub bridge: Option<String>,
+    /// Hired member directory names.
+    pub members: Vec<String>,
+    /// Configured projects as (name, fork_url) pairs.
+    pub projects: Vec<(String, String)>,
+}
+
+impl TeamState {
+    /// Reads the state of a registered team from config and its team repo.
+    pub fn read(team: &TeamEntry) -> Result<Self> {
+        let team_repo = team.path.join("team");
+        let manifest = profile::read_team_repo_manifest(&team_repo)?;
+        Ok(Self {
+            profile: team.profile.clone(),
+            github_repo: team.github_repo.clone(),
+            coding_agent: team.coding_agent.clone(),
+            bridge: manifest.bridge.clone(),
+            members: profile::discover_member_dirs(&team_repo),
+            projects: manifest
+                .projects
+                .iter()
+                .map(|p| (p.name.clone(), p.fork_url.clone()))
+                .collect(),
+        })
+    }
+}
+
+/// A single reconciliation step.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ApplyAction {
+    /// Create the team repo and register the team (as `bm init` does).
+    CreateTeam {
+        name: String,
+        profile: String,
+        github_repo: String,
+    },
+    /// Set the team's coding agent override in config.
+    SetCodingAgent { agent: String },
+    /// Record the bridge selection in the team's botminter.yml.
+    SetBridge { bridge: String },
+    /// Add a project fork to the team.
+    AddProject { name: String, url: String },
+    /// Hire a member.
+    Hire { role: String, name: String },
+    /// Fire a member not declared in the spec (only with `--prune`).
+    Fire { member: String },
+}
+
+impl fmt::Display for ApplyAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ApplyAction::CreateTeam { name, profile, github_repo } => {
+                write!(f, "+ create team {} (profile {}, repo {})", name, profile, github_repo)
+            }
+            ApplyAction::SetCodingAgent { agent } => write!(f, "~ set coding agent {}", agent),
+            ApplyAction::SetBridge { bridge } => write!(f, "~ set bridge {}", bridge),
+            ApplyAction::AddProject { name, url } => write!(f, "+ add project {} ({})", name, url),
+            ApplyAction::Hire { role, name } => write!(f, "+ hire {}-{}", role, name),
+            ApplyAction::Fire { member } => write!(f, "- fire {}", member),
+        }
+    }
+}
+
+/// Ordered list of actions that brings a team in line with its spec.
+#[derive(Debug, Clone, Default)]
+pub struct ApplyPlan {
+    pub actions: Vec<ApplyAction>,
+}
+
+impl ApplyPlan {
+    pub fn is_empty(&self) -> bool {
+        self.actions.is_empty()
+    }
+
+    /// True when the plan removes members (requires confirmation).
+    pub fn is_destructive(&self) -> bool {
+        self.actions.iter().any(|a| matches!(a, ApplyAction::Fire { .. }))
+    }
+}
+
+/// Diffs a spec against the current team state and returns the plan.
+///
+/// `state` is `None` when the team is not registered yet. Members not
+/// declared in the spec are only fired when `prune` is set. Projects are
+/// never removed — there is no project removal operation.
+pub fn plan(spec: &TeamSpec, state: Option<&TeamState>, prune: bool) -> Result<ApplyPlan> {
+    let mut actions = Vec::new();
+
+    let empty = TeamState::default();
+    let state = match state {
+        Some(s) => {
+            if s.profile != spec.profile {
+                bail!(
+                    "Team '{}' uses profile '{}' but the spec declares '{}'. \
+                     Changing a team's profile is not supported.",
+                    spec.name, s.profile, spec.profile
+                );
+            }
+            if let Some(ref gh) = spec.github {
+                if !s.github_repo.is_empty() && s.github_repo != gh.full_name() {
+                    bail!(
+                        "Team '{}' uses GitHub repo '{}' but the spec declares '{}'. \
+                         Changing a team's repo is not supported.",
+                        spec.name, s.github_repo, gh.full_name()
+                    );
+                }
+            }
+            s
+        }
+        None => {
+            let gh = spec.github.as_ref().with_context(|| {
+                format!(
+                    "Team '{}' does not exist. The spec needs `github.org` and `github.repo` to create it.",
+                    spec.name
+                )
+            })?;
+            actions.push(ApplyAction::CreateTeam {
+                name: spec.name.clone(),
+                profile: spec.profile.clone(),
+                github_repo: gh.full_name(),
+            });
+            &empty
+        }
+    };
+    let creating = matches!(actions.first(), Some(ApplyAction::CreateTeam { .. }));
+
+    if let Some(ref agent) = spec.coding_agent {
+        if state.coding_agent.as_ref() != Some(agent) {
+            actions.push(ApplyAction::SetCodingAgent { agent: agent.clone() });
+        }
+    }
+
+    // Bridge selection on team creation is handled by CreateTeam itself.
+    if let Some(ref bridge) = spec.bridge {
+        match state.bridge {
+            Some(ref current) if current == bridge => {}
+            Some(ref current) => bail!(
+                "Team '{}' already uses bridge '{}' but the spec declares '{}'. \
+                 Changing a team's bridge is not supported.",
+                spec.name, current, bridge
+            ),
+            None if creating => {}
+            None => actions.push(ApplyAction::SetBridge { bridge: bridge.clone() }),
+        }
+    }
+
+    for project in &spec.projects {
+        let name = git::derive_project_name(&project.url);
+        match state.projects.iter().find(|(n, _)| *n == name) {
+            Some((_, url)) if *url != project.url => bail!(
+                "Project '{}' is configured with fork URL '{}' but the spec declares '{}'.",
+                name, url, project.url
+            ),
+            Some(_) => {}
+            None => actions.push(ApplyAction::AddProject {
+                name,
+                url: project.url.clone(),
+            }),
+        }
+    }
+
+    for member in &spec.members {
+        if !state.members.contains(&member.dir_name()) {
+            actions.push(ApplyAction::Hire {
+                role: member.role.clone(),
+                name: member.name.clone(),
+            });
+        }
+    }
+
+    if prune {
+        let declared: Vec<String> = spec.members.iter().map(|m| m.dir_name()).collect();
+        for member in &state.members {
+            if !declared.contains(member) {
+                actions.push(ApplyAction::Fire { member: member.clone() });
+            }
+        }
+    }
+
+    Ok(ApplyPlan { actions })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spec_yaml() -> &'static str {
+        r#"
+name: alpha
+profile: scrum
+github:
+  org: acme
+  repo: alpha-team
+bridge: tuwunel
+formation: local
+projects:
+  - url: https://github.com/acme/app.git
+members:
+  - role: architect
+    name: alice
+  - role: human-assistant
+    name: bob
+"#
+    }
+
+    fn existing_state() -> TeamState {
+        TeamState {
+            profile: "scrum".into(),
+            github_repo: "acme/alpha-team".into(),
+            coding_agent: None,
+            bridge: Some("tuwunel".into()),
+            members: vec!["architect-alice".into(), "qe-carol".into()],
+            projects: vec![("app".into(), "https://github.com/acme/app.git".into())],
+        }
+    }
+
+    #[test]
+    fn spec_parses_all_fields() {
+        let spec: TeamSpec = serde_yml::from_str(spec_yaml()).unwrap();
+        assert_eq!(spec.name, "alpha");
+        assert_eq!(spec.github.as_ref().unwrap().full_name(), "acme/alpha-team");
+        assert_eq!(spec.bridge.as_deref(), Some("tuwunel"));
+        assert_eq!(spec.projects.len(), 1);
+        assert_eq!(spec.members[1].dir_name(), "human-assistant-bob");
+    }
+
+    #[test]
+    fn plan_for_new_team_creates_then_adds_everything() {
+        let spec: TeamSpec = serde_yml::from_str(spec_yaml()).unwrap();
+        let plan = plan(&spec, None, false).unwrap();
+        assert_eq!(
+            plan.actions,
+            vec![
+                ApplyAction::CreateTeam {
+                    name: "alpha".into(),
+                    profile: "scrum".into(),
+                    github_repo: "acme/alpha-team".into(),
+                },
+                ApplyAction::AddProject {
+                    name: "app".into(),
+                    url: "https://github.com/acme/app.git".into(),
+                },
+                ApplyAction::Hire { role: "architect".into(), name: "alice".into() },
+                ApplyAction::Hire { role: "human-assistant".into(), name: "bob".into() },
+            ]
+        );
+    }
+
+    #[test]
+    fn plan_for_existing_team_only_adds_missing() {
+        let spec: TeamSpec = serde_yml::from_str(spec_yaml()).unwrap();
+        let plan = plan(&spec, Some(&existing_state()), false).unwrap();
+        assert_eq!(
+            plan.actions,
+            vec![ApplyAction::Hire { role: "human-assistant".into(), name: "bob".into() }]
+        );
+        assert!(!plan.is_destructive());
+    }
+
+    #[test]
+    fn plan_with_prune_fires_undeclared_members() {
+        let spec: TeamSpec = serde_yml::from_str(spec_yaml()).unwrap();
+        let plan = plan(&spec, Some(&existing_state()), true).unwrap();
+        assert!(plan.actions.contains(&ApplyAction::Fire { member: "qe-carol".into() }));
+        assert!(plan.is_destructive());
+    }
+
+    #[test]
+    fn plan_rejects_profile_change() {
+        let spec: TeamSpec = serde_yml::from_str(spec_yaml()).unwrap();
+        let mut state = existing_state();
+        state.profile = "agentic-sdlc-minimal".into();
+        let err = plan(&spec, Some(&state), false).unwrap_err().to_string();
+        assert!(err.contains("profile"), "{}", err);
+    }
+
+    #[test]
+    fn plan_rejects_bridge_change() {
+        let spec: TeamSpec = serde_yml::from_str(spec_yaml()).unwrap();
+        let mut state = existing_state();
+        state.bridge = Some("telegram".into());
+        let err = plan(&spec, Some(&state), false).unwrap_err().to_string();
+        assert!(err.contains("bridge"), "{}", err);
+    }
+
+    #[test]
+    fn plan_new_team_without_github_errors() {
+        let mut spec: TeamSpec = serde_yml::from_str(spec_yaml()).unwrap();
+        spec.github = None;
+        let err = plan(&spec, None, false).unwrap_err().to_string();
+        assert!(err.contains("github.org"), "{}", err);
+    }
+
+    #[test]
+    fn plan_is_empty_when_in_sync() {
+        let mut spec: TeamSpec = serde_yml::from_str(spec_yaml()).unwrap();
+        spec.members.retain(|m| m.role == "architect");
+        let plan = plan(&spec, Some(&existing_state()), false).unwrap();
+        assert!(plan.is_empty());
+    }
+
+    #[test]
+    fn validate_rejects_unknown_role_and_duplicates() {
+        let (_tmp, base) = crate::profile::test_support::setup_disk_profiles();
+        let manifest = profile::read_manifest_from("scrum", &base).unwrap();
+        let formations = vec!["local".to_string()];
+
+        let mut spec: TeamSpec = serde_yml::from_str(spec_yaml()).unwrap();
+        spec.bridge = None;
+        assert!(validate_spec(&spec, &manifest, &formations).is_ok());
+
+        spec.members.push(MemberSpec { role: "wizard".into(), name: "zed".into() });
+        let err = validate_spec(&spec, &manifest, &formations).unwrap_err().to_string();
+        assert!(err.contains("wizard"), "{}", err);
+
+        spec.members.pop();
+        spec.members.push(MemberSpec { role: "human-assistant".into(), name: "bob".into() });
+        let err = validate_spec(&spec, &manifest, &formations).unwrap_err().to_string();
+        assert!(err.contains("more than once"), "{}", err);
+    }
+
+    #[test]
+    fn validate_rejects_unknown_formation() {
+        let (_tmp, base) = crate::profile::test_support::setup_disk_profiles();
+        let manifest = profile::read_manifest_from("scrum", &base).unwrap();
+        let mut spec: TeamSpec = serde_yml::from_str(spec_yaml()).unwrap();
+        spec.bridge = None;
+        spec.formation = Some("k9s".into());
+        let err = validate_spec(&spec, &manifest, &["local".to_string()])
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("k9s"), "{}", err);
+    }
+}
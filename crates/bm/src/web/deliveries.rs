@@ -0,0 +1,210 @@
+use std::path::PathBuf;
+
+use axum::extract::{Path as AxumPath, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::Json;
+use serde::Serialize;
+
+use super::state::WebState;
+use crate::config;
+use crate::daemon::{self, Delivery, DeliveryAction, Validation};
+
+/// GET /api/teams/:team/deliveries — webhook deliveries recorded by the
+/// daemon, newest first, without payloads.
+pub async fn list_deliveries(
+    State(state): State<WebState>,
+    AxumPath(team_name): AxumPath<String>,
+) -> impl IntoResponse {
+    match deliveries_path(&state, &team_name) {
+        Ok(path) => {
+            let deliveries: Vec<DeliverySummary> = daemon::load_deliveries(&path)
+                .iter()
+                .rev()
+                .map(DeliverySummary::from)
+                .collect();
+            (StatusCode::OK, Json(serde_json::json!(deliveries))).into_response()
+        }
+        Err(e) => error_response(e),
+    }
+}
+
+/// GET /api/teams/:team/deliveries/:id — a single delivery with headers
+/// and payload.
+pub async fn get_delivery(
+    State(state): State<WebState>,
+    AxumPath((team_name, id)): AxumPath<(String, String)>,
+) -> impl IntoResponse {
+    let path = match deliveries_path(&state, &team_name) {
+        Ok(path) => path,
+        Err(e) => return error_response(e),
+    };
+    match daemon::find_delivery(&path, &id) {
+        Some(delivery) => (StatusCode::OK, Json(serde_json::json!(delivery))).into_response(),
+        None => error_response(anyhow::anyhow!("Delivery '{}' not found", id)),
+    }
+}
+
+/// Delivery log of a registered team. The daemon writes it next to config.yml.
+fn deliveries_path(state: &WebState, team_name: &str) -> anyhow::Result<PathBuf> {
+    let cfg = config::load_from(&state.config_path)?;
+    if !cfg.teams.iter().any(|t| t.name == team_name) {
+        anyhow::bail!("Team '{}' not found", team_name);
+    }
+    let dir = state
+        .config_path
+        .parent()
+        .ok_or_else(|| anyhow::anyhow!("Config path has no parent directory"))?;
+    Ok(daemon::deliveries_path(dir, team_name))
+}
+
+fn error_response(e: anyhow::Error) -> axum::response::Response {
+    let status = if e.to_string().contains("not found") {
+        StatusCode::NOT_FOUND
+    } else {
+        StatusCode::INTERNAL_SERVER_ERROR
+    };
+    (status, Json(serde_json::json!({ "error": e.to_string() }))).into_response()
+}
+
+// ── Response types ───────────────────────────────────────────
+
+#[derive(Debug, Serialize)]
+pub struct DeliverySummary {
+    pub id: String,
+    pub received_at: String,
+    pub event: Option<String>,
+    pub summary: Option<String>,
+    pub validation: Validation,
+    pub action: DeliveryAction,
+    pub replay_count: usize,
+}
+
+impl From<&Delivery> for DeliverySummary {
+    fn from(d: &Delivery) -> Self {
+        Self {
+            id: d.id.clone(),
+            received_at: d.received_at.clone(),
+            event: d.event.clone(),
+            summary: d.summary.clone(),
+            validation: d.validation,
+            action: d.action,
+            replay_count: d.replays.len(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::sync::Arc;
+
+    use axum::body::Body;
+    use axum::http::Request;
+    use tower::ServiceExt;
+
+    use super::*;
+    use crate::web::web_router;
+
+    fn test_app(tmp: &std::path::Path) -> axum::Router {
+        let config_path = tmp.join(".botminter").join("config.yml");
+        let cfg = config::BotminterConfig {
+            workzone: tmp.to_path_buf(),
+            default_team: Some("my-team".to_string()),
+            teams: vec![config::TeamEntry {
+                name: "my-team".to_string(),
+                path: tmp.join("my-team"),
+                profile: "scrum-compact".to_string(),
+                github_repo: "org/test".to_string(),
+                credentials: config::Credentials::default(),
+                coding_agent: None,
+                project_number: None,
+                bridge_lifecycle: Default::default(),
+                vm: None,
+                profile_source: None,
+                forge: None,
+            }],
+            vms: Vec::new(),
+            keyring_collection: None,
+        };
+        config::save_to(&config_path, &cfg).unwrap();
+        let lines = [
+            serde_json::json!({
+                "id": "older",
+                "received_at": "2026-03-24T10:00:00+00:00",
+                "event": "push",
+                "headers": { "x-github-event": "push" },
+                "validation": "bad_signature",
+                "action": "rejected"
+            }),
+            serde_json::json!({
+                "id": "newer",
+                "received_at": "2026-03-24T10:05:00+00:00",
+                "event": "issues",
+                "headers": { "x-github-event": "issues" },
+                "payload": "{\"action\":\"opened\"}",
+                "validation": "verified",
+                "action": "launched_members"
+            }),
+            serde_json::json!({
+                "replay_of": "newer",
+                "replayed_at": "2026-03-24T11:00:00+00:00",
+                "action": "launched_members"
+            }),
+        ];
+        fs::write(
+            daemon::deliveries_path(config_path.parent().unwrap(), "my-team"),
+            lines.map(|l| l.to_string() + "\n").concat(),
+        )
+        .unwrap();
+        web_router(WebState {
+            config_path: Arc::new(config_path),
+        })
+    }
+
+    async fn get_json(app: axum::Router, uri: &str) -> (StatusCode, serde_json::Value) {
+        let resp = app
+            .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let status = resp.status();
+        let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    #[tokio::test]
+    async fn deliveries_list_newest_first_without_payload() {
+        let tmp = tempfile::tempdir().unwrap();
+        let (status, body) = get_json(test_app(tmp.path()), "/api/teams/my-team/deliveries").await;
+
+        assert_eq!(status, StatusCode::OK);
+        let list = body.as_array().unwrap();
+        assert_eq!(list.len(), 2);
+        assert_eq!(list[0]["id"], "newer");
+        assert_eq!(list[0]["replay_count"], 1);
+        assert_eq!(list[1]["validation"], "bad_signature");
+        assert!(list[0].get("payload").is_none());
+    }
+
+    #[tokio::test]
+    async fn delivery_detail_and_not_found() {
+        let tmp = tempfile::tempdir().unwrap();
+        let (status, body) =
+            get_json(test_app(tmp.path()), "/api/teams/my-team/deliveries/newer").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["payload"], "{\"action\":\"opened\"}");
+        assert_eq!(body["headers"]["x-github-event"], "issues");
+
+        let (status, _) = get_json(
+            test_app(tmp.path()),
+            "/api/teams/my-team/deliveries/missing",
+        )
+        .await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+
+        let (status, _) = get_json(test_app(tmp.path()), "/api/teams/nope/deliveries").await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+}
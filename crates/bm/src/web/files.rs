@@ -6,8 +6,10 @@ use axum::http::StatusCode;
 use axum::response::IntoResponse;
 use axum::Json;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 
 use super::state::WebState;
+use crate::audit::{self, Actor, Record};
 use crate::config;
 
 /// GET /api/teams/:team/files/*path — read a file from the team repo.
@@ -29,7 +31,9 @@ pub async fn write_file(
     AxumPath((team_name, file_path)): AxumPath<(String, String)>,
     Json(body): Json<FileWriteRequest>,
 ) -> impl IntoResponse {
-    match do_write_file(&state, &team_name, &file_path, &body.content).await {
+    let result = do_write_file(&state, &team_name, &file_path, &body.content).await;
+    record_write(&state, &team_name, &file_path, &body.content, &result);
+    match result {
         Ok(resp) => (StatusCode::OK, Json(serde_json::json!(resp))).into_response(),
         Err((status, msg)) => {
             (status, Json(serde_json::json!({ "error": msg }))).into_response()
@@ -52,6 +56,34 @@ pub async fn list_tree(
     }
 }
 
+/// Appends a console write, including rejected ones, to the team's audit
+/// log. The content itself is not logged, only its size and hash. Writes to
+/// teams that aren't registered have no log to go to.
+fn record_write(
+    state: &WebState,
+    team_name: &str,
+    file_path: &str,
+    content: &str,
+    result: &Result<FileWriteResponse, (StatusCode, String)>,
+) {
+    let registered = config::load_from(&state.config_path)
+        .is_ok_and(|cfg| cfg.teams.iter().any(|t| t.name == team_name));
+    let Some(dir) = state.config_path.parent().filter(|_| registered) else {
+        return;
+    };
+    let record = Record::new(Actor::console(), "files.write")
+        .target(Some(file_path))
+        .param("bytes", content.len())
+        .param("sha256", hex::encode(Sha256::digest(content.as_bytes())));
+    let record = match result {
+        Ok(resp) => record.param("commit", resp.commit_sha.as_str()),
+        Err((_, msg)) => record.failed(msg.as_str()),
+    };
+    if let Err(e) = audit::append(&audit::log_path(dir, team_name), record) {
+        eprintln!("Warning: failed to write audit log: {:#}", e);
+    }
+}
+
 // ── Path security ──────────────────────────────────────────
 
 /// Validates and resolves a relative path within the team repo root.
@@ -601,6 +633,8 @@ mod tests {
                 project_number: None,
                 bridge_lifecycle: Default::default(),
                 vm: None,
+                profile_source: None,
+                forge: None,
             }],
             vms: Vec::new(),
             keyring_collection: None,
@@ -967,6 +1001,46 @@ mod tests {
         assert_eq!(file["content"], new_content);
     }
 
+    #[tokio::test]
+    async fn writes_are_recorded_in_audit_log() {
+        let tmp = tempfile::tempdir().unwrap();
+        let team_path = setup_fixture_team(tmp.path());
+        git_init(&team_path.join("team"));
+        let config_path = tmp.path().join(".botminter").join("config.yml");
+        write_config(&config_path, "my-team", &team_path, "agentic-sdlc-minimal", "org/test");
+
+        for uri in [
+            "/api/teams/my-team/files/PROCESS.md",
+            "/api/teams/my-team/files/%2e%2e/etc/shadow",
+        ] {
+            test_app(config_path.clone())
+                .oneshot(
+                    Request::builder()
+                        .method("PUT")
+                        .uri(uri)
+                        .header("content-type", "application/json")
+                        .body(Body::from(
+                            serde_json::json!({ "content": "# Updated\n" }).to_string(),
+                        ))
+                        .unwrap(),
+                )
+                .await
+                .unwrap();
+        }
+
+        let log = audit::log_path(config_path.parent().unwrap(), "my-team");
+        let entries = audit::load(&log).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].actor, Actor::console());
+        assert_eq!(entries[0].action, "files.write");
+        assert_eq!(entries[0].target.as_deref(), Some("PROCESS.md"));
+        assert_eq!(entries[0].outcome, audit::Outcome::Ok);
+        assert!(entries[0].params.contains_key("commit"));
+        assert!(!entries[0].params.values().any(|v| *v == "# Updated\n"));
+        assert_eq!(entries[1].outcome, audit::Outcome::Error);
+        assert_eq!(audit::verify(&entries).unwrap(), 2);
+    }
+
     #[tokio::test]
     async fn tree_listing_returns_sorted_entries() {
         let tmp = tempfile::tempdir().unwrap();
@@ -0,0 +1,278 @@
+use std::path::PathBuf;
+
+use axum::extract::{Path as AxumPath, Query, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::Json;
+use serde::Deserialize;
+
+use super::state::WebState;
+use crate::config;
+use crate::knowledge::{self, DocKind, KnowledgeResolution, Scope, SearchFilter, SearchHit};
+
+/// GET /api/teams/:team/members/:name/knowledge?project=P — returns the
+/// knowledge and invariants the member's agent sees, in resolution order.
+pub async fn member_knowledge(
+    State(state): State<WebState>,
+    AxumPath((team_name, member_name)): AxumPath<(String, String)>,
+    Query(params): Query<KnowledgeQuery>,
+) -> impl IntoResponse {
+    match build_resolution(&state, &team_name, &member_name, params.project.as_deref()) {
+        Ok(resolution) => (StatusCode::OK, Json(serde_json::json!(resolution))).into_response(),
+        Err(e) => {
+            let status = if e.to_string().contains("not found") {
+                StatusCode::NOT_FOUND
+            } else if e.to_string().starts_with("Invalid") {
+                StatusCode::BAD_REQUEST
+            } else {
+                StatusCode::INTERNAL_SERVER_ERROR
+            };
+            (status, Json(serde_json::json!({ "error": e.to_string() }))).into_response()
+        }
+    }
+}
+
+/// GET /api/teams/:team/search?q=...&scope=&member=&project=&kind=&limit= —
+/// full-text search over knowledge, invariants, prompts and hats.
+pub async fn team_search(
+    State(state): State<WebState>,
+    AxumPath(team_name): AxumPath<String>,
+    Query(params): Query<SearchQuery>,
+) -> impl IntoResponse {
+    if params.q.trim().is_empty() {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({ "error": "Missing search query 'q'" })),
+        )
+            .into_response();
+    }
+    match do_search(&state, &team_name, &params) {
+        Ok(hits) => (
+            StatusCode::OK,
+            Json(serde_json::json!({ "query": params.q, "results": hits })),
+        )
+            .into_response(),
+        Err(e) => {
+            let status = if e.to_string().contains("not found") {
+                StatusCode::NOT_FOUND
+            } else if e.to_string().starts_with("Unknown") || e.to_string().starts_with("Search") {
+                StatusCode::BAD_REQUEST
+            } else {
+                StatusCode::INTERNAL_SERVER_ERROR
+            };
+            (status, Json(serde_json::json!({ "error": e.to_string() }))).into_response()
+        }
+    }
+}
+
+fn resolve_team_repo(state: &WebState, team_name: &str) -> anyhow::Result<PathBuf> {
+    let cfg = config::load_from(&state.config_path)?;
+    let team = cfg
+        .teams
+        .iter()
+        .find(|t| t.name == team_name)
+        .ok_or_else(|| anyhow::anyhow!("Team '{}' not found", team_name))?;
+    Ok(team.path.join("team"))
+}
+
+fn build_resolution(
+    state: &WebState,
+    team_name: &str,
+    member_name: &str,
+    project: Option<&str>,
+) -> anyhow::Result<KnowledgeResolution> {
+    // Both names become path segments under the team repo.
+    for (kind, name) in [("member", Some(member_name)), ("project", project)] {
+        if let Some(name) = name {
+            if name.starts_with('.') || name.contains(['/', '\\']) {
+                anyhow::bail!("Invalid {} name '{}'", kind, name);
+            }
+        }
+    }
+    let team_repo = resolve_team_repo(state, team_name)?;
+    knowledge::resolve_knowledge(&team_repo, member_name, project)
+}
+
+fn do_search(
+    state: &WebState,
+    team_name: &str,
+    params: &SearchQuery,
+) -> anyhow::Result<Vec<SearchHit>> {
+    let team_repo = resolve_team_repo(state, team_name)?;
+    let filter = SearchFilter {
+        scope: params
+            .scope
+            .as_deref()
+            .map(str::parse::<Scope>)
+            .transpose()?,
+        member: params.member.clone(),
+        project: params.project.clone(),
+        kind: params
+            .kind
+            .as_deref()
+            .map(str::parse::<DocKind>)
+            .transpose()?,
+    };
+    knowledge::search_team(&team_repo, &params.q, &filter, params.limit.unwrap_or(20))
+}
+
+#[derive(Deserialize)]
+pub struct KnowledgeQuery {
+    pub project: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct SearchQuery {
+    #[serde(default)]
+    pub q: String,
+    pub scope: Option<String>,
+    pub member: Option<String>,
+    pub project: Option<String>,
+    pub kind: Option<String>,
+    pub limit: Option<usize>,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::Path;
+    use std::sync::Arc;
+
+    use axum::body::Body;
+    use axum::http::Request;
+    use tower::ServiceExt;
+
+    use super::*;
+    use crate::web::web_router;
+
+    fn write(repo: &Path, rel: &str) {
+        let path = repo.join(rel);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, "Prefer small functions.").unwrap();
+    }
+
+    fn setup(tmp: &Path) -> std::path::PathBuf {
+        let team_path = tmp.join("my-team");
+        let repo = team_path.join("team");
+        write(&repo, "knowledge/style.md");
+        write(&repo, "projects/api/knowledge/style.md");
+        write(&repo, "members/dev-alice/invariants/tests.md");
+        crate::git::run_git(&repo, &["init", "-q", "-b", "main"]).unwrap();
+
+        let config_path = tmp.join(".botminter").join("config.yml");
+        let cfg = config::BotminterConfig {
+            workzone: tmp.to_path_buf(),
+            default_team: Some("my-team".to_string()),
+            teams: vec![config::TeamEntry {
+                name: "my-team".to_string(),
+                path: team_path,
+                profile: "scrum".to_string(),
+                github_repo: "org/test".to_string(),
+                credentials: config::Credentials::default(),
+                coding_agent: None,
+                project_number: None,
+                bridge_lifecycle: Default::default(),
+                vm: None,
+                profile_source: None,
+                forge: None,
+            }],
+            vms: Vec::new(),
+            keyring_collection: None,
+        };
+        config::save_to(&config_path, &cfg).unwrap();
+        config_path
+    }
+
+    async fn get(config_path: std::path::PathBuf, uri: &str) -> (StatusCode, serde_json::Value) {
+        let app = web_router(WebState {
+            config_path: Arc::new(config_path),
+        });
+        let resp = app
+            .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let status = resp.status();
+        let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    #[tokio::test]
+    async fn member_knowledge_reports_shadowing() {
+        let tmp = tempfile::tempdir().unwrap();
+        let config_path = setup(tmp.path());
+
+        let (status, json) = get(
+            config_path,
+            "/api/teams/my-team/members/dev-alice/knowledge?project=api",
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json["project"], "api");
+        let knowledge = json["knowledge"].as_array().unwrap();
+        assert_eq!(knowledge.len(), 2);
+        assert_eq!(
+            knowledge[0]["shadowed_by"],
+            "projects/api/knowledge/style.md"
+        );
+        assert_eq!(knowledge[1]["scope"], "project");
+        assert_eq!(json["invariants"][0]["scope"], "member");
+    }
+
+    #[tokio::test]
+    async fn member_knowledge_unknown_member_is_404() {
+        let tmp = tempfile::tempdir().unwrap();
+        let config_path = setup(tmp.path());
+
+        let (status, json) = get(config_path, "/api/teams/my-team/members/nobody/knowledge").await;
+
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert!(json["error"].as_str().unwrap().contains("dev-alice"));
+    }
+
+    #[tokio::test]
+    async fn member_knowledge_rejects_path_traversal() {
+        let tmp = tempfile::tempdir().unwrap();
+        let config_path = setup(tmp.path());
+        write(&tmp.path().join("my-team/team"), "members/dev-alice/knowledge/secret.md");
+
+        for project in ["..", "../members/dev-alice", "api%2F..%2F.."] {
+            let (status, json) = get(
+                config_path.clone(),
+                &format!("/api/teams/my-team/members/dev-alice/knowledge?project={}", project),
+            )
+            .await;
+            assert_eq!(status, StatusCode::BAD_REQUEST, "{}", project);
+            assert!(json["error"].as_str().unwrap().contains("Invalid project name"));
+        }
+    }
+
+    #[tokio::test]
+    async fn search_returns_hits_with_snippets() {
+        let tmp = tempfile::tempdir().unwrap();
+        let config_path = setup(tmp.path());
+
+        let (status, json) = get(
+            config_path.clone(),
+            "/api/teams/my-team/search?q=functions&scope=project",
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let results = json["results"].as_array().unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0]["path"], "projects/api/knowledge/style.md");
+        assert_eq!(results[0]["kind"], "knowledge");
+        assert_eq!(results[0]["snippet"], "Prefer small functions.");
+
+        let (status, _) = get(config_path.clone(), "/api/teams/my-team/search").await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        let (status, _) = get(
+            config_path,
+            "/api/teams/my-team/search?q=functions&kind=bogus",
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+}
@@ -9,6 +9,8 @@ use serde::Serialize;
 
 use super::state::WebState;
 use crate::config;
+use crate::daemon::{self, TokenHealthInfo, TokenHealthMap};
+use crate::state::RuntimeState;
 
 /// GET /api/teams/:team/members — returns list of members with summary info.
 pub async fn list_members(
@@ -61,7 +63,19 @@ fn build_members_list(
     team_name: &str,
 ) -> anyhow::Result<Vec<MemberSummaryResponse>> {
     let team_path = resolve_team_path(state, team_name)?;
-    scan_members(&team_path)
+    // The daemon records token health next to config.yml.
+    let token_health = state
+        .config_path
+        .parent()
+        .map(|dir| daemon::load_token_health(&daemon::token_health_path(dir, team_name)))
+        .unwrap_or_default();
+    // Runtime state (PIDs, pause) also lives next to config.yml.
+    let runtime_state = state
+        .config_path
+        .parent()
+        .and_then(|dir| crate::state::load_from(&crate::state::state_path_in(dir)).ok())
+        .unwrap_or_default();
+    scan_members(&team_path, team_name, &token_health, &runtime_state)
 }
 
 fn build_member_detail(
@@ -130,7 +144,13 @@ fn build_member_detail(
 }
 
 /// Scans the `members/` directory for each member's summary.
-fn scan_members(team_path: &Path) -> anyhow::Result<Vec<MemberSummaryResponse>> {
+fn scan_members(
+    team_path: &Path,
+    team_name: &str,
+    token_health: &TokenHealthMap,
+    runtime_state: &RuntimeState,
+) -> anyhow::Result<Vec<MemberSummaryResponse>> {
+    let now = chrono::Utc::now();
     let members_dir = team_path.join("members");
     if !members_dir.is_dir() {
         return Ok(Vec::new());
@@ -139,7 +159,7 @@ fn scan_members(team_path: &Path) -> anyhow::Result<Vec<MemberSummaryResponse>>
     let mut members = Vec::new();
     let mut entries: Vec<_> = fs::read_dir(&members_dir)?
         .filter_map(|e| e.ok())
-        .filter(|e| e.path().is_dir())
+        .filter(|e| e.path().is_dir() && !e.file_name().to_string_lossy().starts_with('.'))
         .collect();
     entries.sort_by_key(|e| e.file_name());
 
@@ -171,12 +191,24 @@ fn scan_members(team_path: &Path) -> anyhow::Result<Vec<MemberSummaryResponse>>
         let has_ralph_yml = ralph_path.exists();
         let hat_count = count_hats(&ralph_path);
 
+        let token_health = token_health
+            .get(&member_name)
+            .map(|h| TokenHealthInfo::from_health(h, now));
+
+        let paused_at = runtime_state
+            .members
+            .get(&format!("{}/{}", team_name, member_name))
+            .filter(|rt| crate::state::is_alive(rt.pid))
+            .and_then(|rt| rt.paused_at.clone());
+
         members.push(MemberSummaryResponse {
             name: member_name,
             role,
             comment_emoji,
             has_ralph_yml,
             hat_count,
+            token_health,
+            paused_at,
         });
     }
 
@@ -298,6 +330,10 @@ pub struct MemberSummaryResponse {
     pub comment_emoji: String,
     pub has_ralph_yml: bool,
     pub hat_count: usize,
+    /// App token health recorded by the daemon (`None` if not tracked).
+    pub token_health: Option<TokenHealthInfo>,
+    /// When the member was paused with `bm pause` (`None` if not paused).
+    pub paused_at: Option<String>,
 }
 
 #[derive(Serialize)]
@@ -382,6 +418,8 @@ mod tests {
                 project_number: None,
                 bridge_lifecycle: Default::default(),
                 vm: None,
+                profile_source: None,
+                forge: None,
             }],
             vms: Vec::new(),
             keyring_collection: None,
@@ -436,6 +474,94 @@ mod tests {
         assert_eq!(alice["role"], "superman");
         assert!(alice["has_ralph_yml"].as_bool().unwrap());
         assert!(alice["hat_count"].as_u64().unwrap() > 0);
+        assert!(alice["token_health"].is_null());
+    }
+
+    #[tokio::test]
+    async fn members_list_includes_daemon_token_health() {
+        let tmp = tempfile::tempdir().unwrap();
+        let team_path = setup_fixture_team(tmp.path());
+        let config_path = tmp.path().join(".botminter").join("config.yml");
+        write_config(&config_path, "my-team", &team_path, "agentic-sdlc-minimal", "org/test");
+        let expired = (chrono::Utc::now() - chrono::Duration::minutes(5)).to_rfc3339();
+        fs::write(
+            daemon::token_health_path(config_path.parent().unwrap(), "my-team"),
+            serde_json::json!({
+                "superman-alice": {
+                    "expires_at": expired,
+                    "consecutive_failures": 6,
+                    "last_error": "401 Bad credentials",
+                }
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        let app = test_app(config_path);
+        let resp = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api/teams/my-team/members")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let members: Vec<serde_json::Value> = serde_json::from_slice(&body).unwrap();
+        let alice = members.iter().find(|m| m["name"] == "superman-alice").unwrap();
+        assert_eq!(alice["token_health"]["state"], "expired");
+        assert_eq!(alice["token_health"]["consecutive_failures"], 6);
+        assert_eq!(alice["token_health"]["last_error"], "401 Bad credentials");
+    }
+
+    #[tokio::test]
+    async fn members_list_includes_pause_state() {
+        let tmp = tempfile::tempdir().unwrap();
+        let team_path = setup_fixture_team(tmp.path());
+        let config_path = tmp.path().join(".botminter").join("config.yml");
+        write_config(&config_path, "my-team", &team_path, "agentic-sdlc-minimal", "org/test");
+        fs::write(
+            crate::state::state_path_in(config_path.parent().unwrap()),
+            serde_json::json!({
+                "members": {
+                    "my-team/superman-alice": {
+                        // Our own PID, so the member counts as alive
+                        "pid": std::process::id(),
+                        "started_at": "2026-03-24T09:00:00Z",
+                        "workspace": "/tmp/ws",
+                        "paused_at": "2026-03-24T10:00:00Z",
+                    }
+                }
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        let app = test_app(config_path);
+        let resp = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api/teams/my-team/members")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let members: Vec<serde_json::Value> = serde_json::from_slice(&body).unwrap();
+        let alice = members.iter().find(|m| m["name"] == "superman-alice").unwrap();
+        assert_eq!(alice["paused_at"], "2026-03-24T10:00:00Z");
+        let bob = members.iter().find(|m| m["name"] == "superman-bob").unwrap();
+        assert!(bob["paused_at"].is_null());
     }
 
     #[tokio::test]
@@ -1,6 +1,8 @@
 #[cfg(feature = "console")]
 pub mod assets;
+pub mod deliveries;
 pub mod files;
+pub mod knowledge;
 pub mod members;
 pub mod overview;
 pub mod process;
@@ -11,7 +13,9 @@ pub mod teams;
 use axum::routing::{get, post};
 use axum::Router;
 
+use self::deliveries::{get_delivery, list_deliveries};
 use self::files::{list_tree, read_file, write_file};
+use self::knowledge::{member_knowledge, team_search};
 use self::members::{get_member, list_members};
 use self::overview::team_overview;
 use self::process::team_process;
@@ -27,12 +31,19 @@ pub fn web_router(state: WebState) -> Router {
         .route("/api/teams/{team}/process", get(team_process))
         .route("/api/teams/{team}/members", get(list_members))
         .route("/api/teams/{team}/members/{name}", get(get_member))
+        .route(
+            "/api/teams/{team}/members/{name}/knowledge",
+            get(member_knowledge),
+        )
+        .route("/api/teams/{team}/search", get(team_search))
         .route("/api/teams/{team}/tree", get(list_tree))
         .route(
             "/api/teams/{team}/files/{*path}",
             get(read_file).put(write_file),
         )
         .route("/api/teams/{team}/sync", post(team_sync))
+        .route("/api/teams/{team}/deliveries", get(list_deliveries))
+        .route("/api/teams/{team}/deliveries/{id}", get(get_delivery))
         .with_state(state);
 
     // When built with the `console` feature, serve embedded frontend assets
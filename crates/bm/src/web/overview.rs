@@ -9,6 +9,7 @@ use serde::Serialize;
 
 use super::state::WebState;
 use crate::config;
+use crate::git::GitHubHost;
 use crate::profile::ProfileManifest;
 
 /// GET /api/teams/:team/overview — returns rich team overview data.
@@ -78,6 +79,10 @@ fn build_overview(state: &WebState, team_name: &str) -> anyhow::Result<TeamOverv
         description: manifest.description.clone(),
         version: manifest.version.clone(),
         github_repo: team.github_repo.clone(),
+        repo_url: match &team.forge {
+            Some(forge) => format!("{}/{}", forge.url.trim_end_matches('/'), team.github_repo),
+            None => GitHubHost::for_team(team).repo_url(&team.github_repo),
+        },
         default_coding_agent: default_coding_agent_display,
         roles: manifest
             .roles
@@ -114,7 +119,7 @@ fn scan_members(team_path: &Path) -> anyhow::Result<Vec<MemberSummaryResponse>>
     let mut members = Vec::new();
     let mut entries: Vec<_> = fs::read_dir(&members_dir)?
         .filter_map(|e| e.ok())
-        .filter(|e| e.path().is_dir())
+        .filter(|e| e.path().is_dir() && !e.file_name().to_string_lossy().starts_with('.'))
         .collect();
     entries.sort_by_key(|e| e.file_name());
 
@@ -205,6 +210,8 @@ pub struct TeamOverviewResponse {
     pub description: String,
     pub version: String,
     pub github_repo: String,
+    /// Browser URL of the team repo on its GitHub host or forge.
+    pub repo_url: String,
     pub default_coding_agent: Option<String>,
     pub roles: Vec<RoleResponse>,
     pub members: Vec<MemberSummaryResponse>,
@@ -308,6 +315,8 @@ mod tests {
                 project_number: None,
                 bridge_lifecycle: Default::default(),
                 vm: None,
+                profile_source: None,
+                forge: None,
             }],
             vms: Vec::new(),
             keyring_collection: None,
@@ -358,6 +367,7 @@ mod tests {
         assert_eq!(overview["description"], manifest.description);
         assert_eq!(overview["version"], manifest.version);
         assert_eq!(overview["github_repo"], github_repo);
+        assert_eq!(overview["repo_url"], "https://github.com/myorg/my-team");
         assert!(overview["default_coding_agent"].is_string());
 
         // Roles — count and names should match manifest
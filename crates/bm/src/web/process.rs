@@ -172,6 +172,8 @@ mod tests {
                 project_number: None,
                 bridge_lifecycle: Default::default(),
                 vm: None,
+                profile_source: None,
+                forge: None,
             }],
             vms: Vec::new(),
             keyring_collection: None,
@@ -47,7 +47,9 @@ pub async fn team_sync(
     }
 }
 
-fn do_sync(
+/// Runs a workspace sync for `team_name` (no git push, no bridge provisioning).
+/// Shared with the MCP server's `teams_sync` tool.
+pub fn do_sync(
     config_path: &std::path::Path,
     team_name: &str,
 ) -> anyhow::Result<SyncResponse> {
@@ -77,6 +79,7 @@ fn do_sync(
         .parent()
         .ok_or_else(|| anyhow::anyhow!("Team repo has no parent directory"))?;
 
+    let github_host = crate::git::GitHubHost::for_team(team);
     let params = workspace::TeamSyncParams {
         team_repo: &team_repo,
         team_path,
@@ -84,6 +87,8 @@ fn do_sync(
         manifest: &manifest,
         coding_agent,
         github_repo: gh,
+        forge: team.forge.as_ref(),
+        github_host: &github_host,
         repos: false,
         verbose: true,
         bridge_flag: false,
@@ -107,6 +112,12 @@ fn do_sync(
             workspace::TeamSyncEvent::NoMembers => {
                 changed_files.push("no members found".to_string());
             }
+            workspace::TeamSyncEvent::BrainPromptFailed { member, error } => {
+                changed_files.push(format!("{}: brain prompt error: {}", member, error));
+            }
+            workspace::TeamSyncEvent::McpServerFailed { member, error } => {
+                changed_files.push(format!("{}: MCP server not registered: {}", member, error));
+            }
             _ => {}
         }
     }
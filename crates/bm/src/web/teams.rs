@@ -80,6 +80,8 @@ mod tests {
                     project_number: None,
                     bridge_lifecycle: Default::default(),
                     vm: None,
+                    profile_source: None,
+                    forge: None,
                 },
                 config::TeamEntry {
                     name: "beta".to_string(),
@@ -94,6 +96,8 @@ mod tests {
                     project_number: Some(42),
                     bridge_lifecycle: Default::default(),
                     vm: None,
+                    profile_source: None,
+                    forge: None,
                 },
             ],
             vms: Vec::new(),
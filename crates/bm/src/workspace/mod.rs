@@ -9,7 +9,9 @@ pub use repo::{
     RemoteRepoState, WorkspaceRepoParams,
 };
 pub use robot::{inject_robot_config, inject_robot_enabled, RobotBridgeConfig};
-pub use sync::{find_workspace, list_member_dirs, sync_workspace, SyncEvent, SyncResult};
+pub use sync::{
+    find_workspace, list_member_dirs, read_workspace_member, sync_workspace, SyncEvent, SyncResult,
+};
 pub use team_sync::{sync_team_workspaces, TeamSyncEvent, TeamSyncParams, TeamSyncResult};
 pub use util::{
     workspace_git_branch, workspace_remote_url, workspace_submodule_status, SubmoduleState,
@@ -4,6 +4,7 @@ use std::process::Command;
 
 use anyhow::{bail, Context, Result};
 
+use crate::git::GitHubHost;
 use crate::profile::CodingAgentDef;
 use super::sync::write_workspace_marker;
 use super::util::{git_cmd, git_submodule_add, symlink_md_files, symlink_subdirs};
@@ -33,19 +34,34 @@ pub trait RemoteRepoOps {
     fn clone_repo(&self, repo_name: &str, target: &Path, recursive: bool) -> Result<()>;
     /// Push from a local path to the remote.
     fn push_repo(&self, local_path: &Path) -> Result<()>;
+    /// HTTPS clone URL for a repo on this remote.
+    fn clone_url(&self, repo_name: &str) -> String {
+        format!("https://github.com/{}.git", repo_name)
+    }
 }
 
 /// Production implementation backed by `gh` and `git` CLI calls.
 pub struct GhRemoteOps {
     pub gh_token: String,
+    /// github.com or the team's GHES host.
+    pub host: GitHubHost,
+}
+
+impl GhRemoteOps {
+    /// A `gh` command pointed at this host with this token.
+    fn gh(&self, args: &[&str]) -> Command {
+        let mut cmd = Command::new("gh");
+        cmd.args(args);
+        self.host.apply(&mut cmd, &self.gh_token);
+        cmd
+    }
 }
 
 impl RemoteRepoOps for GhRemoteOps {
     fn repo_state(&self, repo_name: &str) -> Result<RemoteRepoState> {
         // First check if the repo exists at all
-        let view = Command::new("gh")
-            .args(["repo", "view", repo_name, "--json", "name"])
-            .env("GH_TOKEN", &self.gh_token)
+        let view = self
+            .gh(&["repo", "view", repo_name, "--json", "name"])
             .output()
             .context("Failed to run `gh repo view`")?;
 
@@ -54,10 +70,10 @@ impl RemoteRepoOps for GhRemoteOps {
         }
 
         // Repo exists — check if it has any commits via git ls-remote
-        let url = format!("https://github.com/{}.git", repo_name);
+        let url = self.clone_url(repo_name);
         let ls = Command::new("git")
             .args(["ls-remote", "--heads", &url])
-            .env("GH_TOKEN", &self.gh_token)
+            .env(self.host.token_env(), &self.gh_token)
             .output()
             .context("Failed to run `git ls-remote`")?;
 
@@ -75,9 +91,8 @@ impl RemoteRepoOps for GhRemoteOps {
     }
 
     fn create_repo(&self, repo_name: &str) -> Result<()> {
-        let output = Command::new("gh")
-            .args(["repo", "create", repo_name, "--private"])
-            .env("GH_TOKEN", &self.gh_token)
+        let output = self
+            .gh(&["repo", "create", repo_name, "--private"])
             .output()
             .context("Failed to run `gh repo create`")?;
 
@@ -97,16 +112,13 @@ impl RemoteRepoOps for GhRemoteOps {
     }
 
     fn delete_repo(&self, repo_name: &str) -> Result<()> {
-        let _ = Command::new("gh")
-            .args(["repo", "delete", repo_name, "--yes"])
-            .env("GH_TOKEN", &self.gh_token)
-            .output();
+        let _ = self.gh(&["repo", "delete", repo_name, "--yes"]).output();
         // Best-effort — ignore errors
         Ok(())
     }
 
     fn clone_repo(&self, repo_name: &str, target: &Path, recursive: bool) -> Result<()> {
-        let url = format!("https://github.com/{}.git", repo_name);
+        let url = self.clone_url(repo_name);
         let target_str = target.to_string_lossy().to_string();
         let parent = target.parent().unwrap_or(Path::new("."));
 
@@ -129,6 +141,10 @@ impl RemoteRepoOps for GhRemoteOps {
     fn push_repo(&self, local_path: &Path) -> Result<()> {
         git_cmd(local_path, &["push", "-u", "origin", "main"])
     }
+
+    fn clone_url(&self, repo_name: &str) -> String {
+        self.host.clone_url(repo_name)
+    }
 }
 
 /// Parameters for creating a workspace repo with submodules.
@@ -236,10 +252,13 @@ pub fn create_workspace_repo(params: &WorkspaceRepoParams) -> Result<()> {
         // Explicit override (used by tests)
         url.to_string()
     } else if params.push {
-        // Use the GitHub URL for the team repo
+        // Use the remote URL for the team repo
         params
             .github_repo
-            .map(|r| format!("https://github.com/{}.git", r))
+            .map(|r| match params.remote_ops {
+                Some(ops) => ops.clone_url(r),
+                None => format!("https://github.com/{}.git", r),
+            })
             .unwrap_or_else(|| {
                 fs::canonicalize(params.team_repo_path)
                     .unwrap_or_else(|_| params.team_repo_path.to_path_buf())
@@ -517,6 +536,9 @@ pub(super) mod tests {
             binary: "claude".into(),
             system_prompt_flag: Some("--append-system-prompt-file".into()),
             skip_permissions_flag: Some("--dangerously-skip-permissions".into()),
+            mcp_config_file: Some(".mcp.json".into()),
+            mcp_config_flag: Some("--mcp-config".into()),
+            mcp_mode: None,
         }
     }
 
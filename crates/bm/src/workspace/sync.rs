@@ -71,6 +71,16 @@ pub(super) fn write_workspace_marker(ws_root: &Path, member_dir_name: &str) -> R
         .context("Failed to write .botminter.workspace marker")
 }
 
+/// Reads the member name from a workspace's `.botminter.workspace` marker.
+pub fn read_workspace_member(ws_root: &Path) -> Option<String> {
+    let content = fs::read_to_string(ws_root.join(".botminter.workspace")).ok()?;
+    content
+        .lines()
+        .find_map(|l| l.strip_prefix("member:"))
+        .map(|m| m.trim().to_string())
+        .filter(|m| !m.is_empty())
+}
+
 /// Syncs an existing workspace by updating submodules, re-copying context files,
 /// re-assembling agent directory, and committing+pushing any changes.
 ///
@@ -268,6 +278,14 @@ mod tests {
         (ws, member.to_string(), agent)
     }
 
+    #[test]
+    fn read_workspace_member_round_trips_marker() {
+        let tmp = tempfile::tempdir().unwrap();
+        assert_eq!(read_workspace_member(tmp.path()), None);
+        write_workspace_marker(tmp.path(), "dev-alice").unwrap();
+        assert_eq!(read_workspace_member(tmp.path()).as_deref(), Some("dev-alice"));
+    }
+
     #[test]
     fn sync_recopies_changed_ralph_yml() {
         let tmp = tempfile::tempdir().unwrap();
@@ -1,13 +1,18 @@
 use std::fs;
 use std::path::{Path, PathBuf};
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 
+use crate::agent_tags::{self, TagContext};
+use crate::audit::Actor;
 use crate::brain;
 use crate::bridge::{self, Bridge, LocalCredentialStore};
+use crate::config::{ForgeConfig, ForgeKind};
+use crate::git::GitHubHost;
+use crate::mcp;
 use crate::profile::{self, CodingAgentDef, ProfileManifest};
 use crate::workspace;
-use crate::workspace::GhRemoteOps;
+use crate::workspace::{GhRemoteOps, RemoteRepoOps};
 
 // ── Sync parameters ─────────────────────────────────────────────────
 
@@ -19,6 +24,10 @@ pub struct TeamSyncParams<'a> {
     pub manifest: &'a ProfileManifest,
     pub coding_agent: &'a CodingAgentDef,
     pub github_repo: Option<&'a str>,
+    /// Team's forge; `None` means GitHub.
+    pub forge: Option<&'a ForgeConfig>,
+    /// GitHub host for GitHub teams (github.com or GHES).
+    pub github_host: &'a GitHubHost,
     pub repos: bool,
     pub verbose: bool,
     pub bridge_flag: bool,
@@ -51,6 +60,11 @@ pub enum TeamSyncEvent {
     WorkspaceCreateFailed { name: String, error: String },
     RobotInjected { member: String, enabled: bool },
     BrainPromptSurfaced { member: String },
+    BrainPromptFailed { member: String, error: String },
+    McpServerRegistered { member: String },
+    McpServerFailed { member: String, error: String },
+    SearchIndexUpdated { indexed: usize, removed: usize },
+    SearchIndexFailed { error: String },
 }
 
 // ── Sync orchestration ──────────────────────────────────────────────
@@ -74,6 +88,17 @@ pub fn sync_team_workspaces(params: &TeamSyncParams) -> Result<TeamSyncResult> {
         provision_bridge(params, &bridge_dir, &mut events)?;
     }
 
+    // Keep the knowledge search index current with the team repo
+    match crate::knowledge::refresh_index(params.team_repo) {
+        Ok(stats) => events.push(TeamSyncEvent::SearchIndexUpdated {
+            indexed: stats.indexed,
+            removed: stats.removed,
+        }),
+        Err(e) => events.push(TeamSyncEvent::SearchIndexFailed {
+            error: format!("{:#}", e),
+        }),
+    }
+
     // Discover hired members
     let members = profile::discover_member_dirs(params.team_repo);
     if members.is_empty() {
@@ -97,11 +122,26 @@ pub fn sync_team_workspaces(params: &TeamSyncParams) -> Result<TeamSyncResult> {
     // Set up bridge context for RObot injection
     let robot_context = build_robot_context(params, &bridge_dir)?;
 
+    // Tag context for workspace files; the formation is only known now
+    let formation = crate::formation::resolve_formation(params.team_repo, None)?;
+    let tags = profile::team_tag_context(params.team_repo, params.coding_agent)
+        .with_opt("formation", formation.as_deref());
+
     // Build remote ops for push mode
-    let gh_ops = if params.repos {
-        crate::git::detect_token().map(|token| GhRemoteOps {
-            gh_token: token,
-        })
+    let remote_ops: Option<Box<dyn RemoteRepoOps>> = if params.repos {
+        match params.forge {
+            Some(f) if f.kind == ForgeKind::Gitea => match crate::forge::detect_token(Some(f)) {
+                Some(token) => Some(Box::new(crate::forge::GiteaForge::new(&f.url, &token)?)
+                    as Box<dyn RemoteRepoOps>),
+                None => None,
+            },
+            _ => params.github_host.detect_token().map(|token| {
+                Box::new(GhRemoteOps {
+                    gh_token: token,
+                    host: params.github_host.clone(),
+                }) as Box<dyn RemoteRepoOps>
+            }),
+        }
     } else {
         None
     };
@@ -143,7 +183,7 @@ pub fn sync_team_workspaces(params: &TeamSyncParams) -> Result<TeamSyncResult> {
                 github_repo: params.github_repo,
                 push: params.repos,
                 coding_agent: params.coding_agent,
-                remote_ops: gh_ops.as_ref().map(|o| o as &dyn workspace::RemoteRepoOps),
+                remote_ops: remote_ops.as_deref(),
                 team_submodule_url: None,
             };
             match workspace::create_workspace_repo(&ws_params) {
@@ -162,6 +202,8 @@ pub fn sync_team_workspaces(params: &TeamSyncParams) -> Result<TeamSyncResult> {
             }
         }
 
+        resolve_workspace_tags(&ws, params.coding_agent, &tags)?;
+
         // Inject RObot config
         if let Some(ref ctx) = robot_context {
             inject_robot_for_member(&ws, member_dir_name, ctx, params, &mut events)?;
@@ -176,7 +218,13 @@ pub fn sync_team_workspaces(params: &TeamSyncParams) -> Result<TeamSyncResult> {
             params.github_repo,
             params.verbose,
             &mut events,
+            &mut failures,
         );
+
+        // Brain members get botminter's MCP tools through the coding agent
+        if crate::formation::is_brain_member(&ws) {
+            register_mcp_for_member(&ws, member_dir_name, params, &mut events);
+        }
     }
 
     Ok(TeamSyncResult {
@@ -204,18 +252,13 @@ fn provision_bridge(
 
     // Discover members for bridge provisioning
     let members_dir = params.team_repo.join("members");
-    let mut bridge_members: Vec<bridge::BridgeMember> = Vec::new();
-    if members_dir.is_dir() {
-        for entry in fs::read_dir(&members_dir)? {
-            let entry = entry?;
-            if entry.file_type()?.is_dir() {
-                bridge_members.push(bridge::BridgeMember {
-                    name: entry.file_name().to_string_lossy().to_string(),
-                    is_operator: false,
-                });
-            }
-        }
-    }
+    let mut bridge_members: Vec<bridge::BridgeMember> = workspace::list_member_dirs(&members_dir)?
+        .into_iter()
+        .map(|name| bridge::BridgeMember {
+            name,
+            is_operator: false,
+        })
+        .collect();
 
     // Add operator to bridge members
     if let Some(op) = params.manifest.operator.as_ref() {
@@ -302,6 +345,59 @@ fn build_robot_context(
     }))
 }
 
+/// Resolves conditional tags left in the workspace copies of a member's files
+/// (blocks on dimensions unknown at hire time, such as the formation).
+///
+/// Covers every text file sync writes into the workspace: top-level files and
+/// copies under the coding agent's directory. Symlinks (skills, agents,
+/// commands) point into the `team/` submodule and are left alone, as are the
+/// submodules themselves.
+fn resolve_workspace_tags(ws: &Path, coding_agent: &CodingAgentDef, tags: &TagContext) -> Result<()> {
+    let mut files = regular_files(ws, false);
+    files.extend(regular_files(&ws.join(&coding_agent.agent_dir), true));
+
+    for path in files {
+        let name = path
+            .file_name()
+            .map(|f| f.to_string_lossy().to_string())
+            .unwrap_or_default();
+        if !profile::should_filter(&name) {
+            continue;
+        }
+        let Ok(content) = fs::read_to_string(&path) else {
+            continue;
+        };
+        let resolved = agent_tags::filter_file_with(&content, &name, tags);
+        if resolved != content {
+            fs::write(&path, resolved)
+                .with_context(|| format!("Failed to write {}", path.display()))?;
+        }
+    }
+    Ok(())
+}
+
+/// Regular files (not symlinks) in `dir`, optionally recursing into
+/// subdirectories.
+fn regular_files(dir: &Path, recursive: bool) -> Vec<PathBuf> {
+    let mut out = Vec::new();
+    let Ok(entries) = fs::read_dir(dir) else {
+        return out;
+    };
+    for entry in entries.flatten() {
+        let Ok(ft) = entry.file_type() else {
+            continue;
+        };
+        if ft.is_file() {
+            out.push(entry.path());
+        } else if ft.is_dir() && recursive {
+            out.extend(regular_files(&entry.path(), true));
+        }
+    }
+    out.sort();
+    out
+}
+
+#[allow(clippy::too_many_arguments)]
 fn surface_brain_prompt_for_member(
     team_repo: &Path,
     ws: &Path,
@@ -310,24 +406,9 @@ fn surface_brain_prompt_for_member(
     github_repo: Option<&str>,
     verbose: bool,
     events: &mut Vec<TeamSyncEvent>,
+    failures: &mut Vec<String>,
 ) {
-    let (gh_org, gh_repo_name) = github_repo
-        .and_then(brain::parse_github_repo)
-        .map(|(org, repo)| (org.to_string(), repo.to_string()))
-        .unwrap_or_default();
-
-    let role = brain::read_member_role(team_repo, member_dir_name)
-        .unwrap_or_default();
-    let member_name = brain::read_member_name(team_repo, member_dir_name);
-
-    // Read the template from the team repo root (extracted from profile)
-    let vars = brain::BrainPromptVars {
-        member_name,
-        team_name: team_name.to_string(),
-        role,
-        gh_org,
-        gh_repo: gh_repo_name,
-    };
+    let vars = brain::BrainPromptVars::gather(team_repo, member_dir_name, team_name, github_repo);
 
     match brain::surface_brain_prompt(team_repo, ws, &vars) {
         Ok(true) => {
@@ -339,15 +420,48 @@ fn surface_brain_prompt_for_member(
         }
         Ok(false) => {} // No template in profile — skip silently
         Err(e) => {
-            tracing::warn!(
-                member = member_dir_name,
-                error = %e,
-                "Failed to surface brain prompt"
-            );
+            // Template errors (syntax, unknown variables) fail the sync so
+            // they're caught here rather than as a broken prompt at runtime.
+            events.push(TeamSyncEvent::BrainPromptFailed {
+                member: member_dir_name.to_string(),
+                error: format!("{:#}", e),
+            });
+            failures.push(member_dir_name.to_string());
         }
     }
 }
 
+/// Registers `bm mcp serve` (pinned to this team) in the coding agent's
+/// project MCP config, read-only unless the coding agent sets `mcp_mode:
+/// operator`. Its actions are audited as the member's agent. A config we
+/// can't parse is left alone and reported as a warning.
+fn register_mcp_for_member(
+    ws: &Path,
+    member_dir_name: &str,
+    params: &TeamSyncParams,
+    events: &mut Vec<TeamSyncEvent>,
+) {
+    let Some(ref config_file) = params.coding_agent.mcp_config_file else {
+        return;
+    };
+    match mcp::register_server(
+        &ws.join(config_file),
+        &mcp::server_command(),
+        params.coding_agent.mcp_mode.unwrap_or_default(),
+        Some(params.team_name),
+        Some(&Actor::agent(member_dir_name)),
+    ) {
+        Ok(true) if params.verbose => events.push(TeamSyncEvent::McpServerRegistered {
+            member: member_dir_name.to_string(),
+        }),
+        Ok(_) => {}
+        Err(e) => events.push(TeamSyncEvent::McpServerFailed {
+            member: member_dir_name.to_string(),
+            error: format!("{:#}", e),
+        }),
+    }
+}
+
 fn inject_robot_for_member(
     ws: &Path,
     member_dir_name: &str,
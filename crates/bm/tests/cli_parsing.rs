@@ -116,6 +116,34 @@ fn force_flag_on_stop() {
     }
 }
 
+#[test]
+fn pause_resume_flags_parse() {
+    let tmp = tempfile::tempdir().unwrap();
+    for args in [
+        &["pause"][..],
+        &["pause", "architect-01", "--now", "-t", "my-team"][..],
+        &["pause", "--timeout", "60"][..],
+        &["resume"][..],
+        &["resume", "architect-01", "-t", "my-team"][..],
+    ] {
+        let output = bm(tmp.path()).args(args).output().unwrap();
+        assert_ne!(
+            output.status.code().unwrap_or(-1),
+            CLAP_PARSE_ERROR_CODE,
+            "`bm {}` should not be a parse error, stderr: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    let output = bm(tmp.path()).args(["pause", "--timeout", "soon"]).output().unwrap();
+    assert_eq!(
+        output.status.code().unwrap_or(-1),
+        CLAP_PARSE_ERROR_CODE,
+        "a non-numeric --timeout should be a parse error"
+    );
+}
+
 #[test]
 fn sync_repos_flag() {
     let tmp = tempfile::tempdir().unwrap();
@@ -268,6 +296,36 @@ fn hire_requires_role_argument() {
     );
 }
 
+#[test]
+fn profiles_update_without_sources_reports_none() {
+    let tmp = tempfile::tempdir().unwrap();
+    let output = bm(tmp.path())
+        .args(["profiles", "update", "--check"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(String::from_utf8_lossy(&output.stdout).contains("No profiles installed from git sources"));
+}
+
+#[test]
+fn profiles_create_requires_from_team() {
+    let tmp = tempfile::tempdir().unwrap();
+    let output = bm(tmp.path())
+        .args(["profiles", "create", "tuned"])
+        .output()
+        .unwrap();
+    assert_eq!(
+        output.status.code(),
+        Some(CLAP_PARSE_ERROR_CODE),
+        "bm profiles create without --from-team should exit with clap error code 2"
+    );
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--from-team"));
+}
+
 #[test]
 fn profiles_describe_requires_profile_name() {
     let tmp = tempfile::tempdir().unwrap();
@@ -432,7 +490,7 @@ fn subcommand_help_works() {
     );
 }
 
-// ── Daemon CLI parsing (4 tests) ─────────────────────────────────────
+// ── Daemon CLI parsing (5 tests) ─────────────────────────────────────
 
 #[test]
 fn daemon_subcommand_help() {
@@ -458,6 +516,7 @@ fn daemon_start_flags_parsed() {
         vec!["daemon", "start", "--mode", "webhook", "--port", "9999"],
         vec!["daemon", "start", "--interval", "30"],
         vec!["daemon", "start", "-t", "myteam"],
+        vec!["daemon", "start", "--webhook-url", "https://bm.example.com/webhook"],
     ] {
         let output = bm(tmp.path())
             .args(&args)
@@ -501,6 +560,69 @@ fn daemon_status_flags_parsed() {
     );
 }
 
+#[test]
+fn daemon_events_flags_parsed() {
+    let tmp = tempfile::tempdir().unwrap();
+    for args in [
+        &["daemon", "events", "list"][..],
+        &["daemon", "events", "list", "-n", "5", "-t", "myteam"][..],
+        &["daemon", "events", "show", "72d3162e", "-t", "myteam"][..],
+        &["daemon", "events", "replay", "72d3162e"][..],
+    ] {
+        let output = bm(tmp.path()).args(args).output().unwrap();
+        assert_ne!(
+            output.status.code().unwrap_or(-1),
+            CLAP_PARSE_ERROR_CODE,
+            "`bm {}` should not be a parse error, stderr: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    let output = bm(tmp.path())
+        .args(["daemon", "events", "replay"])
+        .output()
+        .unwrap();
+    assert_eq!(
+        output.status.code().unwrap_or(-1),
+        CLAP_PARSE_ERROR_CODE,
+        "replay without a delivery ID should be a parse error"
+    );
+}
+
+// ── Audit CLI parsing (1 test) ───────────────────────────────────────
+
+#[test]
+fn audit_flags_parsed() {
+    let tmp = tempfile::tempdir().unwrap();
+    for args in [
+        &["audit", "list"][..],
+        &["audit", "list", "--actor", "agent:dev-bob", "--action", "fire", "-n", "5"][..],
+        &["audit", "list", "--since", "2026-03-24", "-t", "myteam"][..],
+        &["audit", "verify", "-t", "myteam"][..],
+        &["audit", "export", "--format", "csv", "-o", "audit.csv"][..],
+    ] {
+        let output = bm(tmp.path()).args(args).output().unwrap();
+        assert_ne!(
+            output.status.code().unwrap_or(-1),
+            CLAP_PARSE_ERROR_CODE,
+            "`bm {}` should not be a parse error, stderr: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    let output = bm(tmp.path())
+        .args(["audit", "list", "-n", "many"])
+        .output()
+        .unwrap();
+    assert_eq!(
+        output.status.code().unwrap_or(-1),
+        CLAP_PARSE_ERROR_CODE,
+        "a non-numeric limit should be a parse error"
+    );
+}
+
 // ── Show/describe subcommand parsing (6 tests) ───────────────────────
 
 #[test]
@@ -573,6 +695,171 @@ fn members_show_with_member_parses() {
     );
 }
 
+#[test]
+fn members_refresh_flags_parse() {
+    let tmp = tempfile::tempdir().unwrap();
+    for args in [
+        &["members", "refresh", "architect-01", "--dry-run"][..],
+        &["members", "refresh", "--all", "-t", "my-team"][..],
+    ] {
+        let output = bm(tmp.path()).args(args).output().unwrap();
+        assert_ne!(
+            output.status.code().unwrap_or(-1),
+            CLAP_PARSE_ERROR_CODE,
+            "`bm {}` should not be a parse error, stderr: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+}
+
+#[test]
+fn members_doctor_flags_parse() {
+    let tmp = tempfile::tempdir().unwrap();
+    for args in [
+        &["members", "doctor"][..],
+        &["members", "doctor", "architect-01", "--fix", "-t", "my-team"][..],
+    ] {
+        let output = bm(tmp.path()).args(args).output().unwrap();
+        assert_ne!(
+            output.status.code().unwrap_or(-1),
+            CLAP_PARSE_ERROR_CODE,
+            "`bm {}` should not be a parse error, stderr: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+}
+
+#[test]
+fn members_archive_restore_flags_parse() {
+    let tmp = tempfile::tempdir().unwrap();
+    for args in [
+        &["members", "archive", "architect-01"][..],
+        &["members", "archive", "architect-01", "--reason", "between projects", "-t", "my-team"][..],
+        &["members", "restore", "architect-01"][..],
+        &["members", "restore", "architect-01", "-t", "my-team"][..],
+    ] {
+        let output = bm(tmp.path()).args(args).output().unwrap();
+        assert_ne!(
+            output.status.code().unwrap_or(-1),
+            CLAP_PARSE_ERROR_CODE,
+            "`bm {}` should not be a parse error, stderr: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    let output = bm(tmp.path()).args(["members", "restore"]).output().unwrap();
+    assert_eq!(
+        output.status.code().unwrap_or(-1),
+        CLAP_PARSE_ERROR_CODE,
+        "`bm members restore` without a member should be a parse error"
+    );
+}
+
+#[test]
+fn knowledge_resolve_flags_parse() {
+    let tmp = tempfile::tempdir().unwrap();
+    for args in [
+        &["knowledge", "resolve", "dev-alice"][..],
+        &["knowledge", "resolve", "dev-alice", "--project", "api", "-t", "my-team"][..],
+        &["knowledge", "resolve", "dev-alice", "-p", "api"][..],
+    ] {
+        let output = bm(tmp.path()).args(args).output().unwrap();
+        assert_ne!(
+            output.status.code().unwrap_or(-1),
+            CLAP_PARSE_ERROR_CODE,
+            "`bm {}` should not be a parse error, stderr: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    let output = bm(tmp.path()).args(["knowledge", "resolve"]).output().unwrap();
+    assert_eq!(output.status.code(), Some(CLAP_PARSE_ERROR_CODE));
+}
+
+#[test]
+fn knowledge_authoring_flags_parse() {
+    let tmp = tempfile::tempdir().unwrap();
+    for args in [
+        &["knowledge", "add", "style.md", "--from", "style.md"][..],
+        &[
+            "knowledge", "add", "tests", "--scope", "member-project", "-p", "api",
+            "--member", "dev-alice", "--invariant", "-m", "Add test rule",
+        ][..],
+        &["knowledge", "edit", "knowledge/style.md", "-t", "my-team"][..],
+        &["knowledge", "move", "knowledge/style.md", "--scope", "project", "-p", "api"][..],
+        &["knowledge", "rm", "knowledge/style.md", "--message", "Drop style"][..],
+    ] {
+        let output = bm(tmp.path()).args(args).output().unwrap();
+        assert_ne!(
+            output.status.code().unwrap_or(-1),
+            CLAP_PARSE_ERROR_CODE,
+            "`bm {}` should not be a parse error, stderr: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    let output = bm(tmp.path())
+        .args(["knowledge", "move", "knowledge/style.md"])
+        .output()
+        .unwrap();
+    assert_eq!(
+        output.status.code(),
+        Some(CLAP_PARSE_ERROR_CODE),
+        "move without --scope should be a parse error"
+    );
+}
+
+#[test]
+fn knowledge_search_flags_parse() {
+    let tmp = tempfile::tempdir().unwrap();
+    for args in [
+        &["knowledge", "search", "commit", "convention"][..],
+        &[
+            "knowledge", "search", "retry", "--scope", "project", "-p", "api", "--kind",
+            "knowledge", "--limit", "5", "-t", "my-team",
+        ][..],
+        &["knowledge", "search", "review", "--member", "dev-alice"][..],
+    ] {
+        let output = bm(tmp.path()).args(args).output().unwrap();
+        assert_ne!(
+            output.status.code().unwrap_or(-1),
+            CLAP_PARSE_ERROR_CODE,
+            "`bm {}` should not be a parse error, stderr: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    let output = bm(tmp.path()).args(["knowledge", "search"]).output().unwrap();
+    assert_eq!(output.status.code(), Some(CLAP_PARSE_ERROR_CODE));
+}
+
+#[test]
+fn invariants_check_flags_parse() {
+    let tmp = tempfile::tempdir().unwrap();
+    for args in [
+        &["invariants", "check"][..],
+        &["invariants", "check", "dev-alice", "-t", "my-team"][..],
+    ] {
+        let output = bm(tmp.path()).args(args).output().unwrap();
+        assert_ne!(
+            output.status.code().unwrap_or(-1),
+            CLAP_PARSE_ERROR_CODE,
+            "`bm {}` should not be a parse error, stderr: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    let output = bm(tmp.path()).args(["invariants"]).output().unwrap();
+    assert_eq!(output.status.code(), Some(CLAP_PARSE_ERROR_CODE));
+}
+
 #[test]
 fn projects_list_help_works() {
     let tmp = tempfile::tempdir().unwrap();
@@ -936,6 +1223,100 @@ fn init_without_bridge_flag_parses() {
     );
 }
 
+#[test]
+fn init_forge_flags_parse() {
+    let tmp = tempfile::tempdir().unwrap();
+    let output = bm(tmp.path())
+        .args([
+            "init",
+            "--non-interactive",
+            "--profile", "agentic-sdlc-minimal",
+            "--team-name", "test",
+            "--org", "testorg",
+            "--repo", "testrepo",
+            "--forge", "forgejo",
+            "--forge-url", "https://forgejo.example.com",
+            "--skip-github",
+        ])
+        .output()
+        .unwrap();
+    let code = output.status.code().unwrap_or(-1);
+    assert_ne!(
+        code, CLAP_PARSE_ERROR_CODE,
+        "`bm init --forge forgejo --forge-url ...` should not be a parse error, stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[test]
+fn init_github_host_flags_parse() {
+    let tmp = tempfile::tempdir().unwrap();
+    let output = bm(tmp.path())
+        .args([
+            "init",
+            "--non-interactive",
+            "--profile", "agentic-sdlc-minimal",
+            "--team-name", "test",
+            "--org", "testorg",
+            "--repo", "testrepo",
+            "--github-host", "github.acme.corp",
+            "--github-api-base", "https://github.acme.corp/api/v3",
+            "--skip-github",
+        ])
+        .output()
+        .unwrap();
+    let code = output.status.code().unwrap_or(-1);
+    assert_ne!(
+        code, CLAP_PARSE_ERROR_CODE,
+        "`bm init --github-host ... --github-api-base ...` should not be a parse error, stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[test]
+fn credentials_rotate_flags_parse() {
+    let tmp = tempfile::tempdir().unwrap();
+    let output = bm(tmp.path())
+        .args([
+            "credentials", "rotate", "superman",
+            "--key-file", "/tmp/new-key.pem",
+            "-t", "test",
+        ])
+        .output()
+        .unwrap();
+    let code = output.status.code().unwrap_or(-1);
+    assert_ne!(
+        code, CLAP_PARSE_ERROR_CODE,
+        "`bm credentials rotate <member> --key-file` should not be a parse error, stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let output = bm(tmp.path())
+        .args(["credentials", "rotate"])
+        .output()
+        .unwrap();
+    assert_eq!(
+        output.status.code().unwrap_or(-1),
+        CLAP_PARSE_ERROR_CODE,
+        "`bm credentials rotate` without a member should be a parse error"
+    );
+}
+
+#[test]
+fn hire_bot_token_conflicts_with_reuse_app() {
+    let tmp = tempfile::tempdir().unwrap();
+    let output = bm(tmp.path())
+        .args(["hire", "dev", "--bot-token", "tok", "--reuse-app"])
+        .output()
+        .unwrap();
+    assert_eq!(
+        output.status.code(),
+        Some(CLAP_PARSE_ERROR_CODE),
+        "--bot-token and --reuse-app should conflict, stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
 // ── Bridge CLI parsing (10 tests) ────────────────────────────────────
 
 #[test]
@@ -1293,3 +1674,87 @@ fn minty_team_flag_short_and_long() {
         );
     }
 }
+
+// ── MCP server (4 tests) ────────────────────────────────────────────
+
+#[test]
+fn mcp_serve_flags_parsed() {
+    let tmp = tempfile::tempdir().unwrap();
+    for args in [
+        vec!["mcp", "serve"],
+        vec!["mcp", "serve", "--mode", "operator"],
+        vec!["mcp", "serve", "--mode", "read-only", "-t", "my-team"],
+        vec!["mcp", "serve", "--team", "my-team"],
+        vec!["mcp", "serve", "--mode", "operator", "--actor", "agent:chief-ann"],
+    ] {
+        // stdin is closed, so the server exits as soon as it starts.
+        let output = bm(tmp.path()).args(&args).output().unwrap();
+        assert!(
+            output.status.success(),
+            "`bm {}` should exit 0, stderr: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+}
+
+#[test]
+fn mcp_serve_rejects_unknown_mode() {
+    let tmp = tempfile::tempdir().unwrap();
+    let output = bm(tmp.path())
+        .args(["mcp", "serve", "--mode", "admin"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Unknown MCP mode"), "stderr: {}", stderr);
+}
+
+#[test]
+fn mcp_serve_rejects_malformed_actor() {
+    let tmp = tempfile::tempdir().unwrap();
+    let output = bm(tmp.path())
+        .args(["mcp", "serve", "--actor", "chief-ann"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Invalid actor"), "stderr: {}", stderr);
+}
+
+#[test]
+fn mcp_serve_answers_initialize_and_tools_list() {
+    use std::io::Write;
+    use std::process::Stdio;
+
+    let tmp = tempfile::tempdir().unwrap();
+    let mut child = bm(tmp.path())
+        .args(["mcp", "serve"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    {
+        let mut stdin = child.stdin.take().unwrap();
+        writeln!(stdin, r#"{{"jsonrpc":"2.0","id":1,"method":"initialize","params":{{"protocolVersion":"2025-06-18"}}}}"#).unwrap();
+        writeln!(stdin, r#"{{"jsonrpc":"2.0","method":"notifications/initialized"}}"#).unwrap();
+        writeln!(stdin, r#"{{"jsonrpc":"2.0","id":2,"method":"tools/list"}}"#).unwrap();
+    }
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success());
+
+    let responses: Vec<serde_json::Value> = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(|l| serde_json::from_str(l).unwrap())
+        .collect();
+    assert_eq!(responses.len(), 2, "notification must not be answered");
+    assert_eq!(responses[0]["result"]["serverInfo"]["name"], "botminter");
+    let tools: Vec<&str> = responses[1]["result"]["tools"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|t| t["name"].as_str().unwrap())
+        .collect();
+    assert!(tools.contains(&"team_status"));
+    assert!(!tools.contains(&"loop_start"), "read-only mode hides operator tools");
+}
@@ -204,7 +204,7 @@ fn projects_add_fn(gh_org: String, _gh_token: String) -> impl Fn(&mut TestEnv) +
 
             let full_name = format!("{}/bm-e2e-project-{}", gh_org,
                 std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().as_secs());
-            bm::git::create_repo_and_push(&fork, &full_name)
+            bm::git::create_repo_and_push(&bm::git::GitHubHost::default(), &fork, &full_name)
                 .expect("failed to create project repo on GitHub");
             format!("https://github.com/{}.git", full_name)
         };
@@ -585,7 +585,7 @@ fn projects_sync_fn(gh_org: String, _gh_token: String) -> impl Fn(&mut TestEnv)
             .run();
         assert!(stdout.contains("Status field synced"));
 
-        let _projects = bm::git::list_projects(&gh_org)
+        let _projects = bm::git::list_projects(&bm::git::GitHubHost::default(), &gh_org)
             .expect("list_gh_projects should succeed");
 
         // Idempotency
@@ -1252,7 +1252,7 @@ fn build_suite(gh_org: String, gh_token: String, config: &E2eConfig) -> GithubSu
                 let board_title = env.get_export("board_title")
                     .expect("board_title export should survive reset_home")
                     .to_string();
-                let projects = bm::git::list_projects(&gh_org)
+                let projects = bm::git::list_projects(&bm::git::GitHubHost::default(), &gh_org)
                     .expect("list_projects should succeed");
                 assert!(
                     projects.iter().any(|(_, t)| t == &board_title),
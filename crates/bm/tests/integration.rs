@@ -30,6 +30,8 @@ fn claude_code_agent() -> CodingAgentDef {
         binary: "claude".into(),
         system_prompt_flag: Some("--append-system-prompt-file".into()),
         skip_permissions_flag: Some("--dangerously-skip-permissions".into()),
+        mcp_config_file: Some(".mcp.json".into()),
+        mcp_config_flag: Some("--mcp-config".into()),
     }
 }
 
@@ -101,6 +103,8 @@ fn setup_team(tmp: &Path, team_name: &str, profile_name: &str) -> PathBuf {
             project_number: None,
             bridge_lifecycle: Default::default(),
             vm: None,
+            profile_source: None,
+            forge: None,
         }],
         vms: Vec::new(),
         keyring_collection: None,
@@ -149,6 +153,8 @@ fn add_team_to_config(
         project_number: None,
         bridge_lifecycle: Default::default(),
         vm: None,
+        profile_source: None,
+        forge: None,
     });
 
     if make_default {
@@ -536,6 +542,95 @@ fn sync_continues_past_failed_member() {
     );
 }
 
+// ── Apply tests ──────────────────────────────────────────────────────
+
+/// Writes a team spec for `test-team` with the given project URL and members.
+fn write_spec(tmp: &Path, fork_url: &str, members: &[(&str, &str)]) -> PathBuf {
+    let mut spec = format!(
+        "name: test-team\nprofile: scrum\nprojects:\n  - url: {}\nmembers:\n",
+        fork_url
+    );
+    for (role, name) in members {
+        spec.push_str(&format!("  - role: {}\n    name: {}\n", role, name));
+    }
+    let path = tmp.join("team.yml");
+    fs::write(&path, spec).unwrap();
+    path
+}
+
+#[test]
+fn apply_dry_run_makes_no_changes() {
+    let tmp = tempfile::tempdir().unwrap();
+    let team_repo = setup_team(tmp.path(), "test-team", "scrum");
+    let fork_url = create_fake_fork(tmp.path(), "my-repo");
+    let spec = write_spec(tmp.path(), &fork_url, &[("architect", "alice")]);
+
+    let output = bm_run(tmp.path(), &["apply", "-f", spec.to_str().unwrap(), "--dry-run"]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("+ hire architect-alice"), "stdout: {}", stdout);
+    assert!(stdout.contains("+ add project my-repo"), "stdout: {}", stdout);
+
+    assert!(!team_repo.join("members/architect-alice").exists());
+    assert!(!team_repo.join("projects/my-repo").exists());
+}
+
+#[test]
+fn apply_reconciles_and_is_idempotent() {
+    let tmp = tempfile::tempdir().unwrap();
+    let team_repo = setup_team(tmp.path(), "test-team", "scrum");
+    let fork_url = create_fake_fork(tmp.path(), "my-repo");
+    let spec = write_spec(
+        tmp.path(),
+        &fork_url,
+        &[("architect", "alice"), ("human-assistant", "bob")],
+    );
+    let spec = spec.to_str().unwrap();
+
+    bm_run(tmp.path(), &["apply", "-f", spec]);
+    assert!(team_repo.join("members/architect-alice").is_dir());
+    assert!(team_repo.join("members/human-assistant-bob").is_dir());
+    assert!(team_repo.join("projects/my-repo").is_dir());
+
+    let output = bm_run(tmp.path(), &["apply", "-f", spec]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Nothing to do"), "stdout: {}", stdout);
+}
+
+#[test]
+fn apply_prune_fires_undeclared_members() {
+    let tmp = tempfile::tempdir().unwrap();
+    let team_repo = setup_team(tmp.path(), "test-team", "scrum");
+    let fork_url = create_fake_fork(tmp.path(), "my-repo");
+    bm_hire(tmp.path(), "architect", "alice", "test-team");
+    bm_hire(tmp.path(), "architect", "carol", "test-team");
+    let spec = write_spec(tmp.path(), &fork_url, &[("architect", "alice")]);
+    let spec = spec.to_str().unwrap();
+
+    // Without --prune, extra members are left alone
+    bm_run(tmp.path(), &["apply", "-f", spec]);
+    assert!(team_repo.join("members/architect-carol").is_dir());
+
+    // --prune without --yes refuses in a non-TTY
+    let stderr = bm_run_fail(tmp.path(), &["apply", "-f", spec, "--prune"]);
+    assert!(stderr.contains("--yes"), "stderr: {}", stderr);
+    assert!(team_repo.join("members/architect-carol").is_dir());
+
+    bm_run(tmp.path(), &["apply", "-f", spec, "--prune", "--yes"]);
+    assert!(team_repo.join("members/architect-alice").is_dir());
+    assert!(!team_repo.join("members/architect-carol").exists());
+}
+
+#[test]
+fn apply_unknown_role_errors() {
+    let tmp = tempfile::tempdir().unwrap();
+    setup_team(tmp.path(), "test-team", "scrum");
+    let fork_url = create_fake_fork(tmp.path(), "my-repo");
+    let spec = write_spec(tmp.path(), &fork_url, &[("nonexistent", "x")]);
+
+    let stderr = bm_run_fail(tmp.path(), &["apply", "-f", spec.to_str().unwrap()]);
+    assert!(stderr.contains("not available in profile"), "stderr: {}", stderr);
+}
+
 // ── Schema version guard ─────────────────────────────────────────────
 
 #[test]
@@ -2899,6 +2994,8 @@ fn setup_bridge_test() -> (tempfile::TempDir, String, PathBuf, PathBuf) {
             project_number: None,
             bridge_lifecycle: Default::default(),
             vm: None,
+            profile_source: None,
+            forge: None,
         }],
         vms: Vec::new(),
         keyring_collection: None,
@@ -2939,6 +3036,8 @@ fn setup_no_bridge_test() -> (tempfile::TempDir, String) {
             project_number: None,
             bridge_lifecycle: Default::default(),
             vm: None,
+            profile_source: None,
+            forge: None,
         }],
         vms: Vec::new(),
         keyring_collection: None,
@@ -3017,6 +3116,8 @@ remove username:
             project_number: None,
             bridge_lifecycle: Default::default(),
             vm: None,
+            profile_source: None,
+            forge: None,
         }],
         vms: Vec::new(),
         keyring_collection: None,
@@ -3666,6 +3767,8 @@ fn setup_fixture_team(tmp: &Path, team_name: &str) -> PathBuf {
             project_number: None,
             bridge_lifecycle: Default::default(),
             vm: None,
+            profile_source: None,
+            forge: None,
         }],
         vms: Vec::new(),
         keyring_collection: None,